@@ -8,49 +8,116 @@ pub trait Generator {
     fn jmp(&self, no: usize) -> String;
     fn je(&self, no: usize) -> String;
     fn jne(&self, no: usize) -> String;
+    fn jo(&self, no: usize) -> String;
+    fn ja(&self, no: usize) -> String;
+    fn jae(&self, no: usize) -> String;
+    fn jcc(&self, cc: &str, no: usize) -> String;
+    fn jmp_table(&self, no: usize, base: &str, index: &str) -> String;
+    fn rodata_section(&self) -> String;
+    fn stack_note_section(&self) -> String;
+    fn comment(&self, s: &str) -> String;
+    fn jump_table(&self, no: usize, labels: &[usize]) -> String;
     fn cmpl(&self, f: usize, r: &str) -> String;
+    fn cmp_imm(&self, n: i64, r: &str) -> String;
+    fn cmp(&self, src: &str, dst: &str) -> String;
+    fn test(&self, r: &str) -> String;
     fn mul(&self, reg: &str) -> String;
+    fn imul(&self, reg: &str) -> String;
     fn multiple(&self) -> String;
     fn plus(&self) -> String;
     fn minus(&self) -> String;
     fn equal(&self) -> String;
     fn not_equal(&self) -> String;
+    fn equal_zero(&self) -> String;
+    fn not_equal_zero(&self) -> String;
     fn less_than(&self) -> String;
     fn less_than_equal(&self) -> String;
     fn greater_than(&self) -> String;
     fn greater_than_equal(&self) -> String;
+    fn below(&self) -> String;
+    fn below_equal(&self) -> String;
+    fn above(&self) -> String;
+    fn above_equal(&self) -> String;
+    fn float_equal(&self) -> String;
+    fn float_not_equal(&self) -> String;
+    fn float_less_than(&self) -> String;
+    fn float_less_than_equal(&self) -> String;
+    fn float_greater_than(&self) -> String;
+    fn float_greater_than_equal(&self) -> String;
     fn left_shift(&self) -> String;
     fn right_shift(&self) -> String;
+    fn left_shift_l(&self) -> String;
+    fn right_shift_l(&self) -> String;
+    fn logical_right_shift_l(&self) -> String;
+    fn division_q(&self) -> String;
+    fn division_l(&self) -> String;
+    fn unsigned_division_l(&self) -> String;
+    fn plus_q(&self) -> String;
+    fn minus_l(&self) -> String;
+    fn multiple_q(&self) -> String;
+    fn logical_right_shift_imm_l(&self, n: u32) -> String;
+    fn bit_and_imm_l(&self, n: i64) -> String;
+    fn addsd(&self, src: &str, dst: &str) -> String;
+    fn subsd(&self, src: &str, dst: &str) -> String;
+    fn mulsd(&self, src: &str, dst: &str) -> String;
+    fn divsd(&self, src: &str, dst: &str) -> String;
+    fn cvtsi2sd(&self, src: &str, dst: &str) -> String;
+    fn cvttsd2si(&self, src: &str, dst: &str) -> String;
+    fn cvtss2sd(&self, src: &str, dst: &str) -> String;
+    fn cvtsd2ss(&self, src: &str, dst: &str) -> String;
+    fn cltq(&self) -> String;
     fn bit_and(&self) -> String;
     fn bit_or(&self) -> String;
     fn bit_xor(&self) -> String;
-    fn bit_division(&self) -> String;
     fn lea(&self, p: i64) -> String;
     fn lea_glb(&self, n: &str) -> String;
     fn not(&self, reg: &str) -> String;
     fn set(&self, reg: &str) -> String;
+    fn setne(&self, reg: &str) -> String;
+    fn rep_movsb(&self) -> String;
     fn neg(&self, reg: &str) -> String;
     fn add(&self, src: &str, dst: &str) -> String;
-    fn add_src(&self, src: &str, dst: &str, n: i64) -> String;
     fn add_imm(&self, i: usize, reg: &str) -> String;
+    fn and(&self, src: &str, dst: &str) -> String;
+    fn or(&self, src: &str, dst: &str) -> String;
+    fn shl_imm(&self, n: u32, reg: &str) -> String;
+    fn shr_imm(&self, n: u32, reg: &str) -> String;
+    fn sar_imm(&self, n: u32, reg: &str) -> String;
     fn sub(&self, src: &str, dst: &str) -> String;
     fn sub_imm(&self, i: usize, reg: &str) -> String;
     fn ret(&self) -> String;
     fn mov(&self, src: &str, dst: &str) -> String;
     fn movq(&self, src: &str, dst: &str) -> String;
+    fn movd(&self, src: &str, dst: &str) -> String;
     fn mov_src(&self, src: &str, dst: &str, n: i64) -> String;
     fn movl_src(&self, src: &str, dst: &str, n: i64) -> String;
     fn movq_src(&self, src: &str, dst: &str, n: i64) -> String;
     fn mov_dst(&self, src: &str, dst: &str, n: i64) -> String;
     fn mov_imm(&self, dst: &str, n: i64) -> String;
+    #[allow(dead_code)]
     fn mov_imm_dst(&self, dst: &str, n: i64, offset: i64) -> String;
     fn movz(&self, src: &str, dst: &str) -> String;
+    #[allow(dead_code)]
     fn movb_src(&self, src: &str, dst: &str, n: i64) -> String;
     fn movb_dst(&self, src: &str, dst: &str, n: i64) -> String;
+    #[allow(dead_code)]
     fn movsbl_src(&self, src: &str, dst: &str, n: i64) -> String;
+    fn movsbq_src(&self, src: &str, dst: &str, n: i64) -> String;
+    fn movsbq(&self, src: &str, dst: &str) -> String;
+    fn movw_dst(&self, src: &str, dst: &str, n: i64) -> String;
+    fn movswq_src(&self, src: &str, dst: &str, n: i64) -> String;
+    fn movswq(&self, src: &str, dst: &str) -> String;
+    fn movzbq_src(&self, src: &str, dst: &str, n: i64) -> String;
+    fn movzbq(&self, src: &str, dst: &str) -> String;
+    fn movslq(&self, src: &str, dst: &str) -> String;
+    fn movslq_src(&self, src: &str, dst: &str, n: i64) -> String;
+    #[allow(dead_code)]
     fn mov_from_glb(&self, dst: &str, name: &str) -> String;
+    #[allow(dead_code)]
     fn movb_from_glb(&self, dst: &str, name: &str) -> String;
+    #[allow(dead_code)]
     fn mov_to_glb(&self, src: &str, name: &str) -> String;
+    #[allow(dead_code)]
     fn movb_to_glb(&self, src: &str, name: &str) -> String;
     fn call(&self, a: &str) -> String;
     fn leave(&self) -> String;
@@ -18,15 +18,58 @@ impl Generator for X64 {
     fn je(&self, no: usize) -> String {
         format!("  je .L{}\n", no)
     }
+    fn jo(&self, no: usize) -> String {
+        format!("  jo .L{}\n", no)
+    }
+    fn ja(&self, no: usize) -> String {
+        format!("  ja .L{}\n", no)
+    }
+    fn jae(&self, no: usize) -> String {
+        format!("  jae .L{}\n", no)
+    }
+    fn jcc(&self, cc: &str, no: usize) -> String {
+        format!("  j{} .L{}\n", cc, no)
+    }
+    fn jmp_table(&self, no: usize, base: &str, index: &str) -> String {
+        format!("  lea .L{}(%rip), %{}\n  jmp *(%{},%{},8)\n", no, base, base, index)
+    }
+    // 文字列リテラルを配置するセクション
+    fn rodata_section(&self) -> String {
+        "  .section .rodata\n".to_string()
+    }
+    // スタックを実行不可とする注記セクション
+    fn stack_note_section(&self) -> String {
+        "  .section .note.GNU-stack,\"\",@progbits\n".to_string()
+    }
+    fn comment(&self, s: &str) -> String {
+        format!("  # {}\n", s)
+    }
+    fn jump_table(&self, no: usize, labels: &[usize]) -> String {
+        let table = labels.iter().map(|l| format!("  .quad .L{}\n", l)).collect::<String>();
+        format!("  .section .rodata\n  .align 8\n.L{}:\n{}", no, table)
+    }
     fn jne(&self, no: usize) -> String {
         format!("  jne .L{}\n", no)
     }
     fn cmpl(&self, f: usize, r: &str) -> String {
         format!("  cmp ${}, %{}\n", f, r)
     }
+    fn cmp_imm(&self, n: i64, r: &str) -> String {
+        format!("  cmp ${}, %{}\n", n, r)
+    }
+    fn cmp(&self, src: &str, dst: &str) -> String {
+        format!("  cmp %{}, %{}\n", src, dst)
+    }
+    // 0との比較(cmp $0より命令長が短い)
+    fn test(&self, r: &str) -> String {
+        format!("  test %{}, %{}\n", r, r)
+    }
     fn mul(&self, reg: &str) -> String {
         format!("  mul %{}\n", reg)
     }
+    fn imul(&self, reg: &str) -> String {
+        format!("  imul %{}\n", reg)
+    }
     fn multiple(&self) -> String {
         "  imul %ecx\n".to_string()
     }
@@ -42,6 +85,12 @@ impl Generator for X64 {
     fn not_equal(&self) -> String {
         "  cmp %rcx, %rax\n  setne %al\n  movzb %al, %rax\n".to_string()
     }
+    fn equal_zero(&self) -> String {
+        "  test %rax, %rax\n  sete %al\n  movzb %al, %rax\n".to_string()
+    }
+    fn not_equal_zero(&self) -> String {
+        "  test %rax, %rax\n  setne %al\n  movzb %al, %rax\n".to_string()
+    }
     fn less_than(&self) -> String {
         "  cmp %rcx, %rax\n  setl %al\n  movzb %al, %rax\n".to_string()
     }
@@ -54,12 +103,105 @@ impl Generator for X64 {
     fn greater_than_equal(&self) -> String {
         "  cmp %rcx, %rax\n  setge %al\n  movzb %al, %rax\n".to_string()
     }
+    fn below(&self) -> String {
+        "  cmp %rcx, %rax\n  setb %al\n  movzb %al, %rax\n".to_string()
+    }
+    fn below_equal(&self) -> String {
+        "  cmp %rcx, %rax\n  setbe %al\n  movzb %al, %rax\n".to_string()
+    }
+    fn above(&self) -> String {
+        "  cmp %rcx, %rax\n  seta %al\n  movzb %al, %rax\n".to_string()
+    }
+    fn above_equal(&self) -> String {
+        "  cmp %rcx, %rax\n  setae %al\n  movzb %al, %rax\n".to_string()
+    }
+    fn float_equal(&self) -> String {
+        "  ucomisd %xmm1, %xmm0\n  sete %al\n  setnp %cl\n  and %cl, %al\n  movzb %al, %rax\n".to_string()
+    }
+    fn float_not_equal(&self) -> String {
+        "  ucomisd %xmm1, %xmm0\n  setne %al\n  setp %cl\n  or %cl, %al\n  movzb %al, %rax\n".to_string()
+    }
+    fn float_less_than(&self) -> String {
+        "  ucomisd %xmm0, %xmm1\n  seta %al\n  movzb %al, %rax\n".to_string()
+    }
+    fn float_less_than_equal(&self) -> String {
+        "  ucomisd %xmm0, %xmm1\n  setae %al\n  movzb %al, %rax\n".to_string()
+    }
+    fn float_greater_than(&self) -> String {
+        "  ucomisd %xmm1, %xmm0\n  seta %al\n  movzb %al, %rax\n".to_string()
+    }
+    fn float_greater_than_equal(&self) -> String {
+        "  ucomisd %xmm1, %xmm0\n  setae %al\n  movzb %al, %rax\n".to_string()
+    }
     fn left_shift(&self) -> String {
         "  sal %cl, %rax\n".to_string()
     }
     fn right_shift(&self) -> String {
         "  sar %cl, %rax\n".to_string()
     }
+    // 32bitのシフト(シフト数はCPUにより下位5bitでマスクされる)
+    fn left_shift_l(&self) -> String {
+        "  sall %cl, %eax\n".to_string()
+    }
+    fn right_shift_l(&self) -> String {
+        "  sarl %cl, %eax\n".to_string()
+    }
+    fn logical_right_shift_l(&self) -> String {
+        "  shrl %cl, %eax\n".to_string()
+    }
+    // 64bitの符号付き除算(rax / rcx)
+    fn division_q(&self) -> String {
+        "  cqo\n  idiv %rcx\n".to_string()
+    }
+    fn division_l(&self) -> String {
+        "  cltd\n  idivl %ecx\n".to_string()
+    }
+    fn unsigned_division_l(&self) -> String {
+        "  mov $0, %edx\n  divl %ecx\n".to_string()
+    }
+    fn plus_q(&self) -> String {
+        "  add %rcx, %rax\n".to_string()
+    }
+    fn minus_l(&self) -> String {
+        "  sub %ecx, %eax\n".to_string()
+    }
+    fn multiple_q(&self) -> String {
+        "  imul %rcx, %rax\n".to_string()
+    }
+    fn logical_right_shift_imm_l(&self, n: u32) -> String {
+        format!("  shrl ${}, %eax\n", n)
+    }
+    fn bit_and_imm_l(&self, n: i64) -> String {
+        format!("  andl ${}, %eax\n", n)
+    }
+    fn addsd(&self, src: &str, dst: &str) -> String {
+        format!("  addsd %{}, %{}\n", src, dst)
+    }
+    fn subsd(&self, src: &str, dst: &str) -> String {
+        format!("  subsd %{}, %{}\n", src, dst)
+    }
+    fn mulsd(&self, src: &str, dst: &str) -> String {
+        format!("  mulsd %{}, %{}\n", src, dst)
+    }
+    fn divsd(&self, src: &str, dst: &str) -> String {
+        format!("  divsd %{}, %{}\n", src, dst)
+    }
+    fn cvtsi2sd(&self, src: &str, dst: &str) -> String {
+        format!("  cvtsi2sd %{}, %{}\n", src, dst)
+    }
+    fn cvttsd2si(&self, src: &str, dst: &str) -> String {
+        format!("  cvttsd2si %{}, %{}\n", src, dst)
+    }
+    fn cvtss2sd(&self, src: &str, dst: &str) -> String {
+        format!("  cvtss2sd %{}, %{}\n", src, dst)
+    }
+    fn cvtsd2ss(&self, src: &str, dst: &str) -> String {
+        format!("  cvtsd2ss %{}, %{}\n", src, dst)
+    }
+    // %eaxを%raxへ符号拡張
+    fn cltq(&self) -> String {
+        "  cltq\n".to_string()
+    }
     fn bit_and(&self) -> String {
         "  and %rcx, %rax\n".to_string()
     }
@@ -69,9 +211,6 @@ impl Generator for X64 {
     fn bit_xor(&self) -> String {
         "  xor %rcx, %rax\n".to_string()
     }
-    fn bit_division(&self) -> String {
-        "  mov $0, %rdx\n  idiv %rcx\n".to_string()
-    }
     fn lea(&self, p: i64) -> String {
         format!("  lea -{}(%rbp), %rax\n", p)
     }
@@ -84,6 +223,12 @@ impl Generator for X64 {
     fn set(&self, reg: &str) -> String {
         format!("  sete %{}\n", reg)
     }
+    fn setne(&self, reg: &str) -> String {
+        format!("  setne %{}\n", reg)
+    }
+    fn rep_movsb(&self) -> String {
+        "  rep movsb\n".to_string()
+    }
     fn neg(&self, reg: &str) -> String {
         format!("  neg %{}\n", reg)
     }
@@ -96,12 +241,24 @@ impl Generator for X64 {
     fn add(&self, src: &str, dst: &str) -> String {
         format!("  add %{}, %{}\n", src, dst)
     }
-    fn add_src(&self, src: &str, dst: &str, n: i64) -> String {
-        format!("  add {}(%{}), %{}\n", n, src, dst)
-    }
     fn add_imm(&self, i: usize, reg: &str) -> String {
         format!("  add ${}, %{}\n", i, reg)
     }
+    fn and(&self, src: &str, dst: &str) -> String {
+        format!("  and %{}, %{}\n", src, dst)
+    }
+    fn or(&self, src: &str, dst: &str) -> String {
+        format!("  or %{}, %{}\n", src, dst)
+    }
+    fn shl_imm(&self, n: u32, reg: &str) -> String {
+        format!("  shl ${}, %{}\n", n, reg)
+    }
+    fn shr_imm(&self, n: u32, reg: &str) -> String {
+        format!("  shr ${}, %{}\n", n, reg)
+    }
+    fn sar_imm(&self, n: u32, reg: &str) -> String {
+        format!("  sar ${}, %{}\n", n, reg)
+    }
     fn ret(&self) -> String {
         "  ret\n".to_string()
     }
@@ -111,6 +268,9 @@ impl Generator for X64 {
     fn movq(&self, src: &str, dst: &str) -> String {
         format!("  movq %{}, %{}\n", src, dst)
     }
+    fn movd(&self, src: &str, dst: &str) -> String {
+        format!("  movd %{}, %{}\n", src, dst)
+    }
     fn mov_src(&self, src: &str, dst: &str, n: i64) -> String {
         format!("  mov {}(%{}), %{}\n", n, src, dst)
     }
@@ -143,6 +303,40 @@ impl Generator for X64 {
     fn movsbl_src(&self, src: &str, dst: &str, n: i64) -> String {
         format!("  movsbl {}(%{}), %{}\n", n, src, dst)
     }
+    // n(%src)から%dstへ符号拡張して転送
+    fn movsbq_src(&self, src: &str, dst: &str, n: i64) -> String {
+        format!("  movsbq {}(%{}), %{}\n", n, src, dst)
+    }
+    // %srcの下位8bitを%dstへ符号拡張して転送
+    fn movsbq(&self, src: &str, dst: &str) -> String {
+        format!("  movsbq %{}, %{}\n", src, dst)
+    }
+    // %srcの下位16bitをn(%dst)へ転送
+    fn movw_dst(&self, src: &str, dst: &str, n: i64) -> String {
+        format!("  movw %{}, {}(%{})\n", src, n, dst)
+    }
+    // n(%src)から%dstへ16bitを符号拡張して転送
+    fn movswq_src(&self, src: &str, dst: &str, n: i64) -> String {
+        format!("  movswq {}(%{}), %{}\n", n, src, dst)
+    }
+    // %srcの下位16bitを%dstへ符号拡張して転送
+    fn movswq(&self, src: &str, dst: &str) -> String {
+        format!("  movswq %{}, %{}\n", src, dst)
+    }
+    // n(%src)から%dstへゼロ拡張して転送
+    fn movzbq_src(&self, src: &str, dst: &str, n: i64) -> String {
+        format!("  movzbq {}(%{}), %{}\n", n, src, dst)
+    }
+    // %srcの下位8bitを%dstへゼロ拡張して転送
+    fn movzbq(&self, src: &str, dst: &str) -> String {
+        format!("  movzbq %{}, %{}\n", src, dst)
+    }
+    fn movslq(&self, src: &str, dst: &str) -> String {
+        format!("  movslq %{}, %{}\n", src, dst)
+    }
+    fn movslq_src(&self, src: &str, dst: &str, n: i64) -> String {
+        format!("  movslq {}(%{}), %{}\n", n, src, dst)
+    }
     // global変数からの代入
     fn mov_from_glb(&self, dst: &str, name: &str) -> String {
         format!("  mov {}(%rip), %{}\n", name, dst)
@@ -2,7 +2,8 @@ use arch::Generator;
 use arch::{x64::X64};
 use arch::{x64_mac::X64Mac};
 use ast::{AstTree, AstType};
-use config::Config;
+use codegen::CodeGen;
+use config::{Config, OptLevel};
 use std::process;
 use symbol::{Scope, Structure, Symbol, SymbolTable, Type};
 
@@ -11,6 +12,8 @@ struct Label {
     label_no: usize,
     continue_labels: Vec<usize>,
     break_labels: Vec<usize>,
+    case_labels: Vec<Vec<(Option<i64>, usize)>>, // switchごとのcase値(defaultはNone)とラベル
+    goto_labels: Vec<(String, usize)>,            // 関数内のラベル名とラベル
     return_label: usize,
 }
 
@@ -21,11 +24,15 @@ impl Label {
             label_no: 0,
             continue_labels: vec![],
             break_labels: vec![],
+            case_labels: vec![],
+            goto_labels: vec![],
             return_label: 0,
         }
     }
 
     // ラベル番号インクリメント.
+    //
+    // 関数ごとにリセットしないため、ラベル名は出力全体で一意となる
     pub fn next_label(&mut self) -> usize {
         self.label_no += 1;
         self.label_no
@@ -44,9 +51,11 @@ impl Label {
     pub fn push_continue(&mut self, no: usize) {
         self.continue_labels.push(no);
     }
-    // continueラベルpop.
-    pub fn pop_continue(&mut self) -> Option<usize> {
-        self.continue_labels.pop()
+    // continueラベル取得(最も内側のループ).
+    //
+    // switchはcontinueラベルを積まないため、switch内のcontinueは外側のループが対象となる
+    pub fn get_continue(&self) -> Option<usize> {
+        self.continue_labels.last().cloned()
     }
     // continueラベル削除.
     pub fn remove_continue(&mut self, no: usize) {
@@ -61,9 +70,9 @@ impl Label {
     pub fn push_break(&mut self, no: usize) {
         self.break_labels.push(no);
     }
-    // breakラベルpop.
-    pub fn pop_break(&mut self) -> Option<usize> {
-        self.break_labels.pop()
+    // breakラベル取得(levelは内側からのネスト数).
+    pub fn get_break(&self, level: usize) -> Option<usize> {
+        self.break_labels.len().checked_sub(level).map(|i| self.break_labels[i])
     }
     // breakラベル削除.
     pub fn remove_break(&mut self, no: usize) {
@@ -74,11 +83,44 @@ impl Label {
             .filter(|d| *d != no)
             .collect();
     }
+    // caseラベル追加.
+    pub fn push_cases(&mut self, cases: Vec<(Option<i64>, usize)>) {
+        self.case_labels.push(cases);
+    }
+    // caseラベル取得(最も内側のswitchから検索).
+    pub fn get_case(&self, value: Option<i64>) -> Option<usize> {
+        self.case_labels
+            .last()
+            .and_then(|c| c.iter().find(|(v, _)| *v == value))
+            .map(|(_, no)| *no)
+    }
+    // caseラベル削除.
+    pub fn pop_cases(&mut self) {
+        self.case_labels.pop();
+    }
+    // 関数内のラベル登録(関数ごとに登録しなおす).
+    pub fn regist_gotos(&mut self, names: &[&str]) {
+        let labels = names.iter().map(|n| (n.to_string(), self.next_label())).collect();
+        self.goto_labels = labels;
+    }
+    // gotoラベル取得.
+    pub fn get_goto(&self, name: &str) -> Option<usize> {
+        self.goto_labels.iter().find(|(n, _)| n == name).map(|(_, no)| *no)
+    }
 }
 
 // 関数引数レジスタ.
 const REGS: &[&str] = &["rdi", "rsi", "rdx", "rcx", "r8", "r9"];
 
+// 変数を割り当てるレジスタ(callee-savedレジスタと、その下位32bit).
+const VAR_REGS: &[(&str, &str)] = &[("rbx", "ebx"), ("r12", "r12d"), ("r13", "r13d"), ("r14", "r14d"), ("r15", "r15d")];
+
+// ジャンプテーブルを使用するcase数の下限.
+const JUMP_TABLE_MIN_CASES: usize = 4;
+
+// ジャンプテーブルのエントリ数の上限(case数に対する倍率).
+const JUMP_TABLE_DENSITY: usize = 2;
+
 #[doc = "アセンブラ生成部"]
 pub struct Asm<'a> {
     inst: String,
@@ -86,6 +128,32 @@ pub struct Asm<'a> {
     sym_table: &'a SymbolTable,
     cur_scope: Scope,
     label: Label,
+    config: Config,
+    trap_label: Option<usize>, // オーバーフロー、範囲外アクセス時のabort呼び出しラベル
+    tail_label: Option<usize>, // 自己末尾呼び出しのジャンプ先（引数の格納処理）ラベル
+    reg_vars: Vec<String>,     // レジスタへ割り当てた変数(VAR_REGSの順)
+}
+
+impl<'a> CodeGen for Asm<'a> {
+    // アセンブラ生成開始.
+    fn exec(&mut self, tree: &AstTree) {
+        tree.get_tree().iter().for_each(|a| self.generate(a));
+
+        // オーバーフロー、範囲検査を行った場合、abort呼び出しを生成
+        if let Some(no) = self.trap_label {
+            self.generate_label_inst(no);
+            self.inst = format!("{}{}", self.inst, self.gen_asm().call(&self.generate_func_symbol("abort")));
+        }
+
+        // スタックを実行不可とする(リンカの警告を抑止)
+        self.inst = format!("{}{}", self.inst, self.gen_asm().stack_note_section());
+    }
+
+    // アセンブラ取得
+    fn get_inst(&self) -> String {
+        // 定数領域と結合
+        format!("{}{}", self.const_literal, self.inst)
+    }
 }
 
 impl<'a> Asm<'a> {
@@ -97,9 +165,18 @@ impl<'a> Asm<'a> {
             label: Label::new(),
             sym_table: table,
             cur_scope: Scope::Unknown,
+            config: Config::default(),
+            trap_label: None,
+            tail_label: None,
+            reg_vars: vec![],
         }
     }
 
+    // 設定データ登録
+    pub fn set_config(&mut self, c: &Config) {
+        self.config = c.clone();
+    }
+
     // アセンブラ生成部取得
     fn gen_asm(&self) -> Box<dyn Generator> {
         if Config::is_mac() {
@@ -109,15 +186,23 @@ impl<'a> Asm<'a> {
         }
     }
 
-    // アセンブラ取得
-    pub fn get_inst(&self) -> String {
-        // 定数領域と結合
-        format!("{}{}", self.const_literal, self.inst)
-    }
-
-    // アセンブラ生成開始.
-    pub fn exec(&mut self, tree: &AstTree) {
-        tree.get_tree().iter().for_each(|a| self.generate(a));
+    // 構文木のノード種別をコメント出力(関数、変数は名前を付加する)
+    fn generate_ast_comment(&mut self, ast: &AstType) {
+        let debug = format!("{:?}", ast);
+        let kind = debug.split('(').next().unwrap_or_default();
+        let name = match *ast {
+            AstType::FuncDef(_, _, ref n, _, _) | AstType::Variable(_, _, ref n) => Some(n),
+            AstType::FuncCall(ref f, _) => match **f {
+                AstType::Variable(_, _, ref n) => Some(n),
+                _ => None,
+            },
+            _ => None,
+        };
+        let comment = match name {
+            Some(n) => format!("{} {}", kind, n),
+            None => kind.to_string(),
+        };
+        self.inst = format!("{}{}", self.inst, self.gen_asm().comment(&comment));
     }
 
     // 現在スコープ切り替え
@@ -127,6 +212,9 @@ impl<'a> Asm<'a> {
 
     // アセンブラ生成.
     fn generate(&mut self, ast: &AstType) {
+        if self.config.annotate_ast {
+            self.generate_ast_comment(ast);
+        }
         match *ast {
             AstType::Global(ref a) => {
                 self.switch_scope(Scope::Global);
@@ -136,6 +224,7 @@ impl<'a> Asm<'a> {
                 self.switch_scope(Scope::Local(a.clone()));
                 self.generate_funcdef(t, a, b, c);
             }
+            AstType::FuncDecl(_, _, _, _) => {} // 関数宣言は、リンク時に解決する
             AstType::FuncCall(ref a, ref b) => self.generate_call_func(a, b),
             AstType::Statement(_) => self.generate_statement(ast),
             AstType::While(ref a, ref b) => self.generate_statement_while(a, b),
@@ -143,10 +232,16 @@ impl<'a> Asm<'a> {
             AstType::If(ref a, ref b, ref c) => self.generate_statement_if(a, b, c),
             AstType::For(ref a, ref b, ref c, ref d) => self.generate_statement_for(a, b, c, d),
             AstType::Continue() => self.generate_statement_continue(),
-            AstType::Break() => self.generate_statement_break(),
+            AstType::Switch(ref a, ref b) => self.generate_statement_switch(a, b),
+            AstType::Case(n) => self.generate_case_label(Some(n)),
+            AstType::Default() => self.generate_case_label(None),
+            AstType::Goto(ref n) => self.generate_statement_goto(n),
+            AstType::Label(ref n) => self.generate_goto_label(n),
+            AstType::Break(a) => self.generate_statement_break(a),
             AstType::Return(ref a) => self.generate_statement_return(a),
             AstType::SizeOf(a) => self.generate_sizeof(a),
             AstType::Factor(a) => self.generate_factor(a),
+            AstType::FloatFactor(a) => self.generate_float_factor(a),
             AstType::LogicalAnd(ref a, ref b) => self.generate_logical_and(a, b),
             AstType::LogicalOr(ref a, ref b) => self.generate_logical_or(a, b),
             AstType::Condition(ref a, ref b, ref c) => self.generate_condition(a, b, c),
@@ -155,6 +250,17 @@ impl<'a> Asm<'a> {
             AstType::Not(ref a) => self.generate_not(a),
             AstType::BitReverse(ref a) => self.generate_bit_reverse(a),
             AstType::Assign(ref a, ref b) => self.generate_assign(a, b),
+            AstType::PlusAssign(ref a, ref b)
+            | AstType::MinusAssign(ref a, ref b)
+            | AstType::MultipleAssign(ref a, ref b)
+            | AstType::DivisionAssign(ref a, ref b) if Asm::is_float_operands(a, b) => {
+                self.generate_float_compound_assign(ast, a, b)
+            }
+            AstType::PlusAssign(ref a, _)
+            | AstType::MinusAssign(ref a, _)
+            | AstType::MultipleAssign(ref a, _)
+            | AstType::DivisionAssign(ref a, _)
+            | AstType::RemainderAssign(ref a, _) if self.reg_var(a).is_some() => self.generate_reg_compound_assign(ast),
             AstType::PlusAssign(ref a, ref b) => self.generate_plus_assign(a, b),
             AstType::MinusAssign(ref a, ref b) => self.generate_minus_assign(a, b),
             AstType::MultipleAssign(ref a, ref b) => self.generate_multiple_assign(a, b),
@@ -165,8 +271,14 @@ impl<'a> Asm<'a> {
             AstType::PreDec(ref a) => self.generate_pre_dec(a),
             AstType::PostInc(ref a) => self.generate_post_inc(a),
             AstType::PostDec(ref a) => self.generate_post_dec(a),
-            AstType::Plus(ref a, ref b) => self.generate_plus(a, b),
-            AstType::Minus(ref a, ref b) => self.generate_minus(a, b),
+            AstType::Plus(ref a, ref b)
+            | AstType::Minus(ref a, ref b)
+            | AstType::Multiple(ref a, ref b)
+            | AstType::Division(ref a, ref b) if Asm::is_float_operands(a, b) => {
+                self.generate_float_operator(ast, a, b)
+            }
+            AstType::Plus(ref a, ref b) => self.generate_plus(ast, a, b),
+            AstType::Minus(ref a, ref b) => self.generate_minus(ast, a, b),
             AstType::Multiple(ref a, ref b)
             | AstType::Division(ref a, ref b)
             | AstType::Remainder(ref a, ref b)
@@ -187,39 +299,107 @@ impl<'a> Asm<'a> {
                 self.generate_string_literal(&AstType::StringLiteral(s.to_string(), *i));
                 self.generate_string(s, *i);
             }
+            AstType::CompoundLiteral(ref a, ref b) | AstType::ArrayInit(ref a, ref b) => {
+                self.generate_compound_literal(a, b)
+            }
+            AstType::MemberAccess(ref a, ref b) => self.generate_member(a, b),
+            // voidキャストは評価のみ行い、値は文の終端で破棄する
+            AstType::Cast(_, ref a) => self.generate(a),
+            AstType::StmtExpr(ref a) => self.generate_statement_expression(a),
             AstType::Struct(ref _a, ref _b) => {}, // 構造体定義のみなので、現状は何もしない
+            AstType::Void() => {}, // 値なしのreturnは、エピローグへのジャンプのみ
             _ => panic!("{} {}: not support expression {:?}", file!(), line!(), ast),
         }
     }
 
     // グローバル変数代入
     fn generate_global_assign(&mut self, a: &AstType, b: &AstType) {
-        // 左辺が変数、右辺は数字をサポート
-        match b {
-            AstType::Factor(i) => match a {
+        // 文字列リテラルで初期化するポインタは、文字列のラベルを指す
+        if let (AstType::Variable(_, Structure::Pointer, ref name), AstType::StringLiteral(_, i)) = (a, b) {
+            self.generate_string_literal(b);
+            self.generate_global_label(name);
+            self.inst = format!("{}  .quad .LC{}\n", self.inst, i);
+            return;
+        }
+
+        // 浮動小数点数は、定数のビット列で初期化する(floatは単精度へ丸める)
+        if let AstType::Variable(ref t, Structure::Identifier, ref name) = a {
+            if t.is_float() {
+                let f = match b {
+                    AstType::FloatFactor(f) => Some(*f),
+                    AstType::UnMinus(ref f) => match **f {
+                        AstType::FloatFactor(f) => Some(-f),
+                        _ => f.eval_constant().map(|i| -i as f64),
+                    },
+                    _ => b.eval_constant().map(|i| i as f64),
+                };
+                let f = f.unwrap_or_else(|| panic!("{}{}: cannot support AstType {:?}", file!(), line!(), b));
+                let data = match t {
+                    Type::Float => format!(".long {}", (f as f32).to_bits()),
+                    _ => format!(".quad {}", f.to_bits() as i64),
+                };
+                self.generate_global_label(name);
+                self.inst = format!("{}  {}\n", self.inst, data);
+                return;
+            }
+        }
+
+        // 左辺が変数、右辺は定数式をサポート
+        match b.eval_constant() {
+            Some(i) => match a {
                 AstType::Variable(ref t, _, ref name) => {
-                    self.inst = format!("{}{}:\n", self.inst, name);
-                    self.inst = match t {
-                        Type::Int =>  format!("{}  .long {}\n", self.inst, i),
-                        Type::Char => format!("{}  .byte {}\n", self.inst, i),
+                    let directive = match self.get_var_symbol(name).size {
+                        1 => "byte",
+                        2 => "word",
+                        4 => "long",
+                        8 => "quad",
                         _ => panic!("{}{}: cannot support type {:?}", file!(), line!(), t)
-                    }
+                    };
+                    self.generate_global_label(name);
+                    self.inst = format!("{}  .{} {}\n", self.inst, directive, i);
                 }
-                _ => panic!("{}{}: cannot support AstType {:?}", file!(), line!(), b)
+                _ => panic!("{}{}: cannot support AstType {:?}", file!(), line!(), a)
             }
-            _ => panic!("{}{}: cannot support AstType {:?}", file!(), line!(), a)
+            _ => panic!("{}{}: cannot support AstType {:?}", file!(), line!(), b)
         }
     }
 
     // グローバル変数定義
+    //
+    // 初期値ありは.data、初期値なしは.bssへ、シンボルのサイズ分の領域を確保する
     fn generate_global(&mut self, a: &[AstType]) {
-        self.inst = format!("{}{}", self.inst, "  .data\n");
-        a.iter().for_each(|d| {
+        // 他の翻訳単位から参照可能とする
+        a.iter()
+            .map(|d| match d {
+                AstType::Assign(ref v, _) => &**v,
+                d => d,
+            })
+            .for_each(|d| {
+                if let AstType::Variable(_, _, ref name) = d {
+                    self.inst = format!("{}.global {}\n", self.inst, name);
+                }
+            });
+
+        let (data, bss): (Vec<&AstType>, Vec<&AstType>) =
+            a.iter().partition(|d| matches!(d, AstType::Assign(_, _)));
+
+        if !data.is_empty() {
+            self.inst = format!("{}{}", self.inst, "  .data\n");
+            data.iter().for_each(|d| {
+                if let AstType::Assign(ref a, ref b) = d {
+                    self.generate_global_assign(a, b);
+                }
+            });
+        }
+        if bss.iter().any(|d| matches!(d, AstType::Variable(_, _, _))) {
+            self.inst = format!("{}{}", self.inst, "  .bss\n");
+        }
+        bss.iter().for_each(|d| {
             match d {
-                AstType::Assign(ref a, ref b) => self.generate_global_assign(a, b),
                 AstType::Variable(_, _, ref name) => {
-                    self.inst = format!("{}{}:\n", self.inst, name);
-                    self.inst = format!("{}  .zero 8\n", self.inst);
+                    let size = self.get_var_symbol(name).size;
+                    self.generate_global_label(name);
+                    self.inst = format!("{}  .zero {}\n", self.inst, size);
                 }
                 AstType::Struct(_, _) => {}, // 構造体定義のみなのでSKIP
                 _ => panic!("{}{}: cannot support AstType {:?}", file!(), line!(), d)
@@ -227,16 +407,117 @@ impl<'a> Asm<'a> {
         });
     }
 
+    // グローバル変数のラベル生成(サイズに応じてアライメントする)
+    fn generate_global_label(&mut self, name: &str) {
+        let align = self.get_var_symbol(name).size.clamp(1, 8).next_power_of_two();
+        self.inst = format!("{}  .balign {}\n{}:\n", self.inst, align, name);
+    }
+
     // 関数定義.
     fn generate_funcdef(&mut self, _t: &Type, a: &str, b: &AstType, c: &AstType) {
         // return文のラベルを生成.
         let return_label = self.label.next_return_label();
 
+        // gotoの前方参照を解決できるよう、関数内のラベルを先に登録
+        self.label.regist_gotos(&c.labels());
+
+        // O2ではローカル変数をレジスタへ割り当てる
+        self.reg_vars = if self.config.opt_level >= OptLevel::O2 { self.allocate_registers(b, c) } else { vec![] };
+
         self.generate_func_start(a);
+
+        // O2では自己末尾呼び出しを、引数の格納処理へのジャンプとする
+        // ローカル変数のアドレスを取得する関数は、アドレスが呼び出し先へ渡る可能性があり、フレームを再利用できないため対象外
+        self.tail_label = None;
+        if self.config.opt_level >= OptLevel::O2 && Asm::has_tail_call(a, c) && !Asm::has_local_address(c) {
+            let no = self.label.next_label();
+            self.generate_label_inst(no);
+            self.tail_label = Some(no);
+        }
         self.generate_func_args(b);
         self.generate_statement(c);
+
+        // mainの末尾に到達した場合は、return 0とする
+        if a == "main" && !c.always_returns() {
+            self.inst = format!("{}{}", self.inst, self.gen_asm().mov_imm("rax", 0));
+        }
         self.generate_label_inst(return_label);
         self.generate_func_end();
+        self.reg_vars = vec![];
+    }
+
+    // レジスタへ割り当てる変数の決定.
+    //
+    // アドレスを取らない、引数以外のint型のローカル変数を、出現回数の多い順にcallee-savedレジスタへ割り当てる
+    fn allocate_registers(&self, args: &AstType, body: &AstType) -> Vec<String> {
+        let mut uses: Vec<(String, usize)> = vec![];
+        let mut excluded: Vec<String> = args.children().iter().filter_map(|a| Asm::var_name(a)).collect();
+        Asm::collect_var_uses(body, &mut uses, &mut excluded);
+
+        let mut candidates: Vec<(String, usize)> = uses
+            .into_iter()
+            .filter(|(n, _)| !excluded.contains(n))
+            .filter(|(n, _)| {
+                self.sym_table.search(&self.cur_scope, n).is_some_and(|sym| {
+                    sym.strt == Structure::Identifier && matches!(sym.t, Type::Int | Type::UInt)
+                })
+            })
+            .collect();
+        candidates.sort_by_key(|(_, n)| std::cmp::Reverse(*n));
+        candidates.into_iter().take(VAR_REGS.len()).map(|(n, _)| n).collect()
+    }
+
+    // 変数の出現回数と、レジスタへ割り当てられない変数を収集
+    fn collect_var_uses(a: &AstType, uses: &mut Vec<(String, usize)>, excluded: &mut Vec<String>) {
+        match a {
+            AstType::Variable(_, _, ref n) => match uses.iter_mut().find(|(u, _)| u == n) {
+                Some(u) => u.1 += 1,
+                None => uses.push((n.clone(), 1)),
+            },
+            // アドレスを取る変数、条件演算子への代入先はメモリに置く
+            AstType::Address(ref v) => excluded.extend(Asm::var_name(v)),
+            AstType::Assign(ref l, _) => {
+                if let AstType::Condition(_, ref x, ref y) = **l {
+                    excluded.extend(Asm::var_name(x));
+                    excluded.extend(Asm::var_name(y));
+                }
+            }
+            // 浮動小数点数の複合代入は、左辺のアドレスを使用する
+            AstType::PlusAssign(ref l, ref r)
+            | AstType::MinusAssign(ref l, ref r)
+            | AstType::MultipleAssign(ref l, ref r)
+            | AstType::DivisionAssign(ref l, ref r) if Asm::is_float_operands(l, r) => {
+                excluded.extend(Asm::var_name(l));
+            }
+            _ => {}
+        }
+        a.children().iter().for_each(|c| Asm::collect_var_uses(c, uses, excluded));
+    }
+
+    // 変数名取得(変数以外はNone)
+    fn var_name(a: &AstType) -> Option<String> {
+        match a {
+            AstType::Variable(_, _, ref n) => Some(n.clone()),
+            _ => None,
+        }
+    }
+
+    // 変数を割り当てたレジスタ(64bit、下位32bit)を取得
+    fn reg_var(&self, a: &AstType) -> Option<(&'static str, &'static str)> {
+        match a {
+            AstType::Variable(_, _, ref n) => self.reg_vars.iter().position(|v| v == n).map(|i| VAR_REGS[i]),
+            _ => None,
+        }
+    }
+
+    // callee-savedレジスタの退避先(ローカル変数の領域の下に配置する)
+    fn saved_regs(&self) -> Vec<(&'static str, i64)> {
+        let pos = self.sym_table.frame_size(&self.cur_scope);
+        self.reg_vars
+            .iter()
+            .enumerate()
+            .map(|(i, _)| (VAR_REGS[i].0, (pos + 8 * (i + 1)) as i64))
+            .collect()
     }
 
     // statement生成.
@@ -253,18 +534,38 @@ impl<'a> Asm<'a> {
         }
     }
 
+    // 文式生成.
+    //
+    // 最後の式文の値のみスタックへ残す（式文で終わらない場合は0を積む）
+    fn generate_statement_expression(&mut self, a: &AstType) {
+        match *a {
+            AstType::Statement(ref s) => match s.split_last() {
+                Some((last, rest)) if last.is_expr() => {
+                    self.generate_statement(&AstType::Statement(rest.to_vec()));
+                    self.generate(last);
+                }
+                _ => {
+                    self.generate_statement(a);
+                    self.generate_factor(0);
+                }
+            },
+            _ => panic!("{} {}: not support expr", file!(), line!()),
+        }
+    }
+
     // 関数開始アセンブラ出力.
     fn generate_func_start(&mut self, a: &str) {
-        // スタート部分設定.
-        let mut start = if a == "main" {
+        // スタート部分設定(static関数以外は、他の翻訳単位から参照可能とする).
+        let is_static = self.sym_table.search(&Scope::Func, a).is_some_and(|s| s.is_static);
+        let mut start = if !is_static {
             format!("  .text\n.global {}\n", self.generate_func_symbol(a))
         } else {
             "  .text\n".to_string()
         };
 
-        // 16バイトアライメント
-        let mut pos = self.sym_table.size(&Scope::Local(a.to_string())) ;
-        pos = (pos / 16) * 16 + 16;
+        // 16バイトアライメント済みのフレームサイズ(レジスタを割り当てた場合は、退避領域を含める)
+        let pos = self.sym_table.frame_size(&Scope::Local(a.to_string()));
+        let pos = (pos + 8 * self.reg_vars.len()).next_multiple_of(16);
         start = format!("{}{}{}:\n", self.inst, start, self.generate_func_symbol(a));
         start = format!(
             "{}{}{}{}",
@@ -274,10 +575,16 @@ impl<'a> Asm<'a> {
             self.gen_asm().sub_imm(pos, "rsp")
         );
         self.inst = start;
+        self.saved_regs().iter().for_each(|(r, p)| {
+            self.inst = format!("{}{}", self.inst, self.gen_asm().mov_dst(r, "rbp", -p));
+        });
     }
 
     // 関数終了部分アセンブラ生成
     fn generate_func_end(&mut self) {
+        self.saved_regs().iter().for_each(|(r, p)| {
+            self.inst = format!("{}{}", self.inst, self.gen_asm().mov_src("rbp", r, -p));
+        });
         self.inst = format!(
             "{}{}{}",
             self.inst,
@@ -288,29 +595,61 @@ impl<'a> Asm<'a> {
 
     // 関数引数生成.
     fn generate_func_args(&mut self, a: &AstType) {
-        // レジスタからスタックへ引数を移動(SPを8バイトずつ移動しながら).
-        let st = 8;
+        // レジスタから各引数のスタック領域へ移動.
         match *a {
             AstType::Argment(ref args) => {
-                args.iter().zip(REGS.iter()).fold(st, |p, d| {
+                args.iter().zip(REGS.iter()).for_each(|d| {
                     match d.0 {
-                        AstType::Variable(_, s, _) if *s == Structure::Pointer => {
+                        AstType::Variable(_, s, n) if *s == Structure::Pointer => {
+                            let p = self.get_var_symbol(n).offset as i64;
                             self.inst = format!(
                                 "{}{}",
                                 self.inst,
-                                self.gen_asm().mov_dst(&d.1, "rbp", -(p as i64))
+                                self.gen_asm().mov_dst(d.1, "rbp", -p)
+                            );
+                        }
+                        // char、short型の引数は上位ビットが不定の為、受け取り時に拡張する
+                        AstType::Variable(ref t @ Type::Char, _, n) | AstType::Variable(ref t @ Type::UChar, _, n) => {
+                            let p = self.get_var_symbol(n).offset as i64;
+                            self.inst = format!(
+                                "{}{}{}{}",
+                                self.inst,
+                                self.gen_asm().mov(d.1, "rax"),
+                                self.extend_byte(t, "al", "rax"),
+                                self.gen_asm().movb_dst("al", "rbp", -p)
+                            );
+                        }
+                        AstType::Variable(Type::Short, _, n) => {
+                            let p = self.get_var_symbol(n).offset as i64;
+                            self.inst = format!(
+                                "{}{}{}{}",
+                                self.inst,
+                                self.gen_asm().mov(d.1, "rax"),
+                                self.gen_asm().movswq("ax", "rax"),
+                                self.gen_asm().movw_dst("ax", "rbp", -p)
                             );
                         }
-                        _ => {
+                        AstType::Variable(Type::Float, Structure::Identifier, n) => {
+                            let p = self.get_var_symbol(n).offset as i64;
+                            self.inst = format!(
+                                "{}{}{}{}",
+                                self.inst,
+                                self.gen_asm().mov(d.1, "rax"),
+                                self.narrow_float("rax"),
+                                self.gen_asm().mov_dst("eax", "rbp", -p)
+                            );
+                        }
+                        AstType::Variable(_, _, n) => {
+                            let p = self.get_var_symbol(n).offset as i64;
                             self.inst = format!(
                                 "{}{}{}",
                                 self.inst,
-                                self.gen_asm().mov(&d.1, "rax"),
-                                self.gen_asm().mov_dst("rax", "rbp", -(p as i64))
+                                self.gen_asm().mov(d.1, "rax"),
+                                self.gen_asm().mov_dst("rax", "rbp", -p)
                             );
                         }
+                        _ => panic!("{} {}: not support argment {:?}", file!(), line!(), d.0),
                     };
-                    p + 8
                 });
             }
             _ => panic!("{} {}: not support expr {:?}", file!(), line!(), a),
@@ -321,17 +660,12 @@ impl<'a> Asm<'a> {
     fn generate_statement_if(&mut self, a: &AstType, b: &AstType, c: &Option<AstType>) {
         let label_end = self.label.next_label();
 
-        // 条件式部分生成.
-        self.generate(a);
-        self.inst = format!("{}{}", self.inst, self.gen_asm().pop("rax"));
-        self.generate_cmp_inst(1, "rax"); // 等しい場合は、1に設定されている.
-
         // elseブロック生成.
         match c {
             Some(e) => {
                 // if条件が満たされているとき、ifラベルへ
                 let label_if = self.label.next_label();
-                self.generate_je_inst(label_if);
+                self.generate_branch(a, true, label_if);
 
                 // elseブロック生成.
                 // block部はAstType::Statementなので、演算結果に対するスタック操作は行わない.
@@ -346,7 +680,7 @@ impl<'a> Asm<'a> {
             }
             _ => {
                 // if条件が満たされていない場合、endラベルへ
-                self.generate_jne_inst(label_end);
+                self.generate_branch(a, false, label_end);
 
                 // ifブロック部生成.
                 // block部はAstType::Statementなので、演算結果に対するスタック操作は行わない.
@@ -368,11 +702,8 @@ impl<'a> Asm<'a> {
 
         // condition部生成.
         self.generate_label_inst(label_begin);
-        self.generate(a);
         // conditionが偽であれば、ブロック終端へジャンプ.
-        self.inst = format!("{}{}", self.inst, self.gen_asm().pop("rax"));
-        self.generate_cmp_inst(0, "rax");
-        self.generate_je_inst(label_end);
+        self.generate_branch(a, false, label_end);
 
         // ブロック部生成.
         // block部はAstType::Statementなので、演算結果に対するスタック操作は行わない.
@@ -403,12 +734,8 @@ impl<'a> Asm<'a> {
 
         // condition部生成.
         self.generate_label_inst(label_condition);
-        self.generate(b);
         // conditionが真であれば、ブロック先頭へジャンプ.
-        self.inst = format!("{}{}", self.inst, self.gen_asm().pop("rax"));
-        self.generate_cmp_inst(0, "rax");
-
-        self.generate_jne_inst(label_begin);
+        self.generate_branch(b, true, label_begin);
         self.generate_label_inst(label_end);
 
         // 生成したcontinue/breakラベルを除去.
@@ -432,29 +759,34 @@ impl<'a> Asm<'a> {
         self.label.push_continue(label_continue);
         self.label.push_break(label_end);
 
-        // 初期条件.
-        if let Some(init) = a {
-            self.generate(init);
-            self.inst = format!("{}{}", self.inst, self.gen_asm().pop("rax"));
+        // 初期条件（複数の宣言子はAstType::Statementとなる）.
+        match a {
+            Some(init @ AstType::Statement(_)) => self.generate_statement(init),
+            Some(init) => {
+                self.generate(init);
+                self.inst = format!("{}{}", self.inst, self.gen_asm().pop("rax"));
+            }
+            None => {}
         }
         self.generate_label_inst(label_begin);
 
         // 終了条件.
         if let Some(cond) = b {
-            self.generate(cond);
-            self.inst = format!("{}{}", self.inst, self.gen_asm().pop("rax"));
-            self.generate_cmp_inst(0, "rax");
-            self.generate_je_inst(label_end);
+            self.generate_branch(cond, false, label_end);
         }
 
         // ブロック部.
         self.generate(d);
         self.generate_label_inst(label_continue);
 
-        // 変数変化部分生成
-        if let Some(end) = c {
-            self.generate(end);
-            self.inst = format!("{}{}", self.inst, self.gen_asm().pop("rax"));
+        // 変数変化部分生成（カンマ区切りの複数の式はAstType::Statementとなる）
+        match c {
+            Some(end @ AstType::Statement(_)) => self.generate_statement(end),
+            Some(end) => {
+                self.generate(end);
+                self.inst = format!("{}{}", self.inst, self.gen_asm().pop("rax"));
+            }
+            None => {}
         }
         self.generate_jmp_inst(label_begin);
         self.generate_label_inst(label_end);
@@ -466,45 +798,240 @@ impl<'a> Asm<'a> {
 
     // continue文生成.
     fn generate_statement_continue(&mut self) {
-        let label = self.label.pop_continue();
+        let label = self.label.get_continue();
         let no = label.expect("asm.rs(generate_statement_continue): invalid continue label");
         self.generate_jmp_inst(no);
     }
 
+    // switch statement生成.
+    //
+    // caseが密な場合はジャンプテーブル、それ以外は比較の連鎖で分岐する
+    fn generate_statement_switch(&mut self, a: &AstType, b: &AstType) {
+        let label_end = self.label.next_label();
+
+        // caseラベル(defaultはNone)を採番
+        let labels = match b {
+            AstType::Statement(ref s) => s
+                .iter()
+                .filter_map(|s| match s {
+                    AstType::Case(n) => Some(Some(*n)),
+                    AstType::Default() => Some(None),
+                    _ => None,
+                })
+                .map(|v| (v, self.label.next_label()))
+                .collect::<Vec<_>>(),
+            _ => vec![],
+        };
+        let cases = labels.iter().filter_map(|(v, no)| v.map(|v| (v, *no))).collect::<Vec<_>>();
+        let default = labels.iter().find(|(v, _)| v.is_none()).map_or(label_end, |(_, no)| *no);
+
+        // 条件式をraxへ(int型は符号拡張)
+        self.generate(a);
+        self.inst = format!("{}{}", self.inst, self.gen_asm().pop("rax"));
+        if a.type_of() != Type::Long {
+            self.inst = format!("{}{}", self.inst, self.gen_asm().movslq("eax", "rax"));
+        }
+
+        let min = cases.iter().map(|(v, _)| *v).min().unwrap_or(0);
+        let max = cases.iter().map(|(v, _)| *v).max().unwrap_or(0);
+        let range = max.checked_sub(min).and_then(|r| r.checked_add(1)).map(|r| r as usize);
+        match range {
+            Some(range) if cases.len() >= JUMP_TABLE_MIN_CASES && range <= cases.len() * JUMP_TABLE_DENSITY => {
+                // value - minでジャンプテーブルを引く(範囲外はdefaultへ)
+                let table_no = self.label.next_label();
+                let table = (0..range)
+                    .map(|i| {
+                        let v = min + i as i64;
+                        cases.iter().find(|(c, _)| *c == v).map_or(default, |(_, no)| *no)
+                    })
+                    .collect::<Vec<_>>();
+                self.const_literal = format!("{}{}", self.const_literal, self.gen_asm().jump_table(table_no, &table));
+
+                self.inst = format!("{}{}", self.inst, self.gen_asm().mov_imm("rcx", min));
+                self.inst = format!("{}{}", self.inst, self.gen_asm().sub("rcx", "rax"));
+                self.inst = format!("{}{}", self.inst, self.gen_asm().cmpl(range - 1, "rax"));
+                self.inst = format!("{}{}", self.inst, self.gen_asm().ja(default));
+                self.inst = format!("{}{}", self.inst, self.gen_asm().jmp_table(table_no, "rcx", "rax"));
+            }
+            _ => {
+                cases.iter().for_each(|(v, no)| {
+                    self.inst = format!("{}{}", self.inst, self.gen_asm().cmp_imm(*v, "rax"));
+                    self.inst = format!("{}{}", self.inst, self.gen_asm().je(*no));
+                });
+                self.generate_jmp_inst(default);
+            }
+        }
+
+        // ブロック部生成(breakはswitch終端へ.continueは外側のループへ).
+        self.label.push_break(label_end);
+        self.label.push_cases(labels);
+        self.generate(b);
+        self.label.pop_cases();
+        self.label.remove_break(label_end);
+        self.generate_label_inst(label_end);
+    }
+
+    // case/defaultラベル生成.
+    fn generate_case_label(&mut self, value: Option<i64>) {
+        let label = self.label.get_case(value);
+        let no = label.expect("asm.rs(generate_case_label): invalid case label");
+        self.generate_label_inst(no);
+    }
+
+    // goto文生成.
+    fn generate_statement_goto(&mut self, name: &str) {
+        let label = self.label.get_goto(name);
+        let no = label.expect("asm.rs(generate_statement_goto): invalid goto label");
+        self.generate_jmp_inst(no);
+    }
+
+    // gotoのジャンプ先ラベル生成.
+    fn generate_goto_label(&mut self, name: &str) {
+        let label = self.label.get_goto(name);
+        let no = label.expect("asm.rs(generate_goto_label): invalid label");
+        self.generate_label_inst(no);
+    }
+
     // break文生成.
-    fn generate_statement_break(&mut self) {
-        let label = self.label.pop_break();
-        let no = label.expect("asm.rs(generate_statement_break): invalid continue label");
+    fn generate_statement_break(&mut self, level: usize) {
+        // 指定されたネスト数分、外側のループのbreakラベルへジャンプ.
+        let label = self.label.get_break(level);
+        let no = label.expect("asm.rs(generate_statement_break): invalid break label");
         self.generate_jmp_inst(no);
     }
 
     // return statement.
     fn generate_statement_return(&mut self, a: &AstType) {
+        if let (Some(no), Some(args)) = (self.tail_label, self.tail_call_args(a)) {
+            self.generate_tail_call(no, args);
+            return;
+        }
+
         self.generate(a);
         if a.is_expr() {
             self.inst = format!("{}{}", self.inst, self.gen_asm().pop("rax"));
+
+            // 戻り値の型へ変換し、浮動小数点数はxmm0で返す(ポインタはアドレスのまま)
+            let t = if self.is_func_return_pointer() { Type::Long } else { self.func_return_type() };
+            self.generate_convert(&Asm::value_type(a), &t, "rax");
+            if t.is_float() {
+                // floatは単精度へ変換して返す
+                if t == Type::Float {
+                    self.inst = format!("{}{}", self.inst, self.narrow_float("rax"));
+                }
+                self.inst = format!("{}{}", self.inst, self.gen_asm().movq("rax", "xmm0"));
+            } else if matches!(t, Type::Char | Type::UChar) && !self.is_func_return_pointer() {
+                // char型を返す関数は、戻り値を1バイトへ切り詰めて拡張する
+                let extend = self.extend_byte(&t, "al", "rax");
+                self.inst = format!("{}{}", self.inst, extend);
+            } else if t == Type::Short && !self.is_func_return_pointer() {
+                // short型も同様に2バイトへ切り詰めて拡張する
+                self.inst = format!("{}{}", self.inst, self.gen_asm().movswq("ax", "rax"));
+            }
         }
         let label_no = self.label.get_return_label();
         self.generate_jmp_inst(label_no);
     }
 
+    // 自己末尾呼び出し生成.
+    //
+    // 引数をレジスタへ設定し、callせずに関数先頭（引数の格納処理）へジャンプする
+    fn generate_tail_call(&mut self, label_no: usize, args: &[AstType]) {
+        args.iter().rev().for_each(|d| self.generate(d));
+        args.iter().zip(REGS.iter()).for_each(|d| {
+            self.inst = format!("{}{}", self.inst, self.gen_asm().pop(d.1));
+        });
+        self.generate_jmp_inst(label_no);
+    }
+
+    // 自己末尾呼び出しであれば、その引数を取得
+    fn tail_call_args<'b>(&self, a: &'b AstType) -> Option<&'b [AstType]> {
+        match self.cur_scope {
+            Scope::Local(ref f) => Asm::self_call_args(f, a),
+            _ => None,
+        }
+    }
+
+    // 関数fの自己呼び出しであれば、その引数を取得
+    fn self_call_args<'b>(f: &str, a: &'b AstType) -> Option<&'b [AstType]> {
+        match *a {
+            AstType::FuncCall(ref v, ref args) => match (&**v, &**args) {
+                (AstType::Variable(_, _, ref n), AstType::Argment(ref args)) if n == f && args.len() <= REGS.len() => {
+                    Some(args)
+                }
+                _ => None,
+            },
+            _ => None,
+        }
+    }
+
+    // 関数fの本体に自己末尾呼び出しを含むか
+    fn has_tail_call(f: &str, a: &AstType) -> bool {
+        match *a {
+            AstType::Return(ref r) if Asm::self_call_args(f, r).is_some() => true,
+            _ => a.children().iter().any(|c| Asm::has_tail_call(f, c)),
+        }
+    }
+
+    // アドレスを取得する式、もしくは配列を含むか
+    fn has_local_address(a: &AstType) -> bool {
+        match *a {
+            AstType::Address(_) | AstType::Variable(_, Structure::Array(_), _) => true,
+            _ => a.children().iter().any(|c| Asm::has_local_address(c)),
+        }
+    }
+
     // assign indirect
     fn generate_assign_indirect(&mut self, a: &AstType, b: &AstType) {
-        self.generate(a);
+        self.generate_element_address(a);
         self.generate(b);
         self.inst = format!("{}{}", self.inst, self.gen_asm().pop("rax"));
-        self.inst = format!(
-            "{}{}{}",
-            self.inst,
-            self.gen_asm().pop("rcx"),
-            self.gen_asm().mov_dst("rax", "rcx", 0)
-        );
+        self.inst = format!("{}{}", self.inst, self.gen_asm().pop("rcx"));
+        self.generate_convert(&Asm::value_type(b), &a.type_of(), "rax");
+
+        // 参照先の型のサイズで転送(隣接する変数を破壊しない)
+        match a.type_of() {
+            t @ Type::Char | t @ Type::UChar => {
+                self.inst = format!("{}{}", self.inst, self.gen_asm().movb_dst("al", "rcx", 0));
+                self.inst = format!("{}{}", self.inst, self.extend_byte(&t, "al", "rax"));
+            }
+            Type::Short => {
+                self.inst = format!("{}{}", self.inst, self.gen_asm().movw_dst("ax", "rcx", 0));
+                self.inst = format!("{}{}", self.inst, self.gen_asm().movswq("ax", "rax"));
+            }
+            Type::Int => {
+                self.inst = format!("{}{}", self.inst, self.gen_asm().mov_dst("eax", "rcx", 0));
+                self.inst = format!("{}{}", self.inst, self.gen_asm().movslq("eax", "rax"));
+            }
+            Type::UInt => {
+                self.inst = format!("{}{}", self.inst, self.gen_asm().mov_dst("eax", "rcx", 0));
+                self.inst = format!("{}{}", self.inst, self.gen_asm().mov("eax", "eax"));
+            }
+            Type::Float => self.inst = format!("{}{}", self.inst, self.store_float("rax", "rcx")),
+            _ => {
+                self.inst = format!("{}{}", self.inst, self.gen_asm().mov_dst("rax", "rcx", 0));
+            }
+        }
         self.inst = format!("{}{}", self.inst, self.gen_asm().push("rax"));
     }
 
     // assign生成.
     fn generate_assign(&mut self, a: &AstType, b: &AstType) {
+        if let Type::Struct(ref tag) = a.type_of() {
+            if !a.is_pointer() && a.type_of() == b.type_of() && !b.is_pointer() {
+                return self.generate_assign_struct(tag, a, b);
+            }
+        }
+
         match *a {
+            AstType::Variable(_, _, _) if self.reg_var(a).is_some() => {
+                let (r, _) = self.reg_var(a).unwrap();
+                self.generate(b);
+                self.inst = format!("{}{}", self.inst, self.gen_asm().pop("rcx"));
+                self.generate_convert(&Asm::value_type(b), &Asm::value_type(a), "rcx");
+                self.inst = format!("{}{}", self.inst, self.gen_asm().mov("rcx", r));
+                self.inst = format!("{}{}", self.inst, self.gen_asm().push("rcx"));
+            }
             AstType::Variable(ref t, ref s, _) => {
                 self.generate_lvalue_address(a);
                 self.generate(b);
@@ -519,11 +1046,26 @@ impl<'a> Asm<'a> {
                     _ => {
                         // 型に応じた転送サイズを考慮
                         match t {
-                            Type::Char => {
+                            Type::Char | Type::UChar => {
                                 self.inst = format!("{}{}", self.inst, self.gen_asm().movb_dst("cl", "rax", 0));
+
+                                // int型の演算結果をchar型へ格納する場合、明示的に切り詰める
+                                if b.type_of() != *t {
+                                    self.inst = format!("{}{}", self.inst, self.extend_byte(t, "cl", "rcx"));
+                                }
+                            }
+                            Type::Short => {
+                                self.inst = format!("{}{}", self.inst, self.gen_asm().movw_dst("cx", "rax", 0));
+                                self.inst = format!("{}{}", self.inst, self.gen_asm().movswq("cx", "rcx"));
+                            }
+                            Type::Float => {
+                                self.generate_convert(&b.type_of(), t, "rcx");
+                                self.inst = format!("{}{}", self.inst, self.store_float("rcx", "rax"));
                             }
                             _ =>  {
-                                self.inst = format!("{}{}", self.inst, self.gen_asm().mov_dst("rcx", "rax", 0));
+                                self.generate_convert(&b.type_of(), t, "rcx");
+                                let src = if self.lvalue_size(a) == 4 { "ecx" } else { "rcx" };
+                                self.inst = format!("{}{}", self.inst, self.gen_asm().mov_dst(src, "rax", 0));
                             }
                         }
                     }
@@ -531,137 +1073,359 @@ impl<'a> Asm<'a> {
                 self.inst = format!("{}{}", self.inst, self.gen_asm().push("rcx"));
             }
             AstType::Indirect(ref a) => self.generate_assign_indirect(a, b),
+            AstType::MemberAccess(ref s, ref m) => self.generate_assign_member(s, m, b),
+            AstType::Condition(ref c, ref x, ref y) => self.generate_assign_condition(c, x, y, b),
             _ => self.generate(b),
         }
     }
 
-    // plus assign生成.
-    fn generate_plus_assign(&mut self, a: &AstType, b: &AstType) {
-        match a {
-            AstType::Variable(_, _, ref name) => {
-                self.generate_lvalue_address(a);
-                self.generate(b);
-                self.inst = format!("{}{}", self.inst, self.gen_asm().pop("rax"));
-                self.inst = format!("{}{}", self.inst, self.gen_asm().pop("rcx"));
-                self.inst = format!("{}{}", self.inst, self.gen_asm().add_src("rcx", "rax", 0));
+    // 構造体の代入生成.
+    //
+    // 右辺の構造体を、構造体のサイズ分だけ左辺へコピーする(値は左辺のアドレス)
+    fn generate_assign_struct(&mut self, tag: &str, a: &AstType, b: &AstType) {
+        self.generate_address(a);
+        match *b {
+            AstType::Variable(_, _, _) | AstType::MemberAccess(_, _) | AstType::Indirect(_) => self.generate_address(b),
+            _ => self.generate(b),
+        }
+        let size = self.get_var_symbol(tag).size as i64;
+        self.inst = format!("{}{}", self.inst, self.gen_asm().pop("rsi"));
+        self.inst = format!("{}{}", self.inst, self.gen_asm().pop("rdi"));
+        self.inst = format!("{}{}", self.inst, self.gen_asm().push("rdi"));
+        self.inst = format!("{}{}", self.inst, self.gen_asm().mov_imm("rcx", size));
+        self.inst = format!("{}{}", self.inst, self.gen_asm().rep_movsb());
+    }
+
+    // 条件演算子への代入生成(GNU拡張).
+    //
+    // 条件により選択した分岐のアドレスへ格納する
+    fn generate_assign_condition(&mut self, c: &AstType, x: &AstType, y: &AstType, b: &AstType) {
+        let label_false = self.label.next_label();
+        let label_end = self.label.next_label();
 
-                // 型に応じた転送サイズを考慮
-                let sym = self.get_var_symbol(name);
-                match sym.t {
-                    Type::Char => {
-                        self.inst = format!("{}{}", self.inst, self.gen_asm().movb_dst("al", "rcx", 0));
-                    }
-                    _ =>  {
-                        self.inst = format!("{}{}", self.inst, self.gen_asm().mov_dst("rax", "rcx", 0));
-                    }
-                }
+        self.generate_condition_test(c);
+        self.generate_je_inst(label_false);
+        self.generate_compound_lvalue(x);
+        self.generate_jmp_inst(label_end);
+        self.generate_label_inst(label_false);
+        self.generate_compound_lvalue(y);
+        self.generate_label_inst(label_end);
+
+        self.generate(b);
+        self.inst = format!("{}{}", self.inst, self.gen_asm().pop("rax"));
+        self.inst = format!("{}{}", self.inst, self.gen_asm().pop("rcx"));
+
+        // char型へ格納する場合、式の値も切り詰める
+        self.generate_truncate(x, "al", "rax");
+        self.generate_compound_store(x, "rax", "rcx");
+    }
+
+    // メンバーへの代入生成.
+    fn generate_assign_member(&mut self, s: &AstType, m: &AstType, b: &AstType) {
+        self.generate_member_address(s, m);
+        self.generate(b);
+        self.inst = format!("{}{}", self.inst, self.gen_asm().pop("rcx"));
+        self.inst = format!("{}{}", self.inst, self.gen_asm().pop("rax"));
+        self.generate_convert(&Asm::value_type(b), &Asm::value_type(m), "rcx");
+
+        // メンバーの型に応じた転送サイズを考慮
+        match m {
+            AstType::Variable(ref t, Structure::BitField(width, bit), _) => {
+                self.inst = format!("{}{}", self.inst, self.store_bit_field(t, *width, *bit, "rcx", "rax"));
+            }
+            AstType::Variable(_, Structure::Pointer, _) => {
+                self.inst = format!("{}{}", self.inst, self.gen_asm().mov_dst("rcx", "rax", 0));
+            }
+            AstType::Variable(ref t @ Type::Char, _, _) | AstType::Variable(ref t @ Type::UChar, _, _) => {
+                self.inst = format!("{}{}", self.inst, self.gen_asm().movb_dst("cl", "rax", 0));
+                self.inst = format!("{}{}", self.inst, self.extend_byte(t, "cl", "rcx"));
+            }
+            AstType::Variable(Type::Short, _, _) => {
+                self.inst = format!("{}{}", self.inst, self.gen_asm().movw_dst("cx", "rax", 0));
+                self.inst = format!("{}{}", self.inst, self.gen_asm().movswq("cx", "rcx"));
+            }
+            AstType::Variable(Type::UInt, _, _) => {
+                self.inst = format!("{}{}", self.inst, self.gen_asm().mov_dst("ecx", "rax", 0));
+                self.inst = format!("{}{}", self.inst, self.gen_asm().mov("ecx", "ecx"));
+            }
+            AstType::Variable(Type::Float, _, _) => {
+                self.inst = format!("{}{}", self.inst, self.store_float("rcx", "rax"));
+            }
+            AstType::Variable(Type::Double, _, _) => {
+                self.inst = format!("{}{}", self.inst, self.gen_asm().mov_dst("rcx", "rax", 0));
+            }
+            _ => {
+                self.inst = format!("{}{}", self.inst, self.gen_asm().mov_dst("ecx", "rax", 0));
+                self.inst = format!("{}{}", self.inst, self.gen_asm().movslq("ecx", "rcx"));
             }
-            _ => panic!("{} {}: cannot support AstType {:?}", file!(), line!(), a)
         }
+        self.inst = format!("{}{}", self.inst, self.gen_asm().push("rcx"));
     }
 
-    // minus assign生成.
-    fn generate_minus_assign(&mut self, a: &AstType, b: &AstType) {
+    // 複合代入の左辺アドレス生成.
+    //
+    // 左辺のアドレスは一度だけ評価し、スタックへ積む
+    fn generate_compound_lvalue(&mut self, a: &AstType) {
         match a {
-            AstType::Variable(_, _, ref name) => {
-                self.generate_lvalue_address(a);
-                self.generate(b);
-                self.inst = format!("{}{}", self.inst, self.gen_asm().pop("rax"));
-                self.inst = format!("{}{}", self.inst, self.gen_asm().pop("rcx"));
-                self.inst = format!("{}{}", self.inst, self.gen_asm().mov_src("rcx", "rdx", 0));
-                self.inst = format!("{}{}", self.inst, self.gen_asm().sub("rax", "rdx"));
+            AstType::Variable(_, _, _) => self.generate_lvalue_address(a),
+            AstType::Indirect(ref p) => self.generate_element_address(p),
+            AstType::MemberAccess(ref s, ref m) => self.generate_member_address(s, m),
+            _ => panic!("{} {}: cannot support AstType {:?}", file!(), line!(), a),
+        }
+    }
+
+    // 複合代入の左辺の転送サイズ(代入と同じ転送サイズとする)
+    fn compound_lvalue_size(a: &AstType) -> usize {
+        match a {
+            AstType::Variable(_, Structure::Pointer, _) => 8,
+            AstType::Indirect(ref p) => Asm::scalar_size(&p.type_of()),
+            AstType::MemberAccess(_, ref m) => match **m {
+                AstType::Variable(_, Structure::Pointer, _) => 8,
+                ref m => Asm::scalar_size(&m.type_of()),
+            },
+            _ if matches!(a.type_of(), Type::Char | Type::UChar) => 1,
+            _ if a.type_of() == Type::Short => 2,
+            _ => 8,
+        }
+    }
 
-                // 型に応じた転送サイズを考慮
+    // 左辺の転送サイズ
+    //
+    // グローバル変数は型のサイズ分の領域しかないため、型のサイズで読み書きする
+    fn lvalue_size(&self, a: &AstType) -> usize {
+        match *a {
+            AstType::Variable(_, Structure::Identifier, ref name) => {
                 let sym = self.get_var_symbol(name);
-                match sym.t {
-                    Type::Char => {
-                        self.inst = format!("{}{}", self.inst, self.gen_asm().movb_dst("dl", "rcx", 0));
-                    }
-                    _ =>  {
-                        self.inst = format!("{}{}", self.inst, self.gen_asm().mov_dst("rdx", "rcx", 0));
-                    }
+                match sym.scope {
+                    Scope::Global => sym.size,
+                    _ => Asm::compound_lvalue_size(a),
                 }
             }
-            _ => panic!("{} {}: cannot support AstType {:?}", file!(), line!(), a)
+            _ => Asm::compound_lvalue_size(a),
         }
     }
 
-    // multiple assign生成.
-    fn generate_multiple_assign(&mut self, a: &AstType, b: &AstType) {
+    // ビットフィールドのメンバーであれば、型、幅、ビット位置を取得
+    fn bit_field(a: &AstType) -> Option<(Type, usize, usize)> {
         match a {
-            AstType::Variable(_, _, ref name) => {
-                self.generate_lvalue_address(a);
-                self.generate(b);
-                self.inst = format!("{}{}", self.inst, self.gen_asm().pop("rax"));
-                self.inst = format!("{}{}", self.inst, self.gen_asm().pop("rcx"));
-                self.inst = format!("{}{}", self.inst, self.gen_asm().mov_src("rcx", "rdx", 0));
-                self.inst = format!("{}{}", self.inst, self.gen_asm().mul("rdx"));
+            AstType::MemberAccess(_, ref m) => match **m {
+                AstType::Variable(ref t, Structure::BitField(width, bit), _) => Some((t.clone(), width, bit)),
+                _ => None,
+            },
+            _ => None,
+        }
+    }
 
-                // 型に応じた転送サイズを考慮
-                let sym = self.get_var_symbol(name);
-                match sym.t {
-                    Type::Char => {
-                        self.inst = format!("{}{}", self.inst, self.gen_asm().movb_dst("al", "rcx", 0));
-                    }
-                    _ =>  {
-                        self.inst = format!("{}{}", self.inst, self.gen_asm().mov_dst("rax", "rcx", 0));
-                    }
-                }
+    // ビットフィールドの値を、格納単位から取り出して64bitへ拡張(intは符号拡張、unsignedはゼロ拡張).
+    fn load_bit_field(&self, t: &Type, width: usize, bit: usize, addr: &str, dst: &str) -> String {
+        let l = match dst {
+            "rax" => "eax",
+            "rcx" => "ecx",
+            _ => panic!("{} {}: cannot support register {}", file!(), line!(), dst),
+        };
+        format!(
+            "{}{}{}",
+            self.gen_asm().movl_src(addr, l, 0),
+            self.gen_asm().shl_imm((64 - bit - width) as u32, dst),
+            self.extend_bit_field(t, width, dst)
+        )
+    }
+
+    // ビットフィールドへ格納(格納単位の他のビットは保持し、格納した値へ切り詰める).
+    fn store_bit_field(&self, t: &Type, width: usize, bit: usize, src: &str, addr: &str) -> String {
+        let mask = (((1u64 << width) - 1) << bit) as i64;
+        format!(
+            "{}{}{}{}{}{}{}{}{}{}",
+            self.gen_asm().mov(src, "r8"),
+            self.gen_asm().shl_imm((64 - width) as u32, "r8"),
+            self.gen_asm().shr_imm((64 - width - bit) as u32, "r8"),
+            self.gen_asm().movl_src(addr, "r9d", 0),
+            self.gen_asm().mov_imm("r10", !mask & 0xffff_ffff),
+            self.gen_asm().and("r10", "r9"),
+            self.gen_asm().or("r8", "r9"),
+            self.gen_asm().mov_dst("r9d", addr, 0),
+            self.gen_asm().shl_imm((64 - width) as u32, src),
+            self.extend_bit_field(t, width, src)
+        )
+    }
+
+    // 上位へ寄せたビットフィールドの値を、64bitへ拡張.
+    fn extend_bit_field(&self, t: &Type, width: usize, reg: &str) -> String {
+        match t {
+            Type::UInt => self.gen_asm().shr_imm((64 - width) as u32, reg),
+            _ => self.gen_asm().sar_imm((64 - width) as u32, reg),
+        }
+    }
+
+    // 1バイトの値を64bitへ拡張(unsigned charはゼロ拡張、それ以外は符号拡張).
+    fn extend_byte(&self, t: &Type, src: &str, dst: &str) -> String {
+        match t {
+            Type::UChar => self.gen_asm().movzbq(src, dst),
+            _ => self.gen_asm().movsbq(src, dst),
+        }
+    }
+
+    // 1バイトの値をメモリから64bitへ拡張して読み出し.
+    fn load_byte(&self, t: &Type, src: &str, dst: &str) -> String {
+        match t {
+            Type::UChar => self.gen_asm().movzbq_src(src, dst, 0),
+            _ => self.gen_asm().movsbq_src(src, dst, 0),
+        }
+    }
+
+    // 複合代入の左辺の値をraxへ読み出し
+    fn generate_compound_load(&mut self, a: &AstType, addr: &str) {
+        if let Some((t, width, bit)) = Asm::bit_field(a) {
+            self.inst = format!("{}{}", self.inst, self.load_bit_field(&t, width, bit, addr, "rax"));
+            return;
+        }
+        if a.type_of() == Type::Float && !a.is_pointer() {
+            self.inst = format!("{}{}", self.inst, self.load_float(addr, "rax"));
+            return;
+        }
+        let load = match self.lvalue_size(a) {
+            1 => self.load_byte(&a.type_of(), addr, "rax"),
+            2 => self.gen_asm().movswq_src(addr, "rax", 0),
+            4 => self.gen_asm().movslq_src(addr, "rax", 0),
+            _ => self.gen_asm().mov_src(addr, "rax", 0),
+        };
+        self.inst = format!("{}{}", self.inst, load);
+    }
+
+    // 複合代入の結果を左辺へ格納し、式の値としてスタックへ積む
+    fn generate_compound_store(&mut self, a: &AstType, src: &str, addr: &str) {
+        self.generate_lvalue_store(a, src, addr);
+        self.inst = format!("{}{}", self.inst, self.gen_asm().push(src));
+    }
+
+    // 左辺の転送サイズで格納
+    fn generate_lvalue_store(&mut self, a: &AstType, src: &str, addr: &str) {
+        if let Some((t, width, bit)) = Asm::bit_field(a) {
+            self.inst = format!("{}{}", self.inst, self.store_bit_field(&t, width, bit, src, addr));
+            return;
+        }
+        if a.type_of() == Type::Float && !a.is_pointer() {
+            self.inst = format!("{}{}", self.inst, self.store_float(src, addr));
+            return;
+        }
+        let (b, w, l) = match src {
+            "rax" => ("al", "ax", "eax"),
+            "rdx" => ("dl", "dx", "edx"),
+            _ => panic!("{} {}: cannot support register {}", file!(), line!(), src),
+        };
+        let store = match self.lvalue_size(a) {
+            1 => self.gen_asm().movb_dst(b, addr, 0),
+            2 => self.gen_asm().movw_dst(w, addr, 0),
+            4 => self.gen_asm().mov_dst(l, addr, 0),
+            _ => self.gen_asm().mov_dst(src, addr, 0),
+        };
+        self.inst = format!("{}{}", self.inst, store);
+    }
+
+    // char、short型の左辺へ格納する値を、式の値として切り詰める
+    fn generate_truncate(&mut self, a: &AstType, src: &str, dst: &str) {
+        match Asm::compound_lvalue_size(a) {
+            1 => self.inst = format!("{}{}", self.inst, self.extend_byte(&a.type_of(), src, dst)),
+            2 => {
+                let w = match dst {
+                    "rax" => "ax",
+                    _ => panic!("{} {}: cannot support register {}", file!(), line!(), dst),
+                };
+                self.inst = format!("{}{}", self.inst, self.gen_asm().movswq(w, dst));
             }
-            _ => panic!("{} {}: cannot support AstType {:?}", file!(), line!(), a)
+            _ => {}
+        }
+    }
+
+    // plus assign生成.
+    fn generate_plus_assign(&mut self, a: &AstType, b: &AstType) {
+        self.generate_compound_lvalue(a);
+        self.generate(b);
+        if a.is_pointer() {
+            self.generate_scale(a);
+        }
+        self.inst = format!("{}{}", self.inst, self.gen_asm().pop("rdx"));
+        self.inst = format!("{}{}", self.inst, self.gen_asm().pop("rcx"));
+        self.generate_compound_load(a, "rcx");
+        if self.is_overflow_check_assign(a, b) {
+            self.inst = format!("{}{}", self.inst, self.gen_asm().add("edx", "eax"));
+            self.generate_overflow_check(a, b);
+        } else {
+            self.inst = format!("{}{}", self.inst, self.gen_asm().add("rdx", "rax"));
+        }
+        self.generate_compound_store(a, "rax", "rcx");
+    }
+
+    // ポインタへの加減算のため、スタック上の値を要素サイズ倍する
+    fn generate_scale(&mut self, a: &AstType) {
+        let size = self.element_size(a);
+        self.inst = format!("{}{}", self.inst, self.gen_asm().pop("rax"));
+        self.inst = format!("{}{}", self.inst, self.gen_asm().mov_imm("rcx", size as i64));
+        self.inst = format!("{}{}", self.inst, self.gen_asm().mul("rcx"));
+        self.inst = format!("{}{}", self.inst, self.gen_asm().push("rax"));
+    }
+
+    // minus assign生成.
+    fn generate_minus_assign(&mut self, a: &AstType, b: &AstType) {
+        self.generate_compound_lvalue(a);
+        self.generate(b);
+        if a.is_pointer() {
+            self.generate_scale(a);
+        }
+        self.inst = format!("{}{}", self.inst, self.gen_asm().pop("rdx"));
+        self.inst = format!("{}{}", self.inst, self.gen_asm().pop("rcx"));
+        self.generate_compound_load(a, "rcx");
+        if self.is_overflow_check_assign(a, b) {
+            self.inst = format!("{}{}", self.inst, self.gen_asm().sub("edx", "eax"));
+            self.generate_overflow_check(a, b);
+        } else {
+            self.inst = format!("{}{}", self.inst, self.gen_asm().sub("rdx", "rax"));
+        }
+        self.generate_compound_store(a, "rax", "rcx");
+    }
+
+    // multiple assign生成.
+    fn generate_multiple_assign(&mut self, a: &AstType, b: &AstType) {
+        self.generate_compound_lvalue(a);
+        self.generate(b);
+        self.inst = format!("{}{}", self.inst, self.gen_asm().pop("rdx"));
+        self.inst = format!("{}{}", self.inst, self.gen_asm().pop("rcx"));
+        self.generate_compound_load(a, "rcx");
+        if self.is_overflow_check_assign(a, b) {
+            self.inst = format!("{}{}", self.inst, self.gen_asm().imul("edx"));
+            self.generate_overflow_check(a, b);
+        } else {
+            self.inst = format!("{}{}", self.inst, self.gen_asm().mul("rdx"));
         }
+        self.generate_compound_store(a, "rax", "rcx");
     }
 
     // division assign生成.
     fn generate_division_assign(&mut self, a: &AstType, b: &AstType) {
-        match a {
-            AstType::Variable(_, _, ref name) => {
-                self.generate_lvalue_address(a);
-                self.generate(b);
-                self.inst = format!("{}{}", self.inst, self.gen_asm().pop("rcx"));
-                self.inst = format!("{}{}", self.inst, self.gen_asm().pop("rbx"));
-                self.inst = format!("{}{}", self.inst, self.gen_asm().mov_src("rbx", "rax", 0));
-                self.inst = format!("{}{}", self.inst, self.gen_asm().bit_division());
-
-                // 型に応じた転送サイズを考慮
-                let sym = self.get_var_symbol(name);
-                match sym.t {
-                    Type::Char => {
-                        self.inst = format!("{}{}", self.inst, self.gen_asm().movb_dst("al", "rbx", 0));
-                    }
-                    _ =>  {
-                        self.inst = format!("{}{}", self.inst, self.gen_asm().mov_dst("rax", "rbx", 0));
-                    }
-                }
-            }
-            _ => panic!("{} {}: cannot support AstType {:?}", file!(), line!(), a)
+        self.generate_compound_lvalue(a);
+        self.generate(b);
+        self.inst = format!("{}{}", self.inst, self.gen_asm().pop("rcx"));
+        self.inst = format!("{}{}", self.inst, self.gen_asm().pop("rsi"));
+        self.generate_compound_load(a, "rsi");
+        self.inst = format!("{}{}", self.inst, self.division(&a.type_of()));
+        if a.type_of() == Type::Int {
+            self.inst = format!("{}{}", self.inst, self.gen_asm().cltq());
         }
+        self.generate_compound_store(a, "rax", "rsi");
     }
 
     // remainder assign生成.
     fn generate_remainder_assign(&mut self, a: &AstType, b: &AstType) {
-        match a {
-            AstType::Variable(_, _, ref name) => {
-                self.generate_lvalue_address(a);
-                self.generate(b);
-                self.inst = format!("{}{}", self.inst, self.gen_asm().pop("rcx"));
-                self.inst = format!("{}{}", self.inst, self.gen_asm().pop("rbx"));
-                self.inst = format!("{}{}", self.inst, self.gen_asm().mov_src("rbx", "rax", 0));
-                self.inst = format!("{}{}", self.inst, self.gen_asm().bit_division());
-
-                // 型に応じた転送サイズを考慮
-                let sym = self.get_var_symbol(name);
-                match sym.t {
-                    Type::Char => {
-                        self.inst = format!("{}{}", self.inst, self.gen_asm().movb_dst("dl", "rbx", 0));
-                    }
-                    _ =>  {
-                        self.inst = format!("{}{}", self.inst, self.gen_asm().mov_dst("rdx", "rbx", 0));
-                    }
-                }
-            }
-            _ => panic!("{} {}: cannot support AstType {:?}", file!(), line!(), a)
+        self.generate_compound_lvalue(a);
+        self.generate(b);
+        self.inst = format!("{}{}", self.inst, self.gen_asm().pop("rcx"));
+        self.inst = format!("{}{}", self.inst, self.gen_asm().pop("rsi"));
+        self.generate_compound_load(a, "rsi");
+        self.inst = format!("{}{}", self.inst, self.division(&a.type_of()));
+        if a.type_of() == Type::Int {
+            self.inst = format!("{}{}", self.inst, self.gen_asm().movslq("edx", "rdx"));
         }
+        self.generate_compound_store(a, "rdx", "rsi");
     }
 
     // 型や構造を判断し、variable生成
@@ -675,11 +1439,20 @@ impl<'a> Asm<'a> {
             }
             Structure::Identifier => {
                 match sym.t {
-                    Type::Int => {
+                    Type::Int | Type::UInt => {
                         self.inst = format!("{}{}", self.inst, self.gen_asm().movl_src("rcx", "eax", 0));
                     }
-                    Type::Char => {
-                        self.inst = format!("{}{}", self.inst, self.gen_asm().movsbl_src("rcx", "eax", 0));
+                    Type::Char | Type::UChar => {
+                        self.inst = format!("{}{}", self.inst, self.load_byte(&sym.t, "rcx", "rax"));
+                    }
+                    Type::Short => {
+                        self.inst = format!("{}{}", self.inst, self.gen_asm().movswq_src("rcx", "rax", 0));
+                    }
+                    Type::Float => {
+                        self.inst = format!("{}{}", self.inst, self.load_float("rcx", "rax"));
+                    }
+                    Type::Double => {
+                        self.inst = format!("{}{}", self.inst, self.gen_asm().mov_src("rcx", "rax", 0));
                     }
                     _ => panic!("{}{}: cannot support type: {:?}", file!(), line!(), sym.t)
                 }
@@ -693,6 +1466,12 @@ impl<'a> Asm<'a> {
 
     // variable生成.
     fn generate_variable(&mut self, a: &AstType) {
+        // レジスタの変数は、メモリと同様に下位32bitを読み出す
+        if let Some((_, r)) = self.reg_var(a) {
+            self.inst = format!("{}{}", self.inst, self.gen_asm().mov(r, "eax"));
+            self.inst = format!("{}{}", self.inst, self.gen_asm().push("rax"));
+            return;
+        }
         self.generate_lvalue_address(a);
         match a {
             AstType::Variable(_, _, ref name) => {
@@ -718,12 +1497,12 @@ impl<'a> Asm<'a> {
                         // 関数引数をスタックからレジスタへ.
                         v.iter().zip(REGS.iter()).for_each(|d| match d.0 {
                             AstType::Variable(_, s, _) if *s == Structure::Pointer => {
-                                self.inst = format!("{}{}", self.inst, self.gen_asm().pop(&d.1));
+                                self.inst = format!("{}{}", self.inst, self.gen_asm().pop(d.1));
                             }
                             _ => {
                                 self.inst = format!("{}{}", self.inst, self.gen_asm().pop("rax"));
                                 self.inst =
-                                    format!("{}{}", self.inst, self.gen_asm().mov("rax", &d.1));
+                                    format!("{}{}", self.inst, self.gen_asm().mov("rax", d.1));
                             }
                         });
                     }
@@ -735,12 +1514,37 @@ impl<'a> Asm<'a> {
                     self.inst,
                     self.gen_asm().call(&self.generate_func_symbol(n))
                 );
+
+                // 浮動小数点数の戻り値はxmm0で受け取る
+                // char型の戻り値は上位ビットが不定の為、1バイトから拡張する
+                let is_pointer = self.sym_table.search(&Scope::Func, n).is_some_and(|s| s.strt == Structure::Pointer);
+                match lhs.type_of() {
+                    Type::Float if !is_pointer => {
+                        self.inst = format!("{}{}", self.inst, self.gen_asm().movd("xmm0", "eax"));
+                        self.inst = format!("{}{}", self.inst, self.widen_float("rax"));
+                    }
+                    Type::Double if !is_pointer => self.inst = format!("{}{}", self.inst, self.gen_asm().movq("xmm0", "rax")),
+                    t @ (Type::Char | Type::UChar) if !is_pointer => {
+                        self.inst = format!("{}{}", self.inst, self.extend_byte(&t, "al", "rax"))
+                    }
+                    Type::Short if !is_pointer => {
+                        self.inst = format!("{}{}", self.inst, self.gen_asm().movswq("ax", "rax"))
+                    }
+                    _ => {}
+                }
                 self.inst = format!("{}{}", self.inst, self.gen_asm().push("rax"));
             }
             _ => panic!("{} {}: Not Exists Function name", file!(), line!()),
         }
     }
 
+    // 関数シンボルであるか(同名の変数がある場合は変数を優先).
+    fn is_func_symbol(&self, n: &str) -> bool {
+        self.sym_table.search(&Scope::Func, n).is_some()
+            && self.sym_table.search(&self.cur_scope, n).is_none()
+            && self.sym_table.search(&Scope::Global, n).is_none()
+    }
+
     // 関数シンボル生成.
     fn generate_func_symbol(&self, s: &str) -> String {
         if Config::is_mac() {
@@ -760,9 +1564,7 @@ impl<'a> Asm<'a> {
 
     // Not演算子生成.
     fn generate_not(&mut self, a: &AstType) {
-        self.generate(a);
-        self.inst = format!("{}{}", self.inst, self.gen_asm().pop("rax"));
-        self.generate_cmp_inst(0, "rax");
+        self.generate_condition_test(a);
         self.inst = format!("{}{}", self.inst, self.gen_asm().set("al"));
         self.inst = format!("{}{}", self.inst, self.gen_asm().movz("al", "rax"));
         self.inst = format!("{}{}", self.inst, self.gen_asm().push("rax"));
@@ -772,7 +1574,13 @@ impl<'a> Asm<'a> {
     fn generate_unminus(&mut self, a: &AstType) {
         self.generate(a);
         self.inst = format!("{}{}", self.inst, self.gen_asm().pop("rax"));
-        self.inst = format!("{}{}", self.inst, self.gen_asm().neg("rax"));
+        if a.type_of().is_float() {
+            // 浮動小数点数は符号ビットを反転
+            self.inst = format!("{}{}", self.inst, self.gen_asm().mov_imm("rcx", i64::MIN));
+            self.inst = format!("{}{}", self.inst, self.gen_asm().bit_xor());
+        } else {
+            self.inst = format!("{}{}", self.inst, self.gen_asm().neg("rax"));
+        }
         self.inst = format!("{}{}", self.inst, self.gen_asm().push("rax"));
     }
 
@@ -787,8 +1595,20 @@ impl<'a> Asm<'a> {
         let label_end = self.label.next_label();
 
         self.generate(a);
+        if let AstType::Temporary() = *b {
+            // 中間項省略時は、スタック上の条件式の評価結果をそのまま結果とする
+            self.inst = format!("{}{}", self.inst, self.gen_asm().mov_src("rsp", "rax", 0));
+            self.generate_truth_flag(&a.type_of());
+            self.generate_je_inst(label_false);
+            self.generate_jmp_inst(label_end);
+            self.generate_label_inst(label_false);
+            self.inst = format!("{}{}", self.inst, self.gen_asm().pop("rax"));
+            self.generate(c);
+            self.generate_label_inst(label_end);
+            return;
+        }
         self.inst = format!("{}{}", self.inst, self.gen_asm().pop("rax"));
-        self.generate_cmp_inst(0, "rax");
+        self.generate_truth_flag(&a.type_of());
         self.generate_je_inst(label_false);
 
         self.generate(b);
@@ -801,45 +1621,34 @@ impl<'a> Asm<'a> {
 
     // &&演算子生成.
     fn generate_logical_and(&mut self, a: &AstType, b: &AstType) {
-        let label_false = self.label.next_label();
         let label_end = self.label.next_label();
 
-        self.generate(a);
-        self.inst = format!("{}{}", self.inst, self.gen_asm().pop("rax"));
-        self.generate_cmp_inst(0, "rax");
-        self.generate_je_inst(label_false);
-        self.generate(b);
-        self.inst = format!("{}{}", self.inst, self.gen_asm().pop("rax"));
-        self.generate_cmp_inst(0, "rax");
-        self.generate_je_inst(label_false);
+        self.generate_condition_test(a);
+        self.generate_je_inst(label_end);
+        self.generate_condition_test(b);
 
-        self.inst = format!("{}{}", self.inst, self.gen_asm().mov_imm("rax", 1));
-        self.generate_jmp_inst(label_end);
-        self.generate_label_inst(label_false);
-        self.inst = format!("{}{}", self.inst, self.gen_asm().mov_imm("rax", 0));
+        // 合流点で、直前の判定結果を0/1へ正規化
         self.generate_label_inst(label_end);
-        self.inst = format!("{}{}", self.inst, self.gen_asm().push("rax"));
+        self.generate_truth_value();
     }
 
     // ||演算子生成.
     fn generate_logical_or(&mut self, a: &AstType, b: &AstType) {
-        let label_true = self.label.next_label();
         let label_end = self.label.next_label();
 
-        self.generate(a);
-        self.inst = format!("{}{}", self.inst, self.gen_asm().pop("rax"));
-        self.generate_cmp_inst(0, "rax");
-        self.generate_jne_inst(label_true);
-        self.generate(b);
-        self.inst = format!("{}{}", self.inst, self.gen_asm().pop("rax"));
-        self.generate_cmp_inst(0, "rax");
-        self.generate_jne_inst(label_true);
+        self.generate_condition_test(a);
+        self.generate_jne_inst(label_end);
+        self.generate_condition_test(b);
 
-        self.inst = format!("{}{}", self.inst, self.gen_asm().mov_imm("rax", 0));
-        self.generate_jmp_inst(label_end);
-        self.generate_label_inst(label_true);
-        self.inst = format!("{}{}", self.inst, self.gen_asm().mov_imm("rax", 1));
+        // 合流点で、直前の判定結果を0/1へ正規化
         self.generate_label_inst(label_end);
+        self.generate_truth_value();
+    }
+
+    // ゼロフラグが示す真偽を、0/1としてスタックへ積む
+    fn generate_truth_value(&mut self) {
+        self.inst = format!("{}{}", self.inst, self.gen_asm().setne("al"));
+        self.inst = format!("{}{}", self.inst, self.gen_asm().movz("al", "rax"));
         self.inst = format!("{}{}", self.inst, self.gen_asm().push("rax"));
     }
 
@@ -850,6 +1659,197 @@ impl<'a> Asm<'a> {
         self.inst = format!("{}{}", self.inst, self.gen_asm().push("rax"));
     }
 
+    // 浮動小数点数生成(ビット列を整数としてスタックへ積む).
+    fn generate_float_factor(&mut self, a: f64) {
+        self.inst = format!("{}{}", self.inst, self.gen_asm().mov_imm("rax", a.to_bits() as i64));
+        self.inst = format!("{}{}", self.inst, self.gen_asm().push("rax"));
+    }
+
+    // 浮動小数点数の四則演算生成.
+    //
+    // 値はビット列のままスタックへ積み、演算時にxmmレジスタへ転送する
+    fn generate_float_operator(&mut self, ope: &AstType, a: &AstType, b: &AstType) {
+        self.generate_float_operands(a, b);
+        let inst = match ope {
+            AstType::Plus(_, _) => self.gen_asm().addsd("xmm1", "xmm0"),
+            AstType::Minus(_, _) => self.gen_asm().subsd("xmm1", "xmm0"),
+            AstType::Multiple(_, _) => self.gen_asm().mulsd("xmm1", "xmm0"),
+            _ => self.gen_asm().divsd("xmm1", "xmm0"),
+        };
+        self.inst = format!("{}{}", self.inst, inst);
+        self.inst = format!("{}{}", self.inst, self.gen_asm().movq("xmm0", "rax"));
+        self.inst = format!("{}{}", self.inst, self.gen_asm().push("rax"));
+    }
+
+    // 浮動小数点数の比較演算子か
+    fn is_float_compare(ope: &AstType, a: &AstType, b: &AstType) -> bool {
+        let is_compare = matches!(
+            ope,
+            AstType::Equal(_, _)
+                | AstType::NotEqual(_, _)
+                | AstType::LessThan(_, _)
+                | AstType::GreaterThan(_, _)
+                | AstType::LessThanEqual(_, _)
+                | AstType::GreaterThanEqual(_, _)
+        );
+        is_compare && Asm::is_float_operands(a, b)
+    }
+
+    // 浮動小数点数の演算か(ポインタ演算は整数の演算とする)
+    fn is_float_operands(a: &AstType, b: &AstType) -> bool {
+        (a.type_of().is_float() || b.type_of().is_float()) && !a.is_pointer() && !b.is_pointer()
+    }
+
+    // 浮動小数点数の比較生成.
+    //
+    // NaNとの比較(unordered)は、!=のみ真となる
+    fn generate_float_compare(&mut self, ope: &AstType, a: &AstType, b: &AstType) {
+        self.generate_float_operands(a, b);
+        let inst = match ope {
+            AstType::Equal(_, _) => self.gen_asm().float_equal(),
+            AstType::NotEqual(_, _) => self.gen_asm().float_not_equal(),
+            AstType::LessThan(_, _) => self.gen_asm().float_less_than(),
+            AstType::GreaterThan(_, _) => self.gen_asm().float_greater_than(),
+            AstType::LessThanEqual(_, _) => self.gen_asm().float_less_than_equal(),
+            _ => self.gen_asm().float_greater_than_equal(),
+        };
+        self.inst = format!("{}{}", self.inst, inst);
+        self.inst = format!("{}{}", self.inst, self.gen_asm().push("rax"));
+    }
+
+    // 二項演算の両辺をdoubleへ変換し、左辺をxmm0、右辺をxmm1へ転送
+    fn generate_float_operands(&mut self, a: &AstType, b: &AstType) {
+        self.generate(a);
+        self.generate(b);
+        self.inst = format!("{}{}", self.inst, self.gen_asm().pop("rcx"));
+        self.generate_convert(&b.type_of(), &Type::Double, "rcx");
+        self.inst = format!("{}{}", self.inst, self.gen_asm().pop("rax"));
+        self.generate_convert(&a.type_of(), &Type::Double, "rax");
+        self.inst = format!("{}{}", self.inst, self.gen_asm().movq("rax", "xmm0"));
+        self.inst = format!("{}{}", self.inst, self.gen_asm().movq("rcx", "xmm1"));
+    }
+
+    // doubleの値を単精度へ変換(下位32bitへ格納し、上位はゼロ).
+    fn narrow_float(&self, r: &str) -> String {
+        format!(
+            "{}{}{}",
+            self.gen_asm().movq(r, "xmm0"),
+            self.gen_asm().cvtsd2ss("xmm0", "xmm0"),
+            self.gen_asm().movd("xmm0", &format!("e{}", &r[1..]))
+        )
+    }
+
+    // 下位32bitの単精度の値をdoubleへ変換.
+    fn widen_float(&self, r: &str) -> String {
+        format!(
+            "{}{}{}",
+            self.gen_asm().movd(&format!("e{}", &r[1..]), "xmm0"),
+            self.gen_asm().cvtss2sd("xmm0", "xmm0"),
+            self.gen_asm().movq("xmm0", r)
+        )
+    }
+
+    // float型の値をメモリから読み出し、doubleへ変換.
+    fn load_float(&self, src: &str, dst: &str) -> String {
+        format!("{}{}", self.gen_asm().movl_src(src, &format!("e{}", &dst[1..]), 0), self.widen_float(dst))
+    }
+
+    // doubleの値を単精度へ変換してメモリへ格納(値のレジスタは変更しない).
+    fn store_float(&self, src: &str, dst: &str) -> String {
+        format!(
+            "{}{}{}",
+            self.gen_asm().mov(src, "rsi"),
+            self.narrow_float("rsi"),
+            self.gen_asm().mov_dst("esi", dst, 0)
+        )
+    }
+
+    // 浮動小数点数の複合代入生成.
+    //
+    // 左辺の値と右辺をdoubleへ変換して演算し、左辺の型へ戻して格納する
+    fn generate_float_compound_assign(&mut self, ope: &AstType, a: &AstType, b: &AstType) {
+        self.generate_compound_lvalue(a);
+        self.generate(b);
+        self.inst = format!("{}{}", self.inst, self.gen_asm().pop("rdx"));
+        self.inst = format!("{}{}", self.inst, self.gen_asm().pop("rcx"));
+        self.generate_compound_load(a, "rcx");
+        self.generate_convert(&b.type_of(), &Type::Double, "rdx");
+        self.generate_convert(&a.type_of(), &Type::Double, "rax");
+        self.inst = format!("{}{}", self.inst, self.gen_asm().movq("rax", "xmm0"));
+        self.inst = format!("{}{}", self.inst, self.gen_asm().movq("rdx", "xmm1"));
+        let inst = match ope {
+            AstType::PlusAssign(_, _) => self.gen_asm().addsd("xmm1", "xmm0"),
+            AstType::MinusAssign(_, _) => self.gen_asm().subsd("xmm1", "xmm0"),
+            AstType::MultipleAssign(_, _) => self.gen_asm().mulsd("xmm1", "xmm0"),
+            _ => self.gen_asm().divsd("xmm1", "xmm0"),
+        };
+        self.inst = format!("{}{}", self.inst, inst);
+        self.inst = format!("{}{}", self.inst, self.gen_asm().movq("xmm0", "rax"));
+        self.generate_convert(&Type::Double, &a.type_of(), "rax");
+        self.generate_truncate(a, "al", "rax");
+        self.generate_compound_store(a, "rax", "rcx");
+    }
+
+    // raxの浮動小数点数へ1.0を加算(減算).
+    fn generate_float_step(&mut self, is_inc: bool) {
+        self.inst = format!("{}{}", self.inst, self.gen_asm().movq("rax", "xmm0"));
+        self.inst = format!("{}{}", self.inst, self.gen_asm().mov_imm("rdx", 1.0f64.to_bits() as i64));
+        self.inst = format!("{}{}", self.inst, self.gen_asm().movq("rdx", "xmm1"));
+        let inst = if is_inc {
+            self.gen_asm().addsd("xmm1", "xmm0")
+        } else {
+            self.gen_asm().subsd("xmm1", "xmm0")
+        };
+        self.inst = format!("{}{}", self.inst, inst);
+        self.inst = format!("{}{}", self.inst, self.gen_asm().movq("xmm0", "rax"));
+    }
+
+    // 整数、浮動小数点数間の型変換(変換結果は同じレジスタへ格納).
+    //
+    // 整数からの変換は、unsigned intはゼロ拡張、long以外は下位32bitを符号付きとして扱う
+    // floatの値はdoubleで保持し、floatへの変換時に単精度の精度へ丸める
+    fn generate_convert(&mut self, from: &Type, to: &Type, r: &str) {
+        if *to == Type::Float {
+            if *from != Type::Float {
+                self.generate_convert(from, &Type::Double, r);
+                self.inst = format!("{}{}{}", self.inst, self.narrow_float(r), self.widen_float(r));
+            }
+            return;
+        }
+        let from = if *from == Type::Float { &Type::Double } else { from };
+        let l = format!("e{}", &r[1..]);
+        let inst = match (from, to) {
+            (Type::Double, Type::Double) => return,
+            (Type::Double, _) => format!("{}{}", self.gen_asm().movq(r, "xmm0"), self.gen_asm().cvttsd2si("xmm0", r)),
+            (Type::Long, Type::Double) | (Type::ULong, Type::Double) => self.gen_asm().cvtsi2sd(r, "xmm0"),
+            (Type::UInt, Type::Double) => format!("{}{}", self.gen_asm().mov(&l, &l), self.gen_asm().cvtsi2sd(r, "xmm0")),
+            (_, Type::Double) => self.gen_asm().cvtsi2sd(&l, "xmm0"),
+            _ => return,
+        };
+        self.inst = format!("{}{}", self.inst, inst);
+        if *to == Type::Double {
+            self.inst = format!("{}{}", self.inst, self.gen_asm().movq("xmm0", r));
+        }
+    }
+
+    // 現在の関数の戻り値の型取得
+    fn func_return_type(&self) -> Type {
+        match self.cur_scope {
+            Scope::Local(ref n) => self.sym_table.search(&Scope::Func, n).map_or(Type::Int, |s| s.t),
+            _ => Type::Int,
+        }
+    }
+
+    // 現在の関数がポインタを返すか
+    fn is_func_return_pointer(&self) -> bool {
+        match self.cur_scope {
+            Scope::Local(ref n) => {
+                self.sym_table.search(&Scope::Func, n).is_some_and(|s| s.strt == Structure::Pointer)
+            }
+            _ => false,
+        }
+    }
+
     // sizeof演算子.
     fn generate_sizeof(&mut self, a: usize) {
         // 数値.
@@ -880,162 +1880,94 @@ impl<'a> Asm<'a> {
     fn generate_lvalue_address(&mut self, a: &AstType) {
         let (sym, name) = match *a {
             AstType::Variable(_, _, ref s) => (self.get_var_symbol(s), s),
-            _ => panic!(format!(
+            _ => panic!(
                 "asm.rs(generate_lvalue_address): Not Support AstType {:?}",
                 a
-            )),
+            ),
         };
 
+        if self.reg_var(a).is_some() {
+            panic!("{} {}: register variable has no address {:?}", file!(), line!(), a);
+        }
+
         // アドレスをraxレジスタへ転送
         self.inst = match sym.scope {
             Scope::Global => format!("{}{}", self.inst, self.gen_asm().lea_glb(name)),
-            _ => match sym.strt {
-                Structure::Array(_) => {
-                    format!("{}{}", self.inst, self.gen_asm().lea(sym.size as i64))
-                }
-                _ => format!("{}{}", self.inst, self.gen_asm().lea(sym.offset as i64 + 8))
-            }
+            _ => format!("{}{}", self.inst, self.gen_asm().lea(sym.offset as i64)),
         };
         self.inst = format!("{}{}", self.inst, self.gen_asm().push("rax"));
     }
 
     // 後置インクリメント
     fn generate_post_inc(&mut self, a: &AstType) {
-        self.generate_lvalue_address(a);
-
-        match *a {
-            AstType::Variable(_, ref s, _) => match s {
-                Structure::Identifier => {
-                    self.inst = format!("{}{}", self.inst, self.gen_asm().pop("rcx"));
-                    self.inst =
-                        format!("{}{}", self.inst, self.gen_asm().mov_src("rcx", "rax", 0));
-                    self.inst = format!("{}{}", self.inst, self.gen_asm().push("rax"));
-                    self.inst = format!("{}{}", self.inst, self.gen_asm().add_imm(1, "rax"));
-                    self.inst =
-                        format!("{}{}", self.inst, self.gen_asm().mov_dst("rax", "rcx", 0));
-                }
-                Structure::Pointer => {
-                    self.inst = format!("{}{}", self.inst, self.gen_asm().pop("rcx"));
-                    self.inst =
-                        format!("{}{}", self.inst, self.gen_asm().mov_src("rcx", "rax", 0));
-                    self.inst = format!("{}{}", self.inst, self.gen_asm().push("rax"));
-                    self.inst = format!("{}{}", self.inst, self.gen_asm().add_imm(8, "rax"));
-                    self.inst =
-                        format!("{}{}", self.inst, self.gen_asm().mov_dst("rax", "rcx", 0));
-                }
-                _ => panic!(format!("asm.rs(generate_post_inc): Not Support Structure {:?}", s)),
-            },
-            _ => panic!(format!("asm.rs(generate_post_inc): Not Support AstType {:?}",
-                a
-            )),
-        }
+        self.generate_step(a, true, true);
     }
 
     // 後置デクリメント
     fn generate_post_dec(&mut self, a: &AstType) {
-        self.generate_lvalue_address(a);
+        self.generate_step(a, false, true);
+    }
 
-        match *a {
-            AstType::Variable(_, ref s, _) => match s {
-                Structure::Identifier => {
-                    self.inst = format!("{}{}", self.inst, self.gen_asm().pop("rcx"));
-                    self.inst =
-                        format!("{}{}", self.inst, self.gen_asm().mov_src("rcx", "rax", 0));
-                    self.inst = format!("{}{}", self.inst, self.gen_asm().push("rax"));
-                    self.inst = format!("{}{}", self.inst, self.gen_asm().sub_imm(1, "rax"));
-                    self.inst =
-                        format!("{}{}", self.inst, self.gen_asm().mov_dst("rax", "rcx", 0));
-                }
-                Structure::Pointer => {
-                    self.inst = format!("{}{}", self.inst, self.gen_asm().pop("rcx"));
-                    self.inst =
-                        format!("{}{}", self.inst, self.gen_asm().mov_src("rcx", "rax", 0));
-                    self.inst = format!("{}{}", self.inst, self.gen_asm().push("rax"));
-                    self.inst = format!("{}{}", self.inst, self.gen_asm().sub_imm(8, "rax"));
-                    self.inst =
-                        format!("{}{}", self.inst, self.gen_asm().mov_dst("rax", "rcx", 0));
-                }
-                _ => panic!(format!(
-                    "asm.rs(generate_post_dec): Not Support Structure {:?}",
-                    s
-                )),
-            },
-            _ => panic!(format!(
-                "asm.rs(generate_post_dec): Not Support AstType {:?}",
-                a
-            )),
+    // レジスタへ割り当てた変数のインクリメント、デクリメント
+    fn generate_reg_step(&mut self, r: &str, is_inc: bool, is_post: bool) {
+        if is_post {
+            self.inst = format!("{}{}", self.inst, self.gen_asm().push(r));
+        }
+        let step = if is_inc { self.gen_asm().add_imm(1, r) } else { self.gen_asm().sub_imm(1, r) };
+        self.inst = format!("{}{}", self.inst, step);
+        if !is_post {
+            self.inst = format!("{}{}", self.inst, self.gen_asm().push(r));
         }
     }
 
+    // レジスタへ割り当てた変数の複合代入(演算結果の代入とする)
+    fn generate_reg_compound_assign(&mut self, ast: &AstType) {
+        let (a, ope) = match ast {
+            AstType::PlusAssign(a, b) => (a, AstType::Plus(a.clone(), b.clone())),
+            AstType::MinusAssign(a, b) => (a, AstType::Minus(a.clone(), b.clone())),
+            AstType::MultipleAssign(a, b) => (a, AstType::Multiple(a.clone(), b.clone())),
+            AstType::DivisionAssign(a, b) => (a, AstType::Division(a.clone(), b.clone())),
+            AstType::RemainderAssign(a, b) => (a, AstType::Remainder(a.clone(), b.clone())),
+            _ => panic!("{} {}: cannot support AstType {:?}", file!(), line!(), ast),
+        };
+        self.generate_assign(a, &ope);
+    }
+
     // 前置インクリメント
     fn generate_pre_inc(&mut self, a: &AstType) {
-        self.generate_lvalue_address(a);
-
-        match *a {
-            AstType::Variable(_, ref s, _) => match s {
-                Structure::Identifier => {
-                    self.inst = format!("{}{}", self.inst, self.gen_asm().pop("rcx"));
-                    self.inst =
-                        format!("{}{}", self.inst, self.gen_asm().mov_src("rcx", "rax", 0));
-                    self.inst = format!("{}{}", self.inst, self.gen_asm().add_imm(1, "rax"));
-                    self.inst =
-                        format!("{}{}", self.inst, self.gen_asm().mov_dst("rax", "rcx", 0));
-                    self.inst = format!("{}{}", self.inst, self.gen_asm().push("rax"));
-                }
-                Structure::Pointer => {
-                    self.inst = format!("{}{}", self.inst, self.gen_asm().pop("rcx"));
-                    self.inst =
-                        format!("{}{}", self.inst, self.gen_asm().mov_src("rcx", "rax", 0));
-                    self.inst = format!("{}{}", self.inst, self.gen_asm().add_imm(8, "rax"));
-                    self.inst =
-                        format!("{}{}", self.inst, self.gen_asm().mov_dst("rax", "rcx", 0));
-                    self.inst = format!("{}{}", self.inst, self.gen_asm().push("rax"));
-                }
-                _ => panic!(format!(
-                    "asm.rs(generate_pre_inc): Not Support Structure {:?}",
-                    s
-                )),
-            },
-            _ => panic!(format!(
-                "asm.rs(generate_pre_inc): Not Support AstType {:?}",
-                a
-            )),
-        }
+        self.generate_step(a, true, false);
     }
 
     // 前置デクリメント
     fn generate_pre_dec(&mut self, a: &AstType) {
-        self.generate_lvalue_address(a);
+        self.generate_step(a, false, false);
+    }
 
-        match *a {
-            AstType::Variable(_, ref s, _) => match s {
-                Structure::Identifier => {
-                    self.inst = format!("{}{}", self.inst, self.gen_asm().pop("rcx"));
-                    self.inst =
-                        format!("{}{}", self.inst, self.gen_asm().mov_src("rcx", "rax", 0));
-                    self.inst = format!("{}{}", self.inst, self.gen_asm().sub_imm(1, "rax"));
-                    self.inst =
-                        format!("{}{}", self.inst, self.gen_asm().mov_dst("rax", "rcx", 0));
-                    self.inst = format!("{}{}", self.inst, self.gen_asm().push("rax"));
-                }
-                Structure::Pointer => {
-                    self.inst = format!("{}{}", self.inst, self.gen_asm().pop("rcx"));
-                    self.inst =
-                        format!("{}{}", self.inst, self.gen_asm().mov_src("rcx", "rax", 0));
-                    self.inst = format!("{}{}", self.inst, self.gen_asm().sub_imm(8, "rax"));
-                    self.inst =
-                        format!("{}{}", self.inst, self.gen_asm().mov_dst("rax", "rcx", 0));
-                    self.inst = format!("{}{}", self.inst, self.gen_asm().push("rax"));
-                }
-                _ => panic!(format!(
-                    "asm.rs(generate_pre_dec): Not Support Structure {:?}",
-                    s
-                )),
-            },
-            _ => panic!(format!(
-                "asm.rs(generate_pre_dec): Not Support AstType {:?}",
-                a
-            )),
+    // インクリメント、デクリメント生成.
+    //
+    // 左辺の型のサイズで読み書きし(隣接する変数を破壊しない)、ポインタは要素サイズ分を増減する
+    fn generate_step(&mut self, a: &AstType, is_inc: bool, is_post: bool) {
+        if let Some((r, _)) = self.reg_var(a) {
+            return self.generate_reg_step(r, is_inc, is_post);
+        }
+        self.generate_compound_lvalue(a);
+        self.inst = format!("{}{}", self.inst, self.gen_asm().pop("rcx"));
+        self.generate_compound_load(a, "rcx");
+        if is_post {
+            self.inst = format!("{}{}", self.inst, self.gen_asm().push("rax"));
+        }
+        if a.type_of().is_float() && !a.is_pointer() {
+            self.generate_float_step(is_inc);
+        } else {
+            let n = if a.is_pointer() { self.element_size(a) } else { 1 };
+            let step = if is_inc { self.gen_asm().add_imm(n, "rax") } else { self.gen_asm().sub_imm(n, "rax") };
+            self.inst = format!("{}{}", self.inst, step);
+        }
+        if is_post {
+            self.generate_lvalue_store(a, "rax", "rcx");
+        } else {
+            self.generate_truncate(a, "al", "rax");
+            self.generate_compound_store(a, "rax", "rcx");
         }
     }
 
@@ -1044,7 +1976,13 @@ impl<'a> Asm<'a> {
         self.generate(a);
         self.generate(b);
         self.inst = format!("{}{}", self.inst, self.gen_asm().pop("rax"));
-        self.inst = format!("{}{}", self.inst, self.gen_asm().mov_imm("rcx", 8));
+        let size = self.element_size(a);
+        self.generate_pointer_offset(size);
+    }
+
+    // スタック上のアドレスへ、raxの要素数分のオフセットを加算
+    fn generate_pointer_offset(&mut self, size: usize) {
+        self.inst = format!("{}{}", self.inst, self.gen_asm().mov_imm("rcx", size as i64));
         self.inst = format!("{}{}", self.inst, self.gen_asm().mul("rcx"));
         self.inst = format!("{}{}", self.inst, self.gen_asm().pop("rcx"));
         self.inst = format!("{}{}", self.inst, self.gen_asm().add("rax", "rcx"));
@@ -1052,7 +1990,7 @@ impl<'a> Asm<'a> {
     }
 
     // variable同士の加算
-    fn generate_plus_variable(&mut self, a: &AstType, b: &AstType, s: &Structure) {
+    fn generate_plus_variable(&mut self, ope: &AstType, a: &AstType, b: &AstType, s: &Structure) {
         match s {
             Structure::Array(_) => self.generate_plus_with_pointer(a, b),
             _ => {
@@ -1062,20 +2000,26 @@ impl<'a> Asm<'a> {
                 // 加算処理
                 self.inst = format!("{}{}", self.inst, self.gen_asm().pop("rcx"));
                 self.inst = format!("{}{}", self.inst, self.gen_asm().pop("rax"));
-                self.inst = format!("{}{}", self.inst, self.gen_asm().plus());
+                self.inst = format!("{}{}", self.inst, self.arithmetic(ope, a, b));
+                self.generate_overflow_check(a, b);
+                self.inst = format!("{}{}", self.inst, self.int_extension(ope, a, b));
                 self.inst = format!("{}{}", self.inst, self.gen_asm().push("rax"));
             }
         }
     }
 
     // 加算
-    fn generate_plus(&mut self, a: &AstType, b: &AstType) {
+    fn generate_plus(&mut self, ope: &AstType, a: &AstType, b: &AstType) {
         match (a, b) {
             // ポインタ演算チェック
             (AstType::Variable(ref _t1, ref s1, _), _) if *s1 == Structure::Pointer => {
                 self.generate_plus_with_pointer(a, b)
             }
-            (AstType::Variable(ref _t1, ref s1, _), _) => self.generate_plus_variable(a, b, s1),
+            (AstType::Variable(ref _t1, ref s1, _), _) => self.generate_plus_variable(ope, a, b, s1),
+            (AstType::MemberAccess(_, _), _) if Asm::member_array_type(a).is_some() => {
+                self.generate_plus_with_pointer(a, b)
+            }
+            _ if a.is_pointer() && !b.is_pointer() => self.generate_plus_with_pointer(a, b),
             _ => {
                 self.generate(a);
                 self.generate(b);
@@ -1083,7 +2027,9 @@ impl<'a> Asm<'a> {
                 // 加算処理
                 self.inst = format!("{}{}", self.inst, self.gen_asm().pop("rcx"));
                 self.inst = format!("{}{}", self.inst, self.gen_asm().pop("rax"));
-                self.inst = format!("{}{}", self.inst, self.gen_asm().plus());
+                self.inst = format!("{}{}", self.inst, self.arithmetic(ope, a, b));
+                self.generate_overflow_check(a, b);
+                self.inst = format!("{}{}", self.inst, self.int_extension(ope, a, b));
                 self.inst = format!("{}{}", self.inst, self.gen_asm().push("rax"));
             }
         }
@@ -1094,26 +2040,32 @@ impl<'a> Asm<'a> {
         self.generate(a);
         self.generate(b);
         self.inst = format!("{}{}", self.inst, self.gen_asm().pop("rax"));
-        self.inst = format!("{}{}", self.inst, self.gen_asm().mov_imm("rcx", 8));
+        let size = self.element_size(a);
+        self.inst = format!("{}{}", self.inst, self.gen_asm().mov_imm("rcx", size as i64));
         self.inst = format!("{}{}", self.inst, self.gen_asm().mul("rcx"));
         self.inst = format!("{}{}", self.inst, self.gen_asm().pop("rcx"));
         self.inst = format!("{}{}", self.inst, self.gen_asm().sub("rax", "rcx"));
         self.inst = format!("{}{}", self.inst, self.gen_asm().push("rcx"));
     }
 
+    // ポインタ同士の差(アドレスの差を要素サイズで除算した要素数)
+    fn generate_pointer_difference(&mut self, a: &AstType, b: &AstType) {
+        self.generate(a);
+        self.generate(b);
+        self.inst = format!("{}{}", self.inst, self.gen_asm().pop("rcx"));
+        self.inst = format!("{}{}", self.inst, self.gen_asm().pop("rax"));
+        self.inst = format!("{}{}", self.inst, self.gen_asm().sub("rcx", "rax"));
+        let size = self.element_size(a);
+        self.inst = format!("{}{}", self.inst, self.gen_asm().mov_imm("rcx", size as i64));
+        self.inst = format!("{}{}", self.inst, self.gen_asm().division_q());
+        self.inst = format!("{}{}", self.inst, self.gen_asm().push("rax"));
+    }
+
     // 減算
-    fn generate_minus(&mut self, a: &AstType, b: &AstType) {
+    fn generate_minus(&mut self, ope: &AstType, a: &AstType, b: &AstType) {
         match (a, b) {
-            (AstType::Variable(ref _t1, ref s1, _), AstType::Variable(ref t2, _, _))
-                if *s1 == Structure::Pointer && (*t2 == Type::Int || *t2 == Type::Char) =>
-            {
-                self.generate_minus_with_pointer(a, b)
-            }
-            (AstType::Variable(ref _t1, ref s1, _), AstType::Factor(_))
-                if *s1 == Structure::Pointer =>
-            {
-                self.generate_minus_with_pointer(a, b)
-            }
+            _ if a.is_pointer() && b.is_pointer() => self.generate_pointer_difference(a, b),
+            _ if a.is_pointer() => self.generate_minus_with_pointer(a, b),
             _ => {
                 self.generate(a);
                 self.generate(b);
@@ -1121,21 +2073,80 @@ impl<'a> Asm<'a> {
                 // 減算処理
                 self.inst = format!("{}{}", self.inst, self.gen_asm().pop("rcx"));
                 self.inst = format!("{}{}", self.inst, self.gen_asm().pop("rax"));
-                self.inst = format!("{}{}", self.inst, self.gen_asm().minus());
+                self.inst = format!("{}{}", self.inst, self.arithmetic(ope, a, b));
+                self.generate_overflow_check(a, b);
+                self.inst = format!("{}{}", self.inst, self.int_extension(ope, a, b));
                 self.inst = format!("{}{}", self.inst, self.gen_asm().push("rax"));
             }
         }
     }
 
+    // オーバーフロー検査対象の演算か(-ftrapv指定時のint型演算).
+    fn is_overflow_check(&self, a: &AstType, b: &AstType) -> bool {
+        self.config.trapv
+            && [a, b]
+                .iter()
+                .all(|e| !e.is_pointer() && !matches!(e.type_of(), Type::Long | Type::ULong | Type::UInt))
+    }
+
+    // 複合代入がオーバーフロー検査対象か(代入先がint型の場合のみ).
+    fn is_overflow_check_assign(&self, a: &AstType, b: &AstType) -> bool {
+        a.type_of() == Type::Int && self.is_overflow_check(a, b)
+    }
+
+    // オーバーフロー検査生成.
+    //
+    // 直前の演算でオーバーフローした場合、abort呼び出しへジャンプする
+    fn generate_overflow_check(&mut self, a: &AstType, b: &AstType) {
+        if !self.is_overflow_check(a, b) {
+            return;
+        }
+        let no = self.get_trap_label();
+        self.inst = format!("{}{}", self.inst, self.gen_asm().jo(no));
+    }
+
+    // abort呼び出しラベル取得(全体で一つのラベルを共有する).
+    fn get_trap_label(&mut self) -> usize {
+        match self.trap_label {
+            Some(no) => no,
+            None => {
+                let no = self.label.next_label();
+                self.trap_label = Some(no);
+                no
+            }
+        }
+    }
+
     // 演算子生成.
     fn generate_operator(&mut self, ast: &AstType, a: &AstType, b: &AstType) {
+        if Asm::is_float_compare(ast, a, b) {
+            self.generate_float_compare(ast, a, b);
+            return;
+        }
+        if let Some(e) = Asm::zero_compare_operand(ast, a, b) {
+            self.generate_zero_compare(ast, e);
+            return;
+        }
+        if let Some(n) = Asm::unsigned_pow2_divisor(ast, b) {
+            self.generate_unsigned_pow2_division(ast, a, n);
+            return;
+        }
         self.generate(a);
         self.generate(b);
 
         // 各演算子評価.
         self.inst = format!("{}{}", self.inst, self.gen_asm().pop("rcx"));
         self.inst = format!("{}{}", self.inst, self.gen_asm().pop("rax"));
+        if Asm::is_int_compare(ast, a, b) {
+            // int幅の比較は、符号拡張したうえで比較する
+            self.inst = format!("{}{}", self.inst, self.gen_asm().movslq("ecx", "rcx"));
+            self.inst = format!("{}{}", self.inst, self.gen_asm().movslq("eax", "rax"));
+        }
         self.inst = format!("{}{}", self.inst, self.operator(ast));
+        if let AstType::Multiple(_, _) = ast {
+            self.generate_overflow_check(a, b);
+        }
+        self.inst = format!("{}{}", self.inst, self.int_extension(ast, a, b));
 
         // 演算子に応じて退避するレジスタを変更.
         match *ast {
@@ -1148,52 +2159,360 @@ impl<'a> Asm<'a> {
         }
     }
 
+    // unsigned int型の2のべき乗の定数による除算、剰余であれば、除数を返す.
+    fn unsigned_pow2_divisor(ope: &AstType, b: &AstType) -> Option<i64> {
+        match ope {
+            AstType::Division(_, _) | AstType::Remainder(_, _) if ope.type_of() == Type::UInt => {
+                b.eval_constant().filter(|n| *n > 0 && *n <= 1 << 31 && n.count_ones() == 1)
+            }
+            _ => None,
+        }
+    }
+
+    // 2のべき乗の定数による除算、剰余生成.
+    //
+    // 除算は論理右シフト、剰余はマスクで行う(unsigned int型のみ)
+    fn generate_unsigned_pow2_division(&mut self, ope: &AstType, a: &AstType, n: i64) {
+        self.generate(a);
+        self.inst = format!("{}{}", self.inst, self.gen_asm().pop("rax"));
+        let inst = match ope {
+            AstType::Division(_, _) => self.gen_asm().logical_right_shift_imm_l(n.trailing_zeros()),
+            _ => self.gen_asm().bit_and_imm_l(n - 1),
+        };
+        self.inst = format!("{}{}", self.inst, inst);
+        self.inst = format!("{}{}", self.inst, self.gen_asm().push("rax"));
+    }
+
+    // 定数0との等値比較であれば、比較対象の式を返す.
+    fn zero_compare_operand<'b>(ope: &AstType, a: &'b AstType, b: &'b AstType) -> Option<&'b AstType> {
+        match ope {
+            AstType::Equal(_, _) | AstType::NotEqual(_, _) => match (a.eval_constant(), b.eval_constant()) {
+                (_, Some(0)) => Some(a),
+                (Some(0), _) => Some(b),
+                _ => None,
+            },
+            _ => None,
+        }
+    }
+
+    // 定数0との等値比較生成.
+    //
+    // 定数をレジスタへ転送せず、testで比較する
+    fn generate_zero_compare(&mut self, ope: &AstType, e: &AstType) {
+        self.generate(e);
+        self.inst = format!("{}{}", self.inst, self.gen_asm().pop("rax"));
+        if Asm::is_int_compare(ope, e, &AstType::Factor(0)) {
+            self.inst = format!("{}{}", self.inst, self.gen_asm().movslq("eax", "rax"));
+        }
+        let cmp = match ope {
+            AstType::Equal(_, _) => self.gen_asm().equal_zero(),
+            _ => self.gen_asm().not_equal_zero(),
+        };
+        self.inst = format!("{}{}", self.inst, cmp);
+        self.inst = format!("{}{}", self.inst, self.gen_asm().push("rax"));
+    }
+
+    // int幅で比較する比較演算子か.
+    //
+    // char型は整数拡張によりintとして比較する.ポインタ、long型は64bitのまま比較する
+    fn is_int_compare(ope: &AstType, a: &AstType, b: &AstType) -> bool {
+        let is_int = |e: &AstType| {
+            let pointer = match e {
+                AstType::FuncCall(ref f, _) => f.is_pointer(),
+                _ => e.is_pointer(),
+            };
+            !pointer && !matches!(e.type_of(), Type::Long | Type::ULong)
+        };
+        match ope {
+            AstType::Equal(_, _)
+            | AstType::NotEqual(_, _)
+            | AstType::LessThan(_, _)
+            | AstType::GreaterThan(_, _)
+            | AstType::LessThanEqual(_, _)
+            | AstType::GreaterThanEqual(_, _) => is_int(a) && is_int(b),
+            _ => false,
+        }
+    }
+
+    // 符号なしの型で比較するか(ポインタは対象外).
+    fn is_unsigned_compare(a: &AstType, b: &AstType) -> bool {
+        !a.is_pointer()
+            && !b.is_pointer()
+            && matches!(AstType::promote(&a.type_of(), &b.type_of()), Type::UInt | Type::ULong)
+    }
+
+    // メンバーアクセス生成.
+    fn generate_member(&mut self, s: &AstType, m: &AstType) {
+        self.generate_member_address(s, m);
+        self.inst = format!("{}{}", self.inst, self.gen_asm().pop("rax"));
+
+        // メンバーの型に応じた転送サイズを考慮(配列、構造体はアドレスのまま)
+        let load = match m {
+            AstType::Variable(_, Structure::Pointer, _) => self.gen_asm().mov_src("rax", "rcx", 0),
+            AstType::Variable(_, Structure::Array(_), _) | AstType::Variable(_, Structure::Struct, _) => {
+                self.gen_asm().mov("rax", "rcx")
+            }
+            AstType::Variable(ref t @ Type::Char, _, _) | AstType::Variable(ref t @ Type::UChar, _, _) => {
+                self.load_byte(t, "rax", "rcx")
+            }
+            AstType::Variable(Type::Short, Structure::Identifier, _) => self.gen_asm().movswq_src("rax", "rcx", 0),
+            AstType::Variable(Type::UInt, Structure::Identifier, _) => self.gen_asm().movl_src("rax", "ecx", 0),
+            AstType::Variable(Type::Float, Structure::Identifier, _) => self.load_float("rax", "rcx"),
+            AstType::Variable(Type::Double, Structure::Identifier, _) => self.gen_asm().mov_src("rax", "rcx", 0),
+            AstType::Variable(ref t, Structure::BitField(width, bit), _) => {
+                self.load_bit_field(t, *width, *bit, "rax", "rcx")
+            }
+            _ => self.gen_asm().movslq_src("rax", "rcx", 0),
+        };
+        self.inst = format!("{}{}", self.inst, load);
+        self.inst = format!("{}{}", self.inst, self.gen_asm().push("rcx"));
+    }
+
+    // メンバーのアドレス生成.
+    //
+    // 構造体の先頭アドレスにメンバーのオフセットを加算する
+    fn generate_member_address(&mut self, s: &AstType, m: &AstType) {
+        match s {
+            // ポインタ経由の場合、ポインタの値が構造体の先頭アドレス
+            AstType::Indirect(ref p) => self.generate(p),
+            AstType::MemberAccess(ref s2, ref m2) => self.generate_member_address(s2, m2),
+            _ => self.generate_lvalue_address(s),
+        }
+
+        let offset = match (s.type_of(), m) {
+            (Type::Struct(ref tag), AstType::Variable(_, _, ref name)) => {
+                self.get_var_symbol(tag).member_offset(name)
+            }
+            _ => None,
+        };
+        match offset {
+            Some(0) => {}
+            Some(offset) => {
+                self.inst = format!("{}{}", self.inst, self.gen_asm().pop("rax"));
+                self.inst = format!("{}{}", self.inst, self.gen_asm().add_imm(offset, "rax"));
+                self.inst = format!("{}{}", self.inst, self.gen_asm().push("rax"));
+            }
+            None => panic!("{} {}: not exists member {:?}", file!(), line!(), m),
+        }
+    }
+
     // アドレス演算子.
     fn generate_address(&mut self, a: &AstType) {
         match *a {
-            AstType::Variable(ref _t, ref _s, ref a) => {
-                let sym = self.get_var_symbol(a);
-                let pos = sym.offset as i64 + 8;
-                self.inst = format!("{}{}", self.inst, self.gen_asm().lea(pos));
+            AstType::MemberAccess(ref s, ref m) => self.generate_member_address(s, m),
+            AstType::Variable(_, _, ref n) if self.is_func_symbol(n) => {
+                self.inst = format!("{}{}", self.inst, self.gen_asm().lea_glb(&self.generate_func_symbol(n)));
                 self.inst = format!("{}{}", self.inst, self.gen_asm().push("rax"));
             }
+            // グローバル変数はシンボル名から、ローカル変数はフレーム上の位置から求める
+            AstType::Variable(_, _, _) => self.generate_lvalue_address(a),
+            // &*pはpのアドレスそのもの(&a[n]は末尾の次の要素も指せるよう、添字検査しない)
+            AstType::Indirect(ref p) => self.generate(p),
             _ => panic!("{} {}: Not Support Ast {:?}", file!(), line!(), a),
         }
     }
 
+    // 複合リテラル.
+    fn generate_compound_literal(&mut self, a: &AstType, b: &[AstType]) {
+        // 各要素を初期化(初期化子が無い要素は0).
+        let count = match a {
+            AstType::Variable(_, Structure::Array(ref v), _) => v.iter().product::<usize>(),
+            _ => panic!("{} {}: Not Support Ast {:?}", file!(), line!(), a),
+        };
+        (0..count).for_each(|i| {
+            let elem = AstType::Indirect(Box::new(AstType::Plus(
+                Box::new(a.clone()),
+                Box::new(AstType::Factor(i as i64)),
+            )));
+            let init = b.get(i).cloned().unwrap_or(AstType::Factor(0));
+            self.generate_assign(&elem, &init);
+            self.inst = format!("{}{}", self.inst, self.gen_asm().pop("rax"));
+        });
+
+        // 先頭アドレスを結果とする.
+        self.generate(a);
+    }
+
+    // 配列要素のアドレス生成.
+    //
+    // -fbounds-check指定時は、要素数が既知の配列(ポインタは対象外)の添字を検査し、
+    // 範囲外の場合はabort呼び出しへジャンプする
+    fn generate_element_address(&mut self, p: &AstType) {
+        let (a, b, name) = match p {
+            AstType::Plus(ref a, ref b) if self.config.bounds_check => match **a {
+                AstType::Variable(_, Structure::Array(_), ref name) => (a, b, name),
+                _ => return self.generate(p),
+            },
+            _ => return self.generate(p),
+        };
+        let count = match self.get_var_symbol(name).strt {
+            Structure::Array(ref v) => v.iter().product::<usize>(),
+            ref s => panic!("{} {}: cannot support structure {:?}", file!(), line!(), s),
+        };
+
+        self.generate(a);
+        self.generate(b);
+        self.inst = format!("{}{}", self.inst, self.gen_asm().pop("rax"));
+
+        // 符号なしで比較し、負の添字も範囲外とする
+        let no = self.get_trap_label();
+        self.inst = format!("{}{}", self.inst, self.gen_asm().cmp_imm(count as i64, "rax"));
+        self.inst = format!("{}{}", self.inst, self.gen_asm().jae(no));
+        let size = self.element_size(a);
+        self.generate_pointer_offset(size);
+    }
+
+    // 配列、ポインタの要素サイズ(構造体は構造体のサイズ)
+    //
+    // 配列の要素は、型のサイズで詰めて配置している
+    fn element_size(&self, a: &AstType) -> usize {
+        match (Asm::member_array_type(a), a.type_of()) {
+            (Some(t), _) => Asm::scalar_size(&t),
+            (_, Type::Struct(ref tag)) => self.get_var_symbol(tag).element_size(),
+            (_, t) => Asm::scalar_size(&t),
+        }
+    }
+
+    // 配列のメンバー(もしくはその要素のアドレス)であれば、要素の型を取得
+    fn member_array_type(a: &AstType) -> Option<Type> {
+        match a {
+            AstType::MemberAccess(_, ref m) => match **m {
+                AstType::Variable(ref t, Structure::Array(_), _) => Some(t.clone()),
+                _ => None,
+            },
+            AstType::Plus(ref p, _) => Asm::member_array_type(p),
+            _ => None,
+        }
+    }
+
+    // 型のサイズ
+    fn scalar_size(t: &Type) -> usize {
+        match t {
+            Type::Char | Type::UChar => 1,
+            Type::Short => 2,
+            Type::Int | Type::UInt | Type::Float => 4,
+            _ => 8,
+        }
+    }
+
+    // 式の値の型(ポインタ、配列の値はアドレスのためlongとする)
+    fn value_type(a: &AstType) -> Type {
+        if a.is_pointer() {
+            Type::Long
+        } else {
+            a.type_of()
+        }
+    }
+
     // 間接演算子.
     fn generate_indirect(&mut self, a: &AstType) {
-        self.generate(a);
+        self.generate_element_address(a);
         self.inst = format!("{}{}", self.inst, self.gen_asm().pop("rax"));
-        self.inst = format!("{}{}", self.inst, self.gen_asm().mov_src("rax", "rcx", 0));
+
+        // 参照先の型のサイズで転送(ポインタへのポインタの参照先は64bit)
+        let load = match a.type_of() {
+            t @ Type::Char | t @ Type::UChar => self.load_byte(&t, "rax", "rcx"),
+            Type::Short => self.gen_asm().movswq_src("rax", "rcx", 0),
+            Type::Int => self.gen_asm().movslq_src("rax", "rcx", 0),
+            Type::UInt => self.gen_asm().movl_src("rax", "ecx", 0),
+            Type::Float => self.load_float("rax", "rcx"),
+            _ => self.gen_asm().mov_src("rax", "rcx", 0),
+        };
+        self.inst = format!("{}{}", self.inst, load);
         self.inst = format!("{}{}", self.inst, self.gen_asm().push("rcx"));
     }
 
     // 演算子アセンブラ生成.
     fn operator(&self, ope: &AstType) -> String {
         match *ope {
-            AstType::Multiple(_, _) => self.gen_asm().multiple(),
+            AstType::Multiple(ref a, ref b) => self.arithmetic(ope, a, b),
             AstType::Equal(_, _) => self.gen_asm().equal(),
             AstType::NotEqual(_, _) => self.gen_asm().not_equal(),
+            // 符号なしの型での比較は、符号なしの条件で判定する
+            AstType::LessThan(ref a, ref b) if Asm::is_unsigned_compare(a, b) => self.gen_asm().below(),
+            AstType::GreaterThan(ref a, ref b) if Asm::is_unsigned_compare(a, b) => self.gen_asm().above(),
+            AstType::LessThanEqual(ref a, ref b) if Asm::is_unsigned_compare(a, b) => self.gen_asm().below_equal(),
+            AstType::GreaterThanEqual(ref a, ref b) if Asm::is_unsigned_compare(a, b) => {
+                self.gen_asm().above_equal()
+            }
             AstType::LessThan(_, _) => self.gen_asm().less_than(),
             AstType::GreaterThan(_, _) => self.gen_asm().greater_than(),
             AstType::LessThanEqual(_, _) => self.gen_asm().less_than_equal(),
             AstType::GreaterThanEqual(_, _) => self.gen_asm().greater_than_equal(),
+            // シフト数は%clで指定する(CPUによりオペランド幅でマスクされる)
+            // int型は32bitでシフトし、結果を符号拡張する.符号付きのため右シフトは算術シフト
+            AstType::LeftShift(_, _) if ope.type_of() == Type::Int => {
+                format!("{}{}", self.gen_asm().left_shift_l(), self.gen_asm().cltq())
+            }
+            AstType::RightShift(_, _) if ope.type_of() == Type::Int => {
+                format!("{}{}", self.gen_asm().right_shift_l(), self.gen_asm().cltq())
+            }
+            // unsigned int型は32bit演算でゼロ拡張されるため、右シフトは論理シフト
+            AstType::LeftShift(_, _) if ope.type_of() == Type::UInt => self.gen_asm().left_shift_l(),
+            AstType::RightShift(_, _) if ope.type_of() == Type::UInt => {
+                self.gen_asm().logical_right_shift_l()
+            }
             AstType::LeftShift(_, _) => self.gen_asm().left_shift(),
             AstType::RightShift(_, _) => self.gen_asm().right_shift(),
             AstType::BitAnd(_, _) => self.gen_asm().bit_and(),
             AstType::BitOr(_, _) => self.gen_asm().bit_or(),
             AstType::BitXor(_, _) => self.gen_asm().bit_xor(),
-            AstType::Division(_, _) | AstType::Remainder(_, _) => self.gen_asm().bit_division(),
+            AstType::Division(ref a, ref b) | AstType::Remainder(ref a, ref b) => self.arithmetic(ope, a, b),
             _ => process::abort(),
         }
     }
 
+    // 64bitで演算する二項演算か(long型、ポインタ)
+    fn is_long_operation(ope: &AstType, a: &AstType, b: &AstType) -> bool {
+        a.is_pointer() || b.is_pointer() || matches!(ope.type_of(), Type::Long | Type::ULong)
+    }
+
+    // 算術演算子生成.
+    //
+    // int、unsigned int型は32bitレジスタで演算し、32bitで桁あふれさせる.long型、ポインタは64bitで演算する
+    fn arithmetic(&self, ope: &AstType, a: &AstType, b: &AstType) -> String {
+        let long = Asm::is_long_operation(ope, a, b);
+        match *ope {
+            AstType::Plus(_, _) if long => self.gen_asm().plus_q(),
+            AstType::Plus(_, _) => self.gen_asm().plus(),
+            AstType::Minus(_, _) if long => self.gen_asm().minus(),
+            AstType::Minus(_, _) => self.gen_asm().minus_l(),
+            AstType::Multiple(_, _) if long => self.gen_asm().multiple_q(),
+            AstType::Multiple(_, _) => self.gen_asm().multiple(),
+            AstType::Division(_, _) | AstType::Remainder(_, _) if long => self.gen_asm().division_q(),
+            AstType::Division(_, _) | AstType::Remainder(_, _) => self.division(&ope.type_of()),
+            _ => process::abort(),
+        }
+    }
+
+    // 型に応じた除算(商は%rax、剰余は%rdx)
+    fn division(&self, t: &Type) -> String {
+        match *t {
+            Type::Long | Type::ULong => self.gen_asm().division_q(),
+            Type::UInt => self.gen_asm().unsigned_division_l(),
+            _ => self.gen_asm().division_l(),
+        }
+    }
+
+    // int型の算術演算の結果を、64bitへ符号拡張(剰余は%rdx)
+    fn int_extension(&self, ope: &AstType, a: &AstType, b: &AstType) -> String {
+        if ope.type_of() != Type::Int || Asm::is_long_operation(ope, a, b) {
+            return "".to_string();
+        }
+        match *ope {
+            AstType::Plus(_, _) | AstType::Minus(_, _) | AstType::Multiple(_, _) | AstType::Division(_, _) => {
+                self.gen_asm().cltq()
+            }
+            AstType::Remainder(_, _) => self.gen_asm().movslq("edx", "rdx"),
+            _ => "".to_string(),
+        }
+    }
+
     // 文字列リテラル生成
     fn generate_string_literal(&mut self, a: &AstType) {
         match a {
             AstType::StringLiteral(s, i) => {
-                self.const_literal = format!("{}  .text\n", self.const_literal);
+                self.const_literal = format!("{}{}", self.const_literal, self.gen_asm().rodata_section());
                 self.const_literal = format!("{}.LC{}:\n", self.const_literal, i);
                 self.const_literal = format!("{}  .string \"{}\"\n", self.const_literal, s);
             }
@@ -1201,9 +2520,9 @@ impl<'a> Asm<'a> {
         }
     }
 
-    // 文字列リテラル命令
+    // 文字列リテラル命令(先頭文字のアドレスをスタックへ積む)
     fn generate_string(&mut self, _s: &str, i: usize) {
-        self.inst = format!("{}  movq $.LC{}, %rax\n", self.inst, i);
+        self.inst = format!("{}{}", self.inst, self.gen_asm().lea_glb(&format!(".LC{}", i)));
         self.inst = format!("{}{}", self.inst, self.gen_asm().push("rax"));
     }
 
@@ -1227,8 +2546,109 @@ impl<'a> Asm<'a> {
         self.inst = format!("{}{}", self.inst, self.gen_asm().jne(no));
     }
 
+    // 条件式の評価結果を、0以外で真となるようフラグへ反映.
+    fn generate_condition_test(&mut self, a: &AstType) {
+        self.generate(a);
+        self.inst = format!("{}{}", self.inst, self.gen_asm().pop("rax"));
+        self.generate_truth_flag(&a.type_of());
+    }
+
+    // 条件分岐生成.
+    //
+    // 条件の真偽がjump_ifと一致する場合、ラベルへジャンプする.
+    // 整数の比較演算子が条件の場合、比較結果を0/1にせず、フラグから直接分岐する
+    fn generate_branch(&mut self, a: &AstType, jump_if: bool, no: usize) {
+        let (l, r, cc) = match Asm::compare_condition(a) {
+            Some(c) => c,
+            None => {
+                self.generate_condition_test(a);
+                match jump_if {
+                    true => self.generate_jne_inst(no),
+                    false => self.generate_je_inst(no),
+                }
+                return;
+            }
+        };
+        self.generate(l);
+        self.generate(r);
+        self.inst = format!("{}{}", self.inst, self.gen_asm().pop("rcx"));
+        self.inst = format!("{}{}", self.inst, self.gen_asm().pop("rax"));
+        if Asm::is_int_compare(a, l, r) {
+            // int幅の比較は、符号拡張したうえで比較する
+            self.inst = format!("{}{}", self.inst, self.gen_asm().movslq("ecx", "rcx"));
+            self.inst = format!("{}{}", self.inst, self.gen_asm().movslq("eax", "rax"));
+        }
+        self.inst = format!("{}{}", self.inst, self.gen_asm().cmp("rcx", "rax"));
+        let cc = if jump_if { cc } else { Asm::negate_condition(cc) };
+        self.inst = format!("{}{}", self.inst, self.gen_asm().jcc(cc, no));
+    }
+
+    // 整数の比較演算子であれば、両辺と真となる条件コードを取得
+    fn compare_condition(a: &AstType) -> Option<(&AstType, &AstType, &'static str)> {
+        let (l, r) = match a {
+            AstType::Equal(ref l, ref r)
+            | AstType::NotEqual(ref l, ref r)
+            | AstType::LessThan(ref l, ref r)
+            | AstType::GreaterThan(ref l, ref r)
+            | AstType::LessThanEqual(ref l, ref r)
+            | AstType::GreaterThanEqual(ref l, ref r) => (l, r),
+            _ => return None,
+        };
+        if Asm::is_float_compare(a, l, r) {
+            return None;
+        }
+        let unsigned = Asm::is_unsigned_compare(l, r);
+        let cc = match a {
+            AstType::Equal(_, _) => "e",
+            AstType::NotEqual(_, _) => "ne",
+            AstType::LessThan(_, _) if unsigned => "b",
+            AstType::GreaterThan(_, _) if unsigned => "a",
+            AstType::LessThanEqual(_, _) if unsigned => "be",
+            AstType::GreaterThanEqual(_, _) if unsigned => "ae",
+            AstType::LessThan(_, _) => "l",
+            AstType::GreaterThan(_, _) => "g",
+            AstType::LessThanEqual(_, _) => "le",
+            _ => "ge",
+        };
+        Some((l, r, cc))
+    }
+
+    // 条件コードの否定
+    fn negate_condition(cc: &str) -> &'static str {
+        match cc {
+            "e" => "ne",
+            "ne" => "e",
+            "l" => "ge",
+            "ge" => "l",
+            "g" => "le",
+            "le" => "g",
+            "b" => "ae",
+            "ae" => "b",
+            "a" => "be",
+            "be" => "a",
+            _ => panic!("{} {}: cannot support condition {}", file!(), line!(), cc),
+        }
+    }
+
+    // raxの値の真偽をゼロフラグへ反映.
+    //
+    // 浮動小数点数は符号ビットを除いて判定し、-0.0も偽とする
+    fn generate_truth_flag(&mut self, t: &Type) {
+        if t.is_float() {
+            self.inst = format!("{}{}", self.inst, self.gen_asm().add("rax", "rax"));
+        } else {
+            self.generate_cmp_inst(0, "rax");
+        }
+    }
+
     // cmp命令生成.
+    //
+    // 0との比較はtestで行う
     fn generate_cmp_inst(&mut self, f: usize, r: &str) {
-        self.inst = format!("{}{}", self.inst, self.gen_asm().cmpl(f, r));
+        let cmp = match f {
+            0 => self.gen_asm().test(r),
+            _ => self.gen_asm().cmpl(f, r),
+        };
+        self.inst = format!("{}{}", self.inst, cmp);
     }
 }
@@ -1,3 +1,5 @@
+use config::{Config, Std};
+use diagnostic::Diagnostic;
 use std::collections::HashMap;
 use symbol::{Scope, Structure, Symbol, SymbolTable, Type};
 use token::{Token, TokenInfo};
@@ -6,6 +8,7 @@ use token::{Token, TokenInfo};
 pub enum AstType {
     Global(Vec<AstType>),
     FuncDef(Type, Structure, String, Box<AstType>, Box<AstType>),
+    FuncDecl(Type, Structure, String, Box<AstType>), // 関数宣言(定義は他の翻訳単位).
     Statement(Vec<AstType>),
     While(Box<AstType>, Box<AstType>), // 条件式、ブロック部.
     Do(Box<AstType>, Box<AstType>),    // ブロック部、条件式.
@@ -17,9 +20,16 @@ pub enum AstType {
         Box<AstType>,
     ), // 初期条件、終了条件、更新部、ブロック部.
     Continue(),
-    Break(),
+    Break(usize),
+    Switch(Box<AstType>, Box<AstType>), // 条件式、ブロック部.
+    Case(i64),
+    Default(),
+    Goto(String),  // goto文(ラベル名).
+    Label(String), // ラベル(ラベル名).
     Return(Box<AstType>),
     Condition(Box<AstType>, Box<AstType>, Box<AstType>),
+    Temporary(), // 条件式の評価結果(a ?: bの中間項).
+    Void(),      // 値なし(return;の戻り値).
     LogicalAnd(Box<AstType>, Box<AstType>),
     LogicalOr(Box<AstType>, Box<AstType>),
     BitAnd(Box<AstType>, Box<AstType>),
@@ -44,6 +54,7 @@ pub enum AstType {
     BitReverse(Box<AstType>),
     Assign(Box<AstType>, Box<AstType>),
     Factor(i64),
+    FloatFactor(f64),
     Variable(Type, Structure, String),
     FuncCall(Box<AstType>, Box<AstType>),
     Argment(Vec<AstType>),
@@ -54,6 +65,8 @@ pub enum AstType {
     PostInc(Box<AstType>),
     PostDec(Box<AstType>),
     StringLiteral(String, usize),
+    CompoundLiteral(Box<AstType>, Vec<AstType>), // 無名配列、初期化子.
+    ArrayInit(Box<AstType>, Vec<AstType>),       // 配列変数、要素ごとの初期化子.
     PlusAssign(Box<AstType>, Box<AstType>),
     MinusAssign(Box<AstType>, Box<AstType>),
     MultipleAssign(Box<AstType>, Box<AstType>),
@@ -61,20 +74,526 @@ pub enum AstType {
     RemainderAssign(Box<AstType>, Box<AstType>),
     SizeOf(usize),
     Struct(Box<AstType>, Vec<AstType>),
+    MemberAccess(Box<AstType>, Box<AstType>), // 構造体、メンバー.
+    Cast(Type, Box<AstType>),                 // キャスト(現状はvoidのみ).
+    StmtExpr(Box<AstType>),                   // 文式(GNU拡張).
 }
 
 impl AstType {
+    // 子ノード取得.
+    pub fn children(&self) -> Vec<&AstType> {
+        match self {
+            AstType::Global(ref v) | AstType::Statement(ref v) | AstType::Argment(ref v) => v.iter().collect(),
+            AstType::FuncDef(_, _, _, ref a, ref b) => vec![a, b],
+            AstType::If(ref a, ref b, ref c) => {
+                let mut v = vec![a.as_ref(), b.as_ref()];
+                v.extend(c.as_ref().as_ref());
+                v
+            }
+            AstType::For(ref a, ref b, ref c, ref d) => {
+                let mut v: Vec<&AstType> = vec![];
+                v.extend(a.as_ref().as_ref());
+                v.extend(b.as_ref().as_ref());
+                v.extend(c.as_ref().as_ref());
+                v.push(d);
+                v
+            }
+            AstType::Condition(ref a, ref b, ref c) => vec![a, b, c],
+            AstType::CompoundLiteral(ref a, ref v)
+            | AstType::ArrayInit(ref a, ref v)
+            | AstType::Struct(ref a, ref v) => {
+                let mut c = vec![a.as_ref()];
+                c.extend(v.iter());
+                c
+            }
+            AstType::While(ref a, ref b)
+            | AstType::Do(ref a, ref b)
+            | AstType::Switch(ref a, ref b)
+            | AstType::LogicalAnd(ref a, ref b)
+            | AstType::LogicalOr(ref a, ref b)
+            | AstType::BitAnd(ref a, ref b)
+            | AstType::BitOr(ref a, ref b)
+            | AstType::BitXor(ref a, ref b)
+            | AstType::Equal(ref a, ref b)
+            | AstType::NotEqual(ref a, ref b)
+            | AstType::LessThan(ref a, ref b)
+            | AstType::GreaterThan(ref a, ref b)
+            | AstType::LessThanEqual(ref a, ref b)
+            | AstType::GreaterThanEqual(ref a, ref b)
+            | AstType::Plus(ref a, ref b)
+            | AstType::Minus(ref a, ref b)
+            | AstType::LeftShift(ref a, ref b)
+            | AstType::RightShift(ref a, ref b)
+            | AstType::Multiple(ref a, ref b)
+            | AstType::Division(ref a, ref b)
+            | AstType::Remainder(ref a, ref b)
+            | AstType::Assign(ref a, ref b)
+            | AstType::FuncCall(ref a, ref b)
+            | AstType::PlusAssign(ref a, ref b)
+            | AstType::MinusAssign(ref a, ref b)
+            | AstType::MultipleAssign(ref a, ref b)
+            | AstType::DivisionAssign(ref a, ref b)
+            | AstType::RemainderAssign(ref a, ref b)
+            | AstType::MemberAccess(ref a, ref b) => vec![a, b],
+            AstType::Return(ref a)
+            | AstType::UnPlus(ref a)
+            | AstType::UnMinus(ref a)
+            | AstType::Not(ref a)
+            | AstType::BitReverse(ref a)
+            | AstType::Address(ref a)
+            | AstType::Indirect(ref a)
+            | AstType::PreInc(ref a)
+            | AstType::PreDec(ref a)
+            | AstType::PostInc(ref a)
+            | AstType::PostDec(ref a)
+            | AstType::Cast(_, ref a)
+            | AstType::StmtExpr(ref a) => vec![a],
+            AstType::Continue()
+            | AstType::Break(_)
+            | AstType::Case(_)
+            | AstType::Default()
+            | AstType::Goto(_)
+            | AstType::Label(_)
+            | AstType::Temporary()
+            | AstType::Void()
+            | AstType::Factor(_)
+            | AstType::FloatFactor(_)
+            | AstType::Variable(_, _, _)
+            | AstType::StringLiteral(_, _)
+            | AstType::FuncDecl(_, _, _, _)
+            | AstType::SizeOf(_) => vec![],
+        }
+    }
+
+    // 子ノード取得(変更可能).
+    pub fn children_mut(&mut self) -> Vec<&mut AstType> {
+        match self {
+            AstType::Global(ref mut v) | AstType::Statement(ref mut v) | AstType::Argment(ref mut v) => v.iter_mut().collect(),
+            AstType::FuncDef(_, _, _, ref mut a, ref mut b) => vec![a.as_mut(), b.as_mut()],
+            AstType::If(ref mut a, ref mut b, ref mut c) => {
+                let mut v = vec![a.as_mut(), b.as_mut()];
+                v.extend(c.as_mut().as_mut());
+                v
+            }
+            AstType::For(ref mut a, ref mut b, ref mut c, ref mut d) => {
+                let mut v: Vec<&mut AstType> = vec![];
+                v.extend(a.as_mut().as_mut());
+                v.extend(b.as_mut().as_mut());
+                v.extend(c.as_mut().as_mut());
+                v.push(d.as_mut());
+                v
+            }
+            AstType::Condition(ref mut a, ref mut b, ref mut c) => vec![a.as_mut(), b.as_mut(), c.as_mut()],
+            AstType::CompoundLiteral(ref mut a, ref mut v)
+            | AstType::ArrayInit(ref mut a, ref mut v)
+            | AstType::Struct(ref mut a, ref mut v) => {
+                let mut c = vec![a.as_mut()];
+                c.extend(v.iter_mut());
+                c
+            }
+            AstType::While(ref mut a, ref mut b)
+            | AstType::Do(ref mut a, ref mut b)
+            | AstType::Switch(ref mut a, ref mut b)
+            | AstType::LogicalAnd(ref mut a, ref mut b)
+            | AstType::LogicalOr(ref mut a, ref mut b)
+            | AstType::BitAnd(ref mut a, ref mut b)
+            | AstType::BitOr(ref mut a, ref mut b)
+            | AstType::BitXor(ref mut a, ref mut b)
+            | AstType::Equal(ref mut a, ref mut b)
+            | AstType::NotEqual(ref mut a, ref mut b)
+            | AstType::LessThan(ref mut a, ref mut b)
+            | AstType::GreaterThan(ref mut a, ref mut b)
+            | AstType::LessThanEqual(ref mut a, ref mut b)
+            | AstType::GreaterThanEqual(ref mut a, ref mut b)
+            | AstType::Plus(ref mut a, ref mut b)
+            | AstType::Minus(ref mut a, ref mut b)
+            | AstType::LeftShift(ref mut a, ref mut b)
+            | AstType::RightShift(ref mut a, ref mut b)
+            | AstType::Multiple(ref mut a, ref mut b)
+            | AstType::Division(ref mut a, ref mut b)
+            | AstType::Remainder(ref mut a, ref mut b)
+            | AstType::Assign(ref mut a, ref mut b)
+            | AstType::FuncCall(ref mut a, ref mut b)
+            | AstType::PlusAssign(ref mut a, ref mut b)
+            | AstType::MinusAssign(ref mut a, ref mut b)
+            | AstType::MultipleAssign(ref mut a, ref mut b)
+            | AstType::DivisionAssign(ref mut a, ref mut b)
+            | AstType::RemainderAssign(ref mut a, ref mut b)
+            | AstType::MemberAccess(ref mut a, ref mut b) => vec![a.as_mut(), b.as_mut()],
+            AstType::Return(ref mut a)
+            | AstType::UnPlus(ref mut a)
+            | AstType::UnMinus(ref mut a)
+            | AstType::Not(ref mut a)
+            | AstType::BitReverse(ref mut a)
+            | AstType::Address(ref mut a)
+            | AstType::Indirect(ref mut a)
+            | AstType::PreInc(ref mut a)
+            | AstType::PreDec(ref mut a)
+            | AstType::PostInc(ref mut a)
+            | AstType::PostDec(ref mut a)
+            | AstType::Cast(_, ref mut a)
+            | AstType::StmtExpr(ref mut a) => vec![a.as_mut()],
+            AstType::Continue()
+            | AstType::Break(_)
+            | AstType::Case(_)
+            | AstType::Default()
+            | AstType::Goto(_)
+            | AstType::Label(_)
+            | AstType::Temporary()
+            | AstType::Void()
+            | AstType::Factor(_)
+            | AstType::FloatFactor(_)
+            | AstType::Variable(_, _, _)
+            | AstType::StringLiteral(_, _)
+            | AstType::FuncDecl(_, _, _, _)
+            | AstType::SizeOf(_) => vec![],
+        }
+    }
+
     // 式判定.
     pub fn is_expr(&self) -> bool {
-        match self {
+        !matches!(
+            self,
             AstType::If(_, _, _)
-            | AstType::For(_, _, _, _)
-            | AstType::Do(_, _)
-            | AstType::Continue()
-            | AstType::Break()
-            | AstType::Return(_)
-            | AstType::While(_, _) => false,
-            _ => true,
+                | AstType::For(_, _, _, _)
+                | AstType::Do(_, _)
+                | AstType::Continue()
+                | AstType::Break(_)
+                | AstType::Return(_)
+                | AstType::While(_, _)
+                | AstType::Switch(_, _)
+                | AstType::Case(_)
+                | AstType::Default()
+                | AstType::Goto(_)
+                | AstType::Label(_)
+                | AstType::Void()
+                | AstType::FuncDecl(_, _, _, _)
+                | AstType::Struct(_, _)
+        )
+    }
+
+    // 式の型を取得.
+    //
+    // 算術演算の結果は、intより小さい型をintへ拡張する（整数拡張）
+    pub fn type_of(&self) -> Type {
+        match self {
+            AstType::Variable(ref t, _, _) => t.clone(),
+            AstType::FloatFactor(_) => Type::Double,
+            AstType::Cast(ref t, _) => t.clone(),
+            // sizeofの結果はsize_t(unsigned long)
+            AstType::SizeOf(_) => Type::ULong,
+            AstType::StmtExpr(ref a) => match **a {
+                AstType::Statement(ref v) => v.last().filter(|l| l.is_expr()).map_or(Type::Void, |l| l.type_of()),
+                _ => Type::Void,
+            },
+            // ポインタへのポインタの参照結果は、ポインタの参照先の型
+            AstType::Indirect(ref a) => match a.type_of() {
+                Type::Pointer(t) => *t,
+                t => t,
+            },
+            AstType::FuncCall(ref a, _) => a.type_of(),
+            AstType::CompoundLiteral(ref a, _) => a.type_of(),
+            AstType::MemberAccess(_, ref m) => m.type_of(),
+            AstType::Assign(ref a, _)
+            | AstType::PlusAssign(ref a, _)
+            | AstType::MinusAssign(ref a, _)
+            | AstType::MultipleAssign(ref a, _)
+            | AstType::DivisionAssign(ref a, _)
+            | AstType::RemainderAssign(ref a, _)
+            | AstType::PreInc(ref a)
+            | AstType::PreDec(ref a)
+            | AstType::PostInc(ref a)
+            | AstType::PostDec(ref a) => a.type_of(),
+            // ポインタ同士の減算は要素数の差(ptrdiff_t)となる
+            AstType::Minus(ref a, ref b) if a.is_pointer() && b.is_pointer() => Type::Long,
+            // ポインタ演算の場合は、ポインタの型を引き継ぐ
+            AstType::Plus(ref a, _) | AstType::Minus(ref a, _) if a.is_pointer() => a.type_of(),
+            AstType::Plus(ref a, ref b)
+            | AstType::Minus(ref a, ref b)
+            | AstType::Multiple(ref a, ref b)
+            | AstType::Division(ref a, ref b)
+            | AstType::Remainder(ref a, ref b)
+            | AstType::BitAnd(ref a, ref b)
+            | AstType::BitOr(ref a, ref b)
+            | AstType::BitXor(ref a, ref b) => {
+                AstType::promote(&a.type_of(), &b.type_of())
+            }
+            AstType::LeftShift(ref a, _)
+            | AstType::RightShift(ref a, _)
+            | AstType::UnPlus(ref a)
+            | AstType::UnMinus(ref a)
+            | AstType::BitReverse(ref a) => AstType::promote(&a.type_of(), &Type::Int),
+            AstType::Condition(_, ref a, ref b) => AstType::promote(&a.type_of(), &b.type_of()),
+            _ => Type::Int,
+        }
+    }
+
+    // 整数定数であるか
+    pub fn is_constant(&self) -> bool {
+        self.eval_constant().is_some()
+    }
+
+    // 整数定数の値を取得
+    pub fn eval_constant(&self) -> Option<i64> {
+        match self {
+            AstType::Factor(n) => Some(*n),
+            AstType::UnPlus(ref a) => a.eval_constant(),
+            AstType::UnMinus(ref a) => a.eval_constant().map(|n| -n),
+            AstType::BitReverse(ref a) => a.eval_constant().map(|n| !n),
+            AstType::Plus(ref a, ref b) => AstType::eval_binary(a, b, i64::checked_add),
+            AstType::Minus(ref a, ref b) => AstType::eval_binary(a, b, i64::checked_sub),
+            AstType::Multiple(ref a, ref b) => AstType::eval_binary(a, b, i64::checked_mul),
+            AstType::Division(ref a, ref b) => AstType::eval_binary(a, b, i64::checked_div),
+            AstType::Remainder(ref a, ref b) => AstType::eval_binary(a, b, i64::checked_rem),
+            AstType::BitAnd(ref a, ref b) => AstType::eval_binary(a, b, |x, y| Some(x & y)),
+            AstType::BitOr(ref a, ref b) => AstType::eval_binary(a, b, |x, y| Some(x | y)),
+            AstType::BitXor(ref a, ref b) => AstType::eval_binary(a, b, |x, y| Some(x ^ y)),
+            // シフト数が負、または64以上の場合は定数とみなさない
+            AstType::LeftShift(ref a, ref b) => {
+                AstType::eval_binary(a, b, |x, y| Some(y).filter(|y| (0..64).contains(y)).and_then(|y| x.checked_shl(y as u32)))
+            }
+            AstType::RightShift(ref a, ref b) => {
+                AstType::eval_binary(a, b, |x, y| Some(y).filter(|y| (0..64).contains(y)).and_then(|y| x.checked_shr(y as u32)))
+            }
+            AstType::Equal(ref a, ref b) => AstType::eval_binary(a, b, |x, y| Some((x == y) as i64)),
+            AstType::NotEqual(ref a, ref b) => AstType::eval_binary(a, b, |x, y| Some((x != y) as i64)),
+            AstType::LessThan(ref a, ref b) => AstType::eval_binary(a, b, |x, y| Some((x < y) as i64)),
+            AstType::GreaterThan(ref a, ref b) => AstType::eval_binary(a, b, |x, y| Some((x > y) as i64)),
+            AstType::LessThanEqual(ref a, ref b) => AstType::eval_binary(a, b, |x, y| Some((x <= y) as i64)),
+            AstType::GreaterThanEqual(ref a, ref b) => AstType::eval_binary(a, b, |x, y| Some((x >= y) as i64)),
+            AstType::LogicalAnd(ref a, ref b) => AstType::eval_binary(a, b, |x, y| Some((x != 0 && y != 0) as i64)),
+            AstType::LogicalOr(ref a, ref b) => AstType::eval_binary(a, b, |x, y| Some((x != 0 || y != 0) as i64)),
+            AstType::Not(ref a) => a.eval_constant().map(|n| (n == 0) as i64),
+            // 条件演算子は、全ての項が定数の場合のみ選択した項の値とする
+            AstType::Condition(ref c, ref a, ref b) => {
+                let (a, b) = (a.eval_constant()?, b.eval_constant()?);
+                c.eval_constant().map(|c| if c != 0 { a } else { b })
+            }
+            _ => None,
+        }
+    }
+
+    // 二項演算の定数評価(オーバーフロー、0除算の場合は定数とみなさない)
+    fn eval_binary(a: &AstType, b: &AstType, f: fn(i64, i64) -> Option<i64>) -> Option<i64> {
+        f(a.eval_constant()?, b.eval_constant()?)
+    }
+
+    // 比較、等価演算(結果が0/1)の式であるか
+    pub fn is_comparison(&self) -> bool {
+        matches!(
+            self,
+            AstType::Equal(_, _)
+                | AstType::NotEqual(_, _)
+                | AstType::LessThan(_, _)
+                | AstType::GreaterThan(_, _)
+                | AstType::LessThanEqual(_, _)
+                | AstType::GreaterThanEqual(_, _)
+        )
+    }
+
+    // ポインタ（配列）型の式であるか
+    pub fn is_pointer(&self) -> bool {
+        match self {
+            AstType::Variable(_, ref s, _) => matches!(s, Structure::Pointer | Structure::Array(_)),
+            AstType::Address(_) | AstType::StringLiteral(_, _) | AstType::CompoundLiteral(_, _) => true,
+            AstType::Plus(ref a, _) => a.is_pointer(),
+            AstType::Minus(ref a, ref b) => a.is_pointer() && !b.is_pointer(),
+            AstType::MemberAccess(_, ref m) => m.is_pointer(),
+            AstType::Indirect(ref a) => matches!(a.type_of(), Type::Pointer(_)),
+            _ => false,
+        }
+    }
+
+    // ポインタ（配列）型の式が指す要素の型
+    pub fn pointee_type(&self) -> Option<Type> {
+        match self {
+            AstType::Variable(ref t, ref s, _) if matches!(s, Structure::Pointer | Structure::Array(_)) => {
+                Some(t.clone())
+            }
+            AstType::Address(ref a) => Some(a.type_of()),
+            AstType::StringLiteral(_, _) => Some(Type::Char),
+            AstType::Plus(ref a, _) | AstType::Minus(ref a, _) if self.is_pointer() => a.pointee_type(),
+            AstType::MemberAccess(_, ref m) => m.pointee_type(),
+            AstType::Indirect(_) if self.is_pointer() => Some(self.type_of()),
+            _ => None,
+        }
+    }
+
+    // 末尾まで到達せず、必ずreturnするか.
+    //
+    // 条件が常に真でbreakを含まないループも、末尾へは到達しないとみなす
+    pub fn always_returns(&self) -> bool {
+        match self {
+            AstType::Return(_) => true,
+            // ラベル以降は、gotoで到達しうるため、最後のラベル以降で判定する
+            AstType::Statement(ref v) => v
+                .iter()
+                .rev()
+                .take_while(|s| !matches!(s, AstType::Label(_)))
+                .any(|s| s.always_returns()),
+            AstType::If(_, ref a, ref b) => {
+                a.always_returns() && b.as_ref().as_ref().is_some_and(|e| e.always_returns())
+            }
+            AstType::Do(ref a, _) => a.always_returns(),
+            AstType::While(ref c, ref a) if c.eval_constant().is_some_and(|n| n != 0) => !a.contains_break(),
+            AstType::For(_, ref c, _, ref a) if c.is_none() => !a.contains_break(),
+            _ => false,
+        }
+    }
+
+    // break文を含むか
+    fn contains_break(&self) -> bool {
+        matches!(self, AstType::Break(_)) || self.children().iter().any(|c| c.contains_break())
+    }
+
+    // 含まれるラベル名を、出現順に取得
+    pub fn labels(&self) -> Vec<&str> {
+        match self {
+            AstType::Label(ref n) => vec![n.as_str()],
+            _ => self.children().iter().flat_map(|c| c.labels()).collect(),
+        }
+    }
+
+    // 含まれるgoto文のラベル名を、出現順に取得
+    pub fn gotos(&self) -> Vec<&str> {
+        match self {
+            AstType::Goto(ref n) => vec![n.as_str()],
+            _ => self.children().iter().flat_map(|c| c.gotos()).collect(),
+        }
+    }
+
+    // JSON文字列作成.
+    //
+    // 各ノードはkindにバリアント名を持ち、フィールドは名前付きで出力する
+    pub fn to_json(&self) -> String {
+        let node = |e: &AstType| e.to_json();
+        let opt = |e: &Option<AstType>| e.as_ref().map_or("null".to_string(), |e| e.to_json());
+        let string = |s: &str| AstType::json_string(s);
+        let debug = |d: &dyn std::fmt::Debug| AstType::json_string(&format!("{:?}", d));
+        let bin = |a: &AstType, b: &AstType| vec![("left", node(a)), ("right", node(b))];
+        let un = |a: &AstType| vec![("operand", node(a))];
+        let (kind, fields) = match self {
+            AstType::Global(ref v) => ("Global", vec![("children", AstType::json_list(v))]),
+            AstType::FuncDef(ref t, ref s, ref n, ref a, ref b) => (
+                "FuncDef",
+                vec![("type", debug(t)), ("structure", debug(s)), ("name", string(n)), ("args", node(a)), ("body", node(b))],
+            ),
+            AstType::FuncDecl(ref t, ref s, ref n, ref a) => (
+                "FuncDecl",
+                vec![("type", debug(t)), ("structure", debug(s)), ("name", string(n)), ("args", node(a))],
+            ),
+            AstType::Statement(ref v) => ("Statement", vec![("children", AstType::json_list(v))]),
+            AstType::While(ref a, ref b) => ("While", vec![("condition", node(a)), ("body", node(b))]),
+            AstType::Do(ref a, ref b) => ("Do", vec![("body", node(a)), ("condition", node(b))]),
+            AstType::If(ref a, ref b, ref c) => ("If", vec![("condition", node(a)), ("then", node(b)), ("else", opt(c))]),
+            AstType::For(ref a, ref b, ref c, ref d) => (
+                "For",
+                vec![("init", opt(a)), ("condition", opt(b)), ("update", opt(c)), ("body", node(d))],
+            ),
+            AstType::Continue() => ("Continue", vec![]),
+            AstType::Break(n) => ("Break", vec![("level", n.to_string())]),
+            AstType::Switch(ref a, ref b) => ("Switch", vec![("condition", node(a)), ("body", node(b))]),
+            AstType::Case(n) => ("Case", vec![("value", n.to_string())]),
+            AstType::Default() => ("Default", vec![]),
+            AstType::Goto(ref n) => ("Goto", vec![("label", string(n))]),
+            AstType::Label(ref n) => ("Label", vec![("name", string(n))]),
+            AstType::Return(ref a) => ("Return", vec![("value", node(a))]),
+            AstType::Condition(ref a, ref b, ref c) => {
+                ("Condition", vec![("condition", node(a)), ("then", node(b)), ("else", node(c))])
+            }
+            AstType::Temporary() => ("Temporary", vec![]),
+            AstType::Void() => ("Void", vec![]),
+            AstType::LogicalAnd(ref a, ref b) => ("LogicalAnd", bin(a, b)),
+            AstType::LogicalOr(ref a, ref b) => ("LogicalOr", bin(a, b)),
+            AstType::BitAnd(ref a, ref b) => ("BitAnd", bin(a, b)),
+            AstType::BitOr(ref a, ref b) => ("BitOr", bin(a, b)),
+            AstType::BitXor(ref a, ref b) => ("BitXor", bin(a, b)),
+            AstType::Equal(ref a, ref b) => ("Equal", bin(a, b)),
+            AstType::NotEqual(ref a, ref b) => ("NotEqual", bin(a, b)),
+            AstType::LessThan(ref a, ref b) => ("LessThan", bin(a, b)),
+            AstType::GreaterThan(ref a, ref b) => ("GreaterThan", bin(a, b)),
+            AstType::LessThanEqual(ref a, ref b) => ("LessThanEqual", bin(a, b)),
+            AstType::GreaterThanEqual(ref a, ref b) => ("GreaterThanEqual", bin(a, b)),
+            AstType::Plus(ref a, ref b) => ("Plus", bin(a, b)),
+            AstType::Minus(ref a, ref b) => ("Minus", bin(a, b)),
+            AstType::LeftShift(ref a, ref b) => ("LeftShift", bin(a, b)),
+            AstType::RightShift(ref a, ref b) => ("RightShift", bin(a, b)),
+            AstType::Multiple(ref a, ref b) => ("Multiple", bin(a, b)),
+            AstType::Division(ref a, ref b) => ("Division", bin(a, b)),
+            AstType::Remainder(ref a, ref b) => ("Remainder", bin(a, b)),
+            AstType::UnPlus(ref a) => ("UnPlus", un(a)),
+            AstType::UnMinus(ref a) => ("UnMinus", un(a)),
+            AstType::Not(ref a) => ("Not", un(a)),
+            AstType::BitReverse(ref a) => ("BitReverse", un(a)),
+            AstType::Assign(ref a, ref b) => ("Assign", bin(a, b)),
+            AstType::Factor(n) => ("Factor", vec![("value", n.to_string())]),
+            AstType::FloatFactor(n) => ("FloatFactor", vec![("value", format!("{:?}", n))]),
+            AstType::Variable(ref t, ref s, ref n) => {
+                ("Variable", vec![("type", debug(t)), ("structure", debug(s)), ("name", string(n))])
+            }
+            AstType::FuncCall(ref a, ref b) => ("FuncCall", vec![("func", node(a)), ("args", node(b))]),
+            AstType::Argment(ref v) => ("Argment", vec![("children", AstType::json_list(v))]),
+            AstType::Address(ref a) => ("Address", un(a)),
+            AstType::Indirect(ref a) => ("Indirect", un(a)),
+            AstType::PreInc(ref a) => ("PreInc", un(a)),
+            AstType::PreDec(ref a) => ("PreDec", un(a)),
+            AstType::PostInc(ref a) => ("PostInc", un(a)),
+            AstType::PostDec(ref a) => ("PostDec", un(a)),
+            AstType::StringLiteral(ref s, i) => ("StringLiteral", vec![("value", string(s)), ("index", i.to_string())]),
+            AstType::CompoundLiteral(ref a, ref v) => {
+                ("CompoundLiteral", vec![("variable", node(a)), ("initializers", AstType::json_list(v))])
+            }
+            AstType::ArrayInit(ref a, ref v) => {
+                ("ArrayInit", vec![("variable", node(a)), ("initializers", AstType::json_list(v))])
+            }
+            AstType::PlusAssign(ref a, ref b) => ("PlusAssign", bin(a, b)),
+            AstType::MinusAssign(ref a, ref b) => ("MinusAssign", bin(a, b)),
+            AstType::MultipleAssign(ref a, ref b) => ("MultipleAssign", bin(a, b)),
+            AstType::DivisionAssign(ref a, ref b) => ("DivisionAssign", bin(a, b)),
+            AstType::RemainderAssign(ref a, ref b) => ("RemainderAssign", bin(a, b)),
+            AstType::SizeOf(n) => ("SizeOf", vec![("size", n.to_string())]),
+            AstType::Struct(ref a, ref v) => ("Struct", vec![("definition", node(a)), ("members", AstType::json_list(v))]),
+            AstType::MemberAccess(ref a, ref b) => ("MemberAccess", vec![("struct", node(a)), ("member", node(b))]),
+            AstType::Cast(ref t, ref a) => ("Cast", vec![("type", debug(t)), ("operand", node(a))]),
+            AstType::StmtExpr(ref a) => ("StmtExpr", vec![("block", node(a))]),
+        };
+        let fields: String = fields.iter().map(|(k, v)| format!(",\"{}\":{}", k, v)).collect();
+        format!("{{\"kind\":\"{}\"{}}}", kind, fields)
+    }
+
+    // JSON配列作成.
+    fn json_list(v: &[AstType]) -> String {
+        format!("[{}]", v.iter().map(|e| e.to_json()).collect::<Vec<_>>().join(","))
+    }
+
+    // JSON文字列作成(制御文字、引用符をエスケープ).
+    fn json_string(s: &str) -> String {
+        let escaped: String = s
+            .chars()
+            .map(|c| match c {
+                '"' => "\\\"".to_string(),
+                '\\' => "\\\\".to_string(),
+                '\n' => "\\n".to_string(),
+                '\t' => "\\t".to_string(),
+                c if (c as u32) < 0x20 => format!("\\u{:04x}", c as u32),
+                c => c.to_string(),
+            })
+            .collect();
+        format!("\"{}\"", escaped)
+    }
+
+    // 整数拡張後の型を取得.
+    //
+    // charはintへ拡張し、二項演算の場合はサイズが大きい方の型とする(doubleを含む場合はdouble)
+    // 同じサイズの場合は符号なしの型とする
+    pub fn promote(a: &Type, b: &Type) -> Type {
+        match (a, b) {
+            (Type::Double, _) | (_, Type::Double) => Type::Double,
+            (Type::Float, _) | (_, Type::Float) => Type::Float,
+            (Type::ULong, _) | (_, Type::ULong) => Type::ULong,
+            (Type::Long, _) | (_, Type::Long) => Type::Long,
+            (Type::UInt, _) | (_, Type::UInt) => Type::UInt,
+            _ => Type::Int,
         }
     }
 }
@@ -84,9 +603,23 @@ pub struct AstGen<'a> {
     tokens: &'a [TokenInfo], // トークン配列.
     current_pos: usize,         // 現在読み取り位置.
     str_count: usize,           // 文字列リテラル位置
-    f_sym: HashMap<String, (Type, Structure)>,
+    compound_count: usize,      // 複合リテラル数
+    typedefs: HashMap<String, (Type, Structure)>, // typedef名と実際の型
+    declared_funcs: Vec<String>, // 宣言のみで、定義されていない関数
+    func_params: HashMap<String, Vec<(Type, Structure)>>, // 関数の引数の型
     cur_scope: Scope,
     sym_table: SymbolTable,
+    config: Config,
+    loop_depth: usize,          // ループのネスト数
+    switch_labels: Vec<(Vec<i64>, bool)>, // switchごとのcaseの値とdefaultの有無
+    decl_allowed: bool,         // ブロック内で宣言が可能か(C89では文の後の宣言を禁止)
+    errors: Vec<Diagnostic>,    // 構文解析時に検出したエラー
+    warnings: Vec<Diagnostic>,  // 構文解析時に検出した警告
+    syncing: bool,              // 構文エラー後、文の区切りまで読み飛ばすか
+    in_params: bool,            // 関数の引数を解析中か
+    stmt_expr_depth: usize,     // 文式のネスト数
+    scoped_vars: Vec<(String, String)>, // 文式の中で宣言した変数(宣言した名前、シンボル名)
+    scoped_count: usize,        // 文式の中で宣言した変数数
 }
 
 #[derive(Debug)]
@@ -94,6 +627,14 @@ pub struct AstTree {
     pub tree: Vec<AstType>, // 抽象構文木.
 }
 
+// 関数ごとのスタック使用量.
+#[derive(Debug, Clone, PartialEq)]
+pub struct StackUsage {
+    pub name: String,  // 関数名
+    pub frame: usize,  // ローカル変数のフレームサイズ
+    pub is_leaf: bool, // 関数呼び出しを含まないか
+}
+
 // 抽象構文木.
 impl AstTree {
     // コンストラクタ.
@@ -105,6 +646,128 @@ impl AstTree {
     pub fn get_tree(&self) -> &Vec<AstType> {
         &self.tree
     }
+
+    // コールグラフ取得.
+    //
+    // 関数定義ごとに、呼び出している関数を呼び出し順(重複なし)で返す
+    pub fn call_graph(&self) -> HashMap<String, Vec<String>> {
+        self.tree
+            .iter()
+            .filter_map(|f| match f {
+                AstType::FuncDef(_, _, ref name, _, ref stmt) => {
+                    let mut callees = vec![];
+                    AstTree::collect_callees(stmt, &mut callees);
+                    Some((name.clone(), callees))
+                }
+                _ => None,
+            })
+            .collect()
+    }
+
+    // 到達不能な関数定義を削除.
+    //
+    // rootsの関数から呼び出し(アドレス参照を含む)をたどり、到達しない関数定義を取り除く
+    pub fn remove_unreachable_functions(&mut self, roots: &[String]) {
+        let mut graph = self.call_graph();
+        self.tree.iter().for_each(|f| {
+            if let AstType::FuncDef(_, _, ref name, _, ref stmt) = f {
+                let mut addresses = vec![];
+                AstTree::collect_addresses(stmt, &mut addresses);
+                if let Some(callees) = graph.get_mut(name) {
+                    callees.extend(addresses);
+                }
+            }
+        });
+
+        let mut reachable: Vec<String> = roots.iter().filter(|r| graph.contains_key(*r)).cloned().collect();
+        let mut i = 0;
+        while i < reachable.len() {
+            // 定義のない関数(外部関数)の呼び出し先はたどらない
+            if let Some(callees) = graph.get(&reachable[i]) {
+                callees.iter().for_each(|callee| {
+                    if !reachable.contains(callee) {
+                        reachable.push(callee.clone());
+                    }
+                });
+            }
+            i += 1;
+        }
+        self.tree.retain(|f| match f {
+            AstType::FuncDef(_, _, ref name, _, _) => reachable.contains(name),
+            _ => true,
+        });
+    }
+
+    // コールグラフを出力(呼び出し元の名前順).
+    pub fn dump_call_graph(&self) -> String {
+        let graph = self.call_graph();
+        let mut callers: Vec<&String> = graph.keys().collect();
+        callers.sort();
+        callers
+            .iter()
+            .map(|c| format!("{}\n", format!("{} -> {}", c, graph[*c].join(", ")).trim_end()))
+            .collect()
+    }
+
+    // 関数ごとのスタック使用量取得(定義順).
+    //
+    // フレームサイズはローカル変数の配置結果から求める
+    // (7個以上の引数の呼び出しは意味解析でエラーとなり、引数は全てレジスタで渡すため、スタック上の引数領域はない)
+    pub fn stack_usage(&self, sym: &SymbolTable) -> Vec<StackUsage> {
+        self.tree
+            .iter()
+            .filter_map(|f| match f {
+                AstType::FuncDef(_, _, ref name, _, ref stmt) => Some(StackUsage {
+                    name: name.clone(),
+                    frame: sym.frame_size(&Scope::Local(name.clone())),
+                    is_leaf: !AstTree::has_call(stmt),
+                }),
+                _ => None,
+            })
+            .collect()
+    }
+
+    // スタック使用量を出力.
+    pub fn dump_stack_usage(&self, sym: &SymbolTable) -> String {
+        self.stack_usage(sym)
+            .iter()
+            .map(|u| {
+                format!("{}: frame={} {}\n", u.name, u.frame, if u.is_leaf { "leaf" } else { "non-leaf" })
+            })
+            .collect()
+    }
+
+    // 抽象構文木をJSONで出力.
+    pub fn dump_json(&self) -> String {
+        AstType::json_list(&self.tree)
+    }
+
+    // 呼び出し先の関数名を収集.
+    fn collect_callees(ast: &AstType, callees: &mut Vec<String>) {
+        if let AstType::FuncCall(ref f, _) = ast {
+            if let AstType::Variable(_, _, ref name) = **f {
+                if !callees.contains(name) {
+                    callees.push(name.clone());
+                }
+            }
+        }
+        ast.children().iter().for_each(|c| AstTree::collect_callees(c, callees));
+    }
+
+    // 関数呼び出しを含むか.
+    fn has_call(ast: &AstType) -> bool {
+        matches!(ast, AstType::FuncCall(_, _)) || ast.children().iter().any(|c| AstTree::has_call(c))
+    }
+
+    // アドレスを参照している名前を収集.
+    fn collect_addresses(ast: &AstType, names: &mut Vec<String>) {
+        if let AstType::Address(ref a) = ast {
+            if let AstType::Variable(_, _, ref name) = **a {
+                names.push(name.clone());
+            }
+        }
+        ast.children().iter().for_each(|c| AstTree::collect_addresses(c, names));
+    }
 }
 
 // 抽象構文木をトークン列から作成する
@@ -114,20 +777,64 @@ impl<'a> AstGen<'a> {
         AstGen {
             current_pos: 0,
             str_count: 0,
+            compound_count: 0,
             tokens: t,
-            f_sym: HashMap::new(),
+            typedefs: HashMap::new(),
+            declared_funcs: vec![],
+            func_params: HashMap::new(),
             cur_scope: Scope::Global,
             sym_table: SymbolTable::new(),
+            config: Config::default(),
+            loop_depth: 0,
+            switch_labels: vec![],
+            decl_allowed: true,
+            syncing: false,
+            in_params: false,
+            stmt_expr_depth: 0,
+            scoped_vars: vec![],
+            scoped_count: 0,
+            errors: vec![],
+            warnings: vec![],
         }
     }
 
+    // 解析状態の初期化
+    //
+    // 同じトークン列を再解析する為、読み取り位置やシンボルテーブル、エラーを初期状態へ戻す(設定は維持)
+    #[cfg(test)]
+    pub fn reset(&mut self) {
+        let config = self.config.clone();
+        *self = AstGen::new(self.tokens);
+        self.config = config;
+    }
+
+    // 設定データ登録
+    pub fn set_config(&mut self, c: &Config) {
+        self.config = c.clone();
+    }
+
     // シンボルテーブル取得
     pub fn get_symbol(&self) -> &SymbolTable {
         &self.sym_table
     }
 
+    // エラー取得
+    pub fn get_errors(&self) -> &Vec<Diagnostic> {
+        &self.errors
+    }
+
+    // 警告取得
+    pub fn get_warnings(&self) -> &Vec<Diagnostic> {
+        &self.warnings
+    }
+
     // トークン列を受け取り、抽象構文木を返す.
     pub fn parse(&mut self) -> AstTree {
+        // 括弧の対応が取れていない場合は、構文解析を行わない
+        if !self.check_brackets() {
+            return AstTree::new(vec![]);
+        }
+
         // グローバル変数
         let g = self.global_var(vec![]);
         let mut s = if g.is_empty() {
@@ -144,6 +851,42 @@ impl<'a> AstGen<'a> {
         AstTree::new(s)
     }
 
+    // 括弧の対応チェック.
+    //
+    // ()、{}、[]の対応が取れていない括弧の位置をエラーとする
+    fn check_brackets(&mut self) -> bool {
+        let pair = |t: &Token| match *t {
+            Token::RightParen => Some(Token::LeftParen),
+            Token::RightBrace => Some(Token::LeftBrace),
+            Token::RightBracket => Some(Token::LeftBracket),
+            _ => None,
+        };
+        let mut opens: Vec<&TokenInfo> = vec![];
+        for token in self.tokens {
+            match token.get_token_type() {
+                Token::LeftParen | Token::LeftBrace | Token::LeftBracket => opens.push(token),
+                ref t => match (pair(t), opens.last()) {
+                    (None, _) => {}
+                    (Some(ref o), Some(l)) if *o == l.get_token_type() => {
+                        opens.pop();
+                    }
+                    (Some(ref o), Some(l)) if opens.iter().any(|p| p.get_token_type() == *o) => {
+                        self.errors.push(Diagnostic::error(Some(token.pos.clone()), format!("mismatched '{}' (opening '{}' at {})", token.get_token_value(), l.get_token_value(), l.pos)));
+                        return false;
+                    }
+                    (Some(_), _) => {
+                        self.errors.push(Diagnostic::error(Some(token.pos.clone()), format!("unmatched '{}'", token.get_token_value())));
+                        return false;
+                    }
+                },
+            }
+        }
+        opens
+            .iter()
+            .for_each(|o| self.errors.push(Diagnostic::error(Some(o.pos.clone()), format!("unclosed '{}'", o.get_token_value()))));
+        opens.is_empty()
+    }
+
     // スコープ切り替え
     fn switch_scope(&mut self, scope: Scope) {
         self.cur_scope = scope;
@@ -153,35 +896,64 @@ impl<'a> AstGen<'a> {
     fn global_var(&mut self, acc: Vec<AstType>) -> Vec<AstType> {
         self.switch_scope(Scope::Global);
 
+        // typedef宣言は型を登録するのみ
+        if self.next().get_token_type() == Token::Typedef {
+            self.consume();
+            self.typedef_def();
+            if self.syncing {
+                self.synchronize();
+            }
+            return self.global_var(acc);
+        }
+
         // タイプを判断する為、先読み
+        let pos = self.current_pos;
+        let is_extern = self.next().get_token_type() == Token::Extern;
+        if is_extern {
+            self.consume();
+        }
         let (_t, s) = self.generate_type();
         let token = self.next_consume();
         let paren = self.next();
 
-        // 先読み分を戻る
-        self.back(2);
+        // 先読み分を戻る(型名は複数トークンの場合がある)
+        self.current_pos = pos;
         match token.get_token_type() {
             // 変数定義
             Token::Variable if s != Structure::Struct && Token::LeftParen != paren.get_token_type() => {
                 // グローバル変数
+                if is_extern {
+                    self.consume();
+                }
                 let var = self.assign();
-                self.must_next(
-                    Token::SemiColon,
-                    "ast.rs(global_var): Not exists semi-colon",
-                );
+                if !self.syncing {
+                    self.must_next(Token::SemiColon);
+                }
+
+                // 構文エラーの宣言は、次の宣言の先頭まで読み飛ばす
+                if self.syncing {
+                    self.synchronize();
+                    return self.global_var(acc);
+                }
 
+                // extern宣言はシンボルの登録のみ(領域は他の翻訳単位で確保する)
                 let mut vars = acc;
-                vars.push(var);
+                if !is_extern || matches!(var, AstType::Assign(_, _)) {
+                    vars.push(var);
+                }
                 self.global_var(vars)
             },
             // 構造体定義
             Token::Variable if s == Structure::Struct  => {
-                // Token::Structまでもどっているので一つSKIP
-                self.consume();
+                // Token::Struct(Token::Union)までもどっているので一つSKIP
+                let is_union = self.next_consume().get_token_type() == Token::Union;
 
                 // 構造体定義作成
                 let mut vars = acc;
-                vars.push(self.struct_def_or_var());
+                vars.push(self.struct_def_or_var(is_union));
+                if self.syncing {
+                    self.synchronize();
+                }
                 self.global_var(vars)
             },
             _ => acc,
@@ -190,8 +962,17 @@ impl<'a> AstGen<'a> {
 
     // func def.
     fn func_def(&mut self) -> AstType {
+        // static関数判定(externは指定なしと同じ).
+        let is_static = self.next().get_token_type() == Token::Static;
+        if is_static || self.next().get_token_type() == Token::Extern {
+            self.consume();
+        }
+
         // 型を取得.
-        let (t, s) = self.generate_type();
+        let (t, s) = self.declaration_type();
+        if let Type::Unknown(_) = t {
+            return self.skip_top_level();
+        }
 
         // 関数定義から始まらないとだめ（関数の中に様々な処理が入っている）.
         let token = self.next_consume();
@@ -199,42 +980,97 @@ impl<'a> AstGen<'a> {
             Token::Variable => {
                 self.switch_scope(Scope::Local(token.get_token_value()));
 
-                // 既に同じシンボルが登録されていればエラー.
-                if self.search_symbol(&Scope::Func, &token.get_token_value()).is_some() {
-                    panic!("{} {}: already define {}", file!(), line!(), token.get_token_value());
-                }
+                let name = token.get_token_value();
+                let declared = self.declared_funcs.contains(&name);
+                let prev = self.search_symbol(&Scope::Func, &name);
+                let defined = prev.is_some() && !declared;
 
                 // 関数シンボルを登録.
-                self.sym_table.register_sym(Symbol::new(
-                    Scope::Func,
-                    token.get_token_value(),
-                    t.clone(),
-                    s.clone(),
-                ));
+                let mut sym = Symbol::new(Scope::Func, name.clone(), t.clone(), s.clone());
+                sym.is_static = is_static;
+                self.sym_table.register_sym(sym);
+
+                // 既に宣言、定義された関数とは、戻り値と引数の型が一致すること(引数の省略された宣言は除く)
+                let args = self.func_args();
+                if self.syncing {
+                    return self.skip_top_level();
+                }
+                let params = AstGen::param_types(&args);
+                let is_decl = self.next().get_token_type() == Token::SemiColon;
+                if let Some(prev) = prev {
+                    let prev_params = self.func_params.get(&name).cloned().unwrap_or_default();
+                    let conflict = (prev.t, prev.strt) != (t.clone(), s.clone())
+                        || (!params.is_empty() && !prev_params.is_empty() && params != prev_params);
+                    if conflict {
+                        self.errors.push(Diagnostic::error(Some(token.pos.clone()), format!("conflicting types for '{}'", name)));
+                    } else if defined && !is_decl {
+                        self.errors.push(Diagnostic::error(Some(token.pos.clone()), format!("redefinition of '{}'", name)));
+                    }
+                }
+                if !params.is_empty() {
+                    self.func_params.insert(name.clone(), params);
+                }
+
+                // 本体がなければ関数宣言
+                if is_decl {
+                    self.consume();
+                    if !declared && !defined {
+                        self.declared_funcs.push(name.clone());
+                    }
+                    return AstType::FuncDecl(t, s, name, Box::new(args));
+                }
+                self.declared_funcs.retain(|f| *f != name);
+                let stmt = self.statement();
+
+                // ローカル変数のスタック上の配置を決定.
+                self.sym_table.layout(&Scope::Local(token.get_token_value()));
 
                 AstType::FuncDef(
                     t,
                     s,
                     token.get_token_value(),
-                    Box::new(self.func_args()),
-                    Box::new(self.statement()),
+                    Box::new(args),
+                    Box::new(stmt),
                 )
             }
-            _ => panic!(
-                "{} {}: Not Exists Function def {:?}",
-                file!(),
-                line!(),
-                token
-            ),
+            _ => {
+                self.syntax_error(token, format!("expected identifier or '(' before '{}'", token.get_token_value()));
+                self.skip_top_level()
+            }
+        }
+    }
+
+    // 引数の型一覧
+    fn param_types(args: &AstType) -> Vec<(Type, Structure)> {
+        match args {
+            AstType::Argment(ref a) => a
+                .iter()
+                .map(|e| match e {
+                    AstType::Variable(ref t, ref s, _) => (t.clone(), s.clone()),
+                    _ => (e.type_of(), Structure::Unknown),
+                })
+                .collect(),
+            _ => vec![],
         }
     }
 
     // typeトークンチェック
     fn is_type_token(&mut self) -> bool {
-        match self.next().get_token_type() {
-            Token::Int | Token::IntPointer | Token::Char | Token::CharPointer => true,
-            _ => false,
-        }
+        let token = self.next();
+        matches!(
+            token.get_token_type(),
+            Token::Int
+                | Token::IntPointer
+                | Token::Char
+                | Token::CharPointer
+                | Token::Double
+                | Token::Float
+                | Token::Short
+                | Token::Struct
+                | Token::Union
+                | Token::Unsigned
+                | Token::Signed
+        ) || self.is_typedef_name(token)
     }
 
     // type/struct judge
@@ -245,93 +1081,391 @@ impl<'a> AstGen<'a> {
             Token::IntPointer => (Type::Int, Structure::Pointer),
             Token::Char => (Type::Char, Structure::Identifier),
             Token::CharPointer => (Type::Char, Structure::Pointer),
-            Token::Struct => {
-                // 構造体の定義名を取得
+            Token::Void => (Type::Void, Structure::Identifier),
+            Token::Double => self.pointer_type(Type::Double),
+            Token::Float => self.pointer_type(Type::Float),
+            Token::Short => self.pointer_type(Type::Short),
+            Token::Unsigned => match self.next().get_token_type() {
+                Token::Int => {
+                    self.consume();
+                    (Type::UInt, Structure::Identifier)
+                }
+                Token::IntPointer => {
+                    self.consume();
+                    (Type::UInt, Structure::Pointer)
+                }
+                Token::Char => {
+                    self.consume();
+                    (Type::UChar, Structure::Identifier)
+                }
+                Token::CharPointer => {
+                    self.consume();
+                    (Type::UChar, Structure::Pointer)
+                }
+                _ => (Type::UInt, Structure::Identifier),
+            },
+            // signedは符号付きの型と同じ(intは省略可能)
+            Token::Signed => match self.next().get_token_type() {
+                Token::Int | Token::IntPointer | Token::Char | Token::CharPointer => self.generate_type(),
+                _ => (Type::Int, Structure::Identifier),
+            },
+            Token::Struct | Token::Union => {
+                // 構造体、共用体の定義名を取得
                 let name = self.next();
                 (Type::Struct(name.get_token_value()), Structure::Struct)
             }
+            // typedef名は登録された型(typedef名の後の*はポインタとする)
+            Token::Variable if self.is_typedef_name(token) => {
+                let (t, s) = self.typedefs[&token.get_token_value()].clone();
+                match self.next().get_token_type() {
+                    Token::Multi if s != Structure::Pointer => {
+                        self.consume();
+                        (t, Structure::Pointer)
+                    }
+                    _ => (t, s),
+                }
+            }
             _ => (Type::Unknown("unknown type".to_string()), Structure::Unknown),
         }
     }
 
-    // func argment.
-    fn func_args(&mut self) -> AstType {
-        let token = self.next_consume();
-        match token.get_token_type() {
-            Token::LeftParen => {
-                // 引数を処理.
-                let args = AstType::Argment(self.recur_func_args(vec![]));
-
-                // 閉じ括弧.
-                self.must_next(Token::RightParen, "ast.rs(func_arg): Not Exists RightParen");
-                args
+    // 型名の後の*はポインタとする
+    fn pointer_type(&mut self, t: Type) -> (Type, Structure) {
+        match self.next().get_token_type() {
+            Token::Multi => {
+                self.consume();
+                (t, Structure::Pointer)
             }
-            _ => panic!("{} {}: Not Exists LeftParen {:?}", file!(), line!(), token),
+            _ => (t, Structure::Identifier),
         }
     }
 
-    // recur func argment.
-    fn recur_func_args(&mut self, a: Vec<AstType>) -> Vec<AstType> {
-        // 型が定義されていれば、引数として評価.
-        if !self.is_type_token() {
-            return a;
+    // 宣言の型を取得.
+    //
+    // 型として解釈できない場合は、宣言位置を含むエラーとする
+    fn declaration_type(&mut self) -> (Type, Structure) {
+        let token = self.next();
+        let ts = self.generate_type();
+        if let (Type::Unknown(_), _) = ts {
+            self.unknown_type(token);
         }
+        ts
+    }
 
-        // 引数を評価
-        let mut args = a;
-        args.push(self.assign());
+    // 未知の型名のエラー
+    fn unknown_type(&mut self, token: &TokenInfo) {
+        self.errors.push(Diagnostic::error(Some(token.pos.clone()), format!("unknown type name '{}'", token.get_token_value())));
+    }
 
-        // カンマがあれば引き続き.
-        match self.next().get_token_type() {
-            Token::Comma => {
-                self.consume();
-                self.recur_func_args(args)
-            }
-            _ => args,
+    // 未知の型名による宣言を読み飛ばす(型名、ポインタ、変数名)
+    fn skip_unknown_declaration(&mut self, token: &TokenInfo) -> AstType {
+        self.unknown_type(token);
+        while self.next().get_token_type() == Token::Multi {
+            self.consume();
         }
+        let name = self.next_consume();
+        AstType::Variable(
+            Type::Unknown(token.get_token_value()),
+            Structure::Unknown,
+            name.get_token_value(),
+        )
     }
 
-    // statement.
-    fn statement(&mut self) -> AstType {
-        AstType::Statement(self.sub_statement(&[]))
+    // 未知の型名による宣言であるか(識別子の後に、識別子もしくはポインタ宣言が続く)
+    fn is_unknown_declaration(&self, is_param: bool) -> bool {
+        let kind = |i: usize| self.tokens.get(self.current_pos + i).map(|t| t.get_token_type());
+        match (kind(0), kind(1), kind(2)) {
+            (Some(Token::Variable), Some(Token::Variable), _) => true,
+            (Some(Token::Variable), Some(Token::Multi), _) if is_param => true,
+            (Some(Token::Variable), Some(Token::Multi), Some(Token::Variable)) => true,
+            _ => false,
+        }
     }
 
-    // sub statement.
-    fn sub_statement(&mut self, expr: &[AstType]) -> Vec<AstType> {
-        // トークンがなくなるまで、構文木生成.
-        let mut stmt = expr.to_owned();
-        let token = self.next_consume();
-        match token.get_token_type() {
-            Token::If => {
-                stmt.push(self.statement_if());
-                self.sub_statement(&stmt)
-            }
-            Token::While => {
-                stmt.push(self.statement_while());
-                self.sub_statement(&stmt)
-            }
-            Token::For => {
-                stmt.push(self.statement_for());
-                self.sub_statement(&stmt)
+    // typedef宣言
+    //
+    // typedef名と型を登録する.ASTは生成しない
+    fn typedef_def(&mut self) {
+        let (t, s) = match self.declaration_type() {
+            (t @ Type::Struct(_), _) => {
+                // 構造体の定義名を読み飛ばし(定義を伴う場合は定義を登録)、ポインタかどうか判定
+                let is_union = self.tokens[self.current_pos - 1].get_token_type() == Token::Union;
+                let def_name = self.next_consume();
+                if self.next().get_token_type() == Token::LeftBrace {
+                    self.consume();
+                    self.struct_def(def_name, is_union);
+                }
+                if self.next().get_token_type() == Token::Multi {
+                    self.consume();
+                    (t, Structure::Pointer)
+                } else {
+                    (t, Structure::Struct)
+                }
             }
-            Token::Do => {
+            ts => ts,
+        };
+        let name = self.next_consume();
+        if name.get_token_type() != Token::Variable {
+            self.syntax_error(name, format!("expected identifier before '{}'", name.get_token_value()));
+            return;
+        }
+        self.must_next(Token::SemiColon);
+        self.typedefs.insert(name.get_token_value(), (t, s));
+    }
+
+    // 構文エラーの文を、次の;(もしくはブロック終端の})まで読み飛ばす
+    fn synchronize(&mut self) {
+        let mut depth = 0;
+        loop {
+            match self.next().get_token_type() {
+                Token::End => break,
+                Token::SemiColon if depth == 0 => {
+                    self.consume();
+                    break;
+                }
+                Token::RightBrace if depth == 0 => break,
+                Token::LeftBrace => depth += 1,
+                Token::RightBrace => depth -= 1,
+                _ => {}
+            }
+            self.consume();
+        }
+        self.syncing = false;
+    }
+
+    // 構文エラー.
+    //
+    // 文(トップレベルでは宣言)ごとに最初のエラーのみを記録し、区切りまで読み飛ばして解析を続ける
+    fn syntax_error(&mut self, token: &TokenInfo, message: String) {
+        if !self.syncing {
+            self.errors.push(Diagnostic::error(Some(token.pos.clone()), message));
+            self.syncing = true;
+        }
+
+        // 文や括弧の区切りは読み飛ばさず、呼び出し元での対応に使う
+        if matches!(
+            token.get_token_type(),
+            Token::SemiColon | Token::RightBrace | Token::RightParen | Token::RightBracket | Token::Comma | Token::End
+        ) {
+            self.back(1);
+        }
+    }
+
+    // 解析できないトップレベルの宣言を、次の宣言の先頭まで読み飛ばす
+    fn skip_top_level(&mut self) -> AstType {
+        let mut depth = 0;
+        loop {
+            match self.next().get_token_type() {
+                Token::End => break,
+                Token::SemiColon if depth == 0 => {
+                    self.consume();
+                    break;
+                }
+                Token::LeftBrace => depth += 1,
+                Token::RightBrace => {
+                    depth -= 1;
+                    if depth == 0 {
+                        self.consume();
+                        break;
+                    }
+                }
+                _ => {}
+            }
+            self.consume();
+        }
+        self.syncing = false;
+        AstType::Statement(vec![])
+    }
+
+    // func argment.
+    fn func_args(&mut self) -> AstType {
+        let token = self.next_consume();
+        match token.get_token_type() {
+            Token::LeftParen => {
+                // 引数を処理.
+                let args = AstType::Argment(self.recur_func_args(vec![]));
+
+                // 閉じ括弧.
+                self.must_next(Token::RightParen);
+                args
+            }
+            _ => {
+                self.syntax_error(token, format!("expected '(' before '{}'", token.get_token_value()));
+                AstType::Argment(vec![])
+            }
+        }
+    }
+
+    // recur func argment.
+    fn recur_func_args(&mut self, a: Vec<AstType>) -> Vec<AstType> {
+        // register指定は無視する
+        if self.next().get_token_type() == Token::Register {
+            self.consume();
+        }
+
+        // 型が定義されていれば、引数として評価.
+        let is_type = self.is_type_token();
+        let is_unknown = !is_type && self.is_unknown_declaration(true);
+        if !is_type && !is_unknown {
+            return a;
+        }
+
+        // 引数を評価
+        let mut args = a;
+        if is_unknown {
+            let token = self.next_consume();
+            args.push(self.skip_unknown_declaration(token));
+        } else {
+            self.in_params = true;
+            args.push(self.assign());
+            self.in_params = false;
+        }
+
+        // カンマがあれば引き続き.
+        match self.next().get_token_type() {
+            Token::Comma => {
+                self.consume();
+                self.recur_func_args(args)
+            }
+            _ => args,
+        }
+    }
+
+    // statement.
+    fn statement(&mut self) -> AstType {
+        // ブロック先頭の{を読み飛ばし、対応する}までを解析
+        if self.next().get_token_type() == Token::LeftBrace {
+            self.consume();
+        }
+        AstType::Statement(self.block_statement())
+    }
+
+    // ブロック内の文を解析(宣言の可否はブロックごとに管理する)
+    fn block_statement(&mut self) -> Vec<AstType> {
+        let decl_allowed = self.decl_allowed;
+        self.decl_allowed = true;
+        let stmt = self.sub_statement(&[]);
+        self.decl_allowed = decl_allowed;
+        stmt
+    }
+
+    // 宣言と文の混在チェック.
+    //
+    // C89では、ブロック内の文の後に宣言を置けない
+    fn check_mixed_declaration(&mut self, token: &TokenInfo) {
+        match token.get_token_type() {
+            Token::SemiColon | Token::RightBrace | Token::Comma => {}
+            _ if self.is_declaration_start(token) => {
+                if !self.decl_allowed && self.config.std == Std::C89 {
+                    self.errors.push(Diagnostic::error(Some(token.pos.clone()), "ISO C90 forbids mixed declarations and code".to_string()));
+                }
+            }
+            _ => self.decl_allowed = false,
+        }
+    }
+
+    // 宣言の先頭トークンであるか
+    fn is_declaration_start(&self, token: &TokenInfo) -> bool {
+        match token.get_token_type() {
+            Token::Int
+            | Token::IntPointer
+            | Token::Char
+            | Token::CharPointer
+            | Token::Double
+            | Token::Float
+            | Token::Short
+            | Token::Struct
+            | Token::Union
+            | Token::Unsigned
+            | Token::Signed
+            | Token::Static
+            | Token::Register
+            | Token::Auto
+            | Token::Typedef => true,
+            Token::Variable => self.typedefs.contains_key(&token.get_token_value()),
+            _ => false,
+        }
+    }
+
+    // sub statement.
+    fn sub_statement(&mut self, expr: &[AstType]) -> Vec<AstType> {
+        // 構文エラーの後は、文の区切りから解析を再開する
+        if self.syncing {
+            self.synchronize();
+        }
+
+        // トークンがなくなるまで、構文木生成.
+        let mut stmt = expr.to_owned();
+        let token = self.next_consume();
+        self.check_mixed_declaration(token);
+        match token.get_token_type() {
+            Token::If => {
+                stmt.push(self.statement_if());
+                self.sub_statement(&stmt)
+            }
+            Token::While => {
+                stmt.push(self.statement_while());
+                self.sub_statement(&stmt)
+            }
+            Token::For => {
+                stmt.push(self.statement_for());
+                self.sub_statement(&stmt)
+            }
+            Token::Do => {
                 stmt.push(self.statement_do());
                 self.sub_statement(&stmt)
             }
             Token::Continue => {
-                stmt.push(self.statement_continue());
+                stmt.push(self.statement_continue(token));
                 self.sub_statement(&stmt)
             }
             Token::Break => {
-                stmt.push(self.statement_break());
+                stmt.push(self.statement_break(token));
+                self.sub_statement(&stmt)
+            }
+            Token::Switch => {
+                stmt.push(self.statement_switch());
+                self.sub_statement(&stmt)
+            }
+            Token::Case => {
+                stmt.push(self.statement_case(token));
+                self.sub_statement(&stmt)
+            }
+            Token::Default => {
+                stmt.push(self.statement_default(token));
+                self.sub_statement(&stmt)
+            }
+            Token::Goto => {
+                stmt.push(self.statement_goto());
+                self.sub_statement(&stmt)
+            }
+            // 識別子の直後が:であれば、ラベル
+            Token::Variable if self.next().get_token_type() == Token::Colon => {
+                self.consume();
+                stmt.push(AstType::Label(token.get_token_value()));
+                self.sub_statement(&stmt)
+            }
+            Token::Typedef => {
+                self.typedef_def();
+                self.sub_statement(&stmt)
+            }
+            Token::LeftBrace => {
+                // 入れ子のブロックは対応する}までを解析し、文の並びへ展開する
+                // (break/continueは展開後も外側のループを対象とする)
+                let block = self.block_statement();
+                stmt.extend(block);
                 self.sub_statement(&stmt)
             }
-            Token::LeftBrace => self.sub_statement(&stmt),
             Token::SemiColon => self.sub_statement(&stmt),
             Token::RightBrace => stmt,
             Token::Comma => {
-                // 前の変数の型を考慮
-                let var = self.continue_variable_define(&stmt);
+                // 前の変数の型を考慮(宣言でなければ、カンマ区切りの式とする)
+                let var = match stmt.last() {
+                    Some(AstType::Variable(_, _, _)) | Some(AstType::Assign(_, _)) | Some(AstType::ArrayInit(_, _)) => {
+                        self.continue_variable_define(&stmt)
+                    }
+                    _ => self.assign(),
+                };
                 stmt.push(var);
                 self.sub_statement(&stmt)
             }
@@ -344,24 +1478,38 @@ impl<'a> AstGen<'a> {
     }
 
     // continue variable
+    //
+    // カンマ以降の宣言子は、直前の宣言の型を基本型として、それぞれの*や[]を解析する
     fn continue_variable_define(&mut self, stmt: &[AstType]) -> AstType {
-        let last = stmt.last();
-        match last {
-            Some(ref s) => match s {
-                AstType::Variable(ref t, ref s, ref _n) => match t {
-                    Type::Int if s == &Structure::Identifier => self.factor_int(),
-                    Type::Char if s == &Structure::Identifier => self.factor_char(),
-                    Type::Int if s == &Structure::Pointer => {
-                        self.variable(Type::Int, Structure::Pointer)
-                    }
-                    Type::Char if s == &Structure::Pointer => {
-                        self.variable(Type::Char, Structure::Pointer)
-                    }
-                    _ => panic!("{} {}: Not Support Type {:?}", file!(), line!(), t),
-                },
-                _ => panic!("{} {}: Not Support Ast {:?}", file!(), line!(), s),
-            },
+        let t = match stmt.last() {
+            Some(AstType::Variable(ref t, _, _)) => t.clone(),
+            Some(AstType::Assign(ref a, _)) | Some(AstType::ArrayInit(ref a, _)) => a.type_of(),
+            Some(s) => panic!("{} {}: Not Support Ast {:?}", file!(), line!(), s),
             None => panic!("{} {}: Not exists Variable", file!(), line!()),
+        };
+
+        // ポインタ宣言子
+        let var = if self.next().get_token_type() == Token::Multi {
+            self.consume();
+            self.variable(t, Structure::Pointer)
+        } else {
+            // 配列かどうか決定する為に、一文字読み飛ばして、後で戻る
+            let _ = self.next_consume();
+            let token = self.next();
+            self.back(1);
+            match token.get_token_type() {
+                Token::LeftBracket => return self.variable_array(t),
+                _ => self.variable(t, Structure::Identifier),
+            }
+        };
+
+        // 初期化子があれば代入とする
+        match self.next().get_token_type() {
+            Token::Assign => {
+                self.consume();
+                AstType::Assign(Box::new(var), Box::new(self.condition()))
+            }
+            _ => var,
         }
     }
 
@@ -369,46 +1517,20 @@ impl<'a> AstGen<'a> {
     //
     // ブロック部が一行の場合、asm部が期待しているAstType::Statementでexpression結果を包む
     fn statement_if(&mut self) -> AstType {
-        self.must_next(
-            Token::LeftParen,
-            "ast.rs(statement_if): Not Exists LeftParen",
-        );
+        self.must_next(Token::LeftParen);
 
         // 条件式を解析.
-        let condition = self.assign();
-        self.must_next(
-            Token::RightParen,
-            "ast.rs(statement_if): Not Exists RightParen",
-        );
+        let condition = self.control_condition();
+        self.must_next(Token::RightParen);
 
         // ifブロック内を解析.
-        let stmt = match self.next().get_token_type() {
-            Token::LeftBrace => self.statement(),
-            _ => {
-                let expr = AstType::Statement(vec![self.expression()]);
-                self.must_next(
-                    Token::SemiColon,
-                    "ast.rs(statement_if): Not Exists SemiColon",
-                );
-                expr
-            }
-        };
+        let stmt = self.if_block();
 
         // else部分解析.
         match self.next().get_token_type() {
             Token::Else => {
                 self.consume();
-                let else_stmt = match self.next().get_token_type() {
-                    Token::LeftBrace => self.statement(),
-                    _ => {
-                        let expr = AstType::Statement(vec![self.expression()]);
-                        self.must_next(
-                            Token::SemiColon,
-                            "ast.rs(statement_if): Not Exists SemiColon",
-                        );
-                        expr
-                    }
-                };
+                let else_stmt = self.if_block();
                 AstType::If(
                     Box::new(condition),
                     Box::new(stmt),
@@ -419,98 +1541,253 @@ impl<'a> AstGen<'a> {
         }
     }
 
+    // if/elseのブロック部.
+    //
+    // else ifの場合は、if文を一つ持つブロックとする
+    fn if_block(&mut self) -> AstType {
+        let stmt = match self.next().get_token_type() {
+            Token::LeftBrace => return self.statement(),
+            Token::If => {
+                self.consume();
+                return AstType::Statement(vec![self.statement_if()]);
+            }
+            Token::Break => {
+                let token = self.next_consume();
+                self.statement_break(token)
+            }
+            Token::Continue => {
+                let token = self.next_consume();
+                self.statement_continue(token)
+            }
+            _ => self.expression(),
+        };
+        self.must_next(Token::SemiColon);
+        AstType::Statement(vec![stmt])
+    }
+
+    // 制御式の解析.
+    //
+    // 括弧で囲まれていない代入は'=='の誤記の可能性があるため、警告する（if ((a = b))で抑止）
+    fn control_condition(&mut self) -> AstType {
+        let begin = self.next();
+        let condition = self.assign();
+        let end = &self.tokens[self.current_pos - 1];
+        let is_paren = begin.get_token_type() == Token::LeftParen && end.get_token_type() == Token::RightParen;
+        if matches!(condition, AstType::Assign(_, _)) && !is_paren {
+            self.warnings.push(Diagnostic::warning(Some(begin.pos.clone()), "suggest parentheses around assignment used as truth value".to_string()));
+        }
+        condition
+    }
+
     // while statement.
     fn statement_while(&mut self) -> AstType {
-        self.must_next(
-            Token::LeftParen,
-            "ast.rs(statement_while): Not Exists LeftParen",
-        );
+        self.must_next(Token::LeftParen);
 
         // 条件式を解析.
-        let condition = self.assign();
-        self.must_next(
-            Token::RightParen,
-            "ast.rs(statement_while): Not Exists RightParen",
-        );
+        let condition = self.control_condition();
+        self.must_next(Token::RightParen);
 
-        AstType::While(Box::new(condition), Box::new(self.statement()))
+        AstType::While(Box::new(condition), Box::new(self.loop_statement()))
     }
 
     // do-while statement.
     fn statement_do(&mut self) -> AstType {
         // ブロック部.
-        let stmt = self.statement();
-        self.must_next(Token::While, "ast.rs(statement_do): Not Exists while token");
+        let stmt = self.loop_statement();
+        self.must_next(Token::While);
 
         // 条件式を解析.
-        self.must_next(
-            Token::LeftParen,
-            "ast.rs(statement_do): Not Exists LeftParen",
-        );
+        self.must_next(Token::LeftParen);
         let condition = self.assign();
-        self.must_next(
-            Token::RightParen,
-            "ast.rs(statement_while): Not Exists RightParen",
-        );
+        self.must_next(Token::RightParen);
 
         AstType::Do(Box::new(stmt), Box::new(condition))
     }
 
     // for statement.
     fn statement_for(&mut self) -> AstType {
-        self.must_next(
-            Token::LeftParen,
-            "ast.rs(statement_for): Not Exists LeftParen",
-        );
+        self.must_next(Token::LeftParen);
 
-        // 各種条件を解析.
-        let begin = match self.next().get_token_type() {
+        // 各種条件を解析(初期化部の宣言はC99以降のみ).
+        let token = self.next();
+        if self.config.std == Std::C89 && self.is_declaration_start(token) {
+            self.errors.push(Diagnostic::error(Some(token.pos.clone()), "'for' loop initial declarations are only allowed in C99 mode".to_string()));
+        }
+        let is_declaration = self.is_declaration_start(token);
+        let begin = match token.get_token_type() {
             Token::SemiColon => None,
-            _ => Some(self.assign()),
+            _ if is_declaration => Some(self.for_declaration()),
+            _ => Some(self.for_expressions()),
         };
-        self.must_next(
-            Token::SemiColon,
-            "ast.rs(statement_for): Not Exists Semicolon",
-        );
+        self.must_next(Token::SemiColon);
 
         let condition = match self.next().get_token_type() {
             Token::SemiColon => None,
-            _ => Some(self.assign()),
+            _ => Some(self.control_condition()),
         };
-        self.must_next(
-            Token::SemiColon,
-            "ast.rs(statement_for): Not Exists Semicolon",
-        );
+        self.must_next(Token::SemiColon);
 
         let end = match self.next().get_token_type() {
             Token::RightParen => None,
-            _ => Some(self.assign()),
+            _ => Some(self.for_expressions()),
         };
-        self.must_next(
-            Token::RightParen,
-            "ast.rs(statement_for): Not Exists RightParen",
-        );
+        self.must_next(Token::RightParen);
 
         AstType::For(
             Box::new(begin),
             Box::new(condition),
             Box::new(end),
-            Box::new(self.statement()),
+            Box::new(self.loop_statement()),
         )
     }
 
+    // for文初期化部の宣言.
+    //
+    // カンマ区切りの宣言子が複数ある場合は、AstType::Statementでまとめる
+    fn for_declaration(&mut self) -> AstType {
+        let mut decls = vec![self.assign()];
+        while self.next().get_token_type() == Token::Comma {
+            self.consume();
+            let var = self.continue_variable_define(&decls);
+            decls.push(var);
+        }
+        match decls.len() {
+            1 => decls.remove(0),
+            _ => AstType::Statement(decls),
+        }
+    }
+
+    // for文初期化部、更新部の式.
+    //
+    // カンマ区切りの式が複数ある場合は、AstType::Statementでまとめる(左から順に評価する)
+    fn for_expressions(&mut self) -> AstType {
+        let mut exprs = vec![self.assign()];
+        while self.next().get_token_type() == Token::Comma {
+            self.consume();
+            exprs.push(self.assign());
+        }
+        match exprs.len() {
+            1 => exprs.remove(0),
+            _ => AstType::Statement(exprs),
+        }
+    }
+
+    // ループ本体のstatement.
+    //
+    // 本体が空文(;)の場合は、空のAstType::Statementとする
+    fn loop_statement(&mut self) -> AstType {
+        if self.next().get_token_type() == Token::SemiColon {
+            self.consume();
+            return AstType::Statement(vec![]);
+        }
+
+        self.loop_depth += 1;
+        let stmt = self.statement();
+        self.loop_depth -= 1;
+        stmt
+    }
+
+    // switch statement.
+    //
+    // 暗黙のbreakは挿入せず、caseラベル間はフォールスルーする
+    fn statement_switch(&mut self) -> AstType {
+        self.must_next(Token::LeftParen);
+        let condition = self.assign();
+        self.must_next(Token::RightParen);
+
+        // breakはswitchの終端へジャンプする(caseの重複、defaultの重複はラベルごとにチェック)
+        self.loop_depth += 1;
+        self.switch_labels.push((vec![], false));
+        let stmt = self.statement();
+        self.loop_depth -= 1;
+        self.switch_labels.pop();
+        AstType::Switch(Box::new(condition), Box::new(stmt))
+    }
+
+    // case label.
+    fn statement_case(&mut self, token: &TokenInfo) -> AstType {
+        let expr = self.condition();
+        self.must_next(Token::Colon);
+        let n = match expr.eval_constant() {
+            Some(n) => n,
+            None => {
+                self.errors.push(Diagnostic::error(Some(token.pos.clone()), "case label does not reduce to an integer constant".to_string()));
+                return AstType::Case(0);
+            }
+        };
+        match self.switch_labels.last_mut() {
+            None => self.errors.push(Diagnostic::error(Some(token.pos.clone()), "case label not within a switch statement".to_string())),
+            Some((ref cases, _)) if cases.contains(&n) => {
+                self.errors.push(Diagnostic::error(Some(token.pos.clone()), format!("duplicate case value: {}", n)))
+            }
+            Some((ref mut cases, _)) => cases.push(n),
+        }
+        AstType::Case(n)
+    }
+
+    // default label.
+    fn statement_default(&mut self, token: &TokenInfo) -> AstType {
+        self.must_next(Token::Colon);
+        match self.switch_labels.last_mut() {
+            None => self.errors.push(Diagnostic::error(Some(token.pos.clone()), "'default' label not within a switch statement".to_string())),
+            Some((_, true)) => {
+                self.errors.push(Diagnostic::error(Some(token.pos.clone()), "multiple default labels in one switch".to_string()))
+            }
+            Some((_, ref mut has_default)) => *has_default = true,
+        }
+        AstType::Default()
+    }
+
+    // goto statement.
+    fn statement_goto(&mut self) -> AstType {
+        let token = self.next_consume();
+        if token.get_token_type() != Token::Variable {
+            self.syntax_error(token, format!("expected identifier before '{}'", token.get_token_value()));
+            return AstType::Void();
+        }
+        self.must_next(Token::SemiColon);
+        AstType::Goto(token.get_token_value())
+    }
+
     // continue statement.
-    fn statement_continue(&mut self) -> AstType {
+    //
+    // switchはcontinueの対象とならないため、ループのネスト数のみで判定する
+    fn statement_continue(&mut self, token: &TokenInfo) -> AstType {
+        if self.loop_depth == self.switch_labels.len() {
+            self.errors.push(Diagnostic::error(Some(token.pos.clone()), "continue statement not within a loop".to_string()));
+        }
         AstType::Continue()
     }
 
     // break statement.
-    fn statement_break(&mut self) -> AstType {
-        AstType::Break()
+    //
+    // GNU拡張の場合、break N;で複数のループを抜ける
+    fn statement_break(&mut self, token: &TokenInfo) -> AstType {
+        if self.next().get_token_type() != Token::Number {
+            return AstType::Break(1);
+        }
+        let level = self.next_consume().get_token_value();
+        if !self.config.gnu {
+            self.errors.push(Diagnostic::error(Some(token.pos.clone()), "break with level requires --gnu".to_string()));
+            return AstType::Break(1);
+        }
+
+        match level.parse::<usize>() {
+            Ok(level) if level > 0 && level <= self.loop_depth => AstType::Break(level),
+            _ => {
+                self.errors.push(Diagnostic::error(Some(token.pos.clone()), format!("break level is out of range: {}", level)));
+                AstType::Break(1)
+            }
+        }
     }
 
     // return statement.
+    //
+    // 値を持たないreturn;は、AstType::Voidを返す
     fn statement_return(&mut self) -> AstType {
+        if self.next().get_token_type() == Token::SemiColon {
+            return AstType::Return(Box::new(AstType::Void()));
+        }
         let expr = self.assign();
         AstType::Return(Box::new(expr))
     }
@@ -539,32 +1816,41 @@ impl<'a> AstGen<'a> {
                 self.consume();  // Assignトークン消費
                 AstType::Assign(Box::new(var), Box::new(self.condition()))
             }
-            Token::Variable if Token::PlusAssign == next_token.get_token_type() => {
-                let var = self.factor();
-                self.consume();  // Assignトークン消費
-                AstType::PlusAssign(Box::new(var), Box::new(self.condition()))
-            }
-            Token::Variable if Token::MinusAssign == next_token.get_token_type() => {
-                let var = self.factor();
-                self.consume();  // Assignトークン消費
-                AstType::MinusAssign(Box::new(var), Box::new(self.condition()))
-             }
-            Token::Variable if Token::MultipleAssign == next_token.get_token_type() => {
-                let var = self.factor();
-                self.consume();  // Assignトークン消費
-                AstType::MultipleAssign(Box::new(var), Box::new(self.condition()))
-            }
-            Token::Variable if Token::DivisionAssign == next_token.get_token_type() => {
-                let var = self.factor();
-                self.consume();  // Assignトークン消費
-                AstType::DivisionAssign(Box::new(var), Box::new(self.condition()))
+            _ => {
+                let left = self.condition();
+                self.compound_assign(left)
             }
-            Token::Variable if Token::RemainderAssign == next_token.get_token_type() => {
-                let var = self.factor();
-                self.consume();  // Assignトークン消費
-                AstType::RemainderAssign(Box::new(var), Box::new(self.condition()))
-             }
-             _ => self.condition(),
+        }
+    }
+
+    // 複合代入.
+    //
+    // 左辺は変数に限らず、メンバーアクセスや配列要素等の式も許容する
+    fn compound_assign(&mut self, left: AstType) -> AstType {
+        let ope = self.next();
+        let create = match ope.get_token_type() {
+            Token::PlusAssign => AstType::PlusAssign,
+            Token::MinusAssign => AstType::MinusAssign,
+            Token::MultipleAssign => AstType::MultipleAssign,
+            Token::DivisionAssign => AstType::DivisionAssign,
+            Token::RemainderAssign => AstType::RemainderAssign,
+            _ => return left,
+        };
+        self.consume();  // Assignトークン消費
+        let right = self.condition();
+        self.check_zero_divisor(ope, &left, &right);
+        create(Box::new(left), Box::new(right))
+    }
+
+    // 定数0による整数の除算、剰余はエラー
+    fn check_zero_divisor(&mut self, ope: &TokenInfo, left: &AstType, right: &AstType) {
+        let is_division = matches!(
+            ope.get_token_type(),
+            Token::Division | Token::Remainder | Token::DivisionAssign | Token::RemainderAssign
+        );
+        let is_float = left.type_of().is_float() || right.type_of().is_float();
+        if is_division && !is_float && right.eval_constant() == Some(0) {
+            self.errors.push(Diagnostic::error(Some(ope.pos.clone()), "division by zero".to_string()));
         }
     }
 
@@ -577,10 +1863,7 @@ impl<'a> AstGen<'a> {
                     Box::new(acc),
                     Box::new(self.argment(AstType::Argment(vec![]))),
                 );
-                self.must_next(
-                    Token::RightParen,
-                    "ast.rs(call_func): Not exists RightParen",
-                );
+                self.must_next(Token::RightParen);
                 call_func
             }
             _ => panic!("{} {}: Not exists LeftParen: {:?}", file!(), line!(), token),
@@ -594,10 +1877,15 @@ impl<'a> AstGen<'a> {
                 let mut args = a;
                 args.push(self.assign());
 
-                // カンマがあれば引き続き、引数とみなす.
+                // カンマがあれば引き続き、引数とみなす(カンマの後は引数が必須).
                 if Token::Comma == self.next().get_token_type() {
                     self.next_consume();
-                    self.argment(AstType::Argment(args))
+                    if Token::RightParen == self.next().get_token_type() {
+                        let token = self.next_consume();
+                        self.syntax_error(token, format!("expected expression before '{}'", token.get_token_value()));
+                        return AstType::Argment(args);
+                    }
+                    self.sub_argment(AstType::Argment(args))
                 } else {
                     AstType::Argment(args)
                 }
@@ -627,13 +1915,23 @@ impl<'a> AstGen<'a> {
         let ope_type = self.next().get_token_type();
         match ope_type {
             Token::Question => {
-                self.consume();
-                let middle = self.logical();
-
-                // コロンがない場合、終了.
-                self.must_next(Token::Colon, "ast.rs(sub_condition): Not exists Colon");
+                let token = self.next_consume();
 
-                let right = self.logical();
+                // GNU拡張の場合、中間項を省略可能(a ?: b → a ? a : b)
+                let middle = match self.next().get_token_type() {
+                    Token::Colon => {
+                        if !self.config.gnu {
+                            self.errors.push(Diagnostic::error(Some(token.pos.clone()), "omitted middle operand requires --gnu".to_string()));
+                        }
+                        AstType::Temporary()
+                    }
+                    _ => self.logical(),
+                };
+
+                // コロンがない場合、終了.
+                self.must_next(Token::Colon);
+
+                let right = self.logical();
                 let tree = AstType::Condition(Box::new(acc), Box::new(middle), Box::new(right));
                 self.sub_condition(tree)
             }
@@ -657,7 +1955,16 @@ impl<'a> AstGen<'a> {
 
         let ope_type = self.next().get_token_type();
         match ope_type {
-            Token::LogicalAnd | Token::LogicalOr | Token::Assign => {
+            // 代入は右結合で最も優先順位が低いため、右辺は条件演算子まで含める
+            Token::Assign => {
+                let token = self.next_consume();
+                if let AstType::Condition(_, ref a, ref b) = acc {
+                    self.conditional_lvalue(a, b, token);
+                }
+                let right = self.condition();
+                create(ope_type, acc, right)
+            }
+            Token::LogicalAnd | Token::LogicalOr => {
                 self.consume();
                 let right = self.bit_operator();
                 self.sub_logical(create(ope_type, acc, right))
@@ -666,27 +1973,104 @@ impl<'a> AstGen<'a> {
         }
     }
 
+    // 条件演算子を左辺値とする代入のチェック.
+    //
+    // GNU拡張の場合のみ許可し、両方の分岐が同じ型の左辺値である必要がある
+    fn conditional_lvalue(&mut self, a: &AstType, b: &AstType, token: &TokenInfo) {
+        if !self.config.gnu {
+            self.errors.push(Diagnostic::error(Some(token.pos.clone()), "conditional lvalue requires --gnu".to_string()));
+            return;
+        }
+        let is_lvalue = |e: &AstType| match e {
+            AstType::Variable(_, ref s, _) => matches!(s, Structure::Identifier | Structure::Pointer),
+            AstType::Indirect(_) | AstType::MemberAccess(_, _) => true,
+            _ => false,
+        };
+        if !is_lvalue(a) || !is_lvalue(b) {
+            self.errors.push(Diagnostic::error(Some(token.pos.clone()), "conditional lvalue branch is not an lvalue".to_string()));
+        } else if a.type_of() != b.type_of() || a.is_pointer() != b.is_pointer() {
+            self.errors.push(Diagnostic::error(Some(token.pos.clone()), "conditional lvalue branches must have the same type".to_string()));
+        }
+    }
+
     // bit operator.
+    //
+    // ビット演算子の優先順位は & > ^ > | の順
     fn bit_operator(&mut self) -> AstType {
+        let left = self.bit_xor();
+        self.sub_bit_or(left)
+    }
+
+    // sub bit or.
+    fn sub_bit_or(&mut self, acc: AstType) -> AstType {
+        match self.next().get_token_type() {
+            Token::BitOr => {
+                self.consume();
+                let right = self.bit_xor();
+                self.sub_bit_or(AstType::BitOr(Box::new(acc), Box::new(right)))
+            }
+            _ => acc,
+        }
+    }
+
+    // bit xor.
+    fn bit_xor(&mut self) -> AstType {
+        let left = self.bit_and();
+        self.sub_bit_xor(left)
+    }
+
+    // sub bit xor.
+    fn sub_bit_xor(&mut self, acc: AstType) -> AstType {
+        match self.next().get_token_type() {
+            Token::BitXor => {
+                self.consume();
+                let right = self.bit_and();
+                self.sub_bit_xor(AstType::BitXor(Box::new(acc), Box::new(right)))
+            }
+            _ => acc,
+        }
+    }
+
+    // bit and.
+    fn bit_and(&mut self) -> AstType {
+        let left = self.equality();
+        self.sub_bit_and(left)
+    }
+
+    // sub bit and.
+    fn sub_bit_and(&mut self, acc: AstType) -> AstType {
+        match self.next().get_token_type() {
+            Token::And => {
+                self.consume();
+                let right = self.equality();
+                self.sub_bit_and(AstType::BitAnd(Box::new(acc), Box::new(right)))
+            }
+            _ => acc,
+        }
+    }
+
+    // equality.
+    fn equality(&mut self) -> AstType {
         let left = self.relation();
-        self.sub_bit_operator(left)
+        self.sub_equality(left)
     }
 
-    // sub bit operator.
-    fn sub_bit_operator(&mut self, acc: AstType) -> AstType {
-        let create = |ope, left, right| match ope {
-            Token::BitOr => AstType::BitOr(Box::new(left), Box::new(right)),
-            Token::And => AstType::BitAnd(Box::new(left), Box::new(right)),
-            Token::BitXor => AstType::BitXor(Box::new(left), Box::new(right)),
-            _ => panic!("{} {}: Not Support Token {:?}", file!(), line!(), ope),
+    // sub equality.
+    //
+    // 等価演算子は比較演算子よりも優先順位が低い
+    fn sub_equality(&mut self, acc: AstType) -> AstType {
+        let create = |ope: Token, left, right| match ope {
+            Token::Equal => AstType::Equal(Box::new(left), Box::new(right)),
+            Token::NotEqual => AstType::NotEqual(Box::new(left), Box::new(right)),
+            _ => panic!("{} {}: Not Support Token Type {:?}", file!(), line!(), ope),
         };
 
-        let token = self.next();
-        match token.get_token_type() {
-            Token::BitOr | Token::And | Token::BitXor => {
+        let ope_type = self.next().get_token_type();
+        match ope_type {
+            Token::Equal | Token::NotEqual => {
                 self.consume();
                 let right = self.relation();
-                self.sub_bit_operator(create(token.get_token_type(), acc, right))
+                self.sub_equality(create(ope_type, acc, right))
             }
             _ => acc,
         }
@@ -699,10 +2083,10 @@ impl<'a> AstGen<'a> {
     }
 
     // sub relation.
+    //
+    // 左辺が比較、等価演算の場合(a < b < c)は、0/1との比較となるため警告する
     fn sub_relation(&mut self, acc: AstType) -> AstType {
         let create = |ope: Token, left, right| match ope {
-            Token::Equal => AstType::Equal(Box::new(left), Box::new(right)),
-            Token::NotEqual => AstType::NotEqual(Box::new(left), Box::new(right)),
             Token::LessThan => AstType::LessThan(Box::new(left), Box::new(right)),
             Token::GreaterThan => AstType::GreaterThan(Box::new(left), Box::new(right)),
             Token::LessThanEqual => AstType::LessThanEqual(Box::new(left), Box::new(right)),
@@ -712,13 +2096,14 @@ impl<'a> AstGen<'a> {
 
         let ope_type = self.next().get_token_type();
         match ope_type {
-            Token::Equal
-            | Token::NotEqual
-            | Token::LessThan
+            Token::LessThan
             | Token::LessThanEqual
             | Token::GreaterThan
             | Token::GreaterThanEqual => {
-                self.consume();
+                let token = self.next_consume();
+                if acc.is_comparison() {
+                    self.warnings.push(Diagnostic::warning(Some(token.pos.clone()), "comparisons like 'X<=Y<=Z' do not have their mathematical meaning; did you mean 'X<=Y && Y<=Z'?".to_string()));
+                }
                 let right = self.shift();
                 self.sub_relation(create(ope_type, acc, right))
             }
@@ -793,6 +2178,7 @@ impl<'a> AstGen<'a> {
             Token::Multi | Token::Division | Token::Remainder => {
                 self.consume();
                 let right = self.factor();
+                self.check_zero_divisor(ope, &acc, &right);
                 self.term_multi_div(create(ope.get_token_type(), acc, right))
             }
             _ => acc,
@@ -812,43 +2198,207 @@ impl<'a> AstGen<'a> {
             Token::SizeOf => self.factor_sizeof(),
             Token::IntPointer => self.variable(Type::Int, Structure::Pointer),
             Token::CharPointer => self.variable(Type::Char, Structure::Pointer),
-            Token::And => AstType::Address(Box::new(self.factor())),
-            Token::Multi => AstType::Indirect(Box::new(self.factor())),
+            Token::And => match self.factor() {
+                // 関数名は既にアドレスとなっている
+                f @ AstType::Address(_) => f,
+                f => AstType::Address(Box::new(f)),
+            },
+            Token::Multi => self.indirect(token),
             Token::Number => self.number(token),
+            Token::FloatNumber => self.float_number(token),
+            Token::Double => self.factor_scalar(Type::Double),
+            Token::Float => self.factor_scalar(Type::Float),
+            Token::Short => self.factor_scalar(Type::Short),
             Token::Int => self.factor_int(),
+            Token::Unsigned => self.factor_unsigned(),
+            Token::Signed => self.factor_signed(),
+            Token::Register | Token::Auto => self.storage_class_declaration(token),
             Token::Char => self.factor_char(),
             Token::StringLiteral => self.string_literal(token),
-            Token::Struct => self.struct_def_or_var(),
+            Token::Struct => self.struct_def_or_var(false),
+            Token::Union => self.struct_def_or_var(true),
+            Token::Variable if token.get_token_value() == "__func__" => self.func_name(token),
+            Token::Variable if self.is_typedef_name(token) => self.factor_typedef(token),
             Token::Variable => {
                 // variable位置へ
                 self.back(1);
-                self.factor_variable(&token)
+                self.factor_variable(token)
             }
+            Token::LeftParen if self.is_compound_literal() => self.compound_literal(),
+            Token::LeftParen if self.next().get_token_type() == Token::Void => self.void_cast(),
+            Token::LeftParen if self.next().get_token_type() == Token::LeftBrace => self.statement_expression(token),
             Token::LeftParen => {
                 let tree = self.assign();
-                self.must_next(Token::RightParen, "ast.rs(factor): Not exists RightParen");
+                self.must_next(Token::RightParen);
                 tree
             }
-            _ => panic!("{} {}: failed in factor {:?}", file!(), line!(), token),
+            _ => {
+                self.syntax_error(token, format!("expected expression before '{}'", token.get_token_value()));
+                AstType::Factor(0)
+            }
+        }
+    }
+
+    // 間接参照.
+    fn indirect(&mut self, token: &TokenInfo) -> AstType {
+        let a = self.factor();
+
+        // 関数呼び出し結果を参照する場合、ポインタを返す関数であること
+        if let AstType::FuncCall(ref f, _) = a {
+            if let AstType::Variable(_, Structure::Identifier, ref name) = **f {
+                self.errors.push(Diagnostic::error(Some(token.pos.clone()), format!("invalid type argument of unary '*': {}()", name)));
+            }
+        }
+        AstType::Indirect(Box::new(a))
+    }
+
+    // register/auto指定の宣言.
+    //
+    // 記憶域クラス指定子は無視し、通常の宣言として扱う
+    fn storage_class_declaration(&mut self, token: &TokenInfo) -> AstType {
+        if !self.is_type_token() {
+            // 型名が省略された場合はエラーとし、intとして解析を続ける
+            self.errors.push(Diagnostic::error(Some(token.pos.clone()), format!("expected type name after '{}'", token.get_token_value())));
+            return self.variable(Type::Int, Structure::Identifier);
+        }
+        self.factor()
+    }
+
+    // 文式（GNU拡張）.
+    //
+    // ({ ... })はブロック内の文を実行し、最後の式文の値を結果とする
+    // （ブロックの変数は関数単位のスコープへ登録される）
+    fn statement_expression(&mut self, token: &TokenInfo) -> AstType {
+        if !self.config.gnu {
+            self.errors.push(Diagnostic::error(Some(token.pos.clone()), "statement expression requires --gnu".to_string()));
+        }
+
+        // 文式の中で宣言した変数は、文式の終わりまで有効とする
+        let scoped = self.scoped_vars.len();
+        self.stmt_expr_depth += 1;
+        let block = self.statement();
+        self.stmt_expr_depth -= 1;
+        self.scoped_vars.truncate(scoped);
+        self.must_next(Token::RightParen);
+        AstType::StmtExpr(Box::new(block))
+    }
+
+    // 宣言する変数のシンボル名.
+    //
+    // 文式の中で宣言した変数は、文式の外の同名の変数と区別する為、番号を付けたシンボル名とする
+    fn declare_name(&mut self, token: &TokenInfo) -> String {
+        let name = token.get_token_value();
+        if self.stmt_expr_depth == 0 {
+            return name;
+        }
+        self.scoped_count += 1;
+        let scoped = format!("{}.{}", name, self.scoped_count);
+        self.scoped_vars.push((name, scoped.clone()));
+        scoped
+    }
+
+    // 参照する変数のシンボル名(文式の中で宣言した変数を優先).
+    fn resolve_name(&self, token: &TokenInfo) -> String {
+        let name = token.get_token_value();
+        self.scoped_vars.iter().rev().find(|(n, _)| *n == name).map_or(name, |(_, s)| s.clone())
+    }
+
+    // voidキャスト.
+    //
+    // (void)exprは式を評価し、値を捨てる
+    fn void_cast(&mut self) -> AstType {
+        self.consume();
+        self.must_next(Token::RightParen);
+        AstType::Cast(Type::Void, Box::new(self.factor()))
+    }
+
+    // 複合リテラル判定(型名と[が続く).
+    fn is_compound_literal(&mut self) -> bool {
+        let t = self.next_consume().get_token_type();
+        let bracket = self.next().get_token_type();
+        self.back(1);
+        matches!(t, Token::Int | Token::Char) && bracket == Token::LeftBracket
+    }
+
+    // 複合リテラル.
+    //
+    // (int[]){1, 2, 3}のような無名配列をスタック上に確保する
+    fn compound_literal(&mut self) -> AstType {
+        if self.config.std == Std::C89 {
+            let pos = &self.tokens[self.current_pos - 1].pos;
+            self.errors.push(Diagnostic::error(Some(pos.clone()), "ISO C90 forbids compound literals".to_string()));
+        }
+        let t = match self.next_consume().get_token_type() {
+            Token::Char => Type::Char,
+            _ => Type::Int,
+        };
+
+        // 要素数(省略時は初期化子の数).
+        self.must_next(Token::LeftBracket);
+        let size = match self.next().get_token_type() {
+            Token::Number => Some(self.next_consume().get_token_value().parse::<usize>().unwrap()),
+            _ => None,
+        };
+        self.must_next(Token::RightBracket);
+        self.must_next(Token::RightParen);
+
+        // 初期化子.
+        let brace = self.next();
+        self.must_next(Token::LeftBrace);
+        let mut inits = vec![];
+        while self.next().get_token_type() != Token::RightBrace {
+            inits.push(self.assign());
+            if self.next().get_token_type() == Token::Comma {
+                self.consume();
+            }
         }
+        self.must_next(Token::RightBrace);
+
+        let count = size.unwrap_or(inits.len());
+        if inits.is_empty() || inits.len() > count {
+            self.errors.push(Diagnostic::error(Some(brace.pos.clone()), "invalid compound literal initializer".to_string()));
+            return AstType::Factor(0);
+        }
+
+        // 無名変数としてシンボル登録.
+        let name = format!(".compound{}", self.compound_count);
+        self.compound_count += 1;
+        let strt = Structure::Array(vec![count]);
+        self.sym_table.register_sym(Symbol::new(self.cur_scope.clone(), name.clone(), t.clone(), strt.clone()));
+        AstType::CompoundLiteral(Box::new(AstType::Variable(t, strt, name)), inits)
     }
 
     // 構造体定義、宣言作成
-    fn struct_def_or_var(&mut self) -> AstType {
+    fn struct_def_or_var(&mut self, is_union: bool) -> AstType {
         let def_name = self.next_consume();
         let token = self.next_consume();
         match token.get_token_type() {
-            Token::LeftBrace => self.struct_def(def_name),
-            Token::Variable => self.struct_variable(def_name, token),
-            _ => panic!("{} {}: failed in struct_def_or_var {:?} {:?}", file!(), line!(), def_name, token),
+            Token::LeftBrace => {
+                let def = self.struct_def(def_name, is_union);
+                self.must_next(Token::SemiColon);
+                def
+            }
+            Token::SemiColon => self.struct_declaration(def_name),
+            Token::Variable => self.struct_variable(&def_name.get_token_value(), token),
+            Token::Multi => self.variable(Type::Struct(def_name.get_token_value()), Structure::Pointer),
+            _ => {
+                self.syntax_error(token, format!("expected identifier before '{}'", token.get_token_value()));
+                AstType::Factor(0)
+            }
         }
     }
 
     /// 構造体定義作成
     ///
-    /// 構造体定義でシンボル登録し、ASTを返却
-    fn struct_def(&mut self, def_name: &TokenInfo) -> AstType {
-        // 右波括弧が出てくるまで、メンバー定義
+    /// 構造体定義でシンボル登録し、ASTを返却(共用体は全てのメンバーを先頭に重ねる)
+    fn struct_def(&mut self, def_name: &TokenInfo, is_union: bool) -> AstType {
+        // 自己参照できるよう、メンバー定義前に不完全型として登録
+        let is_declared = self.search_symbol(&self.cur_scope, &def_name.get_token_value()).is_some();
+        self.struct_declaration(def_name);
+
+        // 右波括弧が出てくるまで、メンバー定義(メンバー名はそのままシンボル名とする)
+        let stmt_expr_depth = self.stmt_expr_depth;
+        self.stmt_expr_depth = 0;
         let mut right_brace = self.next();
         let mut members = vec![];
         let mut syms = vec![];
@@ -856,14 +2406,19 @@ impl<'a> AstGen<'a> {
             match right_brace.get_token_type() {
                 Token::RightBrace => {
                     self.consume();
-                    self.must_next(
-                        Token::SemiColon, "ast.rs(struct_def_or_var): Not exists SemiColon"
-                    );
                     break;
                 }
                 _ => {
                     // 構造体に所属しているメンバーをシンボルに登録
                     let member = self.assign();
+
+                    // 構文エラーのメンバーは、次のメンバーの先頭まで読み飛ばす
+                    if self.syncing {
+                        self.synchronize();
+                        right_brace = self.next();
+                        continue;
+                    }
+                    let member = self.bit_field(member);
                     let mem_sym = match member {
                         AstType::Variable(ref t, ref st, ref mem_name) => {
                             Symbol::new(self.cur_scope.clone(), mem_name.clone(), t.clone(), st.clone())
@@ -873,25 +2428,19 @@ impl<'a> AstGen<'a> {
                     members.push(member);
                     syms.push(mem_sym);
 
-                    self.must_next(
-                        Token::SemiColon, "ast.rs(struct_def_or_var): Not exists SemiColon"
-                    );
+                    self.must_next(Token::SemiColon);
                 }
             };
             right_brace = self.next();
         }
+        self.stmt_expr_depth = stmt_expr_depth;
 
-        // シンボルテーブルへ構造体定義を保存（未登録の場合）.
-        if self.search_symbol(&self.cur_scope, &def_name.get_token_value()).is_none() {
-            let mut sym = Symbol::new(
-                self.cur_scope.clone(),
-                def_name.get_token_value(), // 構造体定義名で作成
-                Type::Struct(def_name.get_token_value()),
-                Structure::Struct,
-            );
-            // 構造体メンバーを登録し、シンボル保存
-            sym.regist_mem(syms);
-            self.sym_table.register_sym(sym);
+        // 不完全型の構造体定義へ、メンバーを登録（定義済みの場合は何もしない）.
+        let is_incomplete = self
+            .search_symbol(&self.cur_scope, &def_name.get_token_value())
+            .is_some_and(|s| s.strt == Structure::Incomplete);
+        if !is_declared || is_incomplete {
+            self.sym_table.complete_struct(&self.cur_scope, &def_name.get_token_value(), syms, is_union);
         }
 
         AstType::Struct(
@@ -904,43 +2453,180 @@ impl<'a> AstGen<'a> {
         )
     }
 
+    /// ビットフィールド
+    ///
+    /// メンバーに:幅の指定があれば、幅を持つメンバーとする（int、unsigned intのみ）
+    fn bit_field(&mut self, member: AstType) -> AstType {
+        if self.next().get_token_type() != Token::Colon {
+            return member;
+        }
+        let token = self.next_consume();
+        let width = self.condition().eval_constant();
+        match member {
+            AstType::Variable(t @ (Type::Int | Type::UInt), Structure::Identifier, name) => match width {
+                Some(w) if (1..=32).contains(&w) => AstType::Variable(t, Structure::BitField(w as usize, 0), name),
+                Some(w) if w < 0 => {
+                    self.errors.push(Diagnostic::error(Some(token.pos.clone()), format!("negative width in bit-field '{}'", name)));
+                    AstType::Variable(t, Structure::Identifier, name)
+                }
+                Some(0) => {
+                    self.errors.push(Diagnostic::error(Some(token.pos.clone()), format!("zero width for bit-field '{}'", name)));
+                    AstType::Variable(t, Structure::Identifier, name)
+                }
+                Some(_) => {
+                    self.errors.push(Diagnostic::error(Some(token.pos.clone()), format!("width of '{}' exceeds its type", name)));
+                    AstType::Variable(t, Structure::Identifier, name)
+                }
+                None => {
+                    self.errors.push(Diagnostic::error(Some(token.pos.clone()), format!("bit-field '{}' width not an integer constant", name)));
+                    AstType::Variable(t, Structure::Identifier, name)
+                }
+            },
+            AstType::Variable(t, s, name) => {
+                self.errors.push(Diagnostic::error(Some(token.pos.clone()), format!("bit-field '{}' has invalid type", name)));
+                AstType::Variable(t, s, name)
+            }
+            m => m,
+        }
+    }
+
+    /// 構造体前方宣言
+    ///
+    /// 構造体定義名を不完全型としてシンボル登録し、ASTを返却
+    fn struct_declaration(&mut self, def_name: &TokenInfo) -> AstType {
+        if self.search_symbol(&self.cur_scope, &def_name.get_token_value()).is_none() {
+            self.sym_table.register_sym(Symbol::new(
+                self.cur_scope.clone(),
+                def_name.get_token_value(),
+                Type::Struct(def_name.get_token_value()),
+                Structure::Incomplete,
+            ));
+        }
+
+        AstType::Struct(
+            Box::new(AstType::Variable(
+                Type::Struct(def_name.get_token_value()),
+                Structure::Incomplete,
+                def_name.get_token_value(),
+            )),
+            vec![],
+        )
+    }
+
     /// 構造体変数作成
     ///
     /// 構造体変数名でシンボルに登録し、ASTを返却
-    fn struct_variable(&mut self, def_name: &TokenInfo, name: &TokenInfo) -> AstType {
+    fn struct_variable(&mut self, tag: &str, name: &TokenInfo) -> AstType {
+        // 不完全型の構造体は、ポインタ経由でのみ使用できる
+        if let Some(s) = self.search_symbol(&self.cur_scope, tag) {
+            if s.strt == Structure::Incomplete {
+                self.errors.push(Diagnostic::error(Some(name.pos.clone()), format!(
+                    "variable '{}' has incomplete type 'struct {}'",
+                    name.get_token_value(),
+                    tag
+                )));
+            }
+        }
+
+        // 構造体の配列であれば、要素数を取得
+        let var = self.declare_name(name);
+        let strt = match self.next().get_token_type() {
+            Token::LeftBracket => Structure::Array(self.array_size(&name.get_token_value(), vec![])),
+            _ => Structure::Struct,
+        };
+
         // 定義がシンボルテーブルに保存されているので、それを元にシンボル保存
-        if let Some(s) = self.search_symbol(&self.cur_scope, &def_name.get_token_value()) {
+        if let Some(s) = self.search_symbol(&self.cur_scope, tag) {
             let mut sym = Symbol::new(
                 self.cur_scope.clone(),
-                name.get_token_value(), // 構造体変数名で作成
-                Type::Struct(def_name.get_token_value()),
-                Structure::Struct,
+                var.clone(), // 構造体変数名で作成
+                Type::Struct(tag.to_string()),
+                strt.clone(),
             );
 
             // 構造体定義よりメンバーを設定し、シンボル登録
+            sym.is_union = s.is_union;
             sym.regist_mem(s.members);
             self.sym_table.register_sym(sym);
         }
 
-        AstType::Variable(
-            Type::Struct(def_name.get_token_value()), Structure::Struct, name.get_token_value()
-        )
+        AstType::Variable(Type::Struct(tag.to_string()), strt, var)
+    }
+
+    // typedef名による宣言.
+    //
+    // 登録された型で変数を宣言する(typedef名の後の*はポインタとする)
+    fn factor_typedef(&mut self, token: &TokenInfo) -> AstType {
+        let (t, s) = self.typedefs[&token.get_token_value()].clone();
+        let s = match self.next().get_token_type() {
+            Token::Multi => {
+                self.consume();
+                Structure::Pointer
+            }
+            _ => s,
+        };
+        match s {
+            Structure::Struct => {
+                let name = self.next_consume();
+                match t {
+                    Type::Struct(ref tag) => self.struct_variable(tag, name),
+                    _ => panic!("{} {}: not struct typedef {:?}", file!(), line!(), t),
+                }
+            }
+            Structure::Identifier if self.tokens.get(self.current_pos + 1).map(|t| t.get_token_type()) == Some(Token::LeftBracket) => {
+                self.variable_array(t)
+            }
+            _ => self.variable(t, s),
+        }
+    }
+
+    // 型名として使用されているtypedef名であるか(同名の変数が優先、構造体タグは名前空間が異なる)
+    fn is_typedef_name(&self, token: &TokenInfo) -> bool {
+        let name = token.get_token_value();
+        self.typedefs.contains_key(&name)
+            && self
+                .search_symbol(&self.cur_scope, &name)
+                .is_none_or(|s| s.t == Type::Struct(name.clone()) && s.var == name)
     }
 
     // 文字列作成
+    //
+    // 隣接する文字列リテラルは連結し、一つのリテラルとする
     fn string_literal(&mut self, token: &TokenInfo) -> AstType {
+        let mut s = token.get_token_value();
+        while self.next().get_token_type() == Token::StringLiteral {
+            s.push_str(&self.next_consume().get_token_value());
+        }
         let count = self.str_count;
         self.str_count += 1;
-        AstType::StringLiteral(token.get_token_value(), count)
+        AstType::StringLiteral(s, count)
+    }
+
+    // __func__(C99).
+    //
+    // 関数内では、関数名の文字列リテラルとする
+    fn func_name(&mut self, token: &TokenInfo) -> AstType {
+        match self.cur_scope {
+            Scope::Local(ref name) => {
+                let count = self.str_count;
+                self.str_count += 1;
+                AstType::StringLiteral(name.clone(), count)
+            }
+            _ => {
+                self.errors.push(Diagnostic::error(Some(token.pos.clone()), "'__func__' is not defined outside of function scope".to_string()));
+                AstType::Factor(0)
+            }
+        }
     }
 
     // variable型の作成
     fn factor_variable(&mut self, token: &TokenInfo) -> AstType {
         // 変数シンボルサーチ
-        match self.search_symbol(&self.cur_scope, &token.get_token_value()) {
+        match self.search_symbol(&self.cur_scope, &self.resolve_name(token)) {
             Some(ref sym) => {
                 // 後置演算子判定
-                let var = self.variable(sym.t.clone(), sym.strt.clone());
+                let var = self.variable_ref(sym.t.clone(), sym.strt.clone());
+                let var = self.member_access(var);
                 match self.next().get_token_type() {
                     Token::Inc => {
                         self.consume();
@@ -950,6 +2636,12 @@ impl<'a> AstGen<'a> {
                         self.consume();
                         AstType::PostDec(Box::new(var))
                     }
+                    // 関数ではない変数の呼び出しはエラーとし、引数は読み飛ばす
+                    Token::LeftParen => {
+                        self.errors.push(Diagnostic::error(Some(token.pos.clone()), format!("called object '{}' is not a function or function pointer", token.get_token_value())));
+                        self.call_func(var);
+                        AstType::Factor(0)
+                    }
                     _ => var,
                 }
             }
@@ -958,14 +2650,67 @@ impl<'a> AstGen<'a> {
                 match self.search_symbol(&Scope::Func, &token.get_token_value()) {
                     Some(s) => {
                         let f_sym = self.variable_func(s.t.clone(), s.strt);
-                        self.call_func(f_sym)
+                        match self.next().get_token_type() {
+                            Token::LeftParen => self.call_func(f_sym),
+                            // 呼び出さない場合、関数のアドレスとする
+                            _ => AstType::Address(Box::new(f_sym)),
+                        }
+                    }
+                    _ => {
+                        // 型名として解釈できない識別子による宣言、未定義の識別子はエラー
+                        if !self.is_unknown_declaration(false) {
+                            self.consume();
+                            self.errors.push(Diagnostic::error(Some(token.pos.clone()), format!("'{}' undeclared", token.get_token_value())));
+                            return AstType::Factor(0);
+                        }
+                        self.consume();
+                        self.skip_unknown_declaration(token)
                     }
-                    _ => panic!("{} {}: cannot define {:?}", file!(), line!(), token),
                 }
             }
         }
     }
 
+    // メンバーアクセス.
+    //
+    // p->mは(*p).mとして、構造体のメンバーへアクセスする
+    fn member_access(&mut self, acc: AstType) -> AstType {
+        let base = match self.next().get_token_type() {
+            Token::Dot => acc,
+            Token::Arrow => AstType::Indirect(Box::new(acc)),
+            _ => return acc,
+        };
+        self.consume();
+
+        // 構造体定義からメンバーを検索
+        let name = self.next_consume();
+        let member = match base.type_of() {
+            Type::Struct(ref tag) => self
+                .search_symbol(&self.cur_scope, tag)
+                .and_then(|s| s.members.into_iter().find(|m| m.var == name.get_token_value())),
+            _ => None,
+        };
+        match member {
+            // 配列のメンバーは、変数の配列と同じくIndirectでくるむ
+            Some(m) if matches!(m.strt, Structure::Array(_)) && self.next().get_token_type() == Token::LeftBracket => {
+                let index = self.array_index(&m.strt);
+                let acc = AstType::MemberAccess(Box::new(base), Box::new(AstType::Variable(m.t, m.strt, m.var)));
+                self.member_access(AstType::Indirect(Box::new(AstType::Plus(Box::new(acc), Box::new(index)))))
+            }
+            Some(m) => self.member_access(AstType::MemberAccess(
+                Box::new(base),
+                Box::new(AstType::Variable(m.t, m.strt, m.var)),
+            )),
+            None => {
+                match base.type_of() {
+                    Type::Struct(ref tag) => self.errors.push(Diagnostic::error(Some(name.pos.clone()), format!("'struct {}' has no member named '{}'", tag, name.get_token_value()))),
+                    _ => self.errors.push(Diagnostic::error(Some(name.pos.clone()), format!("request for member '{}' in something not a structure or union", name.get_token_value()))),
+                }
+                AstType::Factor(0)
+            }
+        }
+    }
+
     // int型要素の作成
     fn factor_int(&mut self) -> AstType {
         // 配列かどうか決定する為に、一文字読み飛ばして、後で戻る
@@ -978,6 +2723,61 @@ impl<'a> AstGen<'a> {
         }
     }
 
+    // signed int/char型要素の作成(intは省略可能、符号付きの型と同じ)
+    fn factor_signed(&mut self) -> AstType {
+        match self.next().get_token_type() {
+            Token::Int => {
+                self.consume();
+                self.factor_int()
+            }
+            Token::Char => {
+                self.consume();
+                self.factor_char()
+            }
+            Token::IntPointer => {
+                self.consume();
+                self.variable(Type::Int, Structure::Pointer)
+            }
+            Token::CharPointer => {
+                self.consume();
+                self.variable(Type::Char, Structure::Pointer)
+            }
+            _ => self.factor_int(),
+        }
+    }
+
+    // unsigned int/char型要素の作成(intは省略可能)
+    fn factor_unsigned(&mut self) -> AstType {
+        let t = match self.next().get_token_type() {
+            Token::Int => {
+                self.consume();
+                Type::UInt
+            }
+            Token::Char => {
+                self.consume();
+                Type::UChar
+            }
+            Token::IntPointer => {
+                self.consume();
+                return self.variable(Type::UInt, Structure::Pointer);
+            }
+            Token::CharPointer => {
+                self.consume();
+                return self.variable(Type::UChar, Structure::Pointer);
+            }
+            _ => Type::UInt,
+        };
+
+        // 配列かどうか決定する為に、一文字読み飛ばして、後で戻る
+        let _ = self.next_consume();
+        let token = self.next();
+        self.back(1);
+        match token.get_token_type() {
+            Token::LeftBracket => self.variable_array(t),
+            _ => self.variable(t, Structure::Identifier),
+        }
+    }
+
     // char型要素の作成
     fn factor_char(&mut self) -> AstType {
         // 配列かどうか決定する為に、一文字読み飛ばして、後で戻る
@@ -990,14 +2790,28 @@ impl<'a> AstGen<'a> {
         }
     }
 
+    // short/float/double型要素の作成(*が続けばポインタ)
+    fn factor_scalar(&mut self, t: Type) -> AstType {
+        if self.next().get_token_type() == Token::Multi {
+            self.consume();
+            return self.variable(t, Structure::Pointer);
+        }
+
+        // 配列かどうか決定する為に、一文字読み飛ばして、後で戻る
+        let _ = self.next_consume();
+        let token = self.next();
+        self.back(1);
+        match token.get_token_type() {
+            Token::LeftBracket => self.variable_array(t),
+            _ => self.variable(t, Structure::Identifier),
+        }
+    }
+
     // array index
     fn array_index(&mut self, s: &Structure) -> AstType {
         self.consume();
         let index = self.expression();
-        self.must_next(
-            Token::RightBracket,
-            "ast.rs(variable): Not exists RightBracket",
-        );
+        self.must_next(Token::RightBracket);
         // 多次元配列か？
         match self.next().get_token_type() {
             // 最初のインデックス分のオフセットを算出
@@ -1018,32 +2832,64 @@ impl<'a> AstGen<'a> {
         }
     }
 
-    // variable.
+    // ポインタの添字参照.
+    //
+    // p[i][j]は*(*(p + i) + j)とする
+    fn pointer_index(&mut self, base: AstType) -> AstType {
+        let mut base = base;
+        while self.next().get_token_type() == Token::LeftBracket {
+            self.consume();
+            let index = self.expression();
+            self.must_next(Token::RightBracket);
+            base = AstType::Indirect(Box::new(AstType::Plus(Box::new(base), Box::new(index))));
+        }
+        base
+    }
+
+    // variable(宣言).
     fn variable(&mut self, t: Type, s: Structure) -> AstType {
+        let token = self.next();
+        if token.get_token_type() == Token::Variable {
+            self.declare_name(token);
+        }
+        self.variable_ref(t, s)
+    }
+
+    // variable.
+    fn variable_ref(&mut self, t: Type, s: Structure) -> AstType {
         let token = self.next_consume();
         let next = self.next();
+        let name = self.resolve_name(token);
         match token.get_token_type() {
+            Token::Variable if Token::LeftBracket == next.get_token_type() && s == Structure::Pointer => {
+                self.pointer_index(AstType::Variable(t, s, name))
+            }
             Token::Variable if Token::LeftBracket == next.get_token_type() => {
                 // ポインタと同じようにアクセスするため、Indirectでくるむ
                 let index = self.array_index(&s);
                 AstType::Indirect(Box::new(AstType::Plus(
-                    Box::new(AstType::Variable(t, s, token.get_token_value())),
+                    Box::new(AstType::Variable(t, s, name)),
                     Box::new(index),
                 )))
             }
+            // ポインタへのポインタ(char**など)は、要素の型をポインタとする
+            Token::Multi if s == Structure::Pointer => self.variable(Type::Pointer(Box::new(t)), s),
             Token::Variable => {
                 // シンボルテーブルへ保存（未登録の場合）.
-                if self.search_symbol(&self.cur_scope, &token.get_token_value()).is_none() {
+                if self.search_symbol(&self.cur_scope, &name).is_none() {
                     self.sym_table.register_sym(Symbol::new(
                             self.cur_scope.clone(),
-                            token.get_token_value(),
+                            name.clone(),
                             t.clone(),
                             s.clone(),
                     ));
                 }
-                AstType::Variable(t, s, token.get_token_value())
+                AstType::Variable(t, s, name)
+            }
+            _ => {
+                self.syntax_error(token, format!("expected identifier before '{}'", token.get_token_value()));
+                AstType::Factor(0)
             }
-            _ => panic!("{} {}: not support token {:?}", file!(), line!(), token),
         }
     }
 
@@ -1058,23 +2904,29 @@ impl<'a> AstGen<'a> {
     }
 
     // array num count
-    fn array_size(&mut self, size: Vec<usize>) -> Vec<usize> {
+    //
+    // 0以下の要素数や定数でない要素数(VLA)はエラーとして記録する
+    fn array_size(&mut self, name: &str, size: Vec<usize>) -> Vec<usize> {
         match self.next().get_token_type() {
             Token::LeftBracket => {
                 let mut sizes = size;
                 self.consume();
-                let s = self
-                    .next()
-                    .get_token_value()
-                    .parse::<usize>()
-                    .expect("failed parse");
-                self.must_next(Token::Number, "ast.rs(arra_size): Not exists Number");
-                self.must_next(
-                    Token::RightBracket,
-                    "ast.rs(arra_size): Not exists RightBracket",
-                );
+                let token = self.next();
+                let dim = self.assign();
+                self.must_next(Token::RightBracket);
+                let s = match dim.eval_constant() {
+                    Some(n) if n > 0 => n as usize,
+                    Some(n) => {
+                        self.errors.push(Diagnostic::error(Some(token.pos.clone()), format!("array size must be positive: {}[{}]", name, n)));
+                        1
+                    }
+                    None => {
+                        self.errors.push(Diagnostic::error(Some(token.pos.clone()), format!("variable length array is not supported: {}", name)));
+                        1
+                    }
+                };
                 sizes.push(s);
-                self.array_size(sizes)
+                self.array_size(name, sizes)
             }
             _ => size,
         }
@@ -1084,18 +2936,30 @@ impl<'a> AstGen<'a> {
     fn variable_array(&mut self, t: Type) -> AstType {
         let token = self.next_consume();
         match token.get_token_type() {
+            Token::Variable if self.in_params => self.array_param(t, token),
             Token::Variable => {
                 // シンボルテーブルへ保存（未登録の場合）.
-                let s = Structure::Array(self.array_size(vec![]));
-                if self.search_symbol(&self.cur_scope, &token.get_token_value()).is_none() {
+                let name = self.declare_name(token);
+                let s = Structure::Array(self.array_size(&token.get_token_value(), vec![]));
+                if self.search_symbol(&self.cur_scope, &name).is_none() {
                     self.sym_table.register_sym(Symbol::new(
                             self.cur_scope.clone(),
-                            token.get_token_value(),
+                            name.clone(),
                             t.clone(),
                             s.clone(),
                     ));
                 }
-                AstType::Variable(t, s, token.get_token_value())
+                let var = AstType::Variable(t, s, name);
+
+                // 初期化子があれば、要素ごとに初期化
+                if self.next().get_token_type() == Token::Assign {
+                    self.consume();
+                    if self.next().get_token_type() == Token::LeftBrace {
+                        return self.array_initializer(var);
+                    }
+                    self.back(1);
+                }
+                var
             }
             _ => panic!(
                 "ast.rs(variable_array): Not Support Token {:?}",
@@ -1104,13 +2968,117 @@ impl<'a> AstGen<'a> {
         }
     }
 
-    // sizeof演算子
-    fn factor_sizeof(&mut self) -> AstType {
-        self.must_next(Token::LeftParen, "ast.rs(factor_sizeof): Not exists LeftParen");
+    // 配列として宣言された引数.
+    //
+    // 引数の配列はポインタとなる為、要素数は省略可能とし、ポインタとして登録する
+    fn array_param(&mut self, t: Type, token: &TokenInfo) -> AstType {
+        let name = token.get_token_value();
+        self.must_next(Token::LeftBracket);
+        if self.next().get_token_type() == Token::RightBracket {
+            self.consume();
+        } else {
+            self.back(1);
+            self.array_size(&name, vec![]);
+        }
+        if self.next().get_token_type() == Token::LeftBracket {
+            self.errors.push(Diagnostic::error(Some(token.pos.clone()), format!("multi-dimensional array parameter is not supported: {}", name)));
+            self.array_size(&name, vec![]);
+        }
 
-        // 次のトークンが型であるか判定
-        let token = self.next();
-        let ast = match token.get_token_type() {
+        if self.search_symbol(&self.cur_scope, &name).is_none() {
+            let mut sym = Symbol::new(self.cur_scope.clone(), name.clone(), t.clone(), Structure::Pointer);
+            sym.is_array_param = true;
+            self.sym_table.register_sym(sym);
+        }
+        AstType::Variable(t, Structure::Pointer, name)
+    }
+
+    // 配列の初期化子.
+    //
+    // 指示子([n] =)の要素から格納を続け、初期化子の無い要素は0とする
+    fn array_initializer(&mut self, var: AstType) -> AstType {
+        let (name, count) = match var {
+            AstType::Variable(_, Structure::Array(ref v), ref n) => (n.clone(), v.iter().product::<usize>()),
+            _ => panic!("{} {}: not array {:?}", file!(), line!(), var),
+        };
+        self.must_next(Token::LeftBrace);
+
+        let mut inits: Vec<Option<AstType>> = vec![None; count];
+        let mut index = 0;
+        while self.next().get_token_type() != Token::RightBrace {
+            // 指示子
+            let token = self.next();
+            let designated = token.get_token_type() == Token::LeftBracket;
+            let mut constant = true;
+            if designated {
+                self.consume();
+                let designator = self.assign();
+                self.must_next(Token::RightBracket);
+                self.must_next(Token::Assign);
+                index = match designator.eval_constant() {
+                    Some(n) if n >= 0 => n as usize,
+                    _ => {
+                        self.errors.push(Diagnostic::error(Some(token.pos.clone()), format!("array index in initializer is not a constant: {}", name)));
+                        constant = false;
+                        count
+                    }
+                };
+            }
+
+            let init = self.assign();
+            match inits.get_mut(index) {
+                Some(Some(_)) => self.errors.push(Diagnostic::error(Some(token.pos.clone()), format!("duplicate initializer: {}[{}]", name, index))),
+                Some(slot) => *slot = Some(init),
+                None if !constant => {}
+                None if designated => self.errors.push(Diagnostic::error(
+                    Some(token.pos.clone()),
+                    format!("array index in initializer exceeds array bounds: {}[{}]", name, index),
+                )),
+                None => self.errors.push(Diagnostic::error(Some(token.pos.clone()), format!("excess elements in array initializer: {}", name))),
+            }
+            index += 1;
+
+            if self.next().get_token_type() == Token::Comma {
+                self.consume();
+            }
+        }
+        self.must_next(Token::RightBrace);
+
+        let inits = inits.into_iter().map(|i| i.unwrap_or(AstType::Factor(0))).collect();
+        AstType::ArrayInit(Box::new(var), inits)
+    }
+
+    // sizeof演算子
+    //
+    // 型名は括弧必須、式の場合は括弧を省略可能(sizeof x, sizeof *p)
+    fn factor_sizeof(&mut self) -> AstType {
+        match self.next().get_token_type() {
+            Token::LeftParen => {
+                self.consume();
+                let ast = self.sizeof_paren();
+                self.must_next(Token::RightParen);
+                ast
+            }
+            Token::Int | Token::Char | Token::IntPointer | Token::CharPointer | Token::Struct | Token::Union => {
+                // 型名は括弧必須
+                let token = self.next_consume();
+                self.syntax_error(token, format!("expected expression before '{}'", token.get_token_value()));
+                AstType::Factor(0)
+            }
+            _ => {
+                let token = self.next();
+                let factor = self.factor();
+                self.check_sizeof_array_param(&factor, token);
+                AstType::SizeOf(self.sizeof_expr(&factor, token))
+            }
+        }
+    }
+
+    // 括弧付きsizeof演算子
+    fn sizeof_paren(&mut self) -> AstType {
+        // 次のトークンが型であるか判定
+        let token = self.next();
+        match token.get_token_type() {
             Token::Int => {
                 self.consume();
                 AstType::SizeOf(4)
@@ -1119,11 +3087,30 @@ impl<'a> AstGen<'a> {
                 self.consume();
                 AstType::SizeOf(1)
             }
+            Token::Unsigned | Token::Signed => {
+                self.consume();
+                match self.next_consume().get_token_type() {
+                    Token::Int => AstType::SizeOf(4),
+                    Token::Char => AstType::SizeOf(1),
+                    Token::IntPointer | Token::CharPointer => AstType::SizeOf(8),
+                    _ => {
+                        self.back(1);
+                        AstType::SizeOf(4)
+                    }
+                }
+            }
             Token::IntPointer | Token::CharPointer => {
                 self.consume();
                 AstType::SizeOf(8)
             }
-            Token::Struct => {
+            // ポインタはアドレスのサイズ
+            Token::Double | Token::Float | Token::Short => match self.generate_type() {
+                (_, Structure::Pointer) => AstType::SizeOf(8),
+                (Type::Double, _) => AstType::SizeOf(8),
+                (Type::Float, _) => AstType::SizeOf(4),
+                _ => AstType::SizeOf(2),
+            },
+            Token::Struct | Token::Union => {
                 // シンボルテーブルより、構造体定義を取得し、サイズ算出
                 self.consume();
                 let name = self.next_consume();
@@ -1131,26 +3118,111 @@ impl<'a> AstGen<'a> {
                               .expect("cannot search token");
                 AstType::SizeOf(sym.size)
             }
+            Token::Variable if self.typedefs.contains_key(&token.get_token_value()) => {
+                // typedef名は登録された型のサイズ
+                self.consume();
+                let (t, s) = self.typedefs[&token.get_token_value()].clone();
+                AstType::SizeOf(self.typedef_size(&t, &s))
+            }
             _ => {
-                // 型でない場合は、変数や数値リテラル
-                let factor = self.factor();
+                // 型でない場合は、変数や数値リテラル、式
+                let expr = self.assign();
+                self.check_sizeof_array_param(&expr, token);
+                AstType::SizeOf(self.sizeof_expr(&expr, token))
+            }
+        }
+    }
 
-                // サイズを算出し、AST作成
-                match factor {
-                    AstType::Variable(_, _, _) => {
-                        // シンボルテーブルから変数をサーチし、サイズ算出
-                        let sym = self.search_symbol(&self.cur_scope, &token.get_token_value()).expect("cannot search token");
-                        AstType::SizeOf(sym.size)
+    // 配列として宣言された引数へのsizeofは、ポインタのサイズとなる為警告する
+    fn check_sizeof_array_param(&mut self, ast: &AstType, token: &TokenInfo) {
+        if let AstType::Variable(_, _, ref name) = *ast {
+            if self.search_symbol(&self.cur_scope, name).is_some_and(|s| s.is_array_param) {
+                self.warnings.push(Diagnostic::warning(Some(token.pos.clone()), format!("sizeof on array function parameter '{}' will return size of pointer", name)));
+            }
+        }
+    }
 
+    // sizeof演算子の対象となる式のサイズ算出
+    fn sizeof_expr(&mut self, ast: &AstType, token: &TokenInfo) -> usize {
+        match ast {
+            AstType::Variable(_, _, ref name) => {
+                // シンボルテーブルから変数をサーチし、サイズ算出
+                let sym = self.search_symbol(&self.cur_scope, name).expect("cannot search token");
+                sym.size
+            }
+            AstType::Indirect(ref a) => self.pointee_size(a),
+            // メンバーは、構造体定義に登録したメンバーのサイズ(配列のメンバーは要素の型のサイズ×要素数)
+            AstType::MemberAccess(ref s, ref m) => {
+                let member = match (s.type_of(), &**m) {
+                    (Type::Struct(ref tag), AstType::Variable(_, _, ref name)) => self
+                        .search_symbol(&self.cur_scope, tag)
+                        .and_then(|sym| sym.members.into_iter().find(|x| x.var == *name)),
+                    _ => None,
+                };
+                match member {
+                    Some(x) => x.member_size(),
+                    None => {
+                        self.errors.push(Diagnostic::error(Some(token.pos.clone()), "invalid member in sizeof expression".to_string()));
+                        0
                     }
-                    AstType::Factor(_) => AstType::SizeOf(8),
-                    _ => panic!("{} {}: not supprt ast: {:?}", file!(), line!(), factor)
                 }
             }
-        };
+            AstType::PreInc(ref a)
+            | AstType::PreDec(ref a)
+            | AstType::PostInc(ref a)
+            | AstType::PostDec(ref a) => self.sizeof_expr(a, token),
+            AstType::Factor(_) | AstType::FloatFactor(_) | AstType::SizeOf(_) | AstType::Address(_) => 8,
+            AstType::CompoundLiteral(ref a, _) => self.sizeof_expr(a, token),
+            _ if ast.is_pointer() => 8,
+            // 演算結果は、整数拡張後の型のサイズ
+            _ => match ast.type_of() {
+                Type::Char | Type::UChar => 1,
+                Type::Short => 2,
+                Type::Int | Type::UInt | Type::Float => 4,
+                Type::Long | Type::Double => 8,
+                t => panic!("{} {}: not support type: {:?}", file!(), line!(), t),
+            },
+        }
+    }
+
+    // typedefされた型のサイズ算出
+    fn typedef_size(&self, t: &Type, s: &Structure) -> usize {
+        match (t, s) {
+            (_, Structure::Pointer) => 8,
+            (Type::Struct(ref name), _) => {
+                let sym = self.search_symbol(&self.cur_scope, name).expect("cannot search token");
+                sym.size
+            }
+            (Type::Int, _) | (Type::UInt, _) => 4,
+            (Type::Char, _) | (Type::UChar, _) => 1,
+            (Type::Short, _) => 2,
+            (Type::Float, _) => 4,
+            (Type::Double, _) => 8,
+            _ => panic!("{} {}: not support type: {:?}", file!(), line!(), t),
+        }
+    }
 
-        self.must_next(Token::RightParen, "ast.rs(factor_sizeof): Not exists LeftParen");
-        ast
+    // ポインタ、配列が指し示す先の型サイズ算出
+    fn pointee_size(&self, ast: &AstType) -> usize {
+        match ast {
+            AstType::Variable(ref t, _, _) => match t {
+                Type::Int | Type::UInt => 4,
+                Type::Char | Type::UChar => 1,
+                Type::Short => 2,
+                Type::Float => 4,
+                Type::Double | Type::Long | Type::ULong | Type::Pointer(_) => 8,
+                Type::Struct(ref name) => {
+                    let sym = self.search_symbol(&self.cur_scope, name).expect("cannot search token");
+                    sym.size
+                }
+                _ => panic!("{} {}: not support type: {:?}", file!(), line!(), t),
+            },
+            // 配列アクセス(a[i])やポインタ演算(*(p + 1))は左辺の型に従う
+            AstType::Plus(ref a, _) | AstType::Minus(ref a, _) => self.pointee_size(a),
+            // 配列のメンバーは、メンバーの型に従う
+            AstType::MemberAccess(_, ref m) => self.pointee_size(m),
+            _ => panic!("{} {}: not supprt ast: {:?}", file!(), line!(), ast)
+        }
     }
 
     // number
@@ -1159,6 +3231,12 @@ impl<'a> AstGen<'a> {
         AstType::Factor(n.expect("ast.rs(number): cannot convert i64"))
     }
 
+    // 浮動小数点数
+    fn float_number(&self, token: &TokenInfo) -> AstType {
+        let n = token.get_token_value().parse::<f64>();
+        AstType::FloatFactor(n.expect("ast.rs(float_number): cannot convert f64"))
+    }
+
     // トークン読み取り.
     fn next(&mut self) -> &'a TokenInfo {
         let n = self.tokens.get(self.current_pos);
@@ -1183,10 +3261,23 @@ impl<'a> AstGen<'a> {
     }
 
     // 指定されたトークンでない場合、panicメッセージ表示.
-    fn must_next(&mut self, t: Token, m: &str) {
+    fn must_next(&mut self, t: Token) {
         let token = self.next_consume();
         if token.get_token_type() != t {
-            panic!("{} {}: {} {:?}", file!(), line!(), m, token)
+            let expected = match t {
+                Token::SemiColon => ";".to_string(),
+                Token::Colon => ":".to_string(),
+                Token::LeftParen => "(".to_string(),
+                Token::RightParen => ")".to_string(),
+                Token::LeftBrace => "{".to_string(),
+                Token::RightBrace => "}".to_string(),
+                Token::LeftBracket => "[".to_string(),
+                Token::RightBracket => "]".to_string(),
+                Token::Assign => "=".to_string(),
+                Token::While => "while".to_string(),
+                _ => format!("{:?}", t),
+            };
+            self.syntax_error(token, format!("expected '{}' before '{}'", expected, token.get_token_value()));
         }
     }
 
@@ -1210,11 +3301,17 @@ impl<'a> AstGen<'a> {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use lexer::LexicalAnalysis;
 
     fn create_token(t: Token, s: String) -> TokenInfo {
         TokenInfo::new(t, s, ("".to_string(), 0, 0))
     }
 
+    // 診断を「位置: メッセージ」形式の文字列にする
+    fn messages(d: &[Diagnostic]) -> Vec<String> {
+        d.iter().map(|e| e.to_string()).collect()
+    }
+
     #[test]
     fn test_add_operator() {
         // 単純な加算テスト.
@@ -1870,15 +3967,15 @@ mod tests {
                     Structure::Identifier,
                     "main".to_string(),
                     Box::new(AstType::Argment(vec![])),
-                    Box::new(AstType::Statement(vec![AstType::GreaterThanEqual(
-                        Box::new(AstType::Equal(
-                            Box::new(AstType::LessThan(
-                                Box::new(AstType::Factor(2)),
-                                Box::new(AstType::Factor(3)),
-                            )),
-                            Box::new(AstType::Factor(4)),
+                    Box::new(AstType::Statement(vec![AstType::Equal(
+                        Box::new(AstType::LessThan(
+                            Box::new(AstType::Factor(2)),
+                            Box::new(AstType::Factor(3)),
                         )),
-                        Box::new(AstType::Factor(5))
+                        Box::new(AstType::GreaterThanEqual(
+                            Box::new(AstType::Factor(4)),
+                            Box::new(AstType::Factor(5)),
+                        ))
                     ),])),
                 )
             )
@@ -2769,6 +4866,311 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_condition_omitted_middle() {
+        {
+            let data = vec![
+                create_token(Token::Int, "int".to_string()),
+                create_token(Token::Variable, "main".to_string()),
+                create_token(Token::LeftParen, "(".to_string()),
+                create_token(Token::RightParen, ")".to_string()),
+                create_token(Token::LeftBrace, "{".to_string()),
+                create_token(Token::Int, "int".to_string()),
+                create_token(Token::Variable, "x".to_string()),
+                create_token(Token::SemiColon, ";".to_string()),
+                create_token(Token::Variable, "x".to_string()),
+                create_token(Token::Question, "?".to_string()),
+                create_token(Token::Colon, ":".to_string()),
+                create_token(Token::Number, "5".to_string()),
+                create_token(Token::SemiColon, ";".to_string()),
+                create_token(Token::RightBrace, "}".to_string()),
+                create_token(Token::End, "End".to_string()),
+            ];
+            let mut ast = AstGen::new(&data);
+            ast.set_config(&Config { gnu: true, ..Default::default() });
+            let result = ast.parse();
+
+            // 期待値確認.
+            assert_eq!(
+                result.get_tree()[0],
+                AstType::FuncDef(
+                    Type::Int,
+                    Structure::Identifier,
+                    "main".to_string(),
+                    Box::new(AstType::Argment(vec![])),
+                    Box::new(AstType::Statement(vec![
+                        AstType::Variable(Type::Int, Structure::Identifier, "x".to_string()),
+                        AstType::Condition(
+                            Box::new(AstType::Variable(
+                                Type::Int,
+                                Structure::Identifier,
+                                "x".to_string()
+                            )),
+                            Box::new(AstType::Temporary()),
+                            Box::new(AstType::Factor(5))
+                        ),
+                    ])),
+                )
+            )
+        }
+    }
+
+    #[test]
+    fn test_condition_omitted_middle_strict() {
+        let data = vec![
+            create_token(Token::Int, "int".to_string()),
+            create_token(Token::Variable, "main".to_string()),
+            create_token(Token::LeftParen, "(".to_string()),
+            create_token(Token::RightParen, ")".to_string()),
+            create_token(Token::LeftBrace, "{".to_string()),
+            create_token(Token::Number, "1".to_string()),
+            create_token(Token::Question, "?".to_string()),
+            create_token(Token::Colon, ":".to_string()),
+            create_token(Token::Number, "5".to_string()),
+            create_token(Token::SemiColon, ";".to_string()),
+            create_token(Token::RightBrace, "}".to_string()),
+            create_token(Token::End, "End".to_string()),
+        ];
+        let mut ast = AstGen::new(&data);
+        ast.parse();
+        assert_eq!(messages(ast.get_errors()), vec![":0:0: omitted middle operand requires --gnu".to_string()]);
+    }
+
+    #[test]
+    fn test_indirect_func_call() {
+        // int* get() { return &x; } int main() { *get() = 5; }
+        let data = vec![
+            create_token(Token::IntPointer, "int*".to_string()),
+            create_token(Token::Variable, "get".to_string()),
+            create_token(Token::LeftParen, "(".to_string()),
+            create_token(Token::RightParen, ")".to_string()),
+            create_token(Token::LeftBrace, "{".to_string()),
+            create_token(Token::Int, "int".to_string()),
+            create_token(Token::Variable, "x".to_string()),
+            create_token(Token::SemiColon, ";".to_string()),
+            create_token(Token::Return, "return".to_string()),
+            create_token(Token::And, "&".to_string()),
+            create_token(Token::Variable, "x".to_string()),
+            create_token(Token::SemiColon, ";".to_string()),
+            create_token(Token::RightBrace, "}".to_string()),
+            create_token(Token::Int, "int".to_string()),
+            create_token(Token::Variable, "main".to_string()),
+            create_token(Token::LeftParen, "(".to_string()),
+            create_token(Token::RightParen, ")".to_string()),
+            create_token(Token::LeftBrace, "{".to_string()),
+            create_token(Token::Multi, "*".to_string()),
+            create_token(Token::Variable, "get".to_string()),
+            create_token(Token::LeftParen, "(".to_string()),
+            create_token(Token::RightParen, ")".to_string()),
+            create_token(Token::Assign, "=".to_string()),
+            create_token(Token::Number, "5".to_string()),
+            create_token(Token::SemiColon, ";".to_string()),
+            create_token(Token::RightBrace, "}".to_string()),
+            create_token(Token::End, "End".to_string()),
+        ];
+        {
+            let mut ast = AstGen::new(&data);
+            let result = ast.parse();
+
+            // 期待値確認.
+            assert!(ast.get_errors().is_empty());
+            assert_eq!(
+                result.get_tree()[1],
+                AstType::FuncDef(
+                    Type::Int,
+                    Structure::Identifier,
+                    "main".to_string(),
+                    Box::new(AstType::Argment(vec![])),
+                    Box::new(AstType::Statement(vec![AstType::Assign(
+                        Box::new(AstType::Indirect(Box::new(AstType::FuncCall(
+                            Box::new(AstType::Variable(Type::Int, Structure::Pointer, "get".to_string())),
+                            Box::new(AstType::Argment(vec![])),
+                        )))),
+                        Box::new(AstType::Factor(5)),
+                    )])),
+                )
+            );
+        }
+        {
+            // ポインタを返さない関数の結果は参照できない
+            let mut data = data.clone();
+            data[0] = create_token(Token::Int, "int".to_string());
+            let mut ast = AstGen::new(&data);
+            ast.parse();
+            assert_eq!(messages(ast.get_errors()), vec![":0:0: invalid type argument of unary '*': get()".to_string()]);
+        }
+    }
+
+    #[test]
+    fn test_void_cast() {
+        // int g() { return 1; } int main() { (void)g(); }
+        let data = vec![
+            create_token(Token::Int, "int".to_string()),
+            create_token(Token::Variable, "g".to_string()),
+            create_token(Token::LeftParen, "(".to_string()),
+            create_token(Token::RightParen, ")".to_string()),
+            create_token(Token::LeftBrace, "{".to_string()),
+            create_token(Token::Return, "return".to_string()),
+            create_token(Token::Number, "1".to_string()),
+            create_token(Token::SemiColon, ";".to_string()),
+            create_token(Token::RightBrace, "}".to_string()),
+            create_token(Token::Int, "int".to_string()),
+            create_token(Token::Variable, "main".to_string()),
+            create_token(Token::LeftParen, "(".to_string()),
+            create_token(Token::RightParen, ")".to_string()),
+            create_token(Token::LeftBrace, "{".to_string()),
+            create_token(Token::LeftParen, "(".to_string()),
+            create_token(Token::Void, "void".to_string()),
+            create_token(Token::RightParen, ")".to_string()),
+            create_token(Token::Variable, "g".to_string()),
+            create_token(Token::LeftParen, "(".to_string()),
+            create_token(Token::RightParen, ")".to_string()),
+            create_token(Token::SemiColon, ";".to_string()),
+            create_token(Token::RightBrace, "}".to_string()),
+            create_token(Token::End, "End".to_string()),
+        ];
+        let mut ast = AstGen::new(&data);
+        let result = ast.parse();
+
+        // 期待値確認.
+        let call = AstType::Cast(
+            Type::Void,
+            Box::new(AstType::FuncCall(
+                Box::new(AstType::Variable(Type::Int, Structure::Identifier, "g".to_string())),
+                Box::new(AstType::Argment(vec![])),
+            )),
+        );
+        assert_eq!(call.type_of(), Type::Void);
+        assert_eq!(
+            result.get_tree()[1],
+            AstType::FuncDef(
+                Type::Int,
+                Structure::Identifier,
+                "main".to_string(),
+                Box::new(AstType::Argment(vec![])),
+                Box::new(AstType::Statement(vec![call])),
+            )
+        );
+    }
+
+    #[test]
+    fn test_reset() {
+        // (c ? a : b) = 1; をstrictモードで解析後、GNUモードで再解析
+        let data = vec![
+            create_token(Token::Int, "int".to_string()),
+            create_token(Token::Variable, "main".to_string()),
+            create_token(Token::LeftParen, "(".to_string()),
+            create_token(Token::RightParen, ")".to_string()),
+            create_token(Token::LeftBrace, "{".to_string()),
+            create_token(Token::Int, "int".to_string()),
+            create_token(Token::Variable, "a".to_string()),
+            create_token(Token::Comma, ",".to_string()),
+            create_token(Token::Variable, "b".to_string()),
+            create_token(Token::Comma, ",".to_string()),
+            create_token(Token::Variable, "c".to_string()),
+            create_token(Token::SemiColon, ";".to_string()),
+            create_token(Token::LeftParen, "(".to_string()),
+            create_token(Token::Variable, "c".to_string()),
+            create_token(Token::Question, "?".to_string()),
+            create_token(Token::Variable, "a".to_string()),
+            create_token(Token::Colon, ":".to_string()),
+            create_token(Token::Variable, "b".to_string()),
+            create_token(Token::RightParen, ")".to_string()),
+            create_token(Token::Assign, "=".to_string()),
+            create_token(Token::Number, "1".to_string()),
+            create_token(Token::SemiColon, ";".to_string()),
+            create_token(Token::RightBrace, "}".to_string()),
+            create_token(Token::End, "End".to_string()),
+        ];
+        let mut ast = AstGen::new(&data);
+        let first = ast.parse();
+        assert!(!ast.get_errors().is_empty());
+
+        // 同じトークン列を再解析すると、同じ抽象構文木となる
+        ast.reset();
+        let second = ast.parse();
+        assert_eq!(first.get_tree(), second.get_tree());
+
+        // 設定を切り替えて再解析
+        ast.reset();
+        ast.set_config(&Config { gnu: true, ..Default::default() });
+        ast.parse();
+        assert!(ast.get_errors().is_empty());
+    }
+
+    #[test]
+    fn test_condition_lvalue() {
+        // (c ? a : b) = 1;
+        let data = vec![
+            create_token(Token::Int, "int".to_string()),
+            create_token(Token::Variable, "main".to_string()),
+            create_token(Token::LeftParen, "(".to_string()),
+            create_token(Token::RightParen, ")".to_string()),
+            create_token(Token::LeftBrace, "{".to_string()),
+            create_token(Token::Int, "int".to_string()),
+            create_token(Token::Variable, "a".to_string()),
+            create_token(Token::Comma, ",".to_string()),
+            create_token(Token::Variable, "b".to_string()),
+            create_token(Token::Comma, ",".to_string()),
+            create_token(Token::Variable, "c".to_string()),
+            create_token(Token::SemiColon, ";".to_string()),
+            create_token(Token::LeftParen, "(".to_string()),
+            create_token(Token::Variable, "c".to_string()),
+            create_token(Token::Question, "?".to_string()),
+            create_token(Token::Variable, "a".to_string()),
+            create_token(Token::Colon, ":".to_string()),
+            create_token(Token::Variable, "b".to_string()),
+            create_token(Token::RightParen, ")".to_string()),
+            create_token(Token::Assign, "=".to_string()),
+            create_token(Token::Number, "1".to_string()),
+            create_token(Token::SemiColon, ";".to_string()),
+            create_token(Token::RightBrace, "}".to_string()),
+            create_token(Token::End, "End".to_string()),
+        ];
+        let var = |n: &str| Box::new(AstType::Variable(Type::Int, Structure::Identifier, n.to_string()));
+        {
+            let mut ast = AstGen::new(&data);
+            ast.set_config(&Config { gnu: true, ..Default::default() });
+            let result = ast.parse();
+
+            // 期待値確認.
+            assert!(ast.get_errors().is_empty());
+            assert_eq!(
+                result.get_tree()[0],
+                AstType::FuncDef(
+                    Type::Int,
+                    Structure::Identifier,
+                    "main".to_string(),
+                    Box::new(AstType::Argment(vec![])),
+                    Box::new(AstType::Statement(vec![
+                        *var("a"),
+                        *var("b"),
+                        *var("c"),
+                        AstType::Assign(
+                            Box::new(AstType::Condition(var("c"), var("a"), var("b"))),
+                            Box::new(AstType::Factor(1))
+                        ),
+                    ])),
+                )
+            )
+        }
+        {
+            // GNU拡張でない場合はエラー
+            let mut ast = AstGen::new(&data);
+            ast.parse();
+            assert_eq!(messages(ast.get_errors()), vec![":0:0: conditional lvalue requires --gnu".to_string()]);
+        }
+        {
+            // 左辺値でない分岐はエラー
+            let mut data = data.clone();
+            data[17] = create_token(Token::Number, "2".to_string());
+            let mut ast = AstGen::new(&data);
+            ast.set_config(&Config { gnu: true, ..Default::default() });
+            ast.parse();
+            assert_eq!(messages(ast.get_errors()), vec![":0:0: conditional lvalue branch is not an lvalue".to_string()]);
+        }
+    }
+
     #[test]
     fn test_unary_operator() {
         {
@@ -3142,9 +5544,9 @@ mod tests {
         }
     }
 
-    // ビット演算子テスト.
+    // 演算子の優先順位テスト(シフト、比較、等価、ビット演算).
     #[test]
-    fn test_bit_operator() {
+    fn test_shift_relation_precedence() {
         {
             let data = vec![
                 create_token(Token::Int, "int".to_string()),
@@ -3153,8 +5555,10 @@ mod tests {
                 create_token(Token::RightParen, ")".to_string()),
                 create_token(Token::LeftBrace, "{".to_string()),
                 create_token(Token::Number, "2".to_string()),
-                create_token(Token::And, "&".to_string()),
+                create_token(Token::RightShift, ">>".to_string()),
                 create_token(Token::Number, "3".to_string()),
+                create_token(Token::LessThan, "<".to_string()),
+                create_token(Token::Number, "1".to_string()),
                 create_token(Token::SemiColon, ";".to_string()),
                 create_token(Token::RightBrace, "}".to_string()),
                 create_token(Token::End, "End".to_string()),
@@ -3170,10 +5574,15 @@ mod tests {
                     Structure::Identifier,
                     "main".to_string(),
                     Box::new(AstType::Argment(vec![])),
-                    Box::new(AstType::Statement(vec![AstType::BitAnd(
-                        Box::new(AstType::Factor(2)),
-                        Box::new(AstType::Factor(3))
-                    ),])),
+                    Box::new(AstType::Statement(vec![
+                        AstType::LessThan(
+                            Box::new(AstType::RightShift(
+                                Box::new(AstType::Factor(2)),
+                                Box::new(AstType::Factor(3)),
+                            )),
+                            Box::new(AstType::Factor(1)),
+                        ),
+                    ])),
                 )
             )
         }
@@ -3184,8 +5593,10 @@ mod tests {
                 create_token(Token::LeftParen, "(".to_string()),
                 create_token(Token::RightParen, ")".to_string()),
                 create_token(Token::LeftBrace, "{".to_string()),
+                create_token(Token::Number, "1".to_string()),
+                create_token(Token::Equal, "==".to_string()),
                 create_token(Token::Number, "2".to_string()),
-                create_token(Token::BitOr, "&".to_string()),
+                create_token(Token::LessThan, "<".to_string()),
                 create_token(Token::Number, "3".to_string()),
                 create_token(Token::SemiColon, ";".to_string()),
                 create_token(Token::RightBrace, "}".to_string()),
@@ -3202,10 +5613,15 @@ mod tests {
                     Structure::Identifier,
                     "main".to_string(),
                     Box::new(AstType::Argment(vec![])),
-                    Box::new(AstType::Statement(vec![AstType::BitOr(
-                        Box::new(AstType::Factor(2)),
-                        Box::new(AstType::Factor(3))
-                    ),])),
+                    Box::new(AstType::Statement(vec![
+                        AstType::Equal(
+                            Box::new(AstType::Factor(1)),
+                            Box::new(AstType::LessThan(
+                                Box::new(AstType::Factor(2)),
+                                Box::new(AstType::Factor(3)),
+                            )),
+                        ),
+                    ])),
                 )
             )
         }
@@ -3216,8 +5632,10 @@ mod tests {
                 create_token(Token::LeftParen, "(".to_string()),
                 create_token(Token::RightParen, ")".to_string()),
                 create_token(Token::LeftBrace, "{".to_string()),
+                create_token(Token::Number, "1".to_string()),
+                create_token(Token::LessThan, "<".to_string()),
                 create_token(Token::Number, "2".to_string()),
-                create_token(Token::BitXor, "^".to_string()),
+                create_token(Token::Equal, "==".to_string()),
                 create_token(Token::Number, "3".to_string()),
                 create_token(Token::SemiColon, ";".to_string()),
                 create_token(Token::RightBrace, "}".to_string()),
@@ -3234,10 +5652,15 @@ mod tests {
                     Structure::Identifier,
                     "main".to_string(),
                     Box::new(AstType::Argment(vec![])),
-                    Box::new(AstType::Statement(vec![AstType::BitXor(
-                        Box::new(AstType::Factor(2)),
-                        Box::new(AstType::Factor(3))
-                    ),])),
+                    Box::new(AstType::Statement(vec![
+                        AstType::Equal(
+                            Box::new(AstType::LessThan(
+                                Box::new(AstType::Factor(1)),
+                                Box::new(AstType::Factor(2)),
+                            )),
+                            Box::new(AstType::Factor(3)),
+                        ),
+                    ])),
                 )
             )
         }
@@ -3248,10 +5671,12 @@ mod tests {
                 create_token(Token::LeftParen, "(".to_string()),
                 create_token(Token::RightParen, ")".to_string()),
                 create_token(Token::LeftBrace, "{".to_string()),
+                create_token(Token::Number, "1".to_string()),
+                create_token(Token::GreaterThanEqual, ">=".to_string()),
                 create_token(Token::Number, "2".to_string()),
-                create_token(Token::And, "&".to_string()),
+                create_token(Token::NotEqual, "!=".to_string()),
                 create_token(Token::Number, "3".to_string()),
-                create_token(Token::BitOr, "|".to_string()),
+                create_token(Token::LessThanEqual, "<=".to_string()),
                 create_token(Token::Number, "4".to_string()),
                 create_token(Token::SemiColon, ";".to_string()),
                 create_token(Token::RightBrace, "}".to_string()),
@@ -3268,31 +5693,35 @@ mod tests {
                     Structure::Identifier,
                     "main".to_string(),
                     Box::new(AstType::Argment(vec![])),
-                    Box::new(AstType::Statement(vec![AstType::BitOr(
-                        Box::new(AstType::BitAnd(
-                            Box::new(AstType::Factor(2)),
-                            Box::new(AstType::Factor(3)),
-                        )),
-                        Box::new(AstType::Factor(4))
-                    ),])),
-                )
-            )
-        }
-    }
-
-    #[test]
-    fn test_assign_operator() {
-        {
+                    Box::new(AstType::Statement(vec![
+                        AstType::NotEqual(
+                            Box::new(AstType::GreaterThanEqual(
+                                Box::new(AstType::Factor(1)),
+                                Box::new(AstType::Factor(2)),
+                            )),
+                            Box::new(AstType::LessThanEqual(
+                                Box::new(AstType::Factor(3)),
+                                Box::new(AstType::Factor(4)),
+                            )),
+                        ),
+                    ])),
+                )
+            )
+        }
+        {
             let data = vec![
                 create_token(Token::Int, "int".to_string()),
                 create_token(Token::Variable, "main".to_string()),
                 create_token(Token::LeftParen, "(".to_string()),
                 create_token(Token::RightParen, ")".to_string()),
                 create_token(Token::LeftBrace, "{".to_string()),
-                create_token(Token::Int, "int".to_string()),
-                create_token(Token::Variable, "a".to_string()),
-                create_token(Token::Assign, "=".to_string()),
+                create_token(Token::Number, "1".to_string()),
+                create_token(Token::LeftShift, "<<".to_string()),
+                create_token(Token::Number, "2".to_string()),
+                create_token(Token::NotEqual, "!=".to_string()),
                 create_token(Token::Number, "3".to_string()),
+                create_token(Token::RightShift, ">>".to_string()),
+                create_token(Token::Number, "1".to_string()),
                 create_token(Token::SemiColon, ";".to_string()),
                 create_token(Token::RightBrace, "}".to_string()),
                 create_token(Token::End, "End".to_string()),
@@ -3308,14 +5737,18 @@ mod tests {
                     Structure::Identifier,
                     "main".to_string(),
                     Box::new(AstType::Argment(vec![])),
-                    Box::new(AstType::Statement(vec![AstType::Assign(
-                        Box::new(AstType::Variable(
-                            Type::Int,
-                            Structure::Identifier,
-                            "a".to_string()
-                        )),
-                        Box::new(AstType::Factor(3))
-                    ),])),
+                    Box::new(AstType::Statement(vec![
+                        AstType::NotEqual(
+                            Box::new(AstType::LeftShift(
+                                Box::new(AstType::Factor(1)),
+                                Box::new(AstType::Factor(2)),
+                            )),
+                            Box::new(AstType::RightShift(
+                                Box::new(AstType::Factor(3)),
+                                Box::new(AstType::Factor(1)),
+                            )),
+                        ),
+                    ])),
                 )
             )
         }
@@ -3326,12 +5759,11 @@ mod tests {
                 create_token(Token::LeftParen, "(".to_string()),
                 create_token(Token::RightParen, ")".to_string()),
                 create_token(Token::LeftBrace, "{".to_string()),
-                create_token(Token::Int, "int".to_string()),
-                create_token(Token::Variable, "a".to_string()),
-                create_token(Token::Assign, "=".to_string()),
-                create_token(Token::Number, "3".to_string()),
-                create_token(Token::Plus, "+".to_string()),
                 create_token(Token::Number, "1".to_string()),
+                create_token(Token::And, "&".to_string()),
+                create_token(Token::Number, "2".to_string()),
+                create_token(Token::Equal, "==".to_string()),
+                create_token(Token::Number, "3".to_string()),
                 create_token(Token::SemiColon, ";".to_string()),
                 create_token(Token::RightBrace, "}".to_string()),
                 create_token(Token::End, "End".to_string()),
@@ -3347,17 +5779,15 @@ mod tests {
                     Structure::Identifier,
                     "main".to_string(),
                     Box::new(AstType::Argment(vec![])),
-                    Box::new(AstType::Statement(vec![AstType::Assign(
-                        Box::new(AstType::Variable(
-                            Type::Int,
-                            Structure::Identifier,
-                            "a".to_string()
-                        )),
-                        Box::new(AstType::Plus(
-                            Box::new(AstType::Factor(3)),
+                    Box::new(AstType::Statement(vec![
+                        AstType::BitAnd(
                             Box::new(AstType::Factor(1)),
-                        ))
-                    ),])),
+                            Box::new(AstType::Equal(
+                                Box::new(AstType::Factor(2)),
+                                Box::new(AstType::Factor(3)),
+                            )),
+                        ),
+                    ])),
                 )
             )
         }
@@ -3368,14 +5798,11 @@ mod tests {
                 create_token(Token::LeftParen, "(".to_string()),
                 create_token(Token::RightParen, ")".to_string()),
                 create_token(Token::LeftBrace, "{".to_string()),
-                create_token(Token::Int, "int".to_string()),
-                create_token(Token::Variable, "a".to_string()),
-                create_token(Token::SemiColon, ";".to_string()),
-                create_token(Token::Variable, "a".to_string()),
-                create_token(Token::Assign, "=".to_string()),
-                create_token(Token::Number, "3".to_string()),
-                create_token(Token::LogicalAnd, "&&".to_string()),
                 create_token(Token::Number, "1".to_string()),
+                create_token(Token::Equal, "==".to_string()),
+                create_token(Token::Number, "2".to_string()),
+                create_token(Token::BitOr, "|".to_string()),
+                create_token(Token::Number, "3".to_string()),
                 create_token(Token::SemiColon, ";".to_string()),
                 create_token(Token::RightBrace, "}".to_string()),
                 create_token(Token::End, "End".to_string()),
@@ -3392,17 +5819,12 @@ mod tests {
                     "main".to_string(),
                     Box::new(AstType::Argment(vec![])),
                     Box::new(AstType::Statement(vec![
-                        AstType::Variable(Type::Int, Structure::Identifier, "a".to_string()),
-                        AstType::Assign(
-                            Box::new(AstType::Variable(
-                                Type::Int,
-                                Structure::Identifier,
-                                "a".to_string()
-                            )),
-                            Box::new(AstType::LogicalAnd(
-                                Box::new(AstType::Factor(3)),
+                        AstType::BitOr(
+                            Box::new(AstType::Equal(
                                 Box::new(AstType::Factor(1)),
-                            ))
+                                Box::new(AstType::Factor(2)),
+                            )),
+                            Box::new(AstType::Factor(3)),
                         ),
                     ])),
                 )
@@ -3415,12 +5837,11 @@ mod tests {
                 create_token(Token::LeftParen, "(".to_string()),
                 create_token(Token::RightParen, ")".to_string()),
                 create_token(Token::LeftBrace, "{".to_string()),
-                create_token(Token::Int, "int".to_string()),
-                create_token(Token::Variable, "a".to_string()),
-                create_token(Token::Assign, "=".to_string()),
-                create_token(Token::Number, "3".to_string()),
-                create_token(Token::Multi, "*".to_string()),
                 create_token(Token::Number, "1".to_string()),
+                create_token(Token::BitXor, "^".to_string()),
+                create_token(Token::Number, "2".to_string()),
+                create_token(Token::LessThan, "<".to_string()),
+                create_token(Token::Number, "3".to_string()),
                 create_token(Token::SemiColon, ";".to_string()),
                 create_token(Token::RightBrace, "}".to_string()),
                 create_token(Token::End, "End".to_string()),
@@ -3436,20 +5857,23 @@ mod tests {
                     Structure::Identifier,
                     "main".to_string(),
                     Box::new(AstType::Argment(vec![])),
-                    Box::new(AstType::Statement(vec![AstType::Assign(
-                        Box::new(AstType::Variable(
-                            Type::Int,
-                            Structure::Identifier,
-                            "a".to_string()
-                        )),
-                        Box::new(AstType::Multiple(
-                            Box::new(AstType::Factor(3)),
+                    Box::new(AstType::Statement(vec![
+                        AstType::BitXor(
                             Box::new(AstType::Factor(1)),
-                        ))
-                    ),])),
+                            Box::new(AstType::LessThan(
+                                Box::new(AstType::Factor(2)),
+                                Box::new(AstType::Factor(3)),
+                            )),
+                        ),
+                    ])),
                 )
             )
         }
+    }
+
+    // ビット演算子テスト.
+    #[test]
+    fn test_bit_operator() {
         {
             let data = vec![
                 create_token(Token::Int, "int".to_string()),
@@ -3457,12 +5881,9 @@ mod tests {
                 create_token(Token::LeftParen, "(".to_string()),
                 create_token(Token::RightParen, ")".to_string()),
                 create_token(Token::LeftBrace, "{".to_string()),
-                create_token(Token::Int, "int".to_string()),
-                create_token(Token::Variable, "a".to_string()),
-                create_token(Token::Assign, "=".to_string()),
+                create_token(Token::Number, "2".to_string()),
+                create_token(Token::And, "&".to_string()),
                 create_token(Token::Number, "3".to_string()),
-                create_token(Token::BitOr, "|".to_string()),
-                create_token(Token::Number, "1".to_string()),
                 create_token(Token::SemiColon, ";".to_string()),
                 create_token(Token::RightBrace, "}".to_string()),
                 create_token(Token::End, "End".to_string()),
@@ -3478,40 +5899,23 @@ mod tests {
                     Structure::Identifier,
                     "main".to_string(),
                     Box::new(AstType::Argment(vec![])),
-                    Box::new(AstType::Statement(vec![AstType::Assign(
-                        Box::new(AstType::Variable(
-                            Type::Int,
-                            Structure::Identifier,
-                            "a".to_string()
-                        )),
-                        Box::new(AstType::BitOr(
-                            Box::new(AstType::Factor(3)),
-                            Box::new(AstType::Factor(1)),
-                        ))
+                    Box::new(AstType::Statement(vec![AstType::BitAnd(
+                        Box::new(AstType::Factor(2)),
+                        Box::new(AstType::Factor(3))
                     ),])),
                 )
             )
         }
-    }
-
-    #[test]
-    fn test_call_func() {
         {
             let data = vec![
-                create_token(Token::Int, "int".to_string()),
-                create_token(Token::Variable, "a".to_string()),
-                create_token(Token::LeftParen, "(".to_string()),
-                create_token(Token::RightParen, ")".to_string()),
-                create_token(Token::LeftBrace, "{".to_string()),
-                create_token(Token::RightBrace, "}".to_string()),
                 create_token(Token::Int, "int".to_string()),
                 create_token(Token::Variable, "main".to_string()),
                 create_token(Token::LeftParen, "(".to_string()),
                 create_token(Token::RightParen, ")".to_string()),
                 create_token(Token::LeftBrace, "{".to_string()),
-                create_token(Token::Variable, "a".to_string()),
-                create_token(Token::LeftParen, "(".to_string()),
-                create_token(Token::RightParen, ")".to_string()),
+                create_token(Token::Number, "2".to_string()),
+                create_token(Token::BitOr, "&".to_string()),
+                create_token(Token::Number, "3".to_string()),
                 create_token(Token::SemiColon, ";".to_string()),
                 create_token(Token::RightBrace, "}".to_string()),
                 create_token(Token::End, "End".to_string()),
@@ -3522,54 +5926,28 @@ mod tests {
             // 期待値確認.
             assert_eq!(
                 result.get_tree()[0],
-                AstType::FuncDef(
-                    Type::Int,
-                    Structure::Identifier,
-                    "a".to_string(),
-                    Box::new(AstType::Argment(vec![])),
-                    Box::new(AstType::Statement(vec![])),
-                )
-            );
-            assert_eq!(
-                result.get_tree()[1],
                 AstType::FuncDef(
                     Type::Int,
                     Structure::Identifier,
                     "main".to_string(),
                     Box::new(AstType::Argment(vec![])),
-                    Box::new(AstType::Statement(vec![AstType::FuncCall(
-                        Box::new(AstType::Variable(
-                            Type::Int,
-                            Structure::Identifier,
-                            "a".to_string()
-                        )),
-                        Box::new(AstType::Argment(vec![]))
+                    Box::new(AstType::Statement(vec![AstType::BitOr(
+                        Box::new(AstType::Factor(2)),
+                        Box::new(AstType::Factor(3))
                     ),])),
                 )
-            );
+            )
         }
         {
             let data = vec![
-                create_token(Token::Int, "int".to_string()),
-                create_token(Token::Variable, "a".to_string()),
-                create_token(Token::LeftParen, "(".to_string()),
-                create_token(Token::Int, "int".to_string()),
-                create_token(Token::Variable, "x".to_string()),
-                create_token(Token::RightParen, ")".to_string()),
-                create_token(Token::LeftBrace, "{".to_string()),
-                create_token(Token::RightBrace, "}".to_string()),
                 create_token(Token::Int, "int".to_string()),
                 create_token(Token::Variable, "main".to_string()),
                 create_token(Token::LeftParen, "(".to_string()),
                 create_token(Token::RightParen, ")".to_string()),
                 create_token(Token::LeftBrace, "{".to_string()),
-                create_token(Token::Int, "int".to_string()),
-                create_token(Token::Variable, "b".to_string()),
-                create_token(Token::SemiColon, ";".to_string()),
-                create_token(Token::Variable, "a".to_string()),
-                create_token(Token::LeftParen, "(".to_string()),
-                create_token(Token::Variable, "b".to_string()),
-                create_token(Token::RightParen, ")".to_string()),
+                create_token(Token::Number, "2".to_string()),
+                create_token(Token::BitXor, "^".to_string()),
+                create_token(Token::Number, "3".to_string()),
                 create_token(Token::SemiColon, ";".to_string()),
                 create_token(Token::RightBrace, "}".to_string()),
                 create_token(Token::End, "End".to_string()),
@@ -3580,73 +5958,30 @@ mod tests {
             // 期待値確認.
             assert_eq!(
                 result.get_tree()[0],
-                AstType::FuncDef(
-                    Type::Int,
-                    Structure::Identifier,
-                    "a".to_string(),
-                    Box::new(AstType::Argment(vec![AstType::Variable(
-                        Type::Int,
-                        Structure::Identifier,
-                        "x".to_string()
-                    ),])),
-                    Box::new(AstType::Statement(vec![]))
-                )
-            );
-            assert_eq!(
-                result.get_tree()[1],
                 AstType::FuncDef(
                     Type::Int,
                     Structure::Identifier,
                     "main".to_string(),
                     Box::new(AstType::Argment(vec![])),
-                    Box::new(AstType::Statement(vec![
-                        AstType::Variable(Type::Int, Structure::Identifier, "b".to_string()),
-                        AstType::FuncCall(
-                            Box::new(AstType::Variable(
-                                Type::Int,
-                                Structure::Identifier,
-                                "a".to_string()
-                            )),
-                            Box::new(AstType::Argment(vec![AstType::Variable(
-                                Type::Int,
-                                Structure::Identifier,
-                                'b'.to_string()
-                            )]),)
-                        ),
-                    ])),
+                    Box::new(AstType::Statement(vec![AstType::BitXor(
+                        Box::new(AstType::Factor(2)),
+                        Box::new(AstType::Factor(3))
+                    ),])),
                 )
-            );
+            )
         }
         {
             let data = vec![
-                create_token(Token::Int, "int".to_string()),
-                create_token(Token::Variable, "test".to_string()),
-                create_token(Token::LeftParen, "(".to_string()),
-                create_token(Token::Int, "int".to_string()),
-                create_token(Token::Variable, "x".to_string()),
-                create_token(Token::Comma, ",".to_string()),
-                create_token(Token::Int, "int".to_string()),
-                create_token(Token::Variable, "y".to_string()),
-                create_token(Token::RightParen, ")".to_string()),
-                create_token(Token::LeftBrace, "{".to_string()),
-                create_token(Token::RightBrace, "}".to_string()),
                 create_token(Token::Int, "int".to_string()),
                 create_token(Token::Variable, "main".to_string()),
                 create_token(Token::LeftParen, "(".to_string()),
                 create_token(Token::RightParen, ")".to_string()),
                 create_token(Token::LeftBrace, "{".to_string()),
-                create_token(Token::Int, "int".to_string()),
-                create_token(Token::Variable, "b".to_string()),
-                create_token(Token::SemiColon, ";".to_string()),
-                create_token(Token::Int, "int".to_string()),
-                create_token(Token::Variable, "c".to_string()),
-                create_token(Token::SemiColon, ";".to_string()),
-                create_token(Token::Variable, "test".to_string()),
-                create_token(Token::LeftParen, "(".to_string()),
-                create_token(Token::Variable, "b".to_string()),
-                create_token(Token::Comma, ",".to_string()),
-                create_token(Token::Variable, "c".to_string()),
-                create_token(Token::RightParen, ")".to_string()),
+                create_token(Token::Number, "2".to_string()),
+                create_token(Token::And, "&".to_string()),
+                create_token(Token::Number, "3".to_string()),
+                create_token(Token::BitOr, "|".to_string()),
+                create_token(Token::Number, "4".to_string()),
                 create_token(Token::SemiColon, ";".to_string()),
                 create_token(Token::RightBrace, "}".to_string()),
                 create_token(Token::End, "End".to_string()),
@@ -3657,73 +5992,38 @@ mod tests {
             // 期待値確認.
             assert_eq!(
                 result.get_tree()[0],
-                AstType::FuncDef(
-                    Type::Int,
-                    Structure::Identifier,
-                    "test".to_string(),
-                    Box::new(AstType::Argment(vec![
-                        AstType::Variable(Type::Int, Structure::Identifier, "x".to_string()),
-                        AstType::Variable(Type::Int, Structure::Identifier, "y".to_string()),
-                    ])),
-                    Box::new(AstType::Statement(vec![]))
-                )
-            );
-            assert_eq!(
-                result.get_tree()[1],
                 AstType::FuncDef(
                     Type::Int,
                     Structure::Identifier,
                     "main".to_string(),
                     Box::new(AstType::Argment(vec![])),
-                    Box::new(AstType::Statement(vec![
-                        AstType::Variable(Type::Int, Structure::Identifier, 'b'.to_string()),
-                        AstType::Variable(Type::Int, Structure::Identifier, 'c'.to_string()),
-                        AstType::FuncCall(
-                            Box::new(AstType::Variable(
-                                Type::Int,
-                                Structure::Identifier,
-                                "test".to_string()
-                            )),
-                            Box::new(AstType::Argment(vec![
-                                AstType::Variable(
-                                    Type::Int,
-                                    Structure::Identifier,
-                                    'b'.to_string()
-                                ),
-                                AstType::Variable(
-                                    Type::Int,
-                                    Structure::Identifier,
-                                    'c'.to_string()
-                                ),
-                            ]))
-                        ),
-                    ])),
+                    Box::new(AstType::Statement(vec![AstType::BitOr(
+                        Box::new(AstType::BitAnd(
+                            Box::new(AstType::Factor(2)),
+                            Box::new(AstType::Factor(3)),
+                        )),
+                        Box::new(AstType::Factor(4))
+                    ),])),
                 )
             )
         }
+    }
+
+    // ビット演算子間の優先順位テスト.
+    #[test]
+    fn test_bit_operator_precedence() {
         {
             let data = vec![
-                create_token(Token::Int, "int".to_string()),
-                create_token(Token::Variable, "a".to_string()),
-                create_token(Token::LeftParen, "(".to_string()),
-                create_token(Token::IntPointer, "int*".to_string()),
-                create_token(Token::Variable, "x".to_string()),
-                create_token(Token::RightParen, ")".to_string()),
-                create_token(Token::LeftBrace, "{".to_string()),
-                create_token(Token::RightBrace, "}".to_string()),
                 create_token(Token::Int, "int".to_string()),
                 create_token(Token::Variable, "main".to_string()),
                 create_token(Token::LeftParen, "(".to_string()),
                 create_token(Token::RightParen, ")".to_string()),
                 create_token(Token::LeftBrace, "{".to_string()),
-                create_token(Token::Int, "int".to_string()),
-                create_token(Token::Variable, "y".to_string()),
-                create_token(Token::SemiColon, ";".to_string()),
-                create_token(Token::Variable, "a".to_string()),
-                create_token(Token::LeftParen, "(".to_string()),
+                create_token(Token::Number, "1".to_string()),
+                create_token(Token::BitOr, "|".to_string()),
+                create_token(Token::Number, "2".to_string()),
                 create_token(Token::And, "&".to_string()),
-                create_token(Token::Variable, "y".to_string()),
-                create_token(Token::RightParen, ")".to_string()),
+                create_token(Token::Number, "3".to_string()),
                 create_token(Token::SemiColon, ";".to_string()),
                 create_token(Token::RightBrace, "}".to_string()),
                 create_token(Token::End, "End".to_string()),
@@ -3734,49 +6034,23 @@ mod tests {
             // 期待値確認.
             assert_eq!(
                 result.get_tree()[0],
-                AstType::FuncDef(
-                    Type::Int,
-                    Structure::Identifier,
-                    "a".to_string(),
-                    Box::new(AstType::Argment(vec![AstType::Variable(
-                        Type::Int,
-                        Structure::Pointer,
-                        "x".to_string()
-                    ),])),
-                    Box::new(AstType::Statement(vec![])),
-                )
-            );
-            assert_eq!(
-                result.get_tree()[1],
                 AstType::FuncDef(
                     Type::Int,
                     Structure::Identifier,
                     "main".to_string(),
                     Box::new(AstType::Argment(vec![])),
                     Box::new(AstType::Statement(vec![
-                        AstType::Variable(Type::Int, Structure::Identifier, "y".to_string()),
-                        AstType::FuncCall(
-                            Box::new(AstType::Variable(
-                                Type::Int,
-                                Structure::Identifier,
-                                "a".to_string()
+                        AstType::BitOr(
+                            Box::new(AstType::Factor(1)),
+                            Box::new(AstType::BitAnd(
+                                Box::new(AstType::Factor(2)),
+                                Box::new(AstType::Factor(3)),
                             )),
-                            Box::new(AstType::Argment(vec![AstType::Address(Box::new(
-                                AstType::Variable(
-                                    Type::Int,
-                                    Structure::Identifier,
-                                    'y'.to_string()
-                                )
-                            ))]))
                         ),
                     ])),
                 )
-            );
+            )
         }
-    }
-
-    #[test]
-    fn test_compound() {
         {
             let data = vec![
                 create_token(Token::Int, "int".to_string()),
@@ -3784,18 +6058,13 @@ mod tests {
                 create_token(Token::LeftParen, "(".to_string()),
                 create_token(Token::RightParen, ")".to_string()),
                 create_token(Token::LeftBrace, "{".to_string()),
-                create_token(Token::Int, "int".to_string()),
-                create_token(Token::Variable, "a".to_string()),
-                create_token(Token::Assign, "=".to_string()),
-                create_token(Token::Number, "3".to_string()),
-                create_token(Token::SemiColon, ";".to_string()),
-                create_token(Token::Variable, "a".to_string()),
-                create_token(Token::Assign, "=".to_string()),
-                create_token(Token::Variable, "a".to_string()),
-                create_token(Token::Plus, "+".to_string()),
+                create_token(Token::Number, "1".to_string()),
+                create_token(Token::And, "&".to_string()),
+                create_token(Token::Number, "2".to_string()),
+                create_token(Token::BitOr, "|".to_string()),
                 create_token(Token::Number, "3".to_string()),
                 create_token(Token::SemiColon, ";".to_string()),
-                create_token(Token::RightBrace, "{".to_string()),
+                create_token(Token::RightBrace, "}".to_string()),
                 create_token(Token::End, "End".to_string()),
             ];
             let mut ast = AstGen::new(&data);
@@ -3810,32 +6079,16 @@ mod tests {
                     "main".to_string(),
                     Box::new(AstType::Argment(vec![])),
                     Box::new(AstType::Statement(vec![
-                        AstType::Assign(
-                            Box::new(AstType::Variable(
-                                Type::Int,
-                                Structure::Identifier,
-                                "a".to_string()
-                            )),
-                            Box::new(AstType::Factor(3))
-                        ),
-                        AstType::Assign(
-                            Box::new(AstType::Variable(
-                                Type::Int,
-                                Structure::Identifier,
-                                "a".to_string()
+                        AstType::BitOr(
+                            Box::new(AstType::BitAnd(
+                                Box::new(AstType::Factor(1)),
+                                Box::new(AstType::Factor(2)),
                             )),
-                            Box::new(AstType::Plus(
-                                Box::new(AstType::Variable(
-                                    Type::Int,
-                                    Structure::Identifier,
-                                    "a".to_string()
-                                )),
-                                Box::new(AstType::Factor(3)),
-                            ))
+                            Box::new(AstType::Factor(3)),
                         ),
                     ])),
                 )
-            );
+            )
         }
         {
             let data = vec![
@@ -3844,16 +6097,11 @@ mod tests {
                 create_token(Token::LeftParen, "(".to_string()),
                 create_token(Token::RightParen, ")".to_string()),
                 create_token(Token::LeftBrace, "{".to_string()),
-                create_token(Token::Int, "int".to_string()),
-                create_token(Token::Variable, "a".to_string()),
-                create_token(Token::Assign, "=".to_string()),
-                create_token(Token::Number, "3".to_string()),
-                create_token(Token::SemiColon, ";".to_string()),
-                create_token(Token::Variable, "a".to_string()),
-                create_token(Token::Multi, "*".to_string()),
-                create_token(Token::Variable, "a".to_string()),
-                create_token(Token::Plus, "+".to_string()),
                 create_token(Token::Number, "1".to_string()),
+                create_token(Token::BitXor, "^".to_string()),
+                create_token(Token::Number, "2".to_string()),
+                create_token(Token::BitOr, "|".to_string()),
+                create_token(Token::Number, "3".to_string()),
                 create_token(Token::SemiColon, ";".to_string()),
                 create_token(Token::RightBrace, "}".to_string()),
                 create_token(Token::End, "End".to_string()),
@@ -3870,37 +6118,17 @@ mod tests {
                     "main".to_string(),
                     Box::new(AstType::Argment(vec![])),
                     Box::new(AstType::Statement(vec![
-                        AstType::Assign(
-                            Box::new(AstType::Variable(
-                                Type::Int,
-                                Structure::Identifier,
-                                "a".to_string()
-                            )),
-                            Box::new(AstType::Factor(3))
-                        ),
-                        AstType::Plus(
-                            Box::new(AstType::Multiple(
-                                Box::new(AstType::Variable(
-                                    Type::Int,
-                                    Structure::Identifier,
-                                    "a".to_string()
-                                )),
-                                Box::new(AstType::Variable(
-                                    Type::Int,
-                                    Structure::Identifier,
-                                    "a".to_string()
-                                )),
+                        AstType::BitOr(
+                            Box::new(AstType::BitXor(
+                                Box::new(AstType::Factor(1)),
+                                Box::new(AstType::Factor(2)),
                             )),
-                            Box::new(AstType::Factor(1))
+                            Box::new(AstType::Factor(3)),
                         ),
                     ])),
                 )
             )
         }
-    }
-
-    #[test]
-    fn test_some_func_def() {
         {
             let data = vec![
                 create_token(Token::Int, "int".to_string()),
@@ -3908,21 +6136,11 @@ mod tests {
                 create_token(Token::LeftParen, "(".to_string()),
                 create_token(Token::RightParen, ")".to_string()),
                 create_token(Token::LeftBrace, "{".to_string()),
-                create_token(Token::Int, "int".to_string()),
-                create_token(Token::Variable, "a".to_string()),
-                create_token(Token::Assign, "=".to_string()),
-                create_token(Token::Number, "3".to_string()),
-                create_token(Token::SemiColon, ";".to_string()),
-                create_token(Token::RightBrace, "}".to_string()),
-                create_token(Token::Int, "int".to_string()),
-                create_token(Token::Variable, "test".to_string()),
-                create_token(Token::LeftParen, "(".to_string()),
-                create_token(Token::RightParen, ")".to_string()),
-                create_token(Token::LeftBrace, "{".to_string()),
-                create_token(Token::Int, "int".to_string()),
-                create_token(Token::Variable, "b".to_string()),
-                create_token(Token::Assign, "=".to_string()),
                 create_token(Token::Number, "1".to_string()),
+                create_token(Token::BitOr, "|".to_string()),
+                create_token(Token::Number, "2".to_string()),
+                create_token(Token::BitXor, "^".to_string()),
+                create_token(Token::Number, "3".to_string()),
                 create_token(Token::SemiColon, ";".to_string()),
                 create_token(Token::RightBrace, "}".to_string()),
                 create_token(Token::End, "End".to_string()),
@@ -3938,53 +6156,29 @@ mod tests {
                     Structure::Identifier,
                     "main".to_string(),
                     Box::new(AstType::Argment(vec![])),
-                    Box::new(AstType::Statement(vec![AstType::Assign(
-                        Box::new(AstType::Variable(
-                            Type::Int,
-                            Structure::Identifier,
-                            "a".to_string()
-                        )),
-                        Box::new(AstType::Factor(3))
-                    ),])),
-                )
-            );
-            assert_eq!(
-                result.get_tree()[1],
-                AstType::FuncDef(
-                    Type::Int,
-                    Structure::Identifier,
-                    "test".to_string(),
-                    Box::new(AstType::Argment(vec![])),
-                    Box::new(AstType::Statement(vec![AstType::Assign(
-                        Box::new(AstType::Variable(
-                            Type::Int,
-                            Structure::Identifier,
-                            "b".to_string()
-                        )),
-                        Box::new(AstType::Factor(1))
-                    ),])),
+                    Box::new(AstType::Statement(vec![
+                        AstType::BitOr(
+                            Box::new(AstType::Factor(1)),
+                            Box::new(AstType::BitXor(
+                                Box::new(AstType::Factor(2)),
+                                Box::new(AstType::Factor(3)),
+                            )),
+                        ),
+                    ])),
                 )
-            );
+            )
         }
-    }
-
-    #[test]
-    fn test_func_def_with_args() {
         {
             let data = vec![
                 create_token(Token::Int, "int".to_string()),
                 create_token(Token::Variable, "main".to_string()),
                 create_token(Token::LeftParen, "(".to_string()),
-                create_token(Token::Int, "int".to_string()),
-                create_token(Token::Variable, "a".to_string()),
-                create_token(Token::Comma, ",".to_string()),
-                create_token(Token::Int, "int".to_string()),
-                create_token(Token::Variable, "b".to_string()),
                 create_token(Token::RightParen, ")".to_string()),
                 create_token(Token::LeftBrace, "{".to_string()),
-                create_token(Token::Int, "int".to_string()),
-                create_token(Token::Variable, "c".to_string()),
-                create_token(Token::Assign, "=".to_string()),
+                create_token(Token::Number, "1".to_string()),
+                create_token(Token::BitXor, "^".to_string()),
+                create_token(Token::Number, "2".to_string()),
+                create_token(Token::And, "&".to_string()),
                 create_token(Token::Number, "3".to_string()),
                 create_token(Token::SemiColon, ";".to_string()),
                 create_token(Token::RightBrace, "}".to_string()),
@@ -4000,36 +6194,30 @@ mod tests {
                     Type::Int,
                     Structure::Identifier,
                     "main".to_string(),
-                    Box::new(AstType::Argment(vec![
-                        AstType::Variable(Type::Int, Structure::Identifier, "a".to_string()),
-                        AstType::Variable(Type::Int, Structure::Identifier, "b".to_string()),
+                    Box::new(AstType::Argment(vec![])),
+                    Box::new(AstType::Statement(vec![
+                        AstType::BitXor(
+                            Box::new(AstType::Factor(1)),
+                            Box::new(AstType::BitAnd(
+                                Box::new(AstType::Factor(2)),
+                                Box::new(AstType::Factor(3)),
+                            )),
+                        ),
                     ])),
-                    Box::new(AstType::Statement(vec![AstType::Assign(
-                        Box::new(AstType::Variable(
-                            Type::Int,
-                            Structure::Identifier,
-                            "c".to_string()
-                        )),
-                        Box::new(AstType::Factor(3))
-                    ),])),
                 )
-            );
+            )
         }
         {
             let data = vec![
                 create_token(Token::Int, "int".to_string()),
                 create_token(Token::Variable, "main".to_string()),
                 create_token(Token::LeftParen, "(".to_string()),
-                create_token(Token::IntPointer, "int*".to_string()),
-                create_token(Token::Variable, "a".to_string()),
-                create_token(Token::Comma, ",".to_string()),
-                create_token(Token::Int, "int".to_string()),
-                create_token(Token::Variable, "b".to_string()),
                 create_token(Token::RightParen, ")".to_string()),
                 create_token(Token::LeftBrace, "{".to_string()),
-                create_token(Token::Int, "int".to_string()),
-                create_token(Token::Variable, "c".to_string()),
-                create_token(Token::Assign, "=".to_string()),
+                create_token(Token::Number, "1".to_string()),
+                create_token(Token::And, "&".to_string()),
+                create_token(Token::Number, "2".to_string()),
+                create_token(Token::BitXor, "^".to_string()),
                 create_token(Token::Number, "3".to_string()),
                 create_token(Token::SemiColon, ";".to_string()),
                 create_token(Token::RightBrace, "}".to_string()),
@@ -4045,25 +6233,19 @@ mod tests {
                     Type::Int,
                     Structure::Identifier,
                     "main".to_string(),
-                    Box::new(AstType::Argment(vec![
-                        AstType::Variable(Type::Int, Structure::Pointer, "a".to_string()),
-                        AstType::Variable(Type::Int, Structure::Identifier, "b".to_string()),
+                    Box::new(AstType::Argment(vec![])),
+                    Box::new(AstType::Statement(vec![
+                        AstType::BitXor(
+                            Box::new(AstType::BitAnd(
+                                Box::new(AstType::Factor(1)),
+                                Box::new(AstType::Factor(2)),
+                            )),
+                            Box::new(AstType::Factor(3)),
+                        ),
                     ])),
-                    Box::new(AstType::Statement(vec![AstType::Assign(
-                        Box::new(AstType::Variable(
-                            Type::Int,
-                            Structure::Identifier,
-                            "c".to_string()
-                        )),
-                        Box::new(AstType::Factor(3))
-                    ),])),
                 )
-            );
+            )
         }
-    }
-
-    #[test]
-    fn test_statement_if() {
         {
             let data = vec![
                 create_token(Token::Int, "int".to_string()),
@@ -4071,25 +6253,15 @@ mod tests {
                 create_token(Token::LeftParen, "(".to_string()),
                 create_token(Token::RightParen, ")".to_string()),
                 create_token(Token::LeftBrace, "{".to_string()),
-                create_token(Token::Int, "int".to_string()),
-                create_token(Token::Variable, "a".to_string()),
-                create_token(Token::SemiColon, ";".to_string()),
-                create_token(Token::If, "if".to_string()),
-                create_token(Token::LeftParen, "(".to_string()),
-                create_token(Token::Variable, "a".to_string()),
-                create_token(Token::Equal, "==".to_string()),
-                create_token(Token::Number, "3".to_string()),
-                create_token(Token::RightParen, ")".to_string()),
-                create_token(Token::LeftBrace, "{".to_string()),
                 create_token(Token::Number, "1".to_string()),
-                create_token(Token::SemiColon, ";".to_string()),
-                create_token(Token::Int, "int".to_string()),
-                create_token(Token::Variable, "b".to_string()),
-                create_token(Token::Assign, "=".to_string()),
-                create_token(Token::Number, "10".to_string()),
+                create_token(Token::BitOr, "|".to_string()),
+                create_token(Token::Number, "2".to_string()),
+                create_token(Token::BitXor, "^".to_string()),
+                create_token(Token::Number, "3".to_string()),
+                create_token(Token::And, "&".to_string()),
+                create_token(Token::Number, "4".to_string()),
                 create_token(Token::SemiColon, ";".to_string()),
                 create_token(Token::RightBrace, "}".to_string()),
-                create_token(Token::RightBrace, "}".to_string()),
                 create_token(Token::End, "End".to_string()),
             ];
             let mut ast = AstGen::new(&data);
@@ -4104,37 +6276,24 @@ mod tests {
                     "main".to_string(),
                     Box::new(AstType::Argment(vec![])),
                     Box::new(AstType::Statement(vec![
-                        AstType::Variable(Type::Int, Structure::Identifier, "a".to_string()),
-                        AstType::If(
-                            Box::new(AstType::Equal(
-                                Box::new(AstType::Variable(
-                                    Type::Int,
-                                    Structure::Identifier,
-                                    "a".to_string()
+                        AstType::BitOr(
+                            Box::new(AstType::Factor(1)),
+                            Box::new(AstType::BitXor(
+                                Box::new(AstType::Factor(2)),
+                                Box::new(AstType::BitAnd(
+                                    Box::new(AstType::Factor(3)),
+                                    Box::new(AstType::Factor(4)),
                                 )),
-                                Box::new(AstType::Factor(3))
                             )),
-                            Box::new(AstType::Statement(vec![
-                                AstType::Factor(1),
-                                AstType::Assign(
-                                    Box::new(AstType::Variable(
-                                        Type::Int,
-                                        Structure::Identifier,
-                                        "b".to_string()
-                                    )),
-                                    Box::new(AstType::Factor(10))
-                                )
-                            ],)),
-                            Box::new(None)
-                        )
-                    ]))
+                        ),
+                    ])),
                 )
-            );
+            )
         }
     }
 
     #[test]
-    fn test_statement_else() {
+    fn test_assign_operator() {
         {
             let data = vec![
                 create_token(Token::Int, "int".to_string()),
@@ -4144,35 +6303,10 @@ mod tests {
                 create_token(Token::LeftBrace, "{".to_string()),
                 create_token(Token::Int, "int".to_string()),
                 create_token(Token::Variable, "a".to_string()),
-                create_token(Token::SemiColon, ";".to_string()),
-                create_token(Token::Int, "int".to_string()),
-                create_token(Token::Variable, "b".to_string()),
-                create_token(Token::SemiColon, ";".to_string()),
-                create_token(Token::Int, "int".to_string()),
-                create_token(Token::Variable, "e".to_string()),
-                create_token(Token::SemiColon, ";".to_string()),
-                create_token(Token::If, "if".to_string()),
-                create_token(Token::LeftParen, "(".to_string()),
-                create_token(Token::Variable, "a".to_string()),
-                create_token(Token::Equal, "==".to_string()),
-                create_token(Token::Number, "3".to_string()),
-                create_token(Token::RightParen, ")".to_string()),
-                create_token(Token::LeftBrace, "{".to_string()),
-                create_token(Token::Number, "1".to_string()),
-                create_token(Token::SemiColon, ";".to_string()),
-                create_token(Token::Variable, "b".to_string()),
-                create_token(Token::Assign, "=".to_string()),
-                create_token(Token::Number, "10".to_string()),
-                create_token(Token::SemiColon, ";".to_string()),
-                create_token(Token::RightBrace, "}".to_string()),
-                create_token(Token::Else, "else".to_string()),
-                create_token(Token::LeftBrace, "{".to_string()),
-                create_token(Token::Variable, "e".to_string()),
                 create_token(Token::Assign, "=".to_string()),
-                create_token(Token::Number, "9".to_string()),
+                create_token(Token::Number, "3".to_string()),
                 create_token(Token::SemiColon, ";".to_string()),
                 create_token(Token::RightBrace, "}".to_string()),
-                create_token(Token::RightBrace, "}".to_string()),
                 create_token(Token::End, "End".to_string()),
             ];
             let mut ast = AstGen::new(&data);
@@ -4186,42 +6320,16 @@ mod tests {
                     Structure::Identifier,
                     "main".to_string(),
                     Box::new(AstType::Argment(vec![])),
-                    Box::new(AstType::Statement(vec![
-                        AstType::Variable(Type::Int, Structure::Identifier, "a".to_string()),
-                        AstType::Variable(Type::Int, Structure::Identifier, "b".to_string()),
-                        AstType::Variable(Type::Int, Structure::Identifier, "e".to_string()),
-                        AstType::If(
-                            Box::new(AstType::Equal(
-                                Box::new(AstType::Variable(
-                                    Type::Int,
-                                    Structure::Identifier,
-                                    "a".to_string()
-                                )),
-                                Box::new(AstType::Factor(3))
-                            )),
-                            Box::new(AstType::Statement(vec![
-                                AstType::Factor(1),
-                                AstType::Assign(
-                                    Box::new(AstType::Variable(
-                                        Type::Int,
-                                        Structure::Identifier,
-                                        "b".to_string()
-                                    )),
-                                    Box::new(AstType::Factor(10))
-                                )
-                            ],)),
-                            Box::new(Some(AstType::Statement(vec![AstType::Assign(
-                                Box::new(AstType::Variable(
-                                    Type::Int,
-                                    Structure::Identifier,
-                                    "e".to_string()
-                                )),
-                                Box::new(AstType::Factor(9))
-                            )],))),
-                        ),
-                    ]))
+                    Box::new(AstType::Statement(vec![AstType::Assign(
+                        Box::new(AstType::Variable(
+                            Type::Int,
+                            Structure::Identifier,
+                            "a".to_string()
+                        )),
+                        Box::new(AstType::Factor(3))
+                    ),])),
                 )
-            );
+            )
         }
         {
             let data = vec![
@@ -4232,21 +6340,11 @@ mod tests {
                 create_token(Token::LeftBrace, "{".to_string()),
                 create_token(Token::Int, "int".to_string()),
                 create_token(Token::Variable, "a".to_string()),
-                create_token(Token::SemiColon, ";".to_string()),
-                create_token(Token::If, "if".to_string()),
-                create_token(Token::LeftParen, "(".to_string()),
-                create_token(Token::Variable, "a".to_string()),
-                create_token(Token::Equal, "==".to_string()),
+                create_token(Token::Assign, "=".to_string()),
                 create_token(Token::Number, "3".to_string()),
-                create_token(Token::RightParen, ")".to_string()),
+                create_token(Token::Plus, "+".to_string()),
                 create_token(Token::Number, "1".to_string()),
                 create_token(Token::SemiColon, ";".to_string()),
-                create_token(Token::Else, "else".to_string()),
-                create_token(Token::Int, "int".to_string()),
-                create_token(Token::Variable, "e".to_string()),
-                create_token(Token::Assign, "=".to_string()),
-                create_token(Token::Number, "9".to_string()),
-                create_token(Token::SemiColon, ";".to_string()),
                 create_token(Token::RightBrace, "}".to_string()),
                 create_token(Token::End, "End".to_string()),
             ];
@@ -4261,35 +6359,20 @@ mod tests {
                     Structure::Identifier,
                     "main".to_string(),
                     Box::new(AstType::Argment(vec![])),
-                    Box::new(AstType::Statement(vec![
-                        AstType::Variable(Type::Int, Structure::Identifier, "a".to_string()),
-                        AstType::If(
-                            Box::new(AstType::Equal(
-                                Box::new(AstType::Variable(
-                                    Type::Int,
-                                    Structure::Identifier,
-                                    "a".to_string()
-                                )),
-                                Box::new(AstType::Factor(3))
-                            )),
-                            Box::new(AstType::Statement(vec![AstType::Factor(1)])),
-                            Box::new(Some(AstType::Statement(vec![AstType::Assign(
-                                Box::new(AstType::Variable(
-                                    Type::Int,
-                                    Structure::Identifier,
-                                    "e".to_string()
-                                )),
-                                Box::new(AstType::Factor(9))
-                            )])))
-                        ),
-                    ]))
+                    Box::new(AstType::Statement(vec![AstType::Assign(
+                        Box::new(AstType::Variable(
+                            Type::Int,
+                            Structure::Identifier,
+                            "a".to_string()
+                        )),
+                        Box::new(AstType::Plus(
+                            Box::new(AstType::Factor(3)),
+                            Box::new(AstType::Factor(1)),
+                        ))
+                    ),])),
                 )
-            );
+            )
         }
-    }
-
-    #[test]
-    fn test_statement_while() {
         {
             let data = vec![
                 create_token(Token::Int, "int".to_string()),
@@ -4300,21 +6383,12 @@ mod tests {
                 create_token(Token::Int, "int".to_string()),
                 create_token(Token::Variable, "a".to_string()),
                 create_token(Token::SemiColon, ";".to_string()),
-                create_token(Token::While, "while".to_string()),
-                create_token(Token::LeftParen, "(".to_string()),
                 create_token(Token::Variable, "a".to_string()),
-                create_token(Token::Equal, "==".to_string()),
+                create_token(Token::Assign, "=".to_string()),
                 create_token(Token::Number, "3".to_string()),
-                create_token(Token::RightParen, ")".to_string()),
-                create_token(Token::LeftBrace, "{".to_string()),
+                create_token(Token::LogicalAnd, "&&".to_string()),
                 create_token(Token::Number, "1".to_string()),
                 create_token(Token::SemiColon, ";".to_string()),
-                create_token(Token::Int, "int".to_string()),
-                create_token(Token::Variable, "b".to_string()),
-                create_token(Token::Assign, "=".to_string()),
-                create_token(Token::Number, "10".to_string()),
-                create_token(Token::SemiColon, ";".to_string()),
-                create_token(Token::RightBrace, "}".to_string()),
                 create_token(Token::RightBrace, "}".to_string()),
                 create_token(Token::End, "End".to_string()),
             ];
@@ -4331,18 +6405,956 @@ mod tests {
                     Box::new(AstType::Argment(vec![])),
                     Box::new(AstType::Statement(vec![
                         AstType::Variable(Type::Int, Structure::Identifier, "a".to_string()),
-                        AstType::While(
-                            Box::new(AstType::Equal(
-                                Box::new(AstType::Variable(
-                                    Type::Int,
-                                    Structure::Identifier,
-                                    "a".to_string()
-                                )),
-                                Box::new(AstType::Factor(3))
-                            )),
-                            Box::new(AstType::Statement(vec![
-                                AstType::Factor(1),
-                                AstType::Assign(
+                        AstType::Assign(
+                            Box::new(AstType::Variable(
+                                Type::Int,
+                                Structure::Identifier,
+                                "a".to_string()
+                            )),
+                            Box::new(AstType::LogicalAnd(
+                                Box::new(AstType::Factor(3)),
+                                Box::new(AstType::Factor(1)),
+                            ))
+                        ),
+                    ])),
+                )
+            )
+        }
+        {
+            let data = vec![
+                create_token(Token::Int, "int".to_string()),
+                create_token(Token::Variable, "main".to_string()),
+                create_token(Token::LeftParen, "(".to_string()),
+                create_token(Token::RightParen, ")".to_string()),
+                create_token(Token::LeftBrace, "{".to_string()),
+                create_token(Token::Int, "int".to_string()),
+                create_token(Token::Variable, "a".to_string()),
+                create_token(Token::Assign, "=".to_string()),
+                create_token(Token::Number, "3".to_string()),
+                create_token(Token::Multi, "*".to_string()),
+                create_token(Token::Number, "1".to_string()),
+                create_token(Token::SemiColon, ";".to_string()),
+                create_token(Token::RightBrace, "}".to_string()),
+                create_token(Token::End, "End".to_string()),
+            ];
+            let mut ast = AstGen::new(&data);
+            let result = ast.parse();
+
+            // 期待値確認.
+            assert_eq!(
+                result.get_tree()[0],
+                AstType::FuncDef(
+                    Type::Int,
+                    Structure::Identifier,
+                    "main".to_string(),
+                    Box::new(AstType::Argment(vec![])),
+                    Box::new(AstType::Statement(vec![AstType::Assign(
+                        Box::new(AstType::Variable(
+                            Type::Int,
+                            Structure::Identifier,
+                            "a".to_string()
+                        )),
+                        Box::new(AstType::Multiple(
+                            Box::new(AstType::Factor(3)),
+                            Box::new(AstType::Factor(1)),
+                        ))
+                    ),])),
+                )
+            )
+        }
+        {
+            let data = vec![
+                create_token(Token::Int, "int".to_string()),
+                create_token(Token::Variable, "main".to_string()),
+                create_token(Token::LeftParen, "(".to_string()),
+                create_token(Token::RightParen, ")".to_string()),
+                create_token(Token::LeftBrace, "{".to_string()),
+                create_token(Token::Int, "int".to_string()),
+                create_token(Token::Variable, "a".to_string()),
+                create_token(Token::Assign, "=".to_string()),
+                create_token(Token::Number, "3".to_string()),
+                create_token(Token::BitOr, "|".to_string()),
+                create_token(Token::Number, "1".to_string()),
+                create_token(Token::SemiColon, ";".to_string()),
+                create_token(Token::RightBrace, "}".to_string()),
+                create_token(Token::End, "End".to_string()),
+            ];
+            let mut ast = AstGen::new(&data);
+            let result = ast.parse();
+
+            // 期待値確認.
+            assert_eq!(
+                result.get_tree()[0],
+                AstType::FuncDef(
+                    Type::Int,
+                    Structure::Identifier,
+                    "main".to_string(),
+                    Box::new(AstType::Argment(vec![])),
+                    Box::new(AstType::Statement(vec![AstType::Assign(
+                        Box::new(AstType::Variable(
+                            Type::Int,
+                            Structure::Identifier,
+                            "a".to_string()
+                        )),
+                        Box::new(AstType::BitOr(
+                            Box::new(AstType::Factor(3)),
+                            Box::new(AstType::Factor(1)),
+                        ))
+                    ),])),
+                )
+            )
+        }
+    }
+
+    #[test]
+    fn test_call_func() {
+        {
+            let data = vec![
+                create_token(Token::Int, "int".to_string()),
+                create_token(Token::Variable, "a".to_string()),
+                create_token(Token::LeftParen, "(".to_string()),
+                create_token(Token::RightParen, ")".to_string()),
+                create_token(Token::LeftBrace, "{".to_string()),
+                create_token(Token::RightBrace, "}".to_string()),
+                create_token(Token::Int, "int".to_string()),
+                create_token(Token::Variable, "main".to_string()),
+                create_token(Token::LeftParen, "(".to_string()),
+                create_token(Token::RightParen, ")".to_string()),
+                create_token(Token::LeftBrace, "{".to_string()),
+                create_token(Token::Variable, "a".to_string()),
+                create_token(Token::LeftParen, "(".to_string()),
+                create_token(Token::RightParen, ")".to_string()),
+                create_token(Token::SemiColon, ";".to_string()),
+                create_token(Token::RightBrace, "}".to_string()),
+                create_token(Token::End, "End".to_string()),
+            ];
+            let mut ast = AstGen::new(&data);
+            let result = ast.parse();
+
+            // 期待値確認.
+            assert_eq!(
+                result.get_tree()[0],
+                AstType::FuncDef(
+                    Type::Int,
+                    Structure::Identifier,
+                    "a".to_string(),
+                    Box::new(AstType::Argment(vec![])),
+                    Box::new(AstType::Statement(vec![])),
+                )
+            );
+            assert_eq!(
+                result.get_tree()[1],
+                AstType::FuncDef(
+                    Type::Int,
+                    Structure::Identifier,
+                    "main".to_string(),
+                    Box::new(AstType::Argment(vec![])),
+                    Box::new(AstType::Statement(vec![AstType::FuncCall(
+                        Box::new(AstType::Variable(
+                            Type::Int,
+                            Structure::Identifier,
+                            "a".to_string()
+                        )),
+                        Box::new(AstType::Argment(vec![]))
+                    ),])),
+                )
+            );
+        }
+        {
+            let data = vec![
+                create_token(Token::Int, "int".to_string()),
+                create_token(Token::Variable, "a".to_string()),
+                create_token(Token::LeftParen, "(".to_string()),
+                create_token(Token::Int, "int".to_string()),
+                create_token(Token::Variable, "x".to_string()),
+                create_token(Token::RightParen, ")".to_string()),
+                create_token(Token::LeftBrace, "{".to_string()),
+                create_token(Token::RightBrace, "}".to_string()),
+                create_token(Token::Int, "int".to_string()),
+                create_token(Token::Variable, "main".to_string()),
+                create_token(Token::LeftParen, "(".to_string()),
+                create_token(Token::RightParen, ")".to_string()),
+                create_token(Token::LeftBrace, "{".to_string()),
+                create_token(Token::Int, "int".to_string()),
+                create_token(Token::Variable, "b".to_string()),
+                create_token(Token::SemiColon, ";".to_string()),
+                create_token(Token::Variable, "a".to_string()),
+                create_token(Token::LeftParen, "(".to_string()),
+                create_token(Token::Variable, "b".to_string()),
+                create_token(Token::RightParen, ")".to_string()),
+                create_token(Token::SemiColon, ";".to_string()),
+                create_token(Token::RightBrace, "}".to_string()),
+                create_token(Token::End, "End".to_string()),
+            ];
+            let mut ast = AstGen::new(&data);
+            let result = ast.parse();
+
+            // 期待値確認.
+            assert_eq!(
+                result.get_tree()[0],
+                AstType::FuncDef(
+                    Type::Int,
+                    Structure::Identifier,
+                    "a".to_string(),
+                    Box::new(AstType::Argment(vec![AstType::Variable(
+                        Type::Int,
+                        Structure::Identifier,
+                        "x".to_string()
+                    ),])),
+                    Box::new(AstType::Statement(vec![]))
+                )
+            );
+            assert_eq!(
+                result.get_tree()[1],
+                AstType::FuncDef(
+                    Type::Int,
+                    Structure::Identifier,
+                    "main".to_string(),
+                    Box::new(AstType::Argment(vec![])),
+                    Box::new(AstType::Statement(vec![
+                        AstType::Variable(Type::Int, Structure::Identifier, "b".to_string()),
+                        AstType::FuncCall(
+                            Box::new(AstType::Variable(
+                                Type::Int,
+                                Structure::Identifier,
+                                "a".to_string()
+                            )),
+                            Box::new(AstType::Argment(vec![AstType::Variable(
+                                Type::Int,
+                                Structure::Identifier,
+                                'b'.to_string()
+                            )]),)
+                        ),
+                    ])),
+                )
+            );
+        }
+        {
+            let data = vec![
+                create_token(Token::Int, "int".to_string()),
+                create_token(Token::Variable, "test".to_string()),
+                create_token(Token::LeftParen, "(".to_string()),
+                create_token(Token::Int, "int".to_string()),
+                create_token(Token::Variable, "x".to_string()),
+                create_token(Token::Comma, ",".to_string()),
+                create_token(Token::Int, "int".to_string()),
+                create_token(Token::Variable, "y".to_string()),
+                create_token(Token::RightParen, ")".to_string()),
+                create_token(Token::LeftBrace, "{".to_string()),
+                create_token(Token::RightBrace, "}".to_string()),
+                create_token(Token::Int, "int".to_string()),
+                create_token(Token::Variable, "main".to_string()),
+                create_token(Token::LeftParen, "(".to_string()),
+                create_token(Token::RightParen, ")".to_string()),
+                create_token(Token::LeftBrace, "{".to_string()),
+                create_token(Token::Int, "int".to_string()),
+                create_token(Token::Variable, "b".to_string()),
+                create_token(Token::SemiColon, ";".to_string()),
+                create_token(Token::Int, "int".to_string()),
+                create_token(Token::Variable, "c".to_string()),
+                create_token(Token::SemiColon, ";".to_string()),
+                create_token(Token::Variable, "test".to_string()),
+                create_token(Token::LeftParen, "(".to_string()),
+                create_token(Token::Variable, "b".to_string()),
+                create_token(Token::Comma, ",".to_string()),
+                create_token(Token::Variable, "c".to_string()),
+                create_token(Token::RightParen, ")".to_string()),
+                create_token(Token::SemiColon, ";".to_string()),
+                create_token(Token::RightBrace, "}".to_string()),
+                create_token(Token::End, "End".to_string()),
+            ];
+            let mut ast = AstGen::new(&data);
+            let result = ast.parse();
+
+            // 期待値確認.
+            assert_eq!(
+                result.get_tree()[0],
+                AstType::FuncDef(
+                    Type::Int,
+                    Structure::Identifier,
+                    "test".to_string(),
+                    Box::new(AstType::Argment(vec![
+                        AstType::Variable(Type::Int, Structure::Identifier, "x".to_string()),
+                        AstType::Variable(Type::Int, Structure::Identifier, "y".to_string()),
+                    ])),
+                    Box::new(AstType::Statement(vec![]))
+                )
+            );
+            assert_eq!(
+                result.get_tree()[1],
+                AstType::FuncDef(
+                    Type::Int,
+                    Structure::Identifier,
+                    "main".to_string(),
+                    Box::new(AstType::Argment(vec![])),
+                    Box::new(AstType::Statement(vec![
+                        AstType::Variable(Type::Int, Structure::Identifier, 'b'.to_string()),
+                        AstType::Variable(Type::Int, Structure::Identifier, 'c'.to_string()),
+                        AstType::FuncCall(
+                            Box::new(AstType::Variable(
+                                Type::Int,
+                                Structure::Identifier,
+                                "test".to_string()
+                            )),
+                            Box::new(AstType::Argment(vec![
+                                AstType::Variable(
+                                    Type::Int,
+                                    Structure::Identifier,
+                                    'b'.to_string()
+                                ),
+                                AstType::Variable(
+                                    Type::Int,
+                                    Structure::Identifier,
+                                    'c'.to_string()
+                                ),
+                            ]))
+                        ),
+                    ])),
+                )
+            )
+        }
+        {
+            let data = vec![
+                create_token(Token::Int, "int".to_string()),
+                create_token(Token::Variable, "a".to_string()),
+                create_token(Token::LeftParen, "(".to_string()),
+                create_token(Token::IntPointer, "int*".to_string()),
+                create_token(Token::Variable, "x".to_string()),
+                create_token(Token::RightParen, ")".to_string()),
+                create_token(Token::LeftBrace, "{".to_string()),
+                create_token(Token::RightBrace, "}".to_string()),
+                create_token(Token::Int, "int".to_string()),
+                create_token(Token::Variable, "main".to_string()),
+                create_token(Token::LeftParen, "(".to_string()),
+                create_token(Token::RightParen, ")".to_string()),
+                create_token(Token::LeftBrace, "{".to_string()),
+                create_token(Token::Int, "int".to_string()),
+                create_token(Token::Variable, "y".to_string()),
+                create_token(Token::SemiColon, ";".to_string()),
+                create_token(Token::Variable, "a".to_string()),
+                create_token(Token::LeftParen, "(".to_string()),
+                create_token(Token::And, "&".to_string()),
+                create_token(Token::Variable, "y".to_string()),
+                create_token(Token::RightParen, ")".to_string()),
+                create_token(Token::SemiColon, ";".to_string()),
+                create_token(Token::RightBrace, "}".to_string()),
+                create_token(Token::End, "End".to_string()),
+            ];
+            let mut ast = AstGen::new(&data);
+            let result = ast.parse();
+
+            // 期待値確認.
+            assert_eq!(
+                result.get_tree()[0],
+                AstType::FuncDef(
+                    Type::Int,
+                    Structure::Identifier,
+                    "a".to_string(),
+                    Box::new(AstType::Argment(vec![AstType::Variable(
+                        Type::Int,
+                        Structure::Pointer,
+                        "x".to_string()
+                    ),])),
+                    Box::new(AstType::Statement(vec![])),
+                )
+            );
+            assert_eq!(
+                result.get_tree()[1],
+                AstType::FuncDef(
+                    Type::Int,
+                    Structure::Identifier,
+                    "main".to_string(),
+                    Box::new(AstType::Argment(vec![])),
+                    Box::new(AstType::Statement(vec![
+                        AstType::Variable(Type::Int, Structure::Identifier, "y".to_string()),
+                        AstType::FuncCall(
+                            Box::new(AstType::Variable(
+                                Type::Int,
+                                Structure::Identifier,
+                                "a".to_string()
+                            )),
+                            Box::new(AstType::Argment(vec![AstType::Address(Box::new(
+                                AstType::Variable(
+                                    Type::Int,
+                                    Structure::Identifier,
+                                    'y'.to_string()
+                                )
+                            ))]))
+                        ),
+                    ])),
+                )
+            );
+        }
+    }
+
+    #[test]
+    fn test_compound() {
+        {
+            let data = vec![
+                create_token(Token::Int, "int".to_string()),
+                create_token(Token::Variable, "main".to_string()),
+                create_token(Token::LeftParen, "(".to_string()),
+                create_token(Token::RightParen, ")".to_string()),
+                create_token(Token::LeftBrace, "{".to_string()),
+                create_token(Token::Int, "int".to_string()),
+                create_token(Token::Variable, "a".to_string()),
+                create_token(Token::Assign, "=".to_string()),
+                create_token(Token::Number, "3".to_string()),
+                create_token(Token::SemiColon, ";".to_string()),
+                create_token(Token::Variable, "a".to_string()),
+                create_token(Token::Assign, "=".to_string()),
+                create_token(Token::Variable, "a".to_string()),
+                create_token(Token::Plus, "+".to_string()),
+                create_token(Token::Number, "3".to_string()),
+                create_token(Token::SemiColon, ";".to_string()),
+                create_token(Token::RightBrace, "{".to_string()),
+                create_token(Token::End, "End".to_string()),
+            ];
+            let mut ast = AstGen::new(&data);
+            let result = ast.parse();
+
+            // 期待値確認.
+            assert_eq!(
+                result.get_tree()[0],
+                AstType::FuncDef(
+                    Type::Int,
+                    Structure::Identifier,
+                    "main".to_string(),
+                    Box::new(AstType::Argment(vec![])),
+                    Box::new(AstType::Statement(vec![
+                        AstType::Assign(
+                            Box::new(AstType::Variable(
+                                Type::Int,
+                                Structure::Identifier,
+                                "a".to_string()
+                            )),
+                            Box::new(AstType::Factor(3))
+                        ),
+                        AstType::Assign(
+                            Box::new(AstType::Variable(
+                                Type::Int,
+                                Structure::Identifier,
+                                "a".to_string()
+                            )),
+                            Box::new(AstType::Plus(
+                                Box::new(AstType::Variable(
+                                    Type::Int,
+                                    Structure::Identifier,
+                                    "a".to_string()
+                                )),
+                                Box::new(AstType::Factor(3)),
+                            ))
+                        ),
+                    ])),
+                )
+            );
+        }
+        {
+            let data = vec![
+                create_token(Token::Int, "int".to_string()),
+                create_token(Token::Variable, "main".to_string()),
+                create_token(Token::LeftParen, "(".to_string()),
+                create_token(Token::RightParen, ")".to_string()),
+                create_token(Token::LeftBrace, "{".to_string()),
+                create_token(Token::Int, "int".to_string()),
+                create_token(Token::Variable, "a".to_string()),
+                create_token(Token::Assign, "=".to_string()),
+                create_token(Token::Number, "3".to_string()),
+                create_token(Token::SemiColon, ";".to_string()),
+                create_token(Token::Variable, "a".to_string()),
+                create_token(Token::Multi, "*".to_string()),
+                create_token(Token::Variable, "a".to_string()),
+                create_token(Token::Plus, "+".to_string()),
+                create_token(Token::Number, "1".to_string()),
+                create_token(Token::SemiColon, ";".to_string()),
+                create_token(Token::RightBrace, "}".to_string()),
+                create_token(Token::End, "End".to_string()),
+            ];
+            let mut ast = AstGen::new(&data);
+            let result = ast.parse();
+
+            // 期待値確認.
+            assert_eq!(
+                result.get_tree()[0],
+                AstType::FuncDef(
+                    Type::Int,
+                    Structure::Identifier,
+                    "main".to_string(),
+                    Box::new(AstType::Argment(vec![])),
+                    Box::new(AstType::Statement(vec![
+                        AstType::Assign(
+                            Box::new(AstType::Variable(
+                                Type::Int,
+                                Structure::Identifier,
+                                "a".to_string()
+                            )),
+                            Box::new(AstType::Factor(3))
+                        ),
+                        AstType::Plus(
+                            Box::new(AstType::Multiple(
+                                Box::new(AstType::Variable(
+                                    Type::Int,
+                                    Structure::Identifier,
+                                    "a".to_string()
+                                )),
+                                Box::new(AstType::Variable(
+                                    Type::Int,
+                                    Structure::Identifier,
+                                    "a".to_string()
+                                )),
+                            )),
+                            Box::new(AstType::Factor(1))
+                        ),
+                    ])),
+                )
+            )
+        }
+    }
+
+    #[test]
+    fn test_some_func_def() {
+        {
+            let data = vec![
+                create_token(Token::Int, "int".to_string()),
+                create_token(Token::Variable, "main".to_string()),
+                create_token(Token::LeftParen, "(".to_string()),
+                create_token(Token::RightParen, ")".to_string()),
+                create_token(Token::LeftBrace, "{".to_string()),
+                create_token(Token::Int, "int".to_string()),
+                create_token(Token::Variable, "a".to_string()),
+                create_token(Token::Assign, "=".to_string()),
+                create_token(Token::Number, "3".to_string()),
+                create_token(Token::SemiColon, ";".to_string()),
+                create_token(Token::RightBrace, "}".to_string()),
+                create_token(Token::Int, "int".to_string()),
+                create_token(Token::Variable, "test".to_string()),
+                create_token(Token::LeftParen, "(".to_string()),
+                create_token(Token::RightParen, ")".to_string()),
+                create_token(Token::LeftBrace, "{".to_string()),
+                create_token(Token::Int, "int".to_string()),
+                create_token(Token::Variable, "b".to_string()),
+                create_token(Token::Assign, "=".to_string()),
+                create_token(Token::Number, "1".to_string()),
+                create_token(Token::SemiColon, ";".to_string()),
+                create_token(Token::RightBrace, "}".to_string()),
+                create_token(Token::End, "End".to_string()),
+            ];
+            let mut ast = AstGen::new(&data);
+            let result = ast.parse();
+
+            // 期待値確認.
+            assert_eq!(
+                result.get_tree()[0],
+                AstType::FuncDef(
+                    Type::Int,
+                    Structure::Identifier,
+                    "main".to_string(),
+                    Box::new(AstType::Argment(vec![])),
+                    Box::new(AstType::Statement(vec![AstType::Assign(
+                        Box::new(AstType::Variable(
+                            Type::Int,
+                            Structure::Identifier,
+                            "a".to_string()
+                        )),
+                        Box::new(AstType::Factor(3))
+                    ),])),
+                )
+            );
+            assert_eq!(
+                result.get_tree()[1],
+                AstType::FuncDef(
+                    Type::Int,
+                    Structure::Identifier,
+                    "test".to_string(),
+                    Box::new(AstType::Argment(vec![])),
+                    Box::new(AstType::Statement(vec![AstType::Assign(
+                        Box::new(AstType::Variable(
+                            Type::Int,
+                            Structure::Identifier,
+                            "b".to_string()
+                        )),
+                        Box::new(AstType::Factor(1))
+                    ),])),
+                )
+            );
+        }
+    }
+
+    #[test]
+    fn test_func_def_with_args() {
+        {
+            let data = vec![
+                create_token(Token::Int, "int".to_string()),
+                create_token(Token::Variable, "main".to_string()),
+                create_token(Token::LeftParen, "(".to_string()),
+                create_token(Token::Int, "int".to_string()),
+                create_token(Token::Variable, "a".to_string()),
+                create_token(Token::Comma, ",".to_string()),
+                create_token(Token::Int, "int".to_string()),
+                create_token(Token::Variable, "b".to_string()),
+                create_token(Token::RightParen, ")".to_string()),
+                create_token(Token::LeftBrace, "{".to_string()),
+                create_token(Token::Int, "int".to_string()),
+                create_token(Token::Variable, "c".to_string()),
+                create_token(Token::Assign, "=".to_string()),
+                create_token(Token::Number, "3".to_string()),
+                create_token(Token::SemiColon, ";".to_string()),
+                create_token(Token::RightBrace, "}".to_string()),
+                create_token(Token::End, "End".to_string()),
+            ];
+            let mut ast = AstGen::new(&data);
+            let result = ast.parse();
+
+            // 期待値確認.
+            assert_eq!(
+                result.get_tree()[0],
+                AstType::FuncDef(
+                    Type::Int,
+                    Structure::Identifier,
+                    "main".to_string(),
+                    Box::new(AstType::Argment(vec![
+                        AstType::Variable(Type::Int, Structure::Identifier, "a".to_string()),
+                        AstType::Variable(Type::Int, Structure::Identifier, "b".to_string()),
+                    ])),
+                    Box::new(AstType::Statement(vec![AstType::Assign(
+                        Box::new(AstType::Variable(
+                            Type::Int,
+                            Structure::Identifier,
+                            "c".to_string()
+                        )),
+                        Box::new(AstType::Factor(3))
+                    ),])),
+                )
+            );
+        }
+        {
+            let data = vec![
+                create_token(Token::Int, "int".to_string()),
+                create_token(Token::Variable, "main".to_string()),
+                create_token(Token::LeftParen, "(".to_string()),
+                create_token(Token::IntPointer, "int*".to_string()),
+                create_token(Token::Variable, "a".to_string()),
+                create_token(Token::Comma, ",".to_string()),
+                create_token(Token::Int, "int".to_string()),
+                create_token(Token::Variable, "b".to_string()),
+                create_token(Token::RightParen, ")".to_string()),
+                create_token(Token::LeftBrace, "{".to_string()),
+                create_token(Token::Int, "int".to_string()),
+                create_token(Token::Variable, "c".to_string()),
+                create_token(Token::Assign, "=".to_string()),
+                create_token(Token::Number, "3".to_string()),
+                create_token(Token::SemiColon, ";".to_string()),
+                create_token(Token::RightBrace, "}".to_string()),
+                create_token(Token::End, "End".to_string()),
+            ];
+            let mut ast = AstGen::new(&data);
+            let result = ast.parse();
+
+            // 期待値確認.
+            assert_eq!(
+                result.get_tree()[0],
+                AstType::FuncDef(
+                    Type::Int,
+                    Structure::Identifier,
+                    "main".to_string(),
+                    Box::new(AstType::Argment(vec![
+                        AstType::Variable(Type::Int, Structure::Pointer, "a".to_string()),
+                        AstType::Variable(Type::Int, Structure::Identifier, "b".to_string()),
+                    ])),
+                    Box::new(AstType::Statement(vec![AstType::Assign(
+                        Box::new(AstType::Variable(
+                            Type::Int,
+                            Structure::Identifier,
+                            "c".to_string()
+                        )),
+                        Box::new(AstType::Factor(3))
+                    ),])),
+                )
+            );
+        }
+    }
+
+    #[test]
+    fn test_statement_if() {
+        {
+            let data = vec![
+                create_token(Token::Int, "int".to_string()),
+                create_token(Token::Variable, "main".to_string()),
+                create_token(Token::LeftParen, "(".to_string()),
+                create_token(Token::RightParen, ")".to_string()),
+                create_token(Token::LeftBrace, "{".to_string()),
+                create_token(Token::Int, "int".to_string()),
+                create_token(Token::Variable, "a".to_string()),
+                create_token(Token::SemiColon, ";".to_string()),
+                create_token(Token::If, "if".to_string()),
+                create_token(Token::LeftParen, "(".to_string()),
+                create_token(Token::Variable, "a".to_string()),
+                create_token(Token::Equal, "==".to_string()),
+                create_token(Token::Number, "3".to_string()),
+                create_token(Token::RightParen, ")".to_string()),
+                create_token(Token::LeftBrace, "{".to_string()),
+                create_token(Token::Number, "1".to_string()),
+                create_token(Token::SemiColon, ";".to_string()),
+                create_token(Token::Int, "int".to_string()),
+                create_token(Token::Variable, "b".to_string()),
+                create_token(Token::Assign, "=".to_string()),
+                create_token(Token::Number, "10".to_string()),
+                create_token(Token::SemiColon, ";".to_string()),
+                create_token(Token::RightBrace, "}".to_string()),
+                create_token(Token::RightBrace, "}".to_string()),
+                create_token(Token::End, "End".to_string()),
+            ];
+            let mut ast = AstGen::new(&data);
+            let result = ast.parse();
+
+            // 期待値確認.
+            assert_eq!(
+                result.get_tree()[0],
+                AstType::FuncDef(
+                    Type::Int,
+                    Structure::Identifier,
+                    "main".to_string(),
+                    Box::new(AstType::Argment(vec![])),
+                    Box::new(AstType::Statement(vec![
+                        AstType::Variable(Type::Int, Structure::Identifier, "a".to_string()),
+                        AstType::If(
+                            Box::new(AstType::Equal(
+                                Box::new(AstType::Variable(
+                                    Type::Int,
+                                    Structure::Identifier,
+                                    "a".to_string()
+                                )),
+                                Box::new(AstType::Factor(3))
+                            )),
+                            Box::new(AstType::Statement(vec![
+                                AstType::Factor(1),
+                                AstType::Assign(
+                                    Box::new(AstType::Variable(
+                                        Type::Int,
+                                        Structure::Identifier,
+                                        "b".to_string()
+                                    )),
+                                    Box::new(AstType::Factor(10))
+                                )
+                            ],)),
+                            Box::new(None)
+                        )
+                    ]))
+                )
+            );
+        }
+    }
+
+    #[test]
+    fn test_statement_else() {
+        {
+            let data = vec![
+                create_token(Token::Int, "int".to_string()),
+                create_token(Token::Variable, "main".to_string()),
+                create_token(Token::LeftParen, "(".to_string()),
+                create_token(Token::RightParen, ")".to_string()),
+                create_token(Token::LeftBrace, "{".to_string()),
+                create_token(Token::Int, "int".to_string()),
+                create_token(Token::Variable, "a".to_string()),
+                create_token(Token::SemiColon, ";".to_string()),
+                create_token(Token::Int, "int".to_string()),
+                create_token(Token::Variable, "b".to_string()),
+                create_token(Token::SemiColon, ";".to_string()),
+                create_token(Token::Int, "int".to_string()),
+                create_token(Token::Variable, "e".to_string()),
+                create_token(Token::SemiColon, ";".to_string()),
+                create_token(Token::If, "if".to_string()),
+                create_token(Token::LeftParen, "(".to_string()),
+                create_token(Token::Variable, "a".to_string()),
+                create_token(Token::Equal, "==".to_string()),
+                create_token(Token::Number, "3".to_string()),
+                create_token(Token::RightParen, ")".to_string()),
+                create_token(Token::LeftBrace, "{".to_string()),
+                create_token(Token::Number, "1".to_string()),
+                create_token(Token::SemiColon, ";".to_string()),
+                create_token(Token::Variable, "b".to_string()),
+                create_token(Token::Assign, "=".to_string()),
+                create_token(Token::Number, "10".to_string()),
+                create_token(Token::SemiColon, ";".to_string()),
+                create_token(Token::RightBrace, "}".to_string()),
+                create_token(Token::Else, "else".to_string()),
+                create_token(Token::LeftBrace, "{".to_string()),
+                create_token(Token::Variable, "e".to_string()),
+                create_token(Token::Assign, "=".to_string()),
+                create_token(Token::Number, "9".to_string()),
+                create_token(Token::SemiColon, ";".to_string()),
+                create_token(Token::RightBrace, "}".to_string()),
+                create_token(Token::RightBrace, "}".to_string()),
+                create_token(Token::End, "End".to_string()),
+            ];
+            let mut ast = AstGen::new(&data);
+            let result = ast.parse();
+
+            // 期待値確認.
+            assert_eq!(
+                result.get_tree()[0],
+                AstType::FuncDef(
+                    Type::Int,
+                    Structure::Identifier,
+                    "main".to_string(),
+                    Box::new(AstType::Argment(vec![])),
+                    Box::new(AstType::Statement(vec![
+                        AstType::Variable(Type::Int, Structure::Identifier, "a".to_string()),
+                        AstType::Variable(Type::Int, Structure::Identifier, "b".to_string()),
+                        AstType::Variable(Type::Int, Structure::Identifier, "e".to_string()),
+                        AstType::If(
+                            Box::new(AstType::Equal(
+                                Box::new(AstType::Variable(
+                                    Type::Int,
+                                    Structure::Identifier,
+                                    "a".to_string()
+                                )),
+                                Box::new(AstType::Factor(3))
+                            )),
+                            Box::new(AstType::Statement(vec![
+                                AstType::Factor(1),
+                                AstType::Assign(
+                                    Box::new(AstType::Variable(
+                                        Type::Int,
+                                        Structure::Identifier,
+                                        "b".to_string()
+                                    )),
+                                    Box::new(AstType::Factor(10))
+                                )
+                            ],)),
+                            Box::new(Some(AstType::Statement(vec![AstType::Assign(
+                                Box::new(AstType::Variable(
+                                    Type::Int,
+                                    Structure::Identifier,
+                                    "e".to_string()
+                                )),
+                                Box::new(AstType::Factor(9))
+                            )],))),
+                        ),
+                    ]))
+                )
+            );
+        }
+        {
+            let data = vec![
+                create_token(Token::Int, "int".to_string()),
+                create_token(Token::Variable, "main".to_string()),
+                create_token(Token::LeftParen, "(".to_string()),
+                create_token(Token::RightParen, ")".to_string()),
+                create_token(Token::LeftBrace, "{".to_string()),
+                create_token(Token::Int, "int".to_string()),
+                create_token(Token::Variable, "a".to_string()),
+                create_token(Token::SemiColon, ";".to_string()),
+                create_token(Token::If, "if".to_string()),
+                create_token(Token::LeftParen, "(".to_string()),
+                create_token(Token::Variable, "a".to_string()),
+                create_token(Token::Equal, "==".to_string()),
+                create_token(Token::Number, "3".to_string()),
+                create_token(Token::RightParen, ")".to_string()),
+                create_token(Token::Number, "1".to_string()),
+                create_token(Token::SemiColon, ";".to_string()),
+                create_token(Token::Else, "else".to_string()),
+                create_token(Token::Int, "int".to_string()),
+                create_token(Token::Variable, "e".to_string()),
+                create_token(Token::Assign, "=".to_string()),
+                create_token(Token::Number, "9".to_string()),
+                create_token(Token::SemiColon, ";".to_string()),
+                create_token(Token::RightBrace, "}".to_string()),
+                create_token(Token::End, "End".to_string()),
+            ];
+            let mut ast = AstGen::new(&data);
+            let result = ast.parse();
+
+            // 期待値確認.
+            assert_eq!(
+                result.get_tree()[0],
+                AstType::FuncDef(
+                    Type::Int,
+                    Structure::Identifier,
+                    "main".to_string(),
+                    Box::new(AstType::Argment(vec![])),
+                    Box::new(AstType::Statement(vec![
+                        AstType::Variable(Type::Int, Structure::Identifier, "a".to_string()),
+                        AstType::If(
+                            Box::new(AstType::Equal(
+                                Box::new(AstType::Variable(
+                                    Type::Int,
+                                    Structure::Identifier,
+                                    "a".to_string()
+                                )),
+                                Box::new(AstType::Factor(3))
+                            )),
+                            Box::new(AstType::Statement(vec![AstType::Factor(1)])),
+                            Box::new(Some(AstType::Statement(vec![AstType::Assign(
+                                Box::new(AstType::Variable(
+                                    Type::Int,
+                                    Structure::Identifier,
+                                    "e".to_string()
+                                )),
+                                Box::new(AstType::Factor(9))
+                            )])))
+                        ),
+                    ]))
+                )
+            );
+        }
+    }
+
+    #[test]
+    fn test_statement_while() {
+        {
+            let data = vec![
+                create_token(Token::Int, "int".to_string()),
+                create_token(Token::Variable, "main".to_string()),
+                create_token(Token::LeftParen, "(".to_string()),
+                create_token(Token::RightParen, ")".to_string()),
+                create_token(Token::LeftBrace, "{".to_string()),
+                create_token(Token::Int, "int".to_string()),
+                create_token(Token::Variable, "a".to_string()),
+                create_token(Token::SemiColon, ";".to_string()),
+                create_token(Token::While, "while".to_string()),
+                create_token(Token::LeftParen, "(".to_string()),
+                create_token(Token::Variable, "a".to_string()),
+                create_token(Token::Equal, "==".to_string()),
+                create_token(Token::Number, "3".to_string()),
+                create_token(Token::RightParen, ")".to_string()),
+                create_token(Token::LeftBrace, "{".to_string()),
+                create_token(Token::Number, "1".to_string()),
+                create_token(Token::SemiColon, ";".to_string()),
+                create_token(Token::Int, "int".to_string()),
+                create_token(Token::Variable, "b".to_string()),
+                create_token(Token::Assign, "=".to_string()),
+                create_token(Token::Number, "10".to_string()),
+                create_token(Token::SemiColon, ";".to_string()),
+                create_token(Token::RightBrace, "}".to_string()),
+                create_token(Token::RightBrace, "}".to_string()),
+                create_token(Token::End, "End".to_string()),
+            ];
+            let mut ast = AstGen::new(&data);
+            let result = ast.parse();
+
+            // 期待値確認.
+            assert_eq!(
+                result.get_tree()[0],
+                AstType::FuncDef(
+                    Type::Int,
+                    Structure::Identifier,
+                    "main".to_string(),
+                    Box::new(AstType::Argment(vec![])),
+                    Box::new(AstType::Statement(vec![
+                        AstType::Variable(Type::Int, Structure::Identifier, "a".to_string()),
+                        AstType::While(
+                            Box::new(AstType::Equal(
+                                Box::new(AstType::Variable(
+                                    Type::Int,
+                                    Structure::Identifier,
+                                    "a".to_string()
+                                )),
+                                Box::new(AstType::Factor(3))
+                            )),
+                            Box::new(AstType::Statement(vec![
+                                AstType::Factor(1),
+                                AstType::Assign(
                                     Box::new(AstType::Variable(
                                         Type::Int,
                                         Structure::Identifier,
@@ -4366,22 +7378,1054 @@ mod tests {
                 create_token(Token::Int, "int".to_string()),
                 create_token(Token::Variable, "a".to_string()),
                 create_token(Token::SemiColon, ";".to_string()),
-                create_token(Token::While, "while".to_string()),
-                create_token(Token::LeftParen, "(".to_string()),
+                create_token(Token::While, "while".to_string()),
+                create_token(Token::LeftParen, "(".to_string()),
+                create_token(Token::Variable, "a".to_string()),
+                create_token(Token::Equal, "==".to_string()),
+                create_token(Token::Number, "3".to_string()),
+                create_token(Token::RightParen, ")".to_string()),
+                create_token(Token::LeftBrace, "{".to_string()),
+                create_token(Token::Number, "1".to_string()),
+                create_token(Token::SemiColon, ";".to_string()),
+                create_token(Token::Int, "int".to_string()),
+                create_token(Token::Variable, "b".to_string()),
+                create_token(Token::Assign, "=".to_string()),
+                create_token(Token::Number, "10".to_string()),
+                create_token(Token::SemiColon, ";".to_string()),
+                create_token(Token::RightBrace, "}".to_string()),
+                create_token(Token::Variable, "b".to_string()),
+                create_token(Token::SemiColon, ";".to_string()),
+                create_token(Token::RightBrace, "}".to_string()),
+                create_token(Token::End, "End".to_string()),
+            ];
+            let mut ast = AstGen::new(&data);
+            let result = ast.parse();
+
+            // 期待値確認.
+            assert_eq!(
+                result.get_tree()[0],
+                AstType::FuncDef(
+                    Type::Int,
+                    Structure::Identifier,
+                    "main".to_string(),
+                    Box::new(AstType::Argment(vec![])),
+                    Box::new(AstType::Statement(vec![
+                        AstType::Variable(Type::Int, Structure::Identifier, "a".to_string()),
+                        AstType::While(
+                            Box::new(AstType::Equal(
+                                Box::new(AstType::Variable(
+                                    Type::Int,
+                                    Structure::Identifier,
+                                    "a".to_string()
+                                )),
+                                Box::new(AstType::Factor(3))
+                            )),
+                            Box::new(AstType::Statement(vec![
+                                AstType::Factor(1),
+                                AstType::Assign(
+                                    Box::new(AstType::Variable(
+                                        Type::Int,
+                                        Structure::Identifier,
+                                        "b".to_string()
+                                    )),
+                                    Box::new(AstType::Factor(10))
+                                )
+                            ],))
+                        ),
+                        AstType::Variable(Type::Int, Structure::Identifier, "b".to_string())
+                    ]))
+                )
+            );
+        }
+    }
+
+    #[test]
+    fn test_statement_for() {
+        {
+            let data = vec![
+                create_token(Token::Int, "int".to_string()),
+                create_token(Token::Variable, "main".to_string()),
+                create_token(Token::LeftParen, "(".to_string()),
+                create_token(Token::RightParen, ")".to_string()),
+                create_token(Token::LeftBrace, "{".to_string()),
+                create_token(Token::For, "for".to_string()),
+                create_token(Token::LeftParen, "(".to_string()),
+                create_token(Token::SemiColon, ";".to_string()),
+                create_token(Token::SemiColon, ";".to_string()),
+                create_token(Token::RightParen, ")".to_string()),
+                create_token(Token::LeftBrace, "{".to_string()),
+                create_token(Token::Number, "1".to_string()),
+                create_token(Token::SemiColon, ";".to_string()),
+                create_token(Token::Int, "int".to_string()),
+                create_token(Token::Variable, "b".to_string()),
+                create_token(Token::Assign, "=".to_string()),
+                create_token(Token::Number, "10".to_string()),
+                create_token(Token::SemiColon, ";".to_string()),
+                create_token(Token::RightBrace, "}".to_string()),
+                create_token(Token::RightBrace, "}".to_string()),
+                create_token(Token::End, "End".to_string()),
+            ];
+            let mut ast = AstGen::new(&data);
+            let result = ast.parse();
+
+            // 期待値確認.
+            assert_eq!(
+                result.get_tree()[0],
+                AstType::FuncDef(
+                    Type::Int,
+                    Structure::Identifier,
+                    "main".to_string(),
+                    Box::new(AstType::Argment(vec![])),
+                    Box::new(AstType::Statement(vec![AstType::For(
+                        Box::new(None),
+                        Box::new(None),
+                        Box::new(None),
+                        Box::new(AstType::Statement(vec![
+                            AstType::Factor(1),
+                            AstType::Assign(
+                                Box::new(AstType::Variable(
+                                    Type::Int,
+                                    Structure::Identifier,
+                                    "b".to_string()
+                                )),
+                                Box::new(AstType::Factor(10))
+                            )
+                        ],))
+                    )]))
+                )
+            );
+        }
+        {
+            let data = vec![
+                create_token(Token::Int, "int".to_string()),
+                create_token(Token::Variable, "main".to_string()),
+                create_token(Token::LeftParen, "(".to_string()),
+                create_token(Token::RightParen, ")".to_string()),
+                create_token(Token::LeftBrace, "{".to_string()),
+                create_token(Token::For, "for".to_string()),
+                create_token(Token::LeftParen, "(".to_string()),
+                create_token(Token::Int, "int".to_string()),
+                create_token(Token::Variable, "i".to_string()),
+                create_token(Token::Assign, "=".to_string()),
+                create_token(Token::Number, "0".to_string()),
+                create_token(Token::SemiColon, ";".to_string()),
+                create_token(Token::Variable, "i".to_string()),
+                create_token(Token::LessThan, "<".to_string()),
+                create_token(Token::Number, "10".to_string()),
+                create_token(Token::SemiColon, ";".to_string()),
+                create_token(Token::Variable, "i".to_string()),
+                create_token(Token::Assign, "=".to_string()),
+                create_token(Token::Variable, "i".to_string()),
+                create_token(Token::Plus, "+".to_string()),
+                create_token(Token::Number, "1".to_string()),
+                create_token(Token::RightParen, ")".to_string()),
+                create_token(Token::LeftBrace, "{".to_string()),
+                create_token(Token::Number, "1".to_string()),
+                create_token(Token::SemiColon, ";".to_string()),
+                create_token(Token::Int, "int".to_string()),
+                create_token(Token::Variable, "b".to_string()),
+                create_token(Token::Assign, "=".to_string()),
+                create_token(Token::Number, "10".to_string()),
+                create_token(Token::SemiColon, ";".to_string()),
+                create_token(Token::RightBrace, "}".to_string()),
+                create_token(Token::RightBrace, "}".to_string()),
+                create_token(Token::End, "End".to_string()),
+            ];
+            let mut ast = AstGen::new(&data);
+            let result = ast.parse();
+
+            // 期待値確認.
+            assert_eq!(
+                result.get_tree()[0],
+                AstType::FuncDef(
+                    Type::Int,
+                    Structure::Identifier,
+                    "main".to_string(),
+                    Box::new(AstType::Argment(vec![])),
+                    Box::new(AstType::Statement(vec![AstType::For(
+                        Box::new(Some(AstType::Assign(
+                            Box::new(AstType::Variable(
+                                Type::Int,
+                                Structure::Identifier,
+                                "i".to_string()
+                            )),
+                            Box::new(AstType::Factor(0))
+                        ),)),
+                        Box::new(Some(AstType::LessThan(
+                            Box::new(AstType::Variable(
+                                Type::Int,
+                                Structure::Identifier,
+                                "i".to_string()
+                            )),
+                            Box::new(AstType::Factor(10))
+                        ),)),
+                        Box::new(Some(AstType::Assign(
+                            Box::new(AstType::Variable(
+                                Type::Int,
+                                Structure::Identifier,
+                                "i".to_string()
+                            )),
+                            Box::new(AstType::Plus(
+                                Box::new(AstType::Variable(
+                                    Type::Int,
+                                    Structure::Identifier,
+                                    "i".to_string()
+                                )),
+                                Box::new(AstType::Factor(1))
+                            ))
+                        ))),
+                        Box::new(AstType::Statement(vec![
+                            AstType::Factor(1),
+                            AstType::Assign(
+                                Box::new(AstType::Variable(
+                                    Type::Int,
+                                    Structure::Identifier,
+                                    "b".to_string()
+                                )),
+                                Box::new(AstType::Factor(10))
+                            )
+                        ],))
+                    )]))
+                )
+            );
+        }
+    }
+
+    #[test]
+    fn test_statement_do_while() {
+        {
+            let data = vec![
+                create_token(Token::Int, "int".to_string()),
+                create_token(Token::Variable, "main".to_string()),
+                create_token(Token::LeftParen, "(".to_string()),
+                create_token(Token::RightParen, ")".to_string()),
+                create_token(Token::LeftBrace, "{".to_string()),
+                create_token(Token::Do, "do".to_string()),
+                create_token(Token::LeftBrace, "{".to_string()),
+                create_token(Token::Number, "1".to_string()),
+                create_token(Token::SemiColon, ";".to_string()),
+                create_token(Token::Int, "int".to_string()),
+                create_token(Token::Variable, "a".to_string()),
+                create_token(Token::SemiColon, ";".to_string()),
+                create_token(Token::Int, "int".to_string()),
+                create_token(Token::Variable, "b".to_string()),
+                create_token(Token::Assign, "=".to_string()),
+                create_token(Token::Number, "10".to_string()),
+                create_token(Token::SemiColon, ";".to_string()),
+                create_token(Token::RightBrace, "}".to_string()),
+                create_token(Token::While, "while".to_string()),
+                create_token(Token::LeftParen, "(".to_string()),
+                create_token(Token::Variable, "a".to_string()),
+                create_token(Token::Equal, "==".to_string()),
+                create_token(Token::Number, "3".to_string()),
+                create_token(Token::RightParen, ")".to_string()),
+                create_token(Token::RightBrace, "}".to_string()),
+                create_token(Token::End, "End".to_string()),
+            ];
+            let mut ast = AstGen::new(&data);
+            let result = ast.parse();
+
+            // 期待値確認.
+            assert_eq!(
+                result.get_tree()[0],
+                AstType::FuncDef(
+                    Type::Int,
+                    Structure::Identifier,
+                    "main".to_string(),
+                    Box::new(AstType::Argment(vec![])),
+                    Box::new(AstType::Statement(vec![AstType::Do(
+                        Box::new(AstType::Statement(vec![
+                            AstType::Factor(1),
+                            AstType::Variable(Type::Int, Structure::Identifier, "a".to_string()),
+                            AstType::Assign(
+                                Box::new(AstType::Variable(
+                                    Type::Int,
+                                    Structure::Identifier,
+                                    "b".to_string()
+                                )),
+                                Box::new(AstType::Factor(10))
+                            )
+                        ],)),
+                        Box::new(AstType::Equal(
+                            Box::new(AstType::Variable(
+                                Type::Int,
+                                Structure::Identifier,
+                                "a".to_string()
+                            )),
+                            Box::new(AstType::Factor(3))
+                        )),
+                    )]))
+                )
+            );
+        }
+    }
+
+    #[test]
+    fn test_statement_continue_and_break() {
+        {
+            let data = vec![
+                create_token(Token::Int, "int".to_string()),
+                create_token(Token::Variable, "main".to_string()),
+                create_token(Token::LeftParen, "(".to_string()),
+                create_token(Token::RightParen, ")".to_string()),
+                create_token(Token::LeftBrace, "{".to_string()),
+                create_token(Token::Do, "do".to_string()),
+                create_token(Token::LeftBrace, "{".to_string()),
+                create_token(Token::Number, "1".to_string()),
+                create_token(Token::SemiColon, ";".to_string()),
+                create_token(Token::Int, "int".to_string()),
+                create_token(Token::Variable, "a".to_string()),
+                create_token(Token::SemiColon, ";".to_string()),
+                create_token(Token::Int, "int".to_string()),
+                create_token(Token::Variable, "b".to_string()),
+                create_token(Token::Assign, "=".to_string()),
+                create_token(Token::Number, "10".to_string()),
+                create_token(Token::SemiColon, ";".to_string()),
+                create_token(Token::Continue, "continue".to_string()),
+                create_token(Token::SemiColon, ";".to_string()),
+                create_token(Token::RightBrace, "}".to_string()),
+                create_token(Token::While, "while".to_string()),
+                create_token(Token::LeftParen, "(".to_string()),
+                create_token(Token::Variable, "a".to_string()),
+                create_token(Token::Equal, "==".to_string()),
+                create_token(Token::Number, "3".to_string()),
+                create_token(Token::RightParen, ")".to_string()),
+                create_token(Token::RightBrace, "}".to_string()),
+                create_token(Token::End, "End".to_string()),
+            ];
+            let mut ast = AstGen::new(&data);
+            let result = ast.parse();
+
+            // 期待値確認.
+            assert_eq!(
+                result.get_tree()[0],
+                AstType::FuncDef(
+                    Type::Int,
+                    Structure::Identifier,
+                    "main".to_string(),
+                    Box::new(AstType::Argment(vec![])),
+                    Box::new(AstType::Statement(vec![AstType::Do(
+                        Box::new(AstType::Statement(vec![
+                            AstType::Factor(1),
+                            AstType::Variable(Type::Int, Structure::Identifier, "a".to_string()),
+                            AstType::Assign(
+                                Box::new(AstType::Variable(
+                                    Type::Int,
+                                    Structure::Identifier,
+                                    "b".to_string()
+                                )),
+                                Box::new(AstType::Factor(10))
+                            ),
+                            AstType::Continue(),
+                        ],)),
+                        Box::new(AstType::Equal(
+                            Box::new(AstType::Variable(
+                                Type::Int,
+                                Structure::Identifier,
+                                "a".to_string()
+                            )),
+                            Box::new(AstType::Factor(3))
+                        )),
+                    )]))
+                )
+            );
+        }
+        {
+            let data = vec![
+                create_token(Token::Int, "int".to_string()),
+                create_token(Token::Variable, "main".to_string()),
+                create_token(Token::LeftParen, "(".to_string()),
+                create_token(Token::RightParen, ")".to_string()),
+                create_token(Token::LeftBrace, "{".to_string()),
+                create_token(Token::Do, "do".to_string()),
+                create_token(Token::LeftBrace, "{".to_string()),
+                create_token(Token::Int, "int".to_string()),
+                create_token(Token::Variable, "a".to_string()),
+                create_token(Token::SemiColon, ";".to_string()),
+                create_token(Token::Number, "1".to_string()),
+                create_token(Token::SemiColon, ";".to_string()),
+                create_token(Token::Int, "int".to_string()),
+                create_token(Token::Variable, "b".to_string()),
+                create_token(Token::Assign, "=".to_string()),
+                create_token(Token::Number, "10".to_string()),
+                create_token(Token::SemiColon, ";".to_string()),
+                create_token(Token::Break, "break".to_string()),
+                create_token(Token::SemiColon, ";".to_string()),
+                create_token(Token::RightBrace, "}".to_string()),
+                create_token(Token::While, "while".to_string()),
+                create_token(Token::LeftParen, "(".to_string()),
+                create_token(Token::Variable, "a".to_string()),
+                create_token(Token::Equal, "==".to_string()),
+                create_token(Token::Number, "3".to_string()),
+                create_token(Token::RightParen, ")".to_string()),
+                create_token(Token::RightBrace, "}".to_string()),
+                create_token(Token::End, "End".to_string()),
+            ];
+            let mut ast = AstGen::new(&data);
+            let result = ast.parse();
+
+            // 期待値確認.
+            assert_eq!(
+                result.get_tree()[0],
+                AstType::FuncDef(
+                    Type::Int,
+                    Structure::Identifier,
+                    "main".to_string(),
+                    Box::new(AstType::Argment(vec![])),
+                    Box::new(AstType::Statement(vec![AstType::Do(
+                        Box::new(AstType::Statement(vec![
+                            AstType::Variable(Type::Int, Structure::Identifier, "a".to_string()),
+                            AstType::Factor(1),
+                            AstType::Assign(
+                                Box::new(AstType::Variable(
+                                    Type::Int,
+                                    Structure::Identifier,
+                                    "b".to_string()
+                                )),
+                                Box::new(AstType::Factor(10))
+                            ),
+                            AstType::Break(1),
+                        ],)),
+                        Box::new(AstType::Equal(
+                            Box::new(AstType::Variable(
+                                Type::Int,
+                                Structure::Identifier,
+                                "a".to_string()
+                            )),
+                            Box::new(AstType::Factor(3))
+                        )),
+                    )]))
+                )
+            );
+        }
+    }
+
+    #[test]
+    fn test_break_level() {
+        let data = vec![
+            create_token(Token::Int, "int".to_string()),
+            create_token(Token::Variable, "main".to_string()),
+            create_token(Token::LeftParen, "(".to_string()),
+            create_token(Token::RightParen, ")".to_string()),
+            create_token(Token::LeftBrace, "{".to_string()),
+            create_token(Token::While, "while".to_string()),
+            create_token(Token::LeftParen, "(".to_string()),
+            create_token(Token::Number, "1".to_string()),
+            create_token(Token::RightParen, ")".to_string()),
+            create_token(Token::LeftBrace, "{".to_string()),
+            create_token(Token::While, "while".to_string()),
+            create_token(Token::LeftParen, "(".to_string()),
+            create_token(Token::Number, "1".to_string()),
+            create_token(Token::RightParen, ")".to_string()),
+            create_token(Token::LeftBrace, "{".to_string()),
+            create_token(Token::Break, "break".to_string()),
+            create_token(Token::Number, "2".to_string()),
+            create_token(Token::SemiColon, ";".to_string()),
+            create_token(Token::RightBrace, "}".to_string()),
+            create_token(Token::RightBrace, "}".to_string()),
+            create_token(Token::RightBrace, "}".to_string()),
+            create_token(Token::End, "End".to_string()),
+        ];
+        let mut ast = AstGen::new(&data);
+        ast.set_config(&Config { gnu: true, ..Default::default() });
+        let result = ast.parse();
+
+        // 期待値確認.
+        assert_eq!(
+            result.get_tree()[0],
+            AstType::FuncDef(
+                Type::Int,
+                Structure::Identifier,
+                "main".to_string(),
+                Box::new(AstType::Argment(vec![])),
+                Box::new(AstType::Statement(vec![AstType::While(
+                    Box::new(AstType::Factor(1)),
+                    Box::new(AstType::Statement(vec![AstType::While(
+                        Box::new(AstType::Factor(1)),
+                        Box::new(AstType::Statement(vec![AstType::Break(2)])),
+                    )])),
+                )]))
+            )
+        );
+    }
+
+    #[test]
+    fn test_break_level_out_of_range() {
+        let data = vec![
+            create_token(Token::Int, "int".to_string()),
+            create_token(Token::Variable, "main".to_string()),
+            create_token(Token::LeftParen, "(".to_string()),
+            create_token(Token::RightParen, ")".to_string()),
+            create_token(Token::LeftBrace, "{".to_string()),
+            create_token(Token::While, "while".to_string()),
+            create_token(Token::LeftParen, "(".to_string()),
+            create_token(Token::Number, "1".to_string()),
+            create_token(Token::RightParen, ")".to_string()),
+            create_token(Token::LeftBrace, "{".to_string()),
+            create_token(Token::While, "while".to_string()),
+            create_token(Token::LeftParen, "(".to_string()),
+            create_token(Token::Number, "1".to_string()),
+            create_token(Token::RightParen, ")".to_string()),
+            create_token(Token::LeftBrace, "{".to_string()),
+            create_token(Token::Break, "break".to_string()),
+            create_token(Token::Number, "3".to_string()),
+            create_token(Token::SemiColon, ";".to_string()),
+            create_token(Token::RightBrace, "}".to_string()),
+            create_token(Token::RightBrace, "}".to_string()),
+            create_token(Token::RightBrace, "}".to_string()),
+            create_token(Token::End, "End".to_string()),
+        ];
+        let mut ast = AstGen::new(&data);
+        ast.set_config(&Config { gnu: true, ..Default::default() });
+        ast.parse();
+        assert_eq!(messages(ast.get_errors()), vec![":0:0: break level is out of range: 3".to_string()]);
+    }
+
+    #[test]
+    fn test_break_level_strict() {
+        let data = vec![
+            create_token(Token::Int, "int".to_string()),
+            create_token(Token::Variable, "main".to_string()),
+            create_token(Token::LeftParen, "(".to_string()),
+            create_token(Token::RightParen, ")".to_string()),
+            create_token(Token::LeftBrace, "{".to_string()),
+            create_token(Token::While, "while".to_string()),
+            create_token(Token::LeftParen, "(".to_string()),
+            create_token(Token::Number, "1".to_string()),
+            create_token(Token::RightParen, ")".to_string()),
+            create_token(Token::LeftBrace, "{".to_string()),
+            create_token(Token::While, "while".to_string()),
+            create_token(Token::LeftParen, "(".to_string()),
+            create_token(Token::Number, "1".to_string()),
+            create_token(Token::RightParen, ")".to_string()),
+            create_token(Token::LeftBrace, "{".to_string()),
+            create_token(Token::Break, "break".to_string()),
+            create_token(Token::Number, "2".to_string()),
+            create_token(Token::SemiColon, ";".to_string()),
+            create_token(Token::RightBrace, "}".to_string()),
+            create_token(Token::RightBrace, "}".to_string()),
+            create_token(Token::RightBrace, "}".to_string()),
+            create_token(Token::End, "End".to_string()),
+        ];
+        let mut ast = AstGen::new(&data);
+        ast.parse();
+        assert_eq!(messages(ast.get_errors()), vec![":0:0: break with level requires --gnu".to_string()]);
+    }
+
+    #[test]
+    fn test_else_if_chain() {
+        let data = vec![
+            create_token(Token::Int, "int".to_string()),
+            create_token(Token::Variable, "main".to_string()),
+            create_token(Token::LeftParen, "(".to_string()),
+            create_token(Token::RightParen, ")".to_string()),
+            create_token(Token::LeftBrace, "{".to_string()),
+            create_token(Token::If, "if".to_string()),
+            create_token(Token::LeftParen, "(".to_string()),
+            create_token(Token::Number, "1".to_string()),
+            create_token(Token::RightParen, ")".to_string()),
+            create_token(Token::Number, "2".to_string()),
+            create_token(Token::SemiColon, ";".to_string()),
+            create_token(Token::Else, "else".to_string()),
+            create_token(Token::If, "if".to_string()),
+            create_token(Token::LeftParen, "(".to_string()),
+            create_token(Token::Number, "3".to_string()),
+            create_token(Token::RightParen, ")".to_string()),
+            create_token(Token::Number, "4".to_string()),
+            create_token(Token::SemiColon, ";".to_string()),
+            create_token(Token::Else, "else".to_string()),
+            create_token(Token::Number, "5".to_string()),
+            create_token(Token::SemiColon, ";".to_string()),
+            create_token(Token::RightBrace, "}".to_string()),
+            create_token(Token::End, "End".to_string()),
+        ];
+        let mut ast = AstGen::new(&data);
+        let result = ast.parse();
+
+        // 期待値確認.
+        assert_eq!(
+            result.get_tree()[0],
+            AstType::FuncDef(
+                Type::Int,
+                Structure::Identifier,
+                "main".to_string(),
+                Box::new(AstType::Argment(vec![])),
+                Box::new(AstType::Statement(vec![AstType::If(
+                    Box::new(AstType::Factor(1)),
+                    Box::new(AstType::Statement(vec![AstType::Factor(2)])),
+                    Box::new(Some(AstType::Statement(vec![AstType::If(
+                        Box::new(AstType::Factor(3)),
+                        Box::new(AstType::Statement(vec![AstType::Factor(4)])),
+                        Box::new(Some(AstType::Statement(vec![AstType::Factor(5)]))),
+                    )]))),
+                )]))
+            )
+        );
+    }
+
+    #[test]
+    fn test_assign_in_condition() {
+        let warnings = |src: &str| {
+            let mut lexer = LexicalAnalysis::new("test.c".to_string(), src);
+            lexer.read_token();
+            let mut ast = AstGen::new(lexer.get_tokens());
+            ast.parse();
+            messages(ast.get_warnings())
+        };
+        let msg = "suggest parentheses around assignment used as truth value";
+
+        // 括弧で囲まれていない代入は、位置情報付きで警告
+        assert_eq!(
+            warnings("int main() { int a; int b; if (a = b) { return 1; } return 0; }"),
+            vec![format!("test.c:1:32: {}", msg)]
+        );
+        assert_eq!(
+            warnings("int main() { int a; int b;\nwhile (a = b) { b = 0; }\nfor (; a = b;) { b = 0; } return 0; }"),
+            vec![format!("test.c:2:8: {}", msg), format!("test.c:3:8: {}", msg)]
+        );
+
+        // 二重の括弧で囲んだ代入、比較は警告しない
+        assert!(warnings("int main() { int a; int b; if ((a = b)) { return 1; } return 0; }").is_empty());
+        assert!(warnings("int main() { int a; int b; if (a == b) { return 1; } return 0; }").is_empty());
+    }
+
+    #[test]
+    fn test_unknown_type() {
+        let errors = |src: &str| {
+            let mut lexer = LexicalAnalysis::new("test.c".to_string(), src);
+            lexer.read_token();
+            let mut ast = AstGen::new(lexer.get_tokens());
+            ast.parse();
+            messages(ast.get_errors())
+        };
+
+        // 宣言位置と型名を含むエラーとする
+        assert_eq!(
+            errors("int main() {\n  foo x;\n  return 0;\n}"),
+            vec!["test.c:2:3: unknown type name 'foo'".to_string()]
+        );
+        assert_eq!(
+            errors("int main() { foo* p; bar y = 3; return 0; }"),
+            vec![
+                "test.c:1:14: unknown type name 'foo'".to_string(),
+                "test.c:1:22: unknown type name 'bar'".to_string(),
+            ]
+        );
+        assert_eq!(
+            errors("int f(int a, foo b) { return a; } int main() { return 0; }"),
+            vec!["test.c:1:14: unknown type name 'foo'".to_string()]
+        );
+        assert_eq!(
+            errors("foo g; int main() { return 0; }"),
+            vec!["test.c:1:1: unknown type name 'foo'".to_string()]
+        );
+        assert_eq!(
+            errors("typedef foo t; int main() { return 0; }"),
+            vec!["test.c:1:9: unknown type name 'foo'".to_string()]
+        );
+        assert!(errors("int main() { int x; return 0; }").is_empty());
+    }
+
+    #[test]
+    fn test_diagnostic_position() {
+        let errors = |src: &str| {
+            let mut lexer = LexicalAnalysis::new("test.c".to_string(), src);
+            lexer.read_token();
+            let mut ast = AstGen::new(lexer.get_tokens());
+            ast.parse();
+            messages(ast.get_errors())
+        };
+
+        // ラベル、continueのエラーはキーワードの位置とする
+        assert_eq!(
+            errors("int main() {\n  switch (1) {\n  case 1:\n  case 1:\n  default:\n  default:\n    continue;\n  }\n  case 2:\n  return 0;\n}"),
+            vec![
+                "test.c:4:3: duplicate case value: 1".to_string(),
+                "test.c:6:3: multiple default labels in one switch".to_string(),
+                "test.c:7:5: continue statement not within a loop".to_string(),
+                "test.c:9:3: case label not within a switch statement".to_string(),
+            ]
+        );
+        assert_eq!(
+            errors("int main() { while (1) { break 2; } return 0; }"),
+            vec!["test.c:1:26: break with level requires --gnu".to_string()]
+        );
+        assert_eq!(
+            errors("int main() { return 1 ?: 2; }"),
+            vec!["test.c:1:23: omitted middle operand requires --gnu".to_string()]
+        );
+
+        // 配列の要素数、初期化子のエラーは該当する式の位置とする
+        assert_eq!(
+            errors("int main() { int a[0]; int b[2] = {1, 2, 3}; int c[2] = {[1] = 1, [1] = 2}; return 0; }"),
+            vec![
+                "test.c:1:20: array size must be positive: a[0]".to_string(),
+                "test.c:1:42: excess elements in array initializer: b".to_string(),
+                "test.c:1:67: duplicate initializer: c[1]".to_string(),
+            ]
+        );
+
+        // 宣言の構文エラーは、次の宣言から解析を再開する
+        assert_eq!(
+            errors("typedef int 1;\nint 2;\nint g;\nint main() { return sizeof int; }\nint f() { return 0; }"),
+            vec![
+                "test.c:1:13: expected identifier before '1'".to_string(),
+                "test.c:2:5: expected identifier or '(' before '2'".to_string(),
+                "test.c:3:6: expected '(' before ';'".to_string(),
+                "test.c:4:28: expected expression before 'int'".to_string(),
+            ]
+        );
+        assert_eq!(
+            errors("int main() { int 3; struct S 4; int; return 0; }"),
+            vec![
+                "test.c:1:18: expected identifier before '3'".to_string(),
+                "test.c:1:30: expected identifier before '4'".to_string(),
+                "test.c:1:36: expected identifier before ';'".to_string(),
+            ]
+        );
+        assert_eq!(
+            errors("int main() { int *p = (int[1]){1, 2}; int *q = (int[]){}; return 0; }"),
+            vec![
+                "test.c:1:31: invalid compound literal initializer".to_string(),
+                "test.c:1:55: invalid compound literal initializer".to_string(),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_invalid_array_size() {
+        {
+            let data = vec![
+                create_token(Token::Int, "int".to_string()),
+                create_token(Token::Variable, "main".to_string()),
+                create_token(Token::LeftParen, "(".to_string()),
+                create_token(Token::RightParen, ")".to_string()),
+                create_token(Token::LeftBrace, "{".to_string()),
+                create_token(Token::Int, "int".to_string()),
+                create_token(Token::Variable, "a".to_string()),
+                create_token(Token::LeftBracket, "[".to_string()),
+                create_token(Token::Number, "0".to_string()),
+                create_token(Token::RightBracket, "]".to_string()),
+                create_token(Token::SemiColon, ";".to_string()),
+                create_token(Token::RightBrace, "}".to_string()),
+                create_token(Token::End, "End".to_string()),
+            ];
+            let mut ast = AstGen::new(&data);
+            ast.parse();
+            assert_eq!(messages(ast.get_errors()), vec![":0:0: array size must be positive: a[0]".to_string()]);
+        }
+        {
+            let data = vec![
+                create_token(Token::Int, "int".to_string()),
+                create_token(Token::Variable, "main".to_string()),
+                create_token(Token::LeftParen, "(".to_string()),
+                create_token(Token::RightParen, ")".to_string()),
+                create_token(Token::LeftBrace, "{".to_string()),
+                create_token(Token::Int, "int".to_string()),
+                create_token(Token::Variable, "a".to_string()),
+                create_token(Token::LeftBracket, "[".to_string()),
+                create_token(Token::Minus, "-".to_string()),
+                create_token(Token::Number, "1".to_string()),
+                create_token(Token::RightBracket, "]".to_string()),
+                create_token(Token::SemiColon, ";".to_string()),
+                create_token(Token::RightBrace, "}".to_string()),
+                create_token(Token::End, "End".to_string()),
+            ];
+            let mut ast = AstGen::new(&data);
+            ast.parse();
+            assert_eq!(messages(ast.get_errors()), vec![":0:0: array size must be positive: a[-1]".to_string()]);
+        }
+        {
+            let data = vec![
+                create_token(Token::Int, "int".to_string()),
+                create_token(Token::Variable, "main".to_string()),
+                create_token(Token::LeftParen, "(".to_string()),
+                create_token(Token::RightParen, ")".to_string()),
+                create_token(Token::LeftBrace, "{".to_string()),
+                create_token(Token::Int, "int".to_string()),
+                create_token(Token::Variable, "n".to_string()),
+                create_token(Token::SemiColon, ";".to_string()),
+                create_token(Token::Int, "int".to_string()),
+                create_token(Token::Variable, "a".to_string()),
+                create_token(Token::LeftBracket, "[".to_string()),
+                create_token(Token::Variable, "n".to_string()),
+                create_token(Token::RightBracket, "]".to_string()),
+                create_token(Token::SemiColon, ";".to_string()),
+                create_token(Token::RightBrace, "}".to_string()),
+                create_token(Token::End, "End".to_string()),
+            ];
+            let mut ast = AstGen::new(&data);
+            ast.parse();
+            assert_eq!(messages(ast.get_errors()), vec![":0:0: variable length array is not supported: a".to_string()]);
+        }
+        {
+            let data = vec![
+                create_token(Token::Int, "int".to_string()),
+                create_token(Token::Variable, "main".to_string()),
+                create_token(Token::LeftParen, "(".to_string()),
+                create_token(Token::RightParen, ")".to_string()),
+                create_token(Token::LeftBrace, "{".to_string()),
+                create_token(Token::Int, "int".to_string()),
+                create_token(Token::Variable, "a".to_string()),
+                create_token(Token::LeftBracket, "[".to_string()),
+                create_token(Token::Number, "2".to_string()),
+                create_token(Token::Multi, "*".to_string()),
+                create_token(Token::Number, "3".to_string()),
+                create_token(Token::RightBracket, "]".to_string()),
+                create_token(Token::SemiColon, ";".to_string()),
+                create_token(Token::RightBrace, "}".to_string()),
+                create_token(Token::End, "End".to_string()),
+            ];
+            let mut ast = AstGen::new(&data);
+            let result = ast.parse();
+            assert!(ast.get_errors().is_empty());
+            assert_eq!(
+                result.get_tree()[0],
+                AstType::FuncDef(
+                    Type::Int,
+                    Structure::Identifier,
+                    "main".to_string(),
+                    Box::new(AstType::Argment(vec![])),
+                    Box::new(AstType::Statement(vec![AstType::Variable(
+                        Type::Int,
+                        Structure::Array(vec![6]),
+                        "a".to_string()
+                    )]))
+                )
+            );
+        }
+        {
+            // int a[2 > 1 ? 3 : 5];
+            let data = vec![
+                create_token(Token::Int, "int".to_string()),
+                create_token(Token::Variable, "main".to_string()),
+                create_token(Token::LeftParen, "(".to_string()),
+                create_token(Token::RightParen, ")".to_string()),
+                create_token(Token::LeftBrace, "{".to_string()),
+                create_token(Token::Int, "int".to_string()),
+                create_token(Token::Variable, "a".to_string()),
+                create_token(Token::LeftBracket, "[".to_string()),
+                create_token(Token::Number, "2".to_string()),
+                create_token(Token::GreaterThan, ">".to_string()),
+                create_token(Token::Number, "1".to_string()),
+                create_token(Token::Question, "?".to_string()),
+                create_token(Token::Number, "3".to_string()),
+                create_token(Token::Colon, ":".to_string()),
+                create_token(Token::Number, "5".to_string()),
+                create_token(Token::RightBracket, "]".to_string()),
+                create_token(Token::SemiColon, ";".to_string()),
+                create_token(Token::RightBrace, "}".to_string()),
+                create_token(Token::End, "End".to_string()),
+            ];
+            let mut ast = AstGen::new(&data);
+            let result = ast.parse();
+            assert!(ast.get_errors().is_empty());
+            assert_eq!(
+                result.get_tree()[0],
+                AstType::FuncDef(
+                    Type::Int,
+                    Structure::Identifier,
+                    "main".to_string(),
+                    Box::new(AstType::Argment(vec![])),
+                    Box::new(AstType::Statement(vec![AstType::Variable(
+                        Type::Int,
+                        Structure::Array(vec![3]),
+                        "a".to_string()
+                    )]))
+                )
+            );
+        }
+    }
+
+    #[test]
+    fn test_complex_call_argment() {
+        let data = vec![
+            create_token(Token::Int, "int".to_string()),
+            create_token(Token::Variable, "g".to_string()),
+            create_token(Token::LeftParen, "(".to_string()),
+            create_token(Token::RightParen, ")".to_string()),
+            create_token(Token::LeftBrace, "{".to_string()),
+            create_token(Token::RightBrace, "}".to_string()),
+            create_token(Token::Int, "int".to_string()),
+            create_token(Token::Variable, "f".to_string()),
+            create_token(Token::LeftParen, "(".to_string()),
+            create_token(Token::RightParen, ")".to_string()),
+            create_token(Token::LeftBrace, "{".to_string()),
+            create_token(Token::RightBrace, "}".to_string()),
+            create_token(Token::Int, "int".to_string()),
+            create_token(Token::Variable, "main".to_string()),
+            create_token(Token::LeftParen, "(".to_string()),
+            create_token(Token::RightParen, ")".to_string()),
+            create_token(Token::LeftBrace, "{".to_string()),
+            create_token(Token::Int, "int".to_string()),
+            create_token(Token::Variable, "a".to_string()),
+            create_token(Token::LeftBracket, "[".to_string()),
+            create_token(Token::Number, "2".to_string()),
+            create_token(Token::RightBracket, "]".to_string()),
+            create_token(Token::SemiColon, ";".to_string()),
+            create_token(Token::Int, "int".to_string()),
+            create_token(Token::Variable, "x".to_string()),
+            create_token(Token::SemiColon, ";".to_string()),
+            create_token(Token::Int, "int".to_string()),
+            create_token(Token::Variable, "y".to_string()),
+            create_token(Token::SemiColon, ";".to_string()),
+            create_token(Token::Variable, "f".to_string()),
+            create_token(Token::LeftParen, "(".to_string()),
+            create_token(Token::Variable, "g".to_string()),
+            create_token(Token::LeftParen, "(".to_string()),
+            create_token(Token::Number, "1".to_string()),
+            create_token(Token::RightParen, ")".to_string()),
+            create_token(Token::Comma, ",".to_string()),
+            create_token(Token::Variable, "a".to_string()),
+            create_token(Token::LeftBracket, "[".to_string()),
+            create_token(Token::Number, "0".to_string()),
+            create_token(Token::RightBracket, "]".to_string()),
+            create_token(Token::Comma, ",".to_string()),
+            create_token(Token::And, "&".to_string()),
+            create_token(Token::Variable, "x".to_string()),
+            create_token(Token::Comma, ",".to_string()),
+            create_token(Token::Variable, "y".to_string()),
+            create_token(Token::Plus, "+".to_string()),
+            create_token(Token::Number, "1".to_string()),
+            create_token(Token::RightParen, ")".to_string()),
+            create_token(Token::SemiColon, ";".to_string()),
+            create_token(Token::RightBrace, "}".to_string()),
+            create_token(Token::End, "End".to_string()),
+        ];
+        let mut ast = AstGen::new(&data);
+        let result = ast.parse();
+
+        // 期待値確認.
+        let var = |s: Structure, n: &str| Box::new(AstType::Variable(Type::Int, s, n.to_string()));
+        assert_eq!(
+            result.get_tree()[2],
+            AstType::FuncDef(
+                Type::Int,
+                Structure::Identifier,
+                "main".to_string(),
+                Box::new(AstType::Argment(vec![])),
+                Box::new(AstType::Statement(vec![
+                    AstType::Variable(Type::Int, Structure::Array(vec![2]), "a".to_string()),
+                    AstType::Variable(Type::Int, Structure::Identifier, "x".to_string()),
+                    AstType::Variable(Type::Int, Structure::Identifier, "y".to_string()),
+                    AstType::FuncCall(
+                        var(Structure::Identifier, "f"),
+                        Box::new(AstType::Argment(vec![
+                            AstType::FuncCall(
+                                var(Structure::Identifier, "g"),
+                                Box::new(AstType::Argment(vec![AstType::Factor(1)])),
+                            ),
+                            AstType::Indirect(Box::new(AstType::Plus(
+                                var(Structure::Array(vec![2]), "a"),
+                                Box::new(AstType::Factor(0)),
+                            ))),
+                            AstType::Address(var(Structure::Identifier, "x")),
+                            AstType::Plus(var(Structure::Identifier, "y"), Box::new(AstType::Factor(1))),
+                        ])),
+                    ),
+                ]))
+            )
+        );
+    }
+
+    #[test]
+    fn test_call_argment_trailing_comma() {
+        let src = "int f(int a) { return a; } int main() { f(1, ); return 0; }";
+        let mut lexer = LexicalAnalysis::new("test.c".to_string(), src);
+        lexer.read_token();
+        let mut ast = AstGen::new(lexer.get_tokens());
+        ast.parse();
+
+        // カンマの後の引数が無い場合、閉じ括弧の位置でエラーとする
+        assert_eq!(messages(ast.get_errors()), vec!["test.c:1:46: expected expression before ')'".to_string()]);
+    }
+
+    #[test]
+    fn test_empty_loop_body() {
+        let data = vec![
+            create_token(Token::Int, "int".to_string()),
+            create_token(Token::Variable, "main".to_string()),
+            create_token(Token::LeftParen, "(".to_string()),
+            create_token(Token::RightParen, ")".to_string()),
+            create_token(Token::LeftBrace, "{".to_string()),
+            create_token(Token::While, "while".to_string()),
+            create_token(Token::LeftParen, "(".to_string()),
+            create_token(Token::Number, "1".to_string()),
+            create_token(Token::RightParen, ")".to_string()),
+            create_token(Token::SemiColon, ";".to_string()),
+            create_token(Token::Number, "2".to_string()),
+            create_token(Token::SemiColon, ";".to_string()),
+            create_token(Token::RightBrace, "}".to_string()),
+            create_token(Token::End, "End".to_string()),
+        ];
+        let mut ast = AstGen::new(&data);
+        let result = ast.parse();
+
+        // 期待値確認(空文の後続はループ外).
+        assert_eq!(
+            result.get_tree()[0],
+            AstType::FuncDef(
+                Type::Int,
+                Structure::Identifier,
+                "main".to_string(),
+                Box::new(AstType::Argment(vec![])),
+                Box::new(AstType::Statement(vec![
+                    AstType::While(
+                        Box::new(AstType::Factor(1)),
+                        Box::new(AstType::Statement(vec![])),
+                    ),
+                    AstType::Factor(2),
+                ]))
+            )
+        );
+    }
+
+    #[test]
+    fn test_statement_return() {
+        {
+            let data = vec![
+                create_token(Token::Int, "int".to_string()),
+                create_token(Token::Variable, "main".to_string()),
+                create_token(Token::LeftParen, "(".to_string()),
+                create_token(Token::RightParen, ")".to_string()),
+                create_token(Token::LeftBrace, "{".to_string()),
+                create_token(Token::Return, "return".to_string()),
+                create_token(Token::Number, "1".to_string()),
+                create_token(Token::Equal, "==".to_string()),
+                create_token(Token::Number, "2".to_string()),
+                create_token(Token::SemiColon, ";".to_string()),
+                create_token(Token::RightBrace, "}".to_string()),
+                create_token(Token::End, "End".to_string()),
+            ];
+            let mut ast = AstGen::new(&data);
+            let result = ast.parse();
+
+            // 期待値確認.
+            assert_eq!(
+                result.get_tree()[0],
+                AstType::FuncDef(
+                    Type::Int,
+                    Structure::Identifier,
+                    "main".to_string(),
+                    Box::new(AstType::Argment(vec![])),
+                    Box::new(AstType::Statement(vec![AstType::Return(Box::new(
+                        AstType::Equal(Box::new(AstType::Factor(1)), Box::new(AstType::Factor(2)))
+                    ))]))
+                )
+            );
+        }
+        {
+            let data = vec![
+                create_token(Token::Int, "int".to_string()),
+                create_token(Token::Variable, "main".to_string()),
+                create_token(Token::LeftParen, "(".to_string()),
+                create_token(Token::RightParen, ")".to_string()),
+                create_token(Token::LeftBrace, "{".to_string()),
+                create_token(Token::Int, "int".to_string()),
+                create_token(Token::Variable, "a".to_string()),
+                create_token(Token::SemiColon, ";".to_string()),
+                create_token(Token::Return, "return".to_string()),
                 create_token(Token::Variable, "a".to_string()),
-                create_token(Token::Equal, "==".to_string()),
-                create_token(Token::Number, "3".to_string()),
-                create_token(Token::RightParen, ")".to_string()),
-                create_token(Token::LeftBrace, "{".to_string()),
-                create_token(Token::Number, "1".to_string()),
-                create_token(Token::SemiColon, ";".to_string()),
-                create_token(Token::Int, "int".to_string()),
-                create_token(Token::Variable, "b".to_string()),
-                create_token(Token::Assign, "=".to_string()),
-                create_token(Token::Number, "10".to_string()),
-                create_token(Token::SemiColon, ";".to_string()),
-                create_token(Token::RightBrace, "}".to_string()),
-                create_token(Token::Variable, "b".to_string()),
                 create_token(Token::SemiColon, ";".to_string()),
                 create_token(Token::RightBrace, "}".to_string()),
                 create_token(Token::End, "End".to_string()),
@@ -4399,36 +8443,47 @@ mod tests {
                     Box::new(AstType::Argment(vec![])),
                     Box::new(AstType::Statement(vec![
                         AstType::Variable(Type::Int, Structure::Identifier, "a".to_string()),
-                        AstType::While(
-                            Box::new(AstType::Equal(
-                                Box::new(AstType::Variable(
-                                    Type::Int,
-                                    Structure::Identifier,
-                                    "a".to_string()
-                                )),
-                                Box::new(AstType::Factor(3))
-                            )),
-                            Box::new(AstType::Statement(vec![
-                                AstType::Factor(1),
-                                AstType::Assign(
-                                    Box::new(AstType::Variable(
-                                        Type::Int,
-                                        Structure::Identifier,
-                                        "b".to_string()
-                                    )),
-                                    Box::new(AstType::Factor(10))
-                                )
-                            ],))
-                        ),
-                        AstType::Variable(Type::Int, Structure::Identifier, "b".to_string())
+                        AstType::Return(Box::new(AstType::Variable(
+                            Type::Int,
+                            Structure::Identifier,
+                            "a".to_string()
+                        )))
                     ]))
                 )
             );
         }
+        // 値なしのreturn(void関数)
+        {
+            let data = vec![
+                create_token(Token::Void, "void".to_string()),
+                create_token(Token::Variable, "f".to_string()),
+                create_token(Token::LeftParen, "(".to_string()),
+                create_token(Token::RightParen, ")".to_string()),
+                create_token(Token::LeftBrace, "{".to_string()),
+                create_token(Token::Return, "return".to_string()),
+                create_token(Token::SemiColon, ";".to_string()),
+                create_token(Token::RightBrace, "}".to_string()),
+                create_token(Token::End, "End".to_string()),
+            ];
+            let mut ast = AstGen::new(&data);
+            let result = ast.parse();
+
+            // 期待値確認.
+            assert_eq!(
+                result.get_tree()[0],
+                AstType::FuncDef(
+                    Type::Void,
+                    Structure::Identifier,
+                    "f".to_string(),
+                    Box::new(AstType::Argment(vec![])),
+                    Box::new(AstType::Statement(vec![AstType::Return(Box::new(AstType::Void()))]))
+                )
+            );
+        }
     }
 
     #[test]
-    fn test_statement_for() {
+    fn test_address_indirect() {
         {
             let data = vec![
                 create_token(Token::Int, "int".to_string()),
@@ -4436,20 +8491,17 @@ mod tests {
                 create_token(Token::LeftParen, "(".to_string()),
                 create_token(Token::RightParen, ")".to_string()),
                 create_token(Token::LeftBrace, "{".to_string()),
-                create_token(Token::For, "for".to_string()),
-                create_token(Token::LeftParen, "(".to_string()),
-                create_token(Token::SemiColon, ";".to_string()),
-                create_token(Token::SemiColon, ";".to_string()),
-                create_token(Token::RightParen, ")".to_string()),
-                create_token(Token::LeftBrace, "{".to_string()),
-                create_token(Token::Number, "1".to_string()),
-                create_token(Token::SemiColon, ";".to_string()),
                 create_token(Token::Int, "int".to_string()),
-                create_token(Token::Variable, "b".to_string()),
+                create_token(Token::Variable, "a".to_string()),
+                create_token(Token::SemiColon, ";".to_string()),
+                create_token(Token::Variable, "a".to_string()),
                 create_token(Token::Assign, "=".to_string()),
-                create_token(Token::Number, "10".to_string()),
+                create_token(Token::And, "&".to_string()),
+                create_token(Token::Variable, "a".to_string()),
+                create_token(Token::SemiColon, ";".to_string()),
+                create_token(Token::Return, "return".to_string()),
+                create_token(Token::Variable, "a".to_string()),
                 create_token(Token::SemiColon, ";".to_string()),
-                create_token(Token::RightBrace, "}".to_string()),
                 create_token(Token::RightBrace, "}".to_string()),
                 create_token(Token::End, "End".to_string()),
             ];
@@ -4464,22 +8516,26 @@ mod tests {
                     Structure::Identifier,
                     "main".to_string(),
                     Box::new(AstType::Argment(vec![])),
-                    Box::new(AstType::Statement(vec![AstType::For(
-                        Box::new(None),
-                        Box::new(None),
-                        Box::new(None),
-                        Box::new(AstType::Statement(vec![
-                            AstType::Factor(1),
-                            AstType::Assign(
-                                Box::new(AstType::Variable(
-                                    Type::Int,
-                                    Structure::Identifier,
-                                    "b".to_string()
-                                )),
-                                Box::new(AstType::Factor(10))
-                            )
-                        ],))
-                    )]))
+                    Box::new(AstType::Statement(vec![
+                        AstType::Variable(Type::Int, Structure::Identifier, "a".to_string()),
+                        AstType::Assign(
+                            Box::new(AstType::Variable(
+                                Type::Int,
+                                Structure::Identifier,
+                                "a".to_string()
+                            )),
+                            Box::new(AstType::Address(Box::new(AstType::Variable(
+                                Type::Int,
+                                Structure::Identifier,
+                                "a".to_string()
+                            )))),
+                        ),
+                        AstType::Return(Box::new(AstType::Variable(
+                            Type::Int,
+                            Structure::Identifier,
+                            "a".to_string()
+                        )))
+                    ]))
                 )
             );
         }
@@ -4490,33 +8546,18 @@ mod tests {
                 create_token(Token::LeftParen, "(".to_string()),
                 create_token(Token::RightParen, ")".to_string()),
                 create_token(Token::LeftBrace, "{".to_string()),
-                create_token(Token::For, "for".to_string()),
-                create_token(Token::LeftParen, "(".to_string()),
                 create_token(Token::Int, "int".to_string()),
-                create_token(Token::Variable, "i".to_string()),
-                create_token(Token::Assign, "=".to_string()),
-                create_token(Token::Number, "0".to_string()),
-                create_token(Token::SemiColon, ";".to_string()),
-                create_token(Token::Variable, "i".to_string()),
-                create_token(Token::LessThan, "<".to_string()),
-                create_token(Token::Number, "10".to_string()),
+                create_token(Token::Variable, "a".to_string()),
                 create_token(Token::SemiColon, ";".to_string()),
-                create_token(Token::Variable, "i".to_string()),
+                create_token(Token::Variable, "a".to_string()),
                 create_token(Token::Assign, "=".to_string()),
-                create_token(Token::Variable, "i".to_string()),
-                create_token(Token::Plus, "+".to_string()),
-                create_token(Token::Number, "1".to_string()),
-                create_token(Token::RightParen, ")".to_string()),
-                create_token(Token::LeftBrace, "{".to_string()),
-                create_token(Token::Number, "1".to_string()),
+                create_token(Token::Multi, "*".to_string()),
+                create_token(Token::Variable, "a".to_string()),
                 create_token(Token::SemiColon, ";".to_string()),
-                create_token(Token::Int, "int".to_string()),
-                create_token(Token::Variable, "b".to_string()),
-                create_token(Token::Assign, "=".to_string()),
-                create_token(Token::Number, "10".to_string()),
+                create_token(Token::Return, "return".to_string()),
+                create_token(Token::Variable, "a".to_string()),
                 create_token(Token::SemiColon, ";".to_string()),
                 create_token(Token::RightBrace, "}".to_string()),
-                create_token(Token::RightBrace, "}".to_string()),
                 create_token(Token::End, "End".to_string()),
             ];
             let mut ast = AstGen::new(&data);
@@ -4530,57 +8571,33 @@ mod tests {
                     Structure::Identifier,
                     "main".to_string(),
                     Box::new(AstType::Argment(vec![])),
-                    Box::new(AstType::Statement(vec![AstType::For(
-                        Box::new(Some(AstType::Assign(
-                            Box::new(AstType::Variable(
-                                Type::Int,
-                                Structure::Identifier,
-                                "i".to_string()
-                            )),
-                            Box::new(AstType::Factor(0))
-                        ),)),
-                        Box::new(Some(AstType::LessThan(
+                    Box::new(AstType::Statement(vec![
+                        AstType::Variable(Type::Int, Structure::Identifier, "a".to_string()),
+                        AstType::Assign(
                             Box::new(AstType::Variable(
                                 Type::Int,
                                 Structure::Identifier,
-                                "i".to_string()
+                                "a".to_string()
                             )),
-                            Box::new(AstType::Factor(10))
-                        ),)),
-                        Box::new(Some(AstType::Assign(
-                            Box::new(AstType::Variable(
+                            Box::new(AstType::Indirect(Box::new(AstType::Variable(
                                 Type::Int,
                                 Structure::Identifier,
-                                "i".to_string()
-                            )),
-                            Box::new(AstType::Plus(
-                                Box::new(AstType::Variable(
-                                    Type::Int,
-                                    Structure::Identifier,
-                                    "i".to_string()
-                                )),
-                                Box::new(AstType::Factor(1))
-                            ))
-                        ))),
-                        Box::new(AstType::Statement(vec![
-                            AstType::Factor(1),
-                            AstType::Assign(
-                                Box::new(AstType::Variable(
-                                    Type::Int,
-                                    Structure::Identifier,
-                                    "b".to_string()
-                                )),
-                                Box::new(AstType::Factor(10))
-                            )
-                        ],))
-                    )]))
+                                "a".to_string()
+                            )))),
+                        ),
+                        AstType::Return(Box::new(AstType::Variable(
+                            Type::Int,
+                            Structure::Identifier,
+                            "a".to_string()
+                        )))
+                    ]))
                 )
             );
         }
     }
 
     #[test]
-    fn test_statement_do_while() {
+    fn test_type_pointer() {
         {
             let data = vec![
                 create_token(Token::Int, "int".to_string()),
@@ -4588,25 +8605,50 @@ mod tests {
                 create_token(Token::LeftParen, "(".to_string()),
                 create_token(Token::RightParen, ")".to_string()),
                 create_token(Token::LeftBrace, "{".to_string()),
-                create_token(Token::Do, "do".to_string()),
-                create_token(Token::LeftBrace, "{".to_string()),
-                create_token(Token::Number, "1".to_string()),
+                create_token(Token::IntPointer, "int*".to_string()),
+                create_token(Token::Variable, "a".to_string()),
+                create_token(Token::SemiColon, ";".to_string()),
+                create_token(Token::Return, "return".to_string()),
+                create_token(Token::Variable, "a".to_string()),
                 create_token(Token::SemiColon, ";".to_string()),
+                create_token(Token::RightBrace, "}".to_string()),
+                create_token(Token::End, "End".to_string()),
+            ];
+            let mut ast = AstGen::new(&data);
+            let result = ast.parse();
+
+            // 期待値確認.
+            assert_eq!(
+                result.get_tree()[0],
+                AstType::FuncDef(
+                    Type::Int,
+                    Structure::Identifier,
+                    "main".to_string(),
+                    Box::new(AstType::Argment(vec![])),
+                    Box::new(AstType::Statement(vec![
+                        AstType::Variable(Type::Int, Structure::Pointer, "a".to_string()),
+                        AstType::Return(Box::new(AstType::Variable(
+                            Type::Int,
+                            Structure::Pointer,
+                            "a".to_string()
+                        )))
+                    ]))
+                )
+            );
+        }
+        {
+            let data = vec![
                 create_token(Token::Int, "int".to_string()),
+                create_token(Token::Variable, "main".to_string()),
+                create_token(Token::LeftParen, "(".to_string()),
+                create_token(Token::RightParen, ")".to_string()),
+                create_token(Token::LeftBrace, "{".to_string()),
+                create_token(Token::IntPointer, "int*".to_string()),
                 create_token(Token::Variable, "a".to_string()),
                 create_token(Token::SemiColon, ";".to_string()),
-                create_token(Token::Int, "int".to_string()),
-                create_token(Token::Variable, "b".to_string()),
-                create_token(Token::Assign, "=".to_string()),
-                create_token(Token::Number, "10".to_string()),
-                create_token(Token::SemiColon, ";".to_string()),
-                create_token(Token::RightBrace, "}".to_string()),
-                create_token(Token::While, "while".to_string()),
-                create_token(Token::LeftParen, "(".to_string()),
+                create_token(Token::Return, "return".to_string()),
                 create_token(Token::Variable, "a".to_string()),
-                create_token(Token::Equal, "==".to_string()),
-                create_token(Token::Number, "3".to_string()),
-                create_token(Token::RightParen, ")".to_string()),
+                create_token(Token::SemiColon, ";".to_string()),
                 create_token(Token::RightBrace, "}".to_string()),
                 create_token(Token::End, "End".to_string()),
             ];
@@ -4621,35 +8663,17 @@ mod tests {
                     Structure::Identifier,
                     "main".to_string(),
                     Box::new(AstType::Argment(vec![])),
-                    Box::new(AstType::Statement(vec![AstType::Do(
-                        Box::new(AstType::Statement(vec![
-                            AstType::Factor(1),
-                            AstType::Variable(Type::Int, Structure::Identifier, "a".to_string()),
-                            AstType::Assign(
-                                Box::new(AstType::Variable(
-                                    Type::Int,
-                                    Structure::Identifier,
-                                    "b".to_string()
-                                )),
-                                Box::new(AstType::Factor(10))
-                            )
-                        ],)),
-                        Box::new(AstType::Equal(
-                            Box::new(AstType::Variable(
-                                Type::Int,
-                                Structure::Identifier,
-                                "a".to_string()
-                            )),
-                            Box::new(AstType::Factor(3))
-                        )),
-                    )]))
+                    Box::new(AstType::Statement(vec![
+                        AstType::Variable(Type::Int, Structure::Pointer, "a".to_string()),
+                        AstType::Return(Box::new(AstType::Variable(
+                            Type::Int,
+                            Structure::Pointer,
+                            "a".to_string()
+                        )))
+                    ]))
                 )
             );
         }
-    }
-
-    #[test]
-    fn test_statement_continue_and_break() {
         {
             let data = vec![
                 create_token(Token::Int, "int".to_string()),
@@ -4657,27 +8681,22 @@ mod tests {
                 create_token(Token::LeftParen, "(".to_string()),
                 create_token(Token::RightParen, ")".to_string()),
                 create_token(Token::LeftBrace, "{".to_string()),
-                create_token(Token::Do, "do".to_string()),
-                create_token(Token::LeftBrace, "{".to_string()),
-                create_token(Token::Number, "1".to_string()),
-                create_token(Token::SemiColon, ";".to_string()),
-                create_token(Token::Int, "int".to_string()),
+                create_token(Token::IntPointer, "int*".to_string()),
                 create_token(Token::Variable, "a".to_string()),
                 create_token(Token::SemiColon, ";".to_string()),
-                create_token(Token::Int, "int".to_string()),
-                create_token(Token::Variable, "b".to_string()),
+                create_token(Token::Multi, "*".to_string()),
+                create_token(Token::Variable, "a".to_string()),
                 create_token(Token::Assign, "=".to_string()),
-                create_token(Token::Number, "10".to_string()),
-                create_token(Token::SemiColon, ";".to_string()),
-                create_token(Token::Continue, "continue".to_string()),
-                create_token(Token::SemiColon, ";".to_string()),
-                create_token(Token::RightBrace, "}".to_string()),
-                create_token(Token::While, "while".to_string()),
+                create_token(Token::Multi, "*".to_string()),
                 create_token(Token::LeftParen, "(".to_string()),
                 create_token(Token::Variable, "a".to_string()),
-                create_token(Token::Equal, "==".to_string()),
-                create_token(Token::Number, "3".to_string()),
+                create_token(Token::Plus, "+".to_string()),
+                create_token(Token::Number, "1".to_string()),
                 create_token(Token::RightParen, ")".to_string()),
+                create_token(Token::SemiColon, ";".to_string()),
+                create_token(Token::Return, "return".to_string()),
+                create_token(Token::Variable, "a".to_string()),
+                create_token(Token::SemiColon, ";".to_string()),
                 create_token(Token::RightBrace, "}".to_string()),
                 create_token(Token::End, "End".to_string()),
             ];
@@ -4692,29 +8711,29 @@ mod tests {
                     Structure::Identifier,
                     "main".to_string(),
                     Box::new(AstType::Argment(vec![])),
-                    Box::new(AstType::Statement(vec![AstType::Do(
-                        Box::new(AstType::Statement(vec![
-                            AstType::Factor(1),
-                            AstType::Variable(Type::Int, Structure::Identifier, "a".to_string()),
-                            AstType::Assign(
+                    Box::new(AstType::Statement(vec![
+                        AstType::Variable(Type::Int, Structure::Pointer, "a".to_string()),
+                        AstType::Assign(
+                            Box::new(AstType::Indirect(Box::new(AstType::Variable(
+                                Type::Int,
+                                Structure::Pointer,
+                                "a".to_string()
+                            )),)),
+                            Box::new(AstType::Indirect(Box::new(AstType::Plus(
                                 Box::new(AstType::Variable(
                                     Type::Int,
-                                    Structure::Identifier,
-                                    "b".to_string()
+                                    Structure::Pointer,
+                                    "a".to_string()
                                 )),
-                                Box::new(AstType::Factor(10))
-                            ),
-                            AstType::Continue(),
-                        ],)),
-                        Box::new(AstType::Equal(
-                            Box::new(AstType::Variable(
-                                Type::Int,
-                                Structure::Identifier,
-                                "a".to_string()
-                            )),
-                            Box::new(AstType::Factor(3))
-                        )),
-                    )]))
+                                Box::new(AstType::Factor(1))
+                            )),))
+                        ),
+                        AstType::Return(Box::new(AstType::Variable(
+                            Type::Int,
+                            Structure::Pointer,
+                            "a".to_string()
+                        )))
+                    ]))
                 )
             );
         }
@@ -4725,27 +8744,20 @@ mod tests {
                 create_token(Token::LeftParen, "(".to_string()),
                 create_token(Token::RightParen, ")".to_string()),
                 create_token(Token::LeftBrace, "{".to_string()),
-                create_token(Token::Do, "do".to_string()),
-                create_token(Token::LeftBrace, "{".to_string()),
-                create_token(Token::Int, "int".to_string()),
+                create_token(Token::IntPointer, "int*".to_string()),
                 create_token(Token::Variable, "a".to_string()),
                 create_token(Token::SemiColon, ";".to_string()),
-                create_token(Token::Number, "1".to_string()),
-                create_token(Token::SemiColon, ";".to_string()),
-                create_token(Token::Int, "int".to_string()),
-                create_token(Token::Variable, "b".to_string()),
+                create_token(Token::Multi, "*".to_string()),
+                create_token(Token::Variable, "a".to_string()),
                 create_token(Token::Assign, "=".to_string()),
-                create_token(Token::Number, "10".to_string()),
-                create_token(Token::SemiColon, ";".to_string()),
-                create_token(Token::Break, "break".to_string()),
+                create_token(Token::Multi, "*".to_string()),
+                create_token(Token::Variable, "a".to_string()),
+                create_token(Token::Plus, "+".to_string()),
+                create_token(Token::Number, "1".to_string()),
                 create_token(Token::SemiColon, ";".to_string()),
-                create_token(Token::RightBrace, "}".to_string()),
-                create_token(Token::While, "while".to_string()),
-                create_token(Token::LeftParen, "(".to_string()),
+                create_token(Token::Return, "return".to_string()),
                 create_token(Token::Variable, "a".to_string()),
-                create_token(Token::Equal, "==".to_string()),
-                create_token(Token::Number, "3".to_string()),
-                create_token(Token::RightParen, ")".to_string()),
+                create_token(Token::SemiColon, ";".to_string()),
                 create_token(Token::RightBrace, "}".to_string()),
                 create_token(Token::End, "End".to_string()),
             ];
@@ -4760,36 +8772,32 @@ mod tests {
                     Structure::Identifier,
                     "main".to_string(),
                     Box::new(AstType::Argment(vec![])),
-                    Box::new(AstType::Statement(vec![AstType::Do(
-                        Box::new(AstType::Statement(vec![
-                            AstType::Variable(Type::Int, Structure::Identifier, "a".to_string()),
-                            AstType::Factor(1),
-                            AstType::Assign(
-                                Box::new(AstType::Variable(
-                                    Type::Int,
-                                    Structure::Identifier,
-                                    "b".to_string()
-                                )),
-                                Box::new(AstType::Factor(10))
-                            ),
-                            AstType::Break(),
-                        ],)),
-                        Box::new(AstType::Equal(
-                            Box::new(AstType::Variable(
+                    Box::new(AstType::Statement(vec![
+                        AstType::Variable(Type::Int, Structure::Pointer, "a".to_string()),
+                        AstType::Assign(
+                            Box::new(AstType::Indirect(Box::new(AstType::Variable(
                                 Type::Int,
-                                Structure::Identifier,
+                                Structure::Pointer,
                                 "a".to_string()
+                            )),)),
+                            Box::new(AstType::Plus(
+                                Box::new(AstType::Indirect(Box::new(AstType::Variable(
+                                    Type::Int,
+                                    Structure::Pointer,
+                                    "a".to_string()
+                                )))),
+                                Box::new(AstType::Factor(1))
                             )),
-                            Box::new(AstType::Factor(3))
-                        )),
-                    )]))
+                        ),
+                        AstType::Return(Box::new(AstType::Variable(
+                            Type::Int,
+                            Structure::Pointer,
+                            "a".to_string()
+                        )))
+                    ]))
                 )
             );
         }
-    }
-
-    #[test]
-    fn test_statement_return() {
         {
             let data = vec![
                 create_token(Token::Int, "int".to_string()),
@@ -4797,10 +8805,19 @@ mod tests {
                 create_token(Token::LeftParen, "(".to_string()),
                 create_token(Token::RightParen, ")".to_string()),
                 create_token(Token::LeftBrace, "{".to_string()),
-                create_token(Token::Return, "return".to_string()),
+                create_token(Token::IntPointer, "int*".to_string()),
+                create_token(Token::Variable, "a".to_string()),
+                create_token(Token::SemiColon, ";".to_string()),
+                create_token(Token::Multi, "*".to_string()),
+                create_token(Token::Variable, "a".to_string()),
+                create_token(Token::Assign, "=".to_string()),
+                create_token(Token::Multi, "*".to_string()),
+                create_token(Token::Variable, "a".to_string()),
+                create_token(Token::Minus, "-".to_string()),
                 create_token(Token::Number, "1".to_string()),
-                create_token(Token::Equal, "==".to_string()),
-                create_token(Token::Number, "2".to_string()),
+                create_token(Token::SemiColon, ";".to_string()),
+                create_token(Token::Return, "return".to_string()),
+                create_token(Token::Variable, "a".to_string()),
                 create_token(Token::SemiColon, ";".to_string()),
                 create_token(Token::RightBrace, "}".to_string()),
                 create_token(Token::End, "End".to_string()),
@@ -4816,9 +8833,29 @@ mod tests {
                     Structure::Identifier,
                     "main".to_string(),
                     Box::new(AstType::Argment(vec![])),
-                    Box::new(AstType::Statement(vec![AstType::Return(Box::new(
-                        AstType::Equal(Box::new(AstType::Factor(1)), Box::new(AstType::Factor(2)))
-                    ))]))
+                    Box::new(AstType::Statement(vec![
+                        AstType::Variable(Type::Int, Structure::Pointer, "a".to_string()),
+                        AstType::Assign(
+                            Box::new(AstType::Indirect(Box::new(AstType::Variable(
+                                Type::Int,
+                                Structure::Pointer,
+                                "a".to_string()
+                            )),)),
+                            Box::new(AstType::Minus(
+                                Box::new(AstType::Indirect(Box::new(AstType::Variable(
+                                    Type::Int,
+                                    Structure::Pointer,
+                                    "a".to_string()
+                                )))),
+                                Box::new(AstType::Factor(1))
+                            )),
+                        ),
+                        AstType::Return(Box::new(AstType::Variable(
+                            Type::Int,
+                            Structure::Pointer,
+                            "a".to_string()
+                        )))
+                    ]))
                 )
             );
         }
@@ -4829,7 +8866,7 @@ mod tests {
                 create_token(Token::LeftParen, "(".to_string()),
                 create_token(Token::RightParen, ")".to_string()),
                 create_token(Token::LeftBrace, "{".to_string()),
-                create_token(Token::Int, "int".to_string()),
+                create_token(Token::CharPointer, "char*".to_string()),
                 create_token(Token::Variable, "a".to_string()),
                 create_token(Token::SemiColon, ";".to_string()),
                 create_token(Token::Return, "return".to_string()),
@@ -4850,10 +8887,10 @@ mod tests {
                     "main".to_string(),
                     Box::new(AstType::Argment(vec![])),
                     Box::new(AstType::Statement(vec![
-                        AstType::Variable(Type::Int, Structure::Identifier, "a".to_string()),
+                        AstType::Variable(Type::Char, Structure::Pointer, "a".to_string()),
                         AstType::Return(Box::new(AstType::Variable(
-                            Type::Int,
-                            Structure::Identifier,
+                            Type::Char,
+                            Structure::Pointer,
                             "a".to_string()
                         )))
                     ]))
@@ -4863,7 +8900,7 @@ mod tests {
     }
 
     #[test]
-    fn test_address_indirect() {
+    fn test_assign_indirect() {
         {
             let data = vec![
                 create_token(Token::Int, "int".to_string()),
@@ -4874,13 +8911,14 @@ mod tests {
                 create_token(Token::Int, "int".to_string()),
                 create_token(Token::Variable, "a".to_string()),
                 create_token(Token::SemiColon, ";".to_string()),
-                create_token(Token::Variable, "a".to_string()),
+                create_token(Token::IntPointer, "int*".to_string()),
+                create_token(Token::Variable, "b".to_string()),
                 create_token(Token::Assign, "=".to_string()),
                 create_token(Token::And, "&".to_string()),
                 create_token(Token::Variable, "a".to_string()),
                 create_token(Token::SemiColon, ";".to_string()),
                 create_token(Token::Return, "return".to_string()),
-                create_token(Token::Variable, "a".to_string()),
+                create_token(Token::Number, "1".to_string()),
                 create_token(Token::SemiColon, ";".to_string()),
                 create_token(Token::RightBrace, "}".to_string()),
                 create_token(Token::End, "End".to_string()),
@@ -4901,20 +8939,16 @@ mod tests {
                         AstType::Assign(
                             Box::new(AstType::Variable(
                                 Type::Int,
-                                Structure::Identifier,
-                                "a".to_string()
+                                Structure::Pointer,
+                                "b".to_string()
                             )),
                             Box::new(AstType::Address(Box::new(AstType::Variable(
                                 Type::Int,
                                 Structure::Identifier,
                                 "a".to_string()
-                            )))),
+                            )),))
                         ),
-                        AstType::Return(Box::new(AstType::Variable(
-                            Type::Int,
-                            Structure::Identifier,
-                            "a".to_string()
-                        )))
+                        AstType::Return(Box::new(AstType::Factor(1)),)
                     ]))
                 )
             );
@@ -4929,13 +8963,19 @@ mod tests {
                 create_token(Token::Int, "int".to_string()),
                 create_token(Token::Variable, "a".to_string()),
                 create_token(Token::SemiColon, ";".to_string()),
-                create_token(Token::Variable, "a".to_string()),
-                create_token(Token::Assign, "=".to_string()),
+                create_token(Token::IntPointer, "int*".to_string()),
+                create_token(Token::Variable, "b".to_string()),
+                create_token(Token::Assign, "=".to_string()),
+                create_token(Token::And, "&".to_string()),
+                create_token(Token::Variable, "a".to_string()),
+                create_token(Token::SemiColon, ";".to_string()),
                 create_token(Token::Multi, "*".to_string()),
-                create_token(Token::Variable, "a".to_string()),
+                create_token(Token::Variable, "b".to_string()),
+                create_token(Token::Assign, "=".to_string()),
+                create_token(Token::Number, "120".to_string()),
                 create_token(Token::SemiColon, ";".to_string()),
                 create_token(Token::Return, "return".to_string()),
-                create_token(Token::Variable, "a".to_string()),
+                create_token(Token::Number, "1".to_string()),
                 create_token(Token::SemiColon, ";".to_string()),
                 create_token(Token::RightBrace, "}".to_string()),
                 create_token(Token::End, "End".to_string()),
@@ -4956,20 +8996,24 @@ mod tests {
                         AstType::Assign(
                             Box::new(AstType::Variable(
                                 Type::Int,
-                                Structure::Identifier,
-                                "a".to_string()
+                                Structure::Pointer,
+                                "b".to_string()
                             )),
-                            Box::new(AstType::Indirect(Box::new(AstType::Variable(
+                            Box::new(AstType::Address(Box::new(AstType::Variable(
                                 Type::Int,
                                 Structure::Identifier,
                                 "a".to_string()
-                            )))),
+                            )),))
                         ),
-                        AstType::Return(Box::new(AstType::Variable(
-                            Type::Int,
-                            Structure::Identifier,
-                            "a".to_string()
-                        )))
+                        AstType::Assign(
+                            Box::new(AstType::Indirect(Box::new(AstType::Variable(
+                                Type::Int,
+                                Structure::Pointer,
+                                "b".to_string()
+                            )),)),
+                            Box::new(AstType::Factor(120)),
+                        ),
+                        AstType::Return(Box::new(AstType::Factor(1)),)
                     ]))
                 )
             );
@@ -4977,7 +9021,7 @@ mod tests {
     }
 
     #[test]
-    fn test_type_pointer() {
+    fn test_add_sub_indirect() {
         {
             let data = vec![
                 create_token(Token::Int, "int".to_string()),
@@ -4988,8 +9032,12 @@ mod tests {
                 create_token(Token::IntPointer, "int*".to_string()),
                 create_token(Token::Variable, "a".to_string()),
                 create_token(Token::SemiColon, ";".to_string()),
-                create_token(Token::Return, "return".to_string()),
                 create_token(Token::Variable, "a".to_string()),
+                create_token(Token::Plus, "+".to_string()),
+                create_token(Token::Number, "1".to_string()),
+                create_token(Token::SemiColon, ";".to_string()),
+                create_token(Token::Return, "return".to_string()),
+                create_token(Token::Number, "1".to_string()),
                 create_token(Token::SemiColon, ";".to_string()),
                 create_token(Token::RightBrace, "}".to_string()),
                 create_token(Token::End, "End".to_string()),
@@ -5007,11 +9055,15 @@ mod tests {
                     Box::new(AstType::Argment(vec![])),
                     Box::new(AstType::Statement(vec![
                         AstType::Variable(Type::Int, Structure::Pointer, "a".to_string()),
-                        AstType::Return(Box::new(AstType::Variable(
-                            Type::Int,
-                            Structure::Pointer,
-                            "a".to_string()
-                        )))
+                        AstType::Plus(
+                            Box::new(AstType::Variable(
+                                Type::Int,
+                                Structure::Pointer,
+                                "a".to_string()
+                            )),
+                            Box::new(AstType::Factor(1)),
+                        ),
+                        AstType::Return(Box::new(AstType::Factor(1)),)
                     ]))
                 )
             );
@@ -5026,8 +9078,12 @@ mod tests {
                 create_token(Token::IntPointer, "int*".to_string()),
                 create_token(Token::Variable, "a".to_string()),
                 create_token(Token::SemiColon, ";".to_string()),
-                create_token(Token::Return, "return".to_string()),
                 create_token(Token::Variable, "a".to_string()),
+                create_token(Token::Minus, "+".to_string()),
+                create_token(Token::Number, "1".to_string()),
+                create_token(Token::SemiColon, ";".to_string()),
+                create_token(Token::Return, "return".to_string()),
+                create_token(Token::Number, "1".to_string()),
                 create_token(Token::SemiColon, ";".to_string()),
                 create_token(Token::RightBrace, "}".to_string()),
                 create_token(Token::End, "End".to_string()),
@@ -5045,15 +9101,23 @@ mod tests {
                     Box::new(AstType::Argment(vec![])),
                     Box::new(AstType::Statement(vec![
                         AstType::Variable(Type::Int, Structure::Pointer, "a".to_string()),
-                        AstType::Return(Box::new(AstType::Variable(
-                            Type::Int,
-                            Structure::Pointer,
-                            "a".to_string()
-                        )))
+                        AstType::Minus(
+                            Box::new(AstType::Variable(
+                                Type::Int,
+                                Structure::Pointer,
+                                "a".to_string()
+                            )),
+                            Box::new(AstType::Factor(1)),
+                        ),
+                        AstType::Return(Box::new(AstType::Factor(1)),)
                     ]))
                 )
             );
         }
+    }
+
+    #[test]
+    fn test_array() {
         {
             let data = vec![
                 create_token(Token::Int, "int".to_string()),
@@ -5061,21 +9125,14 @@ mod tests {
                 create_token(Token::LeftParen, "(".to_string()),
                 create_token(Token::RightParen, ")".to_string()),
                 create_token(Token::LeftBrace, "{".to_string()),
-                create_token(Token::IntPointer, "int*".to_string()),
-                create_token(Token::Variable, "a".to_string()),
-                create_token(Token::SemiColon, ";".to_string()),
-                create_token(Token::Multi, "*".to_string()),
-                create_token(Token::Variable, "a".to_string()),
-                create_token(Token::Assign, "=".to_string()),
-                create_token(Token::Multi, "*".to_string()),
-                create_token(Token::LeftParen, "(".to_string()),
+                create_token(Token::Int, "int".to_string()),
                 create_token(Token::Variable, "a".to_string()),
-                create_token(Token::Plus, "+".to_string()),
-                create_token(Token::Number, "1".to_string()),
-                create_token(Token::RightParen, ")".to_string()),
+                create_token(Token::LeftBracket, " [".to_string()),
+                create_token(Token::Number, "3".to_string()),
+                create_token(Token::RightBracket, "]".to_string()),
                 create_token(Token::SemiColon, ";".to_string()),
                 create_token(Token::Return, "return".to_string()),
-                create_token(Token::Variable, "a".to_string()),
+                create_token(Token::Number, "1".to_string()),
                 create_token(Token::SemiColon, ";".to_string()),
                 create_token(Token::RightBrace, "}".to_string()),
                 create_token(Token::End, "End".to_string()),
@@ -5092,27 +9149,8 @@ mod tests {
                     "main".to_string(),
                     Box::new(AstType::Argment(vec![])),
                     Box::new(AstType::Statement(vec![
-                        AstType::Variable(Type::Int, Structure::Pointer, "a".to_string()),
-                        AstType::Assign(
-                            Box::new(AstType::Indirect(Box::new(AstType::Variable(
-                                Type::Int,
-                                Structure::Pointer,
-                                "a".to_string()
-                            )),)),
-                            Box::new(AstType::Indirect(Box::new(AstType::Plus(
-                                Box::new(AstType::Variable(
-                                    Type::Int,
-                                    Structure::Pointer,
-                                    "a".to_string()
-                                )),
-                                Box::new(AstType::Factor(1))
-                            )),))
-                        ),
-                        AstType::Return(Box::new(AstType::Variable(
-                            Type::Int,
-                            Structure::Pointer,
-                            "a".to_string()
-                        )))
+                        AstType::Variable(Type::Int, Structure::Array(vec![3]), "a".to_string()),
+                        AstType::Return(Box::new(AstType::Factor(1)),)
                     ]))
                 )
             );
@@ -5124,19 +9162,21 @@ mod tests {
                 create_token(Token::LeftParen, "(".to_string()),
                 create_token(Token::RightParen, ")".to_string()),
                 create_token(Token::LeftBrace, "{".to_string()),
-                create_token(Token::IntPointer, "int*".to_string()),
+                create_token(Token::Int, "int".to_string()),
                 create_token(Token::Variable, "a".to_string()),
+                create_token(Token::LeftBracket, " [".to_string()),
+                create_token(Token::Number, "3".to_string()),
+                create_token(Token::RightBracket, "]".to_string()),
                 create_token(Token::SemiColon, ";".to_string()),
-                create_token(Token::Multi, "*".to_string()),
                 create_token(Token::Variable, "a".to_string()),
+                create_token(Token::LeftBracket, " [".to_string()),
+                create_token(Token::Number, "0".to_string()),
+                create_token(Token::RightBracket, "]".to_string()),
                 create_token(Token::Assign, "=".to_string()),
-                create_token(Token::Multi, "*".to_string()),
-                create_token(Token::Variable, "a".to_string()),
-                create_token(Token::Plus, "+".to_string()),
-                create_token(Token::Number, "1".to_string()),
+                create_token(Token::Number, "10".to_string()),
                 create_token(Token::SemiColon, ";".to_string()),
                 create_token(Token::Return, "return".to_string()),
-                create_token(Token::Variable, "a".to_string()),
+                create_token(Token::Number, "1".to_string()),
                 create_token(Token::SemiColon, ";".to_string()),
                 create_token(Token::RightBrace, "}".to_string()),
                 create_token(Token::End, "End".to_string()),
@@ -5153,27 +9193,19 @@ mod tests {
                     "main".to_string(),
                     Box::new(AstType::Argment(vec![])),
                     Box::new(AstType::Statement(vec![
-                        AstType::Variable(Type::Int, Structure::Pointer, "a".to_string()),
+                        AstType::Variable(Type::Int, Structure::Array(vec![3]), "a".to_string()),
                         AstType::Assign(
-                            Box::new(AstType::Indirect(Box::new(AstType::Variable(
-                                Type::Int,
-                                Structure::Pointer,
-                                "a".to_string()
-                            )),)),
-                            Box::new(AstType::Plus(
-                                Box::new(AstType::Indirect(Box::new(AstType::Variable(
+                            Box::new(AstType::Indirect(Box::new(AstType::Plus(
+                                Box::new(AstType::Variable(
                                     Type::Int,
-                                    Structure::Pointer,
+                                    Structure::Array(vec![3]),
                                     "a".to_string()
-                                )))),
-                                Box::new(AstType::Factor(1))
-                            )),
+                                )),
+                                Box::new(AstType::Factor(0)),
+                            )),)),
+                            Box::new(AstType::Factor(10)),
                         ),
-                        AstType::Return(Box::new(AstType::Variable(
-                            Type::Int,
-                            Structure::Pointer,
-                            "a".to_string()
-                        )))
+                        AstType::Return(Box::new(AstType::Factor(1)),)
                     ]))
                 )
             );
@@ -5185,19 +9217,17 @@ mod tests {
                 create_token(Token::LeftParen, "(".to_string()),
                 create_token(Token::RightParen, ")".to_string()),
                 create_token(Token::LeftBrace, "{".to_string()),
-                create_token(Token::IntPointer, "int*".to_string()),
-                create_token(Token::Variable, "a".to_string()),
-                create_token(Token::SemiColon, ";".to_string()),
-                create_token(Token::Multi, "*".to_string()),
-                create_token(Token::Variable, "a".to_string()),
-                create_token(Token::Assign, "=".to_string()),
-                create_token(Token::Multi, "*".to_string()),
+                create_token(Token::Int, "int".to_string()),
                 create_token(Token::Variable, "a".to_string()),
-                create_token(Token::Minus, "-".to_string()),
-                create_token(Token::Number, "1".to_string()),
+                create_token(Token::LeftBracket, " [".to_string()),
+                create_token(Token::Number, "3".to_string()),
+                create_token(Token::RightBracket, "]".to_string()),
+                create_token(Token::LeftBracket, " [".to_string()),
+                create_token(Token::Number, "3".to_string()),
+                create_token(Token::RightBracket, "]".to_string()),
                 create_token(Token::SemiColon, ";".to_string()),
                 create_token(Token::Return, "return".to_string()),
-                create_token(Token::Variable, "a".to_string()),
+                create_token(Token::Number, "1".to_string()),
                 create_token(Token::SemiColon, ";".to_string()),
                 create_token(Token::RightBrace, "}".to_string()),
                 create_token(Token::End, "End".to_string()),
@@ -5214,31 +9244,16 @@ mod tests {
                     "main".to_string(),
                     Box::new(AstType::Argment(vec![])),
                     Box::new(AstType::Statement(vec![
-                        AstType::Variable(Type::Int, Structure::Pointer, "a".to_string()),
-                        AstType::Assign(
-                            Box::new(AstType::Indirect(Box::new(AstType::Variable(
-                                Type::Int,
-                                Structure::Pointer,
-                                "a".to_string()
-                            )),)),
-                            Box::new(AstType::Minus(
-                                Box::new(AstType::Indirect(Box::new(AstType::Variable(
-                                    Type::Int,
-                                    Structure::Pointer,
-                                    "a".to_string()
-                                )))),
-                                Box::new(AstType::Factor(1))
-                            )),
-                        ),
-                        AstType::Return(Box::new(AstType::Variable(
-                            Type::Int,
-                            Structure::Pointer,
-                            "a".to_string()
-                        )))
+                        AstType::Variable(Type::Int, Structure::Array(vec![3, 3]), "a".to_string()),
+                        AstType::Return(Box::new(AstType::Factor(1)),)
                     ]))
                 )
             );
         }
+    }
+
+    #[test]
+    fn test_variable() {
         {
             let data = vec![
                 create_token(Token::Int, "int".to_string()),
@@ -5246,11 +9261,13 @@ mod tests {
                 create_token(Token::LeftParen, "(".to_string()),
                 create_token(Token::RightParen, ")".to_string()),
                 create_token(Token::LeftBrace, "{".to_string()),
-                create_token(Token::CharPointer, "char*".to_string()),
+                create_token(Token::Int, "int".to_string()),
                 create_token(Token::Variable, "a".to_string()),
+                create_token(Token::Comma, ",".to_string()),
+                create_token(Token::Variable, "b".to_string()),
                 create_token(Token::SemiColon, ";".to_string()),
                 create_token(Token::Return, "return".to_string()),
-                create_token(Token::Variable, "a".to_string()),
+                create_token(Token::Number, "1".to_string()),
                 create_token(Token::SemiColon, ";".to_string()),
                 create_token(Token::RightBrace, "}".to_string()),
                 create_token(Token::End, "End".to_string()),
@@ -5267,36 +9284,23 @@ mod tests {
                     "main".to_string(),
                     Box::new(AstType::Argment(vec![])),
                     Box::new(AstType::Statement(vec![
-                        AstType::Variable(Type::Char, Structure::Pointer, "a".to_string()),
-                        AstType::Return(Box::new(AstType::Variable(
-                            Type::Char,
-                            Structure::Pointer,
-                            "a".to_string()
-                        )))
+                        AstType::Variable(Type::Int, Structure::Identifier, "a".to_string()),
+                        AstType::Variable(Type::Int, Structure::Identifier, "b".to_string()),
+                        AstType::Return(Box::new(AstType::Factor(1)),)
                     ]))
                 )
             );
         }
-    }
-
-    #[test]
-    fn test_assign_indirect() {
         {
             let data = vec![
+                create_token(Token::Int, "int".to_string()),
+                create_token(Token::Variable, "a".to_string()),
+                create_token(Token::SemiColon, ";".to_string()),
                 create_token(Token::Int, "int".to_string()),
                 create_token(Token::Variable, "main".to_string()),
                 create_token(Token::LeftParen, "(".to_string()),
                 create_token(Token::RightParen, ")".to_string()),
                 create_token(Token::LeftBrace, "{".to_string()),
-                create_token(Token::Int, "int".to_string()),
-                create_token(Token::Variable, "a".to_string()),
-                create_token(Token::SemiColon, ";".to_string()),
-                create_token(Token::IntPointer, "int*".to_string()),
-                create_token(Token::Variable, "b".to_string()),
-                create_token(Token::Assign, "=".to_string()),
-                create_token(Token::And, "&".to_string()),
-                create_token(Token::Variable, "a".to_string()),
-                create_token(Token::SemiColon, ";".to_string()),
                 create_token(Token::Return, "return".to_string()),
                 create_token(Token::Number, "1".to_string()),
                 create_token(Token::SemiColon, ";".to_string()),
@@ -5309,51 +9313,37 @@ mod tests {
             // 期待値確認.
             assert_eq!(
                 result.get_tree()[0],
-                AstType::FuncDef(
+                AstType::Global(vec![AstType::Variable(
                     Type::Int,
                     Structure::Identifier,
-                    "main".to_string(),
-                    Box::new(AstType::Argment(vec![])),
-                    Box::new(AstType::Statement(vec![
-                        AstType::Variable(Type::Int, Structure::Identifier, "a".to_string()),
-                        AstType::Assign(
-                            Box::new(AstType::Variable(
-                                Type::Int,
-                                Structure::Pointer,
-                                "b".to_string()
-                            )),
-                            Box::new(AstType::Address(Box::new(AstType::Variable(
-                                Type::Int,
-                                Structure::Identifier,
-                                "a".to_string()
-                            )),))
-                        ),
-                        AstType::Return(Box::new(AstType::Factor(1)),)
-                    ]))
+                    "a".to_string()
+                ),]),
+            );
+            assert_eq!(
+                result.get_tree()[1],
+                AstType::FuncDef(
+                    Type::Int,
+                    Structure::Identifier,
+                    "main".to_string(),
+                    Box::new(AstType::Argment(vec![])),
+                    Box::new(AstType::Statement(vec![AstType::Return(Box::new(
+                        AstType::Factor(1)
+                    ),)]))
                 )
             );
         }
         {
             let data = vec![
+                create_token(Token::Int, "int".to_string()),
+                create_token(Token::Variable, "a".to_string()),
+                create_token(Token::Assign, "=".to_string()),
+                create_token(Token::Number, "100".to_string()),
+                create_token(Token::SemiColon, ";".to_string()),
                 create_token(Token::Int, "int".to_string()),
                 create_token(Token::Variable, "main".to_string()),
                 create_token(Token::LeftParen, "(".to_string()),
                 create_token(Token::RightParen, ")".to_string()),
                 create_token(Token::LeftBrace, "{".to_string()),
-                create_token(Token::Int, "int".to_string()),
-                create_token(Token::Variable, "a".to_string()),
-                create_token(Token::SemiColon, ";".to_string()),
-                create_token(Token::IntPointer, "int*".to_string()),
-                create_token(Token::Variable, "b".to_string()),
-                create_token(Token::Assign, "=".to_string()),
-                create_token(Token::And, "&".to_string()),
-                create_token(Token::Variable, "a".to_string()),
-                create_token(Token::SemiColon, ";".to_string()),
-                create_token(Token::Multi, "*".to_string()),
-                create_token(Token::Variable, "b".to_string()),
-                create_token(Token::Assign, "=".to_string()),
-                create_token(Token::Number, "120".to_string()),
-                create_token(Token::SemiColon, ";".to_string()),
                 create_token(Token::Return, "return".to_string()),
                 create_token(Token::Number, "1".to_string()),
                 create_token(Token::SemiColon, ";".to_string()),
@@ -5366,56 +9356,41 @@ mod tests {
             // 期待値確認.
             assert_eq!(
                 result.get_tree()[0],
+                AstType::Global(vec![AstType::Assign(
+                    Box::new(AstType::Variable(
+                        Type::Int,
+                        Structure::Identifier,
+                        "a".to_string()
+                    )),
+                    Box::new(AstType::Factor(100)),
+                )])
+            );
+            assert_eq!(
+                result.get_tree()[1],
                 AstType::FuncDef(
                     Type::Int,
                     Structure::Identifier,
                     "main".to_string(),
                     Box::new(AstType::Argment(vec![])),
-                    Box::new(AstType::Statement(vec![
-                        AstType::Variable(Type::Int, Structure::Identifier, "a".to_string()),
-                        AstType::Assign(
-                            Box::new(AstType::Variable(
-                                Type::Int,
-                                Structure::Pointer,
-                                "b".to_string()
-                            )),
-                            Box::new(AstType::Address(Box::new(AstType::Variable(
-                                Type::Int,
-                                Structure::Identifier,
-                                "a".to_string()
-                            )),))
-                        ),
-                        AstType::Assign(
-                            Box::new(AstType::Indirect(Box::new(AstType::Variable(
-                                Type::Int,
-                                Structure::Pointer,
-                                "b".to_string()
-                            )),)),
-                            Box::new(AstType::Factor(120)),
-                        ),
-                        AstType::Return(Box::new(AstType::Factor(1)),)
-                    ]))
+                    Box::new(AstType::Statement(vec![AstType::Return(Box::new(
+                        AstType::Factor(1)
+                    ),)]))
                 )
             );
         }
-    }
-
-    #[test]
-    fn test_add_sub_indirect() {
         {
             let data = vec![
+                create_token(Token::Int, "int".to_string()),
+                create_token(Token::Variable, "a".to_string()),
+                create_token(Token::LeftBracket, " [".to_string()),
+                create_token(Token::Number, "10".to_string()),
+                create_token(Token::RightBracket, "]".to_string()),
+                create_token(Token::SemiColon, ";".to_string()),
                 create_token(Token::Int, "int".to_string()),
                 create_token(Token::Variable, "main".to_string()),
                 create_token(Token::LeftParen, "(".to_string()),
                 create_token(Token::RightParen, ")".to_string()),
                 create_token(Token::LeftBrace, "{".to_string()),
-                create_token(Token::IntPointer, "int*".to_string()),
-                create_token(Token::Variable, "a".to_string()),
-                create_token(Token::SemiColon, ";".to_string()),
-                create_token(Token::Variable, "a".to_string()),
-                create_token(Token::Plus, "+".to_string()),
-                create_token(Token::Number, "1".to_string()),
-                create_token(Token::SemiColon, ";".to_string()),
                 create_token(Token::Return, "return".to_string()),
                 create_token(Token::Number, "1".to_string()),
                 create_token(Token::SemiColon, ";".to_string()),
@@ -5428,23 +9403,22 @@ mod tests {
             // 期待値確認.
             assert_eq!(
                 result.get_tree()[0],
+                AstType::Global(vec![AstType::Variable(
+                    Type::Int,
+                    Structure::Array(vec![10]),
+                    "a".to_string()
+                ),])
+            );
+            assert_eq!(
+                result.get_tree()[1],
                 AstType::FuncDef(
                     Type::Int,
                     Structure::Identifier,
                     "main".to_string(),
                     Box::new(AstType::Argment(vec![])),
-                    Box::new(AstType::Statement(vec![
-                        AstType::Variable(Type::Int, Structure::Pointer, "a".to_string()),
-                        AstType::Plus(
-                            Box::new(AstType::Variable(
-                                Type::Int,
-                                Structure::Pointer,
-                                "a".to_string()
-                            )),
-                            Box::new(AstType::Factor(1)),
-                        ),
-                        AstType::Return(Box::new(AstType::Factor(1)),)
-                    ]))
+                    Box::new(AstType::Statement(vec![AstType::Return(Box::new(
+                        AstType::Factor(1)
+                    ),)]))
                 )
             );
         }
@@ -5455,12 +9429,10 @@ mod tests {
                 create_token(Token::LeftParen, "(".to_string()),
                 create_token(Token::RightParen, ")".to_string()),
                 create_token(Token::LeftBrace, "{".to_string()),
-                create_token(Token::IntPointer, "int*".to_string()),
-                create_token(Token::Variable, "a".to_string()),
-                create_token(Token::SemiColon, ";".to_string()),
+                create_token(Token::Char, "char".to_string()),
                 create_token(Token::Variable, "a".to_string()),
-                create_token(Token::Minus, "+".to_string()),
-                create_token(Token::Number, "1".to_string()),
+                create_token(Token::Comma, ",".to_string()),
+                create_token(Token::Variable, "b".to_string()),
                 create_token(Token::SemiColon, ";".to_string()),
                 create_token(Token::Return, "return".to_string()),
                 create_token(Token::Number, "1".to_string()),
@@ -5480,24 +9452,13 @@ mod tests {
                     "main".to_string(),
                     Box::new(AstType::Argment(vec![])),
                     Box::new(AstType::Statement(vec![
-                        AstType::Variable(Type::Int, Structure::Pointer, "a".to_string()),
-                        AstType::Minus(
-                            Box::new(AstType::Variable(
-                                Type::Int,
-                                Structure::Pointer,
-                                "a".to_string()
-                            )),
-                            Box::new(AstType::Factor(1)),
-                        ),
+                        AstType::Variable(Type::Char, Structure::Identifier, "a".to_string()),
+                        AstType::Variable(Type::Char, Structure::Identifier, "b".to_string()),
                         AstType::Return(Box::new(AstType::Factor(1)),)
                     ]))
                 )
             );
         }
-    }
-
-    #[test]
-    fn test_array() {
         {
             let data = vec![
                 create_token(Token::Int, "int".to_string()),
@@ -5505,11 +9466,10 @@ mod tests {
                 create_token(Token::LeftParen, "(".to_string()),
                 create_token(Token::RightParen, ")".to_string()),
                 create_token(Token::LeftBrace, "{".to_string()),
-                create_token(Token::Int, "int".to_string()),
+                create_token(Token::IntPointer, "int*".to_string()),
                 create_token(Token::Variable, "a".to_string()),
-                create_token(Token::LeftBracket, " [".to_string()),
-                create_token(Token::Number, "3".to_string()),
-                create_token(Token::RightBracket, "]".to_string()),
+                create_token(Token::Comma, ",".to_string()),
+                create_token(Token::Variable, "b".to_string()),
                 create_token(Token::SemiColon, ";".to_string()),
                 create_token(Token::Return, "return".to_string()),
                 create_token(Token::Number, "1".to_string()),
@@ -5520,7 +9480,7 @@ mod tests {
             let mut ast = AstGen::new(&data);
             let result = ast.parse();
 
-            // 期待値確認.
+            // 期待値確認(*は宣言子ごとに付くため、bはint型).
             assert_eq!(
                 result.get_tree()[0],
                 AstType::FuncDef(
@@ -5529,7 +9489,8 @@ mod tests {
                     "main".to_string(),
                     Box::new(AstType::Argment(vec![])),
                     Box::new(AstType::Statement(vec![
-                        AstType::Variable(Type::Int, Structure::Array(vec![3]), "a".to_string()),
+                        AstType::Variable(Type::Int, Structure::Pointer, "a".to_string()),
+                        AstType::Variable(Type::Int, Structure::Identifier, "b".to_string()),
                         AstType::Return(Box::new(AstType::Factor(1)),)
                     ]))
                 )
@@ -5542,18 +9503,10 @@ mod tests {
                 create_token(Token::LeftParen, "(".to_string()),
                 create_token(Token::RightParen, ")".to_string()),
                 create_token(Token::LeftBrace, "{".to_string()),
-                create_token(Token::Int, "int".to_string()),
-                create_token(Token::Variable, "a".to_string()),
-                create_token(Token::LeftBracket, " [".to_string()),
-                create_token(Token::Number, "3".to_string()),
-                create_token(Token::RightBracket, "]".to_string()),
-                create_token(Token::SemiColon, ";".to_string()),
+                create_token(Token::CharPointer, "char*".to_string()),
                 create_token(Token::Variable, "a".to_string()),
-                create_token(Token::LeftBracket, " [".to_string()),
-                create_token(Token::Number, "0".to_string()),
-                create_token(Token::RightBracket, "]".to_string()),
-                create_token(Token::Assign, "=".to_string()),
-                create_token(Token::Number, "10".to_string()),
+                create_token(Token::Comma, ",".to_string()),
+                create_token(Token::Variable, "b".to_string()),
                 create_token(Token::SemiColon, ";".to_string()),
                 create_token(Token::Return, "return".to_string()),
                 create_token(Token::Number, "1".to_string()),
@@ -5564,7 +9517,7 @@ mod tests {
             let mut ast = AstGen::new(&data);
             let result = ast.parse();
 
-            // 期待値確認.
+            // 期待値確認(*は宣言子ごとに付くため、bはchar型).
             assert_eq!(
                 result.get_tree()[0],
                 AstType::FuncDef(
@@ -5573,18 +9526,8 @@ mod tests {
                     "main".to_string(),
                     Box::new(AstType::Argment(vec![])),
                     Box::new(AstType::Statement(vec![
-                        AstType::Variable(Type::Int, Structure::Array(vec![3]), "a".to_string()),
-                        AstType::Assign(
-                            Box::new(AstType::Indirect(Box::new(AstType::Plus(
-                                Box::new(AstType::Variable(
-                                    Type::Int,
-                                    Structure::Array(vec![3]),
-                                    "a".to_string()
-                                )),
-                                Box::new(AstType::Factor(0)),
-                            )),)),
-                            Box::new(AstType::Factor(10)),
-                        ),
+                        AstType::Variable(Type::Char, Structure::Pointer, "a".to_string()),
+                        AstType::Variable(Type::Char, Structure::Identifier, "b".to_string()),
                         AstType::Return(Box::new(AstType::Factor(1)),)
                     ]))
                 )
@@ -5592,20 +9535,17 @@ mod tests {
         }
         {
             let data = vec![
+                create_token(Token::Int, "int".to_string()),
+                create_token(Token::Variable, "a".to_string()),
+                create_token(Token::SemiColon, ";".to_string()),
+                create_token(Token::Char, "char".to_string()),
+                create_token(Token::Variable, "x".to_string()),
+                create_token(Token::SemiColon, ";".to_string()),
                 create_token(Token::Int, "int".to_string()),
                 create_token(Token::Variable, "main".to_string()),
                 create_token(Token::LeftParen, "(".to_string()),
                 create_token(Token::RightParen, ")".to_string()),
                 create_token(Token::LeftBrace, "{".to_string()),
-                create_token(Token::Int, "int".to_string()),
-                create_token(Token::Variable, "a".to_string()),
-                create_token(Token::LeftBracket, " [".to_string()),
-                create_token(Token::Number, "3".to_string()),
-                create_token(Token::RightBracket, "]".to_string()),
-                create_token(Token::LeftBracket, " [".to_string()),
-                create_token(Token::Number, "3".to_string()),
-                create_token(Token::RightBracket, "]".to_string()),
-                create_token(Token::SemiColon, ";".to_string()),
                 create_token(Token::Return, "return".to_string()),
                 create_token(Token::Number, "1".to_string()),
                 create_token(Token::SemiColon, ";".to_string()),
@@ -5618,22 +9558,34 @@ mod tests {
             // 期待値確認.
             assert_eq!(
                 result.get_tree()[0],
+                AstType::Global(vec![AstType::Variable(
+                    Type::Int,
+                    Structure::Identifier,
+                    "a".to_string()
+                ),
+                AstType::Variable(
+                    Type::Char,
+                    Structure::Identifier,
+                    "x".to_string()
+                ),])
+            );
+            assert_eq!(
+                result.get_tree()[1],
                 AstType::FuncDef(
                     Type::Int,
                     Structure::Identifier,
                     "main".to_string(),
                     Box::new(AstType::Argment(vec![])),
-                    Box::new(AstType::Statement(vec![
-                        AstType::Variable(Type::Int, Structure::Array(vec![3, 3]), "a".to_string()),
-                        AstType::Return(Box::new(AstType::Factor(1)),)
-                    ]))
+                    Box::new(AstType::Statement(vec![AstType::Return(Box::new(
+                        AstType::Factor(1)
+                    ),)]))
                 )
             );
         }
     }
 
     #[test]
-    fn test_variable() {
+    fn test_lvalue() {
         {
             let data = vec![
                 create_token(Token::Int, "int".to_string()),
@@ -5641,10 +9593,10 @@ mod tests {
                 create_token(Token::LeftParen, "(".to_string()),
                 create_token(Token::RightParen, ")".to_string()),
                 create_token(Token::LeftBrace, "{".to_string()),
-                create_token(Token::Int, "int".to_string()),
+                create_token(Token::IntPointer, "int*".to_string()),
                 create_token(Token::Variable, "a".to_string()),
-                create_token(Token::Comma, ",".to_string()),
-                create_token(Token::Variable, "b".to_string()),
+                create_token(Token::Assign, "=".to_string()),
+                create_token(Token::Number, "10".to_string()),
                 create_token(Token::SemiColon, ";".to_string()),
                 create_token(Token::Return, "return".to_string()),
                 create_token(Token::Number, "1".to_string()),
@@ -5664,8 +9616,14 @@ mod tests {
                     "main".to_string(),
                     Box::new(AstType::Argment(vec![])),
                     Box::new(AstType::Statement(vec![
-                        AstType::Variable(Type::Int, Structure::Identifier, "a".to_string()),
-                        AstType::Variable(Type::Int, Structure::Identifier, "b".to_string()),
+                        AstType::Assign(
+                            Box::new(AstType::Variable(
+                                Type::Int,
+                                Structure::Pointer,
+                                "a".to_string()
+                            )),
+                            Box::new(AstType::Factor(10)),
+                        ),
                         AstType::Return(Box::new(AstType::Factor(1)),)
                     ]))
                 )
@@ -5673,14 +9631,23 @@ mod tests {
         }
         {
             let data = vec![
-                create_token(Token::Int, "int".to_string()),
-                create_token(Token::Variable, "a".to_string()),
-                create_token(Token::SemiColon, ";".to_string()),
                 create_token(Token::Int, "int".to_string()),
                 create_token(Token::Variable, "main".to_string()),
                 create_token(Token::LeftParen, "(".to_string()),
                 create_token(Token::RightParen, ")".to_string()),
                 create_token(Token::LeftBrace, "{".to_string()),
+                create_token(Token::IntPointer, "int*".to_string()),
+                create_token(Token::Variable, "a".to_string()),
+                create_token(Token::SemiColon, ";".to_string()),
+                create_token(Token::Multi, "*".to_string()),
+                create_token(Token::LeftParen, "(".to_string()),
+                create_token(Token::Variable, "a".to_string()),
+                create_token(Token::Plus, "+".to_string()),
+                create_token(Token::Number, "2".to_string()),
+                create_token(Token::RightParen, ")".to_string()),
+                create_token(Token::Assign, "=".to_string()),
+                create_token(Token::Number, "10".to_string()),
+                create_token(Token::SemiColon, ";".to_string()),
                 create_token(Token::Return, "return".to_string()),
                 create_token(Token::Number, "1".to_string()),
                 create_token(Token::SemiColon, ";".to_string()),
@@ -5693,37 +9660,49 @@ mod tests {
             // 期待値確認.
             assert_eq!(
                 result.get_tree()[0],
-                AstType::Global(vec![AstType::Variable(
-                    Type::Int,
-                    Structure::Identifier,
-                    "a".to_string()
-                ),]),
-            );
-            assert_eq!(
-                result.get_tree()[1],
                 AstType::FuncDef(
                     Type::Int,
                     Structure::Identifier,
                     "main".to_string(),
                     Box::new(AstType::Argment(vec![])),
-                    Box::new(AstType::Statement(vec![AstType::Return(Box::new(
-                        AstType::Factor(1)
-                    ),)]))
+                    Box::new(AstType::Statement(vec![
+                        AstType::Variable(Type::Int, Structure::Pointer, "a".to_string()),
+                        AstType::Assign(
+                            Box::new(AstType::Indirect(Box::new(AstType::Plus(
+                                Box::new(AstType::Variable(
+                                    Type::Int,
+                                    Structure::Pointer,
+                                    "a".to_string()
+                                )),
+                                Box::new(AstType::Factor(2)),
+                            )),)),
+                            Box::new(AstType::Factor(10)),
+                        ),
+                        AstType::Return(Box::new(AstType::Factor(1)),)
+                    ]))
                 )
             );
         }
         {
             let data = vec![
-                create_token(Token::Int, "int".to_string()),
-                create_token(Token::Variable, "a".to_string()),
-                create_token(Token::Assign, "=".to_string()),
-                create_token(Token::Number, "100".to_string()),
-                create_token(Token::SemiColon, ";".to_string()),
                 create_token(Token::Int, "int".to_string()),
                 create_token(Token::Variable, "main".to_string()),
                 create_token(Token::LeftParen, "(".to_string()),
                 create_token(Token::RightParen, ")".to_string()),
                 create_token(Token::LeftBrace, "{".to_string()),
+                create_token(Token::Int, "int".to_string()),
+                create_token(Token::Variable, "a".to_string()),
+                create_token(Token::LeftBracket, "[".to_string()),
+                create_token(Token::Number, "10".to_string()),
+                create_token(Token::RightBracket, "]".to_string()),
+                create_token(Token::SemiColon, ";".to_string()),
+                create_token(Token::Variable, "a".to_string()),
+                create_token(Token::LeftBracket, "[".to_string()),
+                create_token(Token::Number, "2".to_string()),
+                create_token(Token::RightBracket, "]".to_string()),
+                create_token(Token::Assign, "=".to_string()),
+                create_token(Token::Number, "10".to_string()),
+                create_token(Token::SemiColon, ";".to_string()),
                 create_token(Token::Return, "return".to_string()),
                 create_token(Token::Number, "1".to_string()),
                 create_token(Token::SemiColon, ";".to_string()),
@@ -5736,41 +9715,55 @@ mod tests {
             // 期待値確認.
             assert_eq!(
                 result.get_tree()[0],
-                AstType::Global(vec![AstType::Assign(
-                    Box::new(AstType::Variable(
-                        Type::Int,
-                        Structure::Identifier,
-                        "a".to_string()
-                    )),
-                    Box::new(AstType::Factor(100)),
-                )])
-            );
-            assert_eq!(
-                result.get_tree()[1],
                 AstType::FuncDef(
                     Type::Int,
                     Structure::Identifier,
                     "main".to_string(),
                     Box::new(AstType::Argment(vec![])),
-                    Box::new(AstType::Statement(vec![AstType::Return(Box::new(
-                        AstType::Factor(1)
-                    ),)]))
+                    Box::new(AstType::Statement(vec![
+                        AstType::Variable(Type::Int, Structure::Array(vec![10]), "a".to_string()),
+                        AstType::Assign(
+                            Box::new(AstType::Indirect(Box::new(AstType::Plus(
+                                Box::new(AstType::Variable(
+                                    Type::Int,
+                                    Structure::Array(vec![10]),
+                                    "a".to_string()
+                                )),
+                                Box::new(AstType::Factor(2)),
+                            )),)),
+                            Box::new(AstType::Factor(10)),
+                        ),
+                        AstType::Return(Box::new(AstType::Factor(1)),)
+                    ]))
                 )
             );
         }
         {
             let data = vec![
-                create_token(Token::Int, "int".to_string()),
-                create_token(Token::Variable, "a".to_string()),
-                create_token(Token::LeftBracket, " [".to_string()),
-                create_token(Token::Number, "10".to_string()),
-                create_token(Token::RightBracket, "]".to_string()),
-                create_token(Token::SemiColon, ";".to_string()),
                 create_token(Token::Int, "int".to_string()),
                 create_token(Token::Variable, "main".to_string()),
                 create_token(Token::LeftParen, "(".to_string()),
                 create_token(Token::RightParen, ")".to_string()),
                 create_token(Token::LeftBrace, "{".to_string()),
+                create_token(Token::Int, "int".to_string()),
+                create_token(Token::Variable, "a".to_string()),
+                create_token(Token::LeftBracket, "[".to_string()),
+                create_token(Token::Number, "10".to_string()),
+                create_token(Token::RightBracket, "]".to_string()),
+                create_token(Token::LeftBracket, "[".to_string()),
+                create_token(Token::Number, "2".to_string()),
+                create_token(Token::RightBracket, "]".to_string()),
+                create_token(Token::SemiColon, ";".to_string()),
+                create_token(Token::Variable, "a".to_string()),
+                create_token(Token::LeftBracket, "[".to_string()),
+                create_token(Token::Number, "2".to_string()),
+                create_token(Token::RightBracket, "]".to_string()),
+                create_token(Token::LeftBracket, "[".to_string()),
+                create_token(Token::Number, "1".to_string()),
+                create_token(Token::RightBracket, "]".to_string()),
+                create_token(Token::Assign, "=".to_string()),
+                create_token(Token::Number, "10".to_string()),
+                create_token(Token::SemiColon, ";".to_string()),
                 create_token(Token::Return, "return".to_string()),
                 create_token(Token::Number, "1".to_string()),
                 create_token(Token::SemiColon, ";".to_string()),
@@ -5783,22 +9776,36 @@ mod tests {
             // 期待値確認.
             assert_eq!(
                 result.get_tree()[0],
-                AstType::Global(vec![AstType::Variable(
-                    Type::Int,
-                    Structure::Array(vec![10]),
-                    "a".to_string()
-                ),])
-            );
-            assert_eq!(
-                result.get_tree()[1],
                 AstType::FuncDef(
                     Type::Int,
                     Structure::Identifier,
                     "main".to_string(),
                     Box::new(AstType::Argment(vec![])),
-                    Box::new(AstType::Statement(vec![AstType::Return(Box::new(
-                        AstType::Factor(1)
-                    ),)]))
+                    Box::new(AstType::Statement(vec![
+                        AstType::Variable(
+                            Type::Int,
+                            Structure::Array(vec![10, 2]),
+                            "a".to_string()
+                        ),
+                        AstType::Assign(
+                            Box::new(AstType::Indirect(Box::new(AstType::Plus(
+                                Box::new(AstType::Variable(
+                                    Type::Int,
+                                    Structure::Array(vec![10, 2]),
+                                    "a".to_string()
+                                )),
+                                Box::new(AstType::Plus(
+                                    Box::new(AstType::Multiple(
+                                        Box::new(AstType::Factor(2)),
+                                        Box::new(AstType::Factor(2)),
+                                    )),
+                                    Box::new(AstType::Factor(1)),
+                                )),
+                            )),)),
+                            Box::new(AstType::Factor(10)),
+                        ),
+                        AstType::Return(Box::new(AstType::Factor(1)),)
+                    ]))
                 )
             );
         }
@@ -5809,10 +9816,30 @@ mod tests {
                 create_token(Token::LeftParen, "(".to_string()),
                 create_token(Token::RightParen, ")".to_string()),
                 create_token(Token::LeftBrace, "{".to_string()),
-                create_token(Token::Char, "char".to_string()),
+                create_token(Token::Int, "int".to_string()),
                 create_token(Token::Variable, "a".to_string()),
-                create_token(Token::Comma, ",".to_string()),
-                create_token(Token::Variable, "b".to_string()),
+                create_token(Token::LeftBracket, "[".to_string()),
+                create_token(Token::Number, "10".to_string()),
+                create_token(Token::RightBracket, "]".to_string()),
+                create_token(Token::LeftBracket, "[".to_string()),
+                create_token(Token::Number, "8".to_string()),
+                create_token(Token::RightBracket, "]".to_string()),
+                create_token(Token::LeftBracket, "[".to_string()),
+                create_token(Token::Number, "2".to_string()),
+                create_token(Token::RightBracket, "]".to_string()),
+                create_token(Token::SemiColon, ";".to_string()),
+                create_token(Token::Variable, "a".to_string()),
+                create_token(Token::LeftBracket, "[".to_string()),
+                create_token(Token::Number, "2".to_string()),
+                create_token(Token::RightBracket, "]".to_string()),
+                create_token(Token::LeftBracket, "[".to_string()),
+                create_token(Token::Number, "4".to_string()),
+                create_token(Token::RightBracket, "]".to_string()),
+                create_token(Token::LeftBracket, "[".to_string()),
+                create_token(Token::Number, "1".to_string()),
+                create_token(Token::RightBracket, "]".to_string()),
+                create_token(Token::Assign, "=".to_string()),
+                create_token(Token::Number, "10".to_string()),
                 create_token(Token::SemiColon, ";".to_string()),
                 create_token(Token::Return, "return".to_string()),
                 create_token(Token::Number, "1".to_string()),
@@ -5832,13 +9859,43 @@ mod tests {
                     "main".to_string(),
                     Box::new(AstType::Argment(vec![])),
                     Box::new(AstType::Statement(vec![
-                        AstType::Variable(Type::Char, Structure::Identifier, "a".to_string()),
-                        AstType::Variable(Type::Char, Structure::Identifier, "b".to_string()),
+                        AstType::Variable(
+                            Type::Int,
+                            Structure::Array(vec![10, 8, 2]),
+                            "a".to_string()
+                        ),
+                        AstType::Assign(
+                            Box::new(AstType::Indirect(Box::new(AstType::Plus(
+                                Box::new(AstType::Variable(
+                                    Type::Int,
+                                    Structure::Array(vec![10, 8, 2]),
+                                    "a".to_string()
+                                )),
+                                Box::new(AstType::Plus(
+                                    Box::new(AstType::Multiple(
+                                        Box::new(AstType::Factor(2)),
+                                        Box::new(AstType::Factor(8)),
+                                    )),
+                                    Box::new(AstType::Plus(
+                                        Box::new(AstType::Multiple(
+                                            Box::new(AstType::Factor(4)),
+                                            Box::new(AstType::Factor(2)),
+                                        )),
+                                        Box::new(AstType::Factor(1)),
+                                    ))
+                                )),
+                            )),)),
+                            Box::new(AstType::Factor(10)),
+                        ),
                         AstType::Return(Box::new(AstType::Factor(1)),)
                     ]))
                 )
             );
         }
+    }
+
+    #[test]
+    fn test_post_inc_dec() {
         {
             let data = vec![
                 create_token(Token::Int, "int".to_string()),
@@ -5846,10 +9903,11 @@ mod tests {
                 create_token(Token::LeftParen, "(".to_string()),
                 create_token(Token::RightParen, ")".to_string()),
                 create_token(Token::LeftBrace, "{".to_string()),
-                create_token(Token::IntPointer, "int*".to_string()),
+                create_token(Token::Int, "int".to_string()),
                 create_token(Token::Variable, "a".to_string()),
-                create_token(Token::Comma, ",".to_string()),
-                create_token(Token::Variable, "b".to_string()),
+                create_token(Token::SemiColon, ";".to_string()),
+                create_token(Token::Variable, "a".to_string()),
+                create_token(Token::Inc, "++".to_string()),
                 create_token(Token::SemiColon, ";".to_string()),
                 create_token(Token::Return, "return".to_string()),
                 create_token(Token::Number, "1".to_string()),
@@ -5869,8 +9927,12 @@ mod tests {
                     "main".to_string(),
                     Box::new(AstType::Argment(vec![])),
                     Box::new(AstType::Statement(vec![
-                        AstType::Variable(Type::Int, Structure::Pointer, "a".to_string()),
-                        AstType::Variable(Type::Int, Structure::Pointer, "b".to_string()),
+                        AstType::Variable(Type::Int, Structure::Identifier, "a".to_string()),
+                        AstType::PostInc(Box::new(AstType::Variable(
+                            Type::Int,
+                            Structure::Identifier,
+                            "a".to_string()
+                        )),),
                         AstType::Return(Box::new(AstType::Factor(1)),)
                     ]))
                 )
@@ -5883,10 +9945,11 @@ mod tests {
                 create_token(Token::LeftParen, "(".to_string()),
                 create_token(Token::RightParen, ")".to_string()),
                 create_token(Token::LeftBrace, "{".to_string()),
-                create_token(Token::CharPointer, "char*".to_string()),
+                create_token(Token::Int, "int".to_string()),
                 create_token(Token::Variable, "a".to_string()),
-                create_token(Token::Comma, ",".to_string()),
-                create_token(Token::Variable, "b".to_string()),
+                create_token(Token::SemiColon, ";".to_string()),
+                create_token(Token::Variable, "a".to_string()),
+                create_token(Token::Dec, "--".to_string()),
                 create_token(Token::SemiColon, ";".to_string()),
                 create_token(Token::Return, "return".to_string()),
                 create_token(Token::Number, "1".to_string()),
@@ -5906,26 +9969,34 @@ mod tests {
                     "main".to_string(),
                     Box::new(AstType::Argment(vec![])),
                     Box::new(AstType::Statement(vec![
-                        AstType::Variable(Type::Char, Structure::Pointer, "a".to_string()),
-                        AstType::Variable(Type::Char, Structure::Pointer, "b".to_string()),
+                        AstType::Variable(Type::Int, Structure::Identifier, "a".to_string()),
+                        AstType::PostDec(Box::new(AstType::Variable(
+                            Type::Int,
+                            Structure::Identifier,
+                            "a".to_string()
+                        )),),
                         AstType::Return(Box::new(AstType::Factor(1)),)
                     ]))
                 )
             );
         }
+    }
+
+    #[test]
+    fn test_pre_inc_dec() {
         {
             let data = vec![
-                create_token(Token::Int, "int".to_string()),
-                create_token(Token::Variable, "a".to_string()),
-                create_token(Token::SemiColon, ";".to_string()),
-                create_token(Token::Char, "char".to_string()),
-                create_token(Token::Variable, "x".to_string()),
-                create_token(Token::SemiColon, ";".to_string()),
                 create_token(Token::Int, "int".to_string()),
                 create_token(Token::Variable, "main".to_string()),
                 create_token(Token::LeftParen, "(".to_string()),
                 create_token(Token::RightParen, ")".to_string()),
                 create_token(Token::LeftBrace, "{".to_string()),
+                create_token(Token::Int, "int".to_string()),
+                create_token(Token::Variable, "a".to_string()),
+                create_token(Token::SemiColon, ";".to_string()),
+                create_token(Token::Inc, "++".to_string()),
+                create_token(Token::Variable, "a".to_string()),
+                create_token(Token::SemiColon, ";".to_string()),
                 create_token(Token::Return, "return".to_string()),
                 create_token(Token::Number, "1".to_string()),
                 create_token(Token::SemiColon, ";".to_string()),
@@ -5938,34 +10009,23 @@ mod tests {
             // 期待値確認.
             assert_eq!(
                 result.get_tree()[0],
-                AstType::Global(vec![AstType::Variable(
-                    Type::Int,
-                    Structure::Identifier,
-                    "a".to_string()
-                ),
-                AstType::Variable(
-                    Type::Char,
-                    Structure::Identifier,
-                    "x".to_string()
-                ),])
-            );
-            assert_eq!(
-                result.get_tree()[1],
                 AstType::FuncDef(
                     Type::Int,
                     Structure::Identifier,
                     "main".to_string(),
                     Box::new(AstType::Argment(vec![])),
-                    Box::new(AstType::Statement(vec![AstType::Return(Box::new(
-                        AstType::Factor(1)
-                    ),)]))
+                    Box::new(AstType::Statement(vec![
+                        AstType::Variable(Type::Int, Structure::Identifier, "a".to_string()),
+                        AstType::PreInc(Box::new(AstType::Variable(
+                            Type::Int,
+                            Structure::Identifier,
+                            "a".to_string()
+                        )),),
+                        AstType::Return(Box::new(AstType::Factor(1)),)
+                    ]))
                 )
             );
         }
-    }
-
-    #[test]
-    fn test_lvalue() {
         {
             let data = vec![
                 create_token(Token::Int, "int".to_string()),
@@ -5973,10 +10033,11 @@ mod tests {
                 create_token(Token::LeftParen, "(".to_string()),
                 create_token(Token::RightParen, ")".to_string()),
                 create_token(Token::LeftBrace, "{".to_string()),
-                create_token(Token::IntPointer, "int*".to_string()),
+                create_token(Token::Int, "int".to_string()),
+                create_token(Token::Variable, "a".to_string()),
+                create_token(Token::SemiColon, ";".to_string()),
+                create_token(Token::Dec, "--".to_string()),
                 create_token(Token::Variable, "a".to_string()),
-                create_token(Token::Assign, "=".to_string()),
-                create_token(Token::Number, "10".to_string()),
                 create_token(Token::SemiColon, ";".to_string()),
                 create_token(Token::Return, "return".to_string()),
                 create_token(Token::Number, "1".to_string()),
@@ -5996,37 +10057,30 @@ mod tests {
                     "main".to_string(),
                     Box::new(AstType::Argment(vec![])),
                     Box::new(AstType::Statement(vec![
-                        AstType::Assign(
-                            Box::new(AstType::Variable(
-                                Type::Int,
-                                Structure::Pointer,
-                                "a".to_string()
-                            )),
-                            Box::new(AstType::Factor(10)),
-                        ),
+                        AstType::Variable(Type::Int, Structure::Identifier, "a".to_string()),
+                        AstType::PreDec(Box::new(AstType::Variable(
+                            Type::Int,
+                            Structure::Identifier,
+                            "a".to_string()
+                        )),),
                         AstType::Return(Box::new(AstType::Factor(1)),)
                     ]))
                 )
             );
         }
+    }
+
+    #[test]
+    fn test_variable_char() {
         {
             let data = vec![
-                create_token(Token::Int, "int".to_string()),
+                create_token(Token::Char, "char".to_string()),
                 create_token(Token::Variable, "main".to_string()),
                 create_token(Token::LeftParen, "(".to_string()),
                 create_token(Token::RightParen, ")".to_string()),
                 create_token(Token::LeftBrace, "{".to_string()),
-                create_token(Token::IntPointer, "int*".to_string()),
-                create_token(Token::Variable, "a".to_string()),
-                create_token(Token::SemiColon, ";".to_string()),
-                create_token(Token::Multi, "*".to_string()),
-                create_token(Token::LeftParen, "(".to_string()),
+                create_token(Token::Char, "char".to_string()),
                 create_token(Token::Variable, "a".to_string()),
-                create_token(Token::Plus, "+".to_string()),
-                create_token(Token::Number, "2".to_string()),
-                create_token(Token::RightParen, ")".to_string()),
-                create_token(Token::Assign, "=".to_string()),
-                create_token(Token::Number, "10".to_string()),
                 create_token(Token::SemiColon, ";".to_string()),
                 create_token(Token::Return, "return".to_string()),
                 create_token(Token::Number, "1".to_string()),
@@ -6041,23 +10095,12 @@ mod tests {
             assert_eq!(
                 result.get_tree()[0],
                 AstType::FuncDef(
-                    Type::Int,
+                    Type::Char,
                     Structure::Identifier,
                     "main".to_string(),
                     Box::new(AstType::Argment(vec![])),
                     Box::new(AstType::Statement(vec![
-                        AstType::Variable(Type::Int, Structure::Pointer, "a".to_string()),
-                        AstType::Assign(
-                            Box::new(AstType::Indirect(Box::new(AstType::Plus(
-                                Box::new(AstType::Variable(
-                                    Type::Int,
-                                    Structure::Pointer,
-                                    "a".to_string()
-                                )),
-                                Box::new(AstType::Factor(2)),
-                            )),)),
-                            Box::new(AstType::Factor(10)),
-                        ),
+                        AstType::Variable(Type::Char, Structure::Identifier, "a".to_string()),
                         AstType::Return(Box::new(AstType::Factor(1)),)
                     ]))
                 )
@@ -6070,18 +10113,11 @@ mod tests {
                 create_token(Token::LeftParen, "(".to_string()),
                 create_token(Token::RightParen, ")".to_string()),
                 create_token(Token::LeftBrace, "{".to_string()),
-                create_token(Token::Int, "int".to_string()),
-                create_token(Token::Variable, "a".to_string()),
-                create_token(Token::LeftBracket, "[".to_string()),
-                create_token(Token::Number, "10".to_string()),
-                create_token(Token::RightBracket, "]".to_string()),
-                create_token(Token::SemiColon, ";".to_string()),
+                create_token(Token::Char, "char".to_string()),
                 create_token(Token::Variable, "a".to_string()),
-                create_token(Token::LeftBracket, "[".to_string()),
-                create_token(Token::Number, "2".to_string()),
+                create_token(Token::LeftBracket, " [".to_string()),
+                create_token(Token::Number, "3".to_string()),
                 create_token(Token::RightBracket, "]".to_string()),
-                create_token(Token::Assign, "=".to_string()),
-                create_token(Token::Number, "10".to_string()),
                 create_token(Token::SemiColon, ";".to_string()),
                 create_token(Token::Return, "return".to_string()),
                 create_token(Token::Number, "1".to_string()),
@@ -6101,23 +10137,16 @@ mod tests {
                     "main".to_string(),
                     Box::new(AstType::Argment(vec![])),
                     Box::new(AstType::Statement(vec![
-                        AstType::Variable(Type::Int, Structure::Array(vec![10]), "a".to_string()),
-                        AstType::Assign(
-                            Box::new(AstType::Indirect(Box::new(AstType::Plus(
-                                Box::new(AstType::Variable(
-                                    Type::Int,
-                                    Structure::Array(vec![10]),
-                                    "a".to_string()
-                                )),
-                                Box::new(AstType::Factor(2)),
-                            )),)),
-                            Box::new(AstType::Factor(10)),
-                        ),
+                        AstType::Variable(Type::Char, Structure::Array(vec![3]), "a".to_string()),
                         AstType::Return(Box::new(AstType::Factor(1)),)
                     ]))
                 )
             );
         }
+    }
+
+    #[test]
+    fn test_string_literal() {
         {
             let data = vec![
                 create_token(Token::Int, "int".to_string()),
@@ -6125,25 +10154,12 @@ mod tests {
                 create_token(Token::LeftParen, "(".to_string()),
                 create_token(Token::RightParen, ")".to_string()),
                 create_token(Token::LeftBrace, "{".to_string()),
-                create_token(Token::Int, "int".to_string()),
+                create_token(Token::CharPointer, "char*".to_string()),
                 create_token(Token::Variable, "a".to_string()),
-                create_token(Token::LeftBracket, "[".to_string()),
-                create_token(Token::Number, "10".to_string()),
-                create_token(Token::RightBracket, "]".to_string()),
-                create_token(Token::LeftBracket, "[".to_string()),
-                create_token(Token::Number, "2".to_string()),
-                create_token(Token::RightBracket, "]".to_string()),
                 create_token(Token::SemiColon, ";".to_string()),
                 create_token(Token::Variable, "a".to_string()),
-                create_token(Token::LeftBracket, "[".to_string()),
-                create_token(Token::Number, "2".to_string()),
-                create_token(Token::RightBracket, "]".to_string()),
-                create_token(Token::LeftBracket, "[".to_string()),
-                create_token(Token::Number, "1".to_string()),
-                create_token(Token::RightBracket, "]".to_string()),
                 create_token(Token::Assign, "=".to_string()),
-                create_token(Token::Number, "10".to_string()),
-                create_token(Token::SemiColon, ";".to_string()),
+                create_token(Token::StringLiteral, "testaaaa".to_string()),
                 create_token(Token::Return, "return".to_string()),
                 create_token(Token::Number, "1".to_string()),
                 create_token(Token::SemiColon, ";".to_string()),
@@ -6162,27 +10178,14 @@ mod tests {
                     "main".to_string(),
                     Box::new(AstType::Argment(vec![])),
                     Box::new(AstType::Statement(vec![
-                        AstType::Variable(
-                            Type::Int,
-                            Structure::Array(vec![10, 2]),
-                            "a".to_string()
-                        ),
+                        AstType::Variable(Type::Char, Structure::Pointer, "a".to_string()),
                         AstType::Assign(
-                            Box::new(AstType::Indirect(Box::new(AstType::Plus(
-                                Box::new(AstType::Variable(
-                                    Type::Int,
-                                    Structure::Array(vec![10, 2]),
-                                    "a".to_string()
-                                )),
-                                Box::new(AstType::Plus(
-                                    Box::new(AstType::Multiple(
-                                        Box::new(AstType::Factor(2)),
-                                        Box::new(AstType::Factor(2)),
-                                    )),
-                                    Box::new(AstType::Factor(1)),
-                                )),
-                            )),)),
-                            Box::new(AstType::Factor(10)),
+                            Box::new(AstType::Variable(
+                                Type::Char,
+                                Structure::Pointer,
+                                "a".to_string()
+                            )),
+                            Box::new(AstType::StringLiteral("testaaaa".to_string(), 0))
                         ),
                         AstType::Return(Box::new(AstType::Factor(1)),)
                     ]))
@@ -6196,30 +10199,12 @@ mod tests {
                 create_token(Token::LeftParen, "(".to_string()),
                 create_token(Token::RightParen, ")".to_string()),
                 create_token(Token::LeftBrace, "{".to_string()),
-                create_token(Token::Int, "int".to_string()),
+                create_token(Token::CharPointer, "char*".to_string()),
                 create_token(Token::Variable, "a".to_string()),
-                create_token(Token::LeftBracket, "[".to_string()),
-                create_token(Token::Number, "10".to_string()),
-                create_token(Token::RightBracket, "]".to_string()),
-                create_token(Token::LeftBracket, "[".to_string()),
-                create_token(Token::Number, "8".to_string()),
-                create_token(Token::RightBracket, "]".to_string()),
-                create_token(Token::LeftBracket, "[".to_string()),
-                create_token(Token::Number, "2".to_string()),
-                create_token(Token::RightBracket, "]".to_string()),
                 create_token(Token::SemiColon, ";".to_string()),
                 create_token(Token::Variable, "a".to_string()),
-                create_token(Token::LeftBracket, "[".to_string()),
-                create_token(Token::Number, "2".to_string()),
-                create_token(Token::RightBracket, "]".to_string()),
-                create_token(Token::LeftBracket, "[".to_string()),
-                create_token(Token::Number, "4".to_string()),
-                create_token(Token::RightBracket, "]".to_string()),
-                create_token(Token::LeftBracket, "[".to_string()),
-                create_token(Token::Number, "1".to_string()),
-                create_token(Token::RightBracket, "]".to_string()),
                 create_token(Token::Assign, "=".to_string()),
-                create_token(Token::Number, "10".to_string()),
+                create_token(Token::StringLiteral, "test, aaaa".to_string()),
                 create_token(Token::SemiColon, ";".to_string()),
                 create_token(Token::Return, "return".to_string()),
                 create_token(Token::Number, "1".to_string()),
@@ -6239,43 +10224,20 @@ mod tests {
                     "main".to_string(),
                     Box::new(AstType::Argment(vec![])),
                     Box::new(AstType::Statement(vec![
-                        AstType::Variable(
-                            Type::Int,
-                            Structure::Array(vec![10, 8, 2]),
-                            "a".to_string()
-                        ),
+                        AstType::Variable(Type::Char, Structure::Pointer, "a".to_string()),
                         AstType::Assign(
-                            Box::new(AstType::Indirect(Box::new(AstType::Plus(
-                                Box::new(AstType::Variable(
-                                    Type::Int,
-                                    Structure::Array(vec![10, 8, 2]),
-                                    "a".to_string()
-                                )),
-                                Box::new(AstType::Plus(
-                                    Box::new(AstType::Multiple(
-                                        Box::new(AstType::Factor(2)),
-                                        Box::new(AstType::Factor(8)),
-                                    )),
-                                    Box::new(AstType::Plus(
-                                        Box::new(AstType::Multiple(
-                                            Box::new(AstType::Factor(4)),
-                                            Box::new(AstType::Factor(2)),
-                                        )),
-                                        Box::new(AstType::Factor(1)),
-                                    ))
-                                )),
-                            )),)),
-                            Box::new(AstType::Factor(10)),
+                            Box::new(AstType::Variable(
+                                Type::Char,
+                                Structure::Pointer,
+                                "a".to_string()
+                            )),
+                            Box::new(AstType::StringLiteral("test, aaaa".to_string(), 0))
                         ),
                         AstType::Return(Box::new(AstType::Factor(1)),)
                     ]))
                 )
             );
         }
-    }
-
-    #[test]
-    fn test_post_inc_dec() {
         {
             let data = vec![
                 create_token(Token::Int, "int".to_string()),
@@ -6283,11 +10245,19 @@ mod tests {
                 create_token(Token::LeftParen, "(".to_string()),
                 create_token(Token::RightParen, ")".to_string()),
                 create_token(Token::LeftBrace, "{".to_string()),
-                create_token(Token::Int, "int".to_string()),
+                create_token(Token::CharPointer, "char*".to_string()),
                 create_token(Token::Variable, "a".to_string()),
                 create_token(Token::SemiColon, ";".to_string()),
                 create_token(Token::Variable, "a".to_string()),
-                create_token(Token::Inc, "++".to_string()),
+                create_token(Token::Assign, "=".to_string()),
+                create_token(Token::StringLiteral, "test, aaaa".to_string()),
+                create_token(Token::SemiColon, ";".to_string()),
+                create_token(Token::CharPointer, "char*".to_string()),
+                create_token(Token::Variable, "b".to_string()),
+                create_token(Token::SemiColon, ";".to_string()),
+                create_token(Token::Variable, "b".to_string()),
+                create_token(Token::Assign, "=".to_string()),
+                create_token(Token::StringLiteral, "test, bbbb".to_string()),
                 create_token(Token::SemiColon, ";".to_string()),
                 create_token(Token::Return, "return".to_string()),
                 create_token(Token::Number, "1".to_string()),
@@ -6307,38 +10277,58 @@ mod tests {
                     "main".to_string(),
                     Box::new(AstType::Argment(vec![])),
                     Box::new(AstType::Statement(vec![
-                        AstType::Variable(Type::Int, Structure::Identifier, "a".to_string()),
-                        AstType::PostInc(Box::new(AstType::Variable(
-                            Type::Int,
-                            Structure::Identifier,
-                            "a".to_string()
-                        )),),
+                        AstType::Variable(Type::Char, Structure::Pointer, "a".to_string()),
+                        AstType::Assign(
+                            Box::new(AstType::Variable(
+                                Type::Char,
+                                Structure::Pointer,
+                                "a".to_string()
+                            )),
+                            Box::new(AstType::StringLiteral("test, aaaa".to_string(), 0))
+                        ),
+                        AstType::Variable(Type::Char, Structure::Pointer, "b".to_string()),
+                        AstType::Assign(
+                            Box::new(AstType::Variable(
+                                Type::Char,
+                                Structure::Pointer,
+                                "b".to_string()
+                            )),
+                            Box::new(AstType::StringLiteral("test, bbbb".to_string(), 1))
+                        ),
                         AstType::Return(Box::new(AstType::Factor(1)),)
                     ]))
                 )
             );
         }
         {
+            // 隣接する文字列リテラルは連結される
             let data = vec![
                 create_token(Token::Int, "int".to_string()),
                 create_token(Token::Variable, "main".to_string()),
                 create_token(Token::LeftParen, "(".to_string()),
                 create_token(Token::RightParen, ")".to_string()),
                 create_token(Token::LeftBrace, "{".to_string()),
-                create_token(Token::Int, "int".to_string()),
+                create_token(Token::CharPointer, "char*".to_string()),
                 create_token(Token::Variable, "a".to_string()),
                 create_token(Token::SemiColon, ";".to_string()),
                 create_token(Token::Variable, "a".to_string()),
-                create_token(Token::Dec, "--".to_string()),
+                create_token(Token::Assign, "=".to_string()),
+                create_token(Token::StringLiteral, "foo".to_string()),
+                create_token(Token::StringLiteral, "bar".to_string()),
                 create_token(Token::SemiColon, ";".to_string()),
-                create_token(Token::Return, "return".to_string()),
-                create_token(Token::Number, "1".to_string()),
+                create_token(Token::CharPointer, "char*".to_string()),
+                create_token(Token::Variable, "b".to_string()),
+                create_token(Token::SemiColon, ";".to_string()),
+                create_token(Token::Variable, "b".to_string()),
+                create_token(Token::Assign, "=".to_string()),
+                create_token(Token::StringLiteral, "baz".to_string()),
                 create_token(Token::SemiColon, ";".to_string()),
                 create_token(Token::RightBrace, "}".to_string()),
                 create_token(Token::End, "End".to_string()),
             ];
             let mut ast = AstGen::new(&data);
             let result = ast.parse();
+            let var = |n: &str| Box::new(AstType::Variable(Type::Char, Structure::Pointer, n.to_string()));
 
             // 期待値確認.
             assert_eq!(
@@ -6349,13 +10339,10 @@ mod tests {
                     "main".to_string(),
                     Box::new(AstType::Argment(vec![])),
                     Box::new(AstType::Statement(vec![
-                        AstType::Variable(Type::Int, Structure::Identifier, "a".to_string()),
-                        AstType::PostDec(Box::new(AstType::Variable(
-                            Type::Int,
-                            Structure::Identifier,
-                            "a".to_string()
-                        )),),
-                        AstType::Return(Box::new(AstType::Factor(1)),)
+                        *var("a"),
+                        AstType::Assign(var("a"), Box::new(AstType::StringLiteral("foobar".to_string(), 0))),
+                        *var("b"),
+                        AstType::Assign(var("b"), Box::new(AstType::StringLiteral("baz".to_string(), 1))),
                     ]))
                 )
             );
@@ -6363,7 +10350,7 @@ mod tests {
     }
 
     #[test]
-    fn test_pre_inc_dec() {
+    fn test_sizeof() {
         {
             let data = vec![
                 create_token(Token::Int, "int".to_string()),
@@ -6371,17 +10358,17 @@ mod tests {
                 create_token(Token::LeftParen, "(".to_string()),
                 create_token(Token::RightParen, ")".to_string()),
                 create_token(Token::LeftBrace, "{".to_string()),
-                create_token(Token::Int, "int".to_string()),
-                create_token(Token::Variable, "a".to_string()),
-                create_token(Token::SemiColon, ";".to_string()),
-                create_token(Token::Inc, "++".to_string()),
+                create_token(Token::Char, "char".to_string()),
                 create_token(Token::Variable, "a".to_string()),
                 create_token(Token::SemiColon, ";".to_string()),
                 create_token(Token::Return, "return".to_string()),
-                create_token(Token::Number, "1".to_string()),
+                create_token(Token::SizeOf, "sizeof".to_string()),
+                create_token(Token::LeftParen, "(".to_string()),
+                create_token(Token::Variable, "a".to_string()),
+                create_token(Token::RightParen, "(".to_string()),
                 create_token(Token::SemiColon, ";".to_string()),
                 create_token(Token::RightBrace, "}".to_string()),
-                create_token(Token::End, "End".to_string()),
+                create_token(Token::End, "end".to_string()),
             ];
             let mut ast = AstGen::new(&data);
             let result = ast.parse();
@@ -6395,13 +10382,8 @@ mod tests {
                     "main".to_string(),
                     Box::new(AstType::Argment(vec![])),
                     Box::new(AstType::Statement(vec![
-                        AstType::Variable(Type::Int, Structure::Identifier, "a".to_string()),
-                        AstType::PreInc(Box::new(AstType::Variable(
-                            Type::Int,
-                            Structure::Identifier,
-                            "a".to_string()
-                        )),),
-                        AstType::Return(Box::new(AstType::Factor(1)),)
+                        AstType::Variable(Type::Char, Structure::Identifier, "a".to_string()),
+                        AstType::Return(Box::new(AstType::SizeOf(1)),)
                     ]))
                 )
             );
@@ -6413,17 +10395,14 @@ mod tests {
                 create_token(Token::LeftParen, "(".to_string()),
                 create_token(Token::RightParen, ")".to_string()),
                 create_token(Token::LeftBrace, "{".to_string()),
-                create_token(Token::Int, "int".to_string()),
-                create_token(Token::Variable, "a".to_string()),
-                create_token(Token::SemiColon, ";".to_string()),
-                create_token(Token::Dec, "--".to_string()),
-                create_token(Token::Variable, "a".to_string()),
-                create_token(Token::SemiColon, ";".to_string()),
                 create_token(Token::Return, "return".to_string()),
-                create_token(Token::Number, "1".to_string()),
+                create_token(Token::SizeOf, "sizeof".to_string()),
+                create_token(Token::LeftParen, "(".to_string()),
+                create_token(Token::Int, "int".to_string()),
+                create_token(Token::RightParen, "(".to_string()),
                 create_token(Token::SemiColon, ";".to_string()),
                 create_token(Token::RightBrace, "}".to_string()),
-                create_token(Token::End, "End".to_string()),
+                create_token(Token::End, "end".to_string()),
             ];
             let mut ast = AstGen::new(&data);
             let result = ast.parse();
@@ -6437,36 +10416,26 @@ mod tests {
                     "main".to_string(),
                     Box::new(AstType::Argment(vec![])),
                     Box::new(AstType::Statement(vec![
-                        AstType::Variable(Type::Int, Structure::Identifier, "a".to_string()),
-                        AstType::PreDec(Box::new(AstType::Variable(
-                            Type::Int,
-                            Structure::Identifier,
-                            "a".to_string()
-                        )),),
-                        AstType::Return(Box::new(AstType::Factor(1)),)
+                        AstType::Return(Box::new(AstType::SizeOf(4)),)
                     ]))
                 )
             );
         }
-    }
-
-    #[test]
-    fn test_variable_char() {
         {
             let data = vec![
-                create_token(Token::Char, "char".to_string()),
+                create_token(Token::Int, "int".to_string()),
                 create_token(Token::Variable, "main".to_string()),
                 create_token(Token::LeftParen, "(".to_string()),
                 create_token(Token::RightParen, ")".to_string()),
                 create_token(Token::LeftBrace, "{".to_string()),
-                create_token(Token::Char, "char".to_string()),
-                create_token(Token::Variable, "a".to_string()),
-                create_token(Token::SemiColon, ";".to_string()),
                 create_token(Token::Return, "return".to_string()),
-                create_token(Token::Number, "1".to_string()),
+                create_token(Token::SizeOf, "sizeof".to_string()),
+                create_token(Token::LeftParen, "(".to_string()),
+                create_token(Token::Char, "char".to_string()),
+                create_token(Token::RightParen, "(".to_string()),
                 create_token(Token::SemiColon, ";".to_string()),
                 create_token(Token::RightBrace, "}".to_string()),
-                create_token(Token::End, "End".to_string()),
+                create_token(Token::End, "end".to_string()),
             ];
             let mut ast = AstGen::new(&data);
             let result = ast.parse();
@@ -6475,13 +10444,12 @@ mod tests {
             assert_eq!(
                 result.get_tree()[0],
                 AstType::FuncDef(
-                    Type::Char,
+                    Type::Int,
                     Structure::Identifier,
                     "main".to_string(),
                     Box::new(AstType::Argment(vec![])),
                     Box::new(AstType::Statement(vec![
-                        AstType::Variable(Type::Char, Structure::Identifier, "a".to_string()),
-                        AstType::Return(Box::new(AstType::Factor(1)),)
+                        AstType::Return(Box::new(AstType::SizeOf(1)),)
                     ]))
                 )
             );
@@ -6493,17 +10461,14 @@ mod tests {
                 create_token(Token::LeftParen, "(".to_string()),
                 create_token(Token::RightParen, ")".to_string()),
                 create_token(Token::LeftBrace, "{".to_string()),
-                create_token(Token::Char, "char".to_string()),
-                create_token(Token::Variable, "a".to_string()),
-                create_token(Token::LeftBracket, " [".to_string()),
-                create_token(Token::Number, "3".to_string()),
-                create_token(Token::RightBracket, "]".to_string()),
-                create_token(Token::SemiColon, ";".to_string()),
                 create_token(Token::Return, "return".to_string()),
-                create_token(Token::Number, "1".to_string()),
+                create_token(Token::SizeOf, "sizeof".to_string()),
+                create_token(Token::LeftParen, "(".to_string()),
+                create_token(Token::IntPointer, "int*".to_string()),
+                create_token(Token::RightParen, "(".to_string()),
                 create_token(Token::SemiColon, ";".to_string()),
                 create_token(Token::RightBrace, "}".to_string()),
-                create_token(Token::End, "End".to_string()),
+                create_token(Token::End, "end".to_string()),
             ];
             let mut ast = AstGen::new(&data);
             let result = ast.parse();
@@ -6517,16 +10482,11 @@ mod tests {
                     "main".to_string(),
                     Box::new(AstType::Argment(vec![])),
                     Box::new(AstType::Statement(vec![
-                        AstType::Variable(Type::Char, Structure::Array(vec![3]), "a".to_string()),
-                        AstType::Return(Box::new(AstType::Factor(1)),)
+                        AstType::Return(Box::new(AstType::SizeOf(8)),)
                     ]))
                 )
             );
         }
-    }
-
-    #[test]
-    fn test_string_literal() {
         {
             let data = vec![
                 create_token(Token::Int, "int".to_string()),
@@ -6534,17 +10494,14 @@ mod tests {
                 create_token(Token::LeftParen, "(".to_string()),
                 create_token(Token::RightParen, ")".to_string()),
                 create_token(Token::LeftBrace, "{".to_string()),
-                create_token(Token::CharPointer, "char*".to_string()),
-                create_token(Token::Variable, "a".to_string()),
-                create_token(Token::SemiColon, ";".to_string()),
-                create_token(Token::Variable, "a".to_string()),
-                create_token(Token::Assign, "=".to_string()),
-                create_token(Token::StringLiteral, "testaaaa".to_string()),
                 create_token(Token::Return, "return".to_string()),
-                create_token(Token::Number, "1".to_string()),
+                create_token(Token::SizeOf, "sizeof".to_string()),
+                create_token(Token::LeftParen, "(".to_string()),
+                create_token(Token::CharPointer, "char*".to_string()),
+                create_token(Token::RightParen, "(".to_string()),
                 create_token(Token::SemiColon, ";".to_string()),
                 create_token(Token::RightBrace, "}".to_string()),
-                create_token(Token::End, "End".to_string()),
+                create_token(Token::End, "end".to_string()),
             ];
             let mut ast = AstGen::new(&data);
             let result = ast.parse();
@@ -6558,16 +10515,7 @@ mod tests {
                     "main".to_string(),
                     Box::new(AstType::Argment(vec![])),
                     Box::new(AstType::Statement(vec![
-                        AstType::Variable(Type::Char, Structure::Pointer, "a".to_string()),
-                        AstType::Assign(
-                            Box::new(AstType::Variable(
-                                Type::Char,
-                                Structure::Pointer,
-                                "a".to_string()
-                            )),
-                            Box::new(AstType::StringLiteral("testaaaa".to_string(), 0))
-                        ),
-                        AstType::Return(Box::new(AstType::Factor(1)),)
+                        AstType::Return(Box::new(AstType::SizeOf(8)),)
                     ]))
                 )
             );
@@ -6579,18 +10527,17 @@ mod tests {
                 create_token(Token::LeftParen, "(".to_string()),
                 create_token(Token::RightParen, ")".to_string()),
                 create_token(Token::LeftBrace, "{".to_string()),
-                create_token(Token::CharPointer, "char*".to_string()),
-                create_token(Token::Variable, "a".to_string()),
-                create_token(Token::SemiColon, ";".to_string()),
+                create_token(Token::Int, "int".to_string()),
                 create_token(Token::Variable, "a".to_string()),
-                create_token(Token::Assign, "=".to_string()),
-                create_token(Token::StringLiteral, "test, aaaa".to_string()),
                 create_token(Token::SemiColon, ";".to_string()),
                 create_token(Token::Return, "return".to_string()),
-                create_token(Token::Number, "1".to_string()),
+                create_token(Token::SizeOf, "sizeof".to_string()),
+                create_token(Token::LeftParen, "(".to_string()),
+                create_token(Token::Variable, "a".to_string()),
+                create_token(Token::RightParen, "(".to_string()),
                 create_token(Token::SemiColon, ";".to_string()),
                 create_token(Token::RightBrace, "}".to_string()),
-                create_token(Token::End, "End".to_string()),
+                create_token(Token::End, "end".to_string()),
             ];
             let mut ast = AstGen::new(&data);
             let result = ast.parse();
@@ -6604,16 +10551,8 @@ mod tests {
                     "main".to_string(),
                     Box::new(AstType::Argment(vec![])),
                     Box::new(AstType::Statement(vec![
-                        AstType::Variable(Type::Char, Structure::Pointer, "a".to_string()),
-                        AstType::Assign(
-                            Box::new(AstType::Variable(
-                                Type::Char,
-                                Structure::Pointer,
-                                "a".to_string()
-                            )),
-                            Box::new(AstType::StringLiteral("test, aaaa".to_string(), 0))
-                        ),
-                        AstType::Return(Box::new(AstType::Factor(1)),)
+                        AstType::Variable(Type::Int, Structure::Identifier, "a".to_string()),
+                        AstType::Return(Box::new(AstType::SizeOf(4)),)
                     ]))
                 )
             );
@@ -6625,22 +10564,17 @@ mod tests {
                 create_token(Token::LeftParen, "(".to_string()),
                 create_token(Token::RightParen, ")".to_string()),
                 create_token(Token::LeftBrace, "{".to_string()),
-                create_token(Token::CharPointer, "char*".to_string()),
-                create_token(Token::Variable, "a".to_string()),
-                create_token(Token::SemiColon, ";".to_string()),
+                create_token(Token::Int, "int".to_string()),
                 create_token(Token::Variable, "a".to_string()),
-                create_token(Token::Assign, "=".to_string()),
-                create_token(Token::StringLiteral, "test, aaaa".to_string()),
-                create_token(Token::SemiColon, ";".to_string()),
-                create_token(Token::CharPointer, "char*".to_string()),
-                create_token(Token::Variable, "b".to_string()),
-                create_token(Token::SemiColon, ";".to_string()),
-                create_token(Token::Variable, "b".to_string()),
-                create_token(Token::Assign, "=".to_string()),
-                create_token(Token::StringLiteral, "test, bbbb".to_string()),
+                create_token(Token::LeftBracket, " [".to_string()),
+                create_token(Token::Number, "3".to_string()),
+                create_token(Token::RightBracket, "]".to_string()),
                 create_token(Token::SemiColon, ";".to_string()),
                 create_token(Token::Return, "return".to_string()),
-                create_token(Token::Number, "1".to_string()),
+                create_token(Token::SizeOf, "sizeof".to_string()),
+                create_token(Token::LeftParen, "(".to_string()),
+                create_token(Token::Variable, "a".to_string()),
+                create_token(Token::RightParen, ")".to_string()),
                 create_token(Token::SemiColon, ";".to_string()),
                 create_token(Token::RightBrace, "}".to_string()),
                 create_token(Token::End, "End".to_string()),
@@ -6657,51 +10591,32 @@ mod tests {
                     "main".to_string(),
                     Box::new(AstType::Argment(vec![])),
                     Box::new(AstType::Statement(vec![
-                        AstType::Variable(Type::Char, Structure::Pointer, "a".to_string()),
-                        AstType::Assign(
-                            Box::new(AstType::Variable(
-                                Type::Char,
-                                Structure::Pointer,
-                                "a".to_string()
-                            )),
-                            Box::new(AstType::StringLiteral("test, aaaa".to_string(), 0))
-                        ),
-                        AstType::Variable(Type::Char, Structure::Pointer, "b".to_string()),
-                        AstType::Assign(
-                            Box::new(AstType::Variable(
-                                Type::Char,
-                                Structure::Pointer,
-                                "b".to_string()
-                            )),
-                            Box::new(AstType::StringLiteral("test, bbbb".to_string(), 1))
-                        ),
-                        AstType::Return(Box::new(AstType::Factor(1)),)
+                        AstType::Variable(Type::Int, Structure::Array(vec![3]), "a".to_string()),
+                        AstType::Return(Box::new(AstType::SizeOf(12)),)
                     ]))
                 )
             );
         }
-    }
-
-    #[test]
-    fn test_sizeof() {
         {
+            // typedef名のsizeofは、登録された型のサイズ
             let data = vec![
+                create_token(Token::Typedef, "typedef".to_string()),
+                create_token(Token::Int, "int".to_string()),
+                create_token(Token::Variable, "t".to_string()),
+                create_token(Token::SemiColon, ";".to_string()),
                 create_token(Token::Int, "int".to_string()),
                 create_token(Token::Variable, "main".to_string()),
                 create_token(Token::LeftParen, "(".to_string()),
                 create_token(Token::RightParen, ")".to_string()),
                 create_token(Token::LeftBrace, "{".to_string()),
-                create_token(Token::Char, "char".to_string()),
-                create_token(Token::Variable, "a".to_string()),
-                create_token(Token::SemiColon, ";".to_string()),
                 create_token(Token::Return, "return".to_string()),
                 create_token(Token::SizeOf, "sizeof".to_string()),
                 create_token(Token::LeftParen, "(".to_string()),
-                create_token(Token::Variable, "a".to_string()),
-                create_token(Token::RightParen, "(".to_string()),
+                create_token(Token::Variable, "t".to_string()),
+                create_token(Token::RightParen, ")".to_string()),
                 create_token(Token::SemiColon, ";".to_string()),
                 create_token(Token::RightBrace, "}".to_string()),
-                create_token(Token::End, "end".to_string()),
+                create_token(Token::End, "End".to_string()),
             ];
             let mut ast = AstGen::new(&data);
             let result = ast.parse();
@@ -6715,12 +10630,15 @@ mod tests {
                     "main".to_string(),
                     Box::new(AstType::Argment(vec![])),
                     Box::new(AstType::Statement(vec![
-                        AstType::Variable(Type::Char, Structure::Identifier, "a".to_string()),
-                        AstType::Return(Box::new(AstType::SizeOf(1)),)
+                        AstType::Return(Box::new(AstType::SizeOf(4)),)
                     ]))
                 )
             );
         }
+    }
+
+    #[test]
+    fn test_sizeof_without_paren() {
         {
             let data = vec![
                 create_token(Token::Int, "int".to_string()),
@@ -6728,14 +10646,15 @@ mod tests {
                 create_token(Token::LeftParen, "(".to_string()),
                 create_token(Token::RightParen, ")".to_string()),
                 create_token(Token::LeftBrace, "{".to_string()),
+                create_token(Token::Char, "char".to_string()),
+                create_token(Token::Variable, "a".to_string()),
+                create_token(Token::SemiColon, ";".to_string()),
                 create_token(Token::Return, "return".to_string()),
                 create_token(Token::SizeOf, "sizeof".to_string()),
-                create_token(Token::LeftParen, "(".to_string()),
-                create_token(Token::Int, "int".to_string()),
-                create_token(Token::RightParen, "(".to_string()),
+                create_token(Token::Variable, "a".to_string()),
                 create_token(Token::SemiColon, ";".to_string()),
                 create_token(Token::RightBrace, "}".to_string()),
-                create_token(Token::End, "end".to_string()),
+                create_token(Token::End, "End".to_string()),
             ];
             let mut ast = AstGen::new(&data);
             let result = ast.parse();
@@ -6749,10 +10668,11 @@ mod tests {
                     "main".to_string(),
                     Box::new(AstType::Argment(vec![])),
                     Box::new(AstType::Statement(vec![
-                        AstType::Return(Box::new(AstType::SizeOf(4)),)
-                    ]))
+                        AstType::Variable(Type::Char, Structure::Identifier, "a".to_string()),
+                        AstType::Return(Box::new(AstType::SizeOf(1))),
+                    ])),
                 )
-            );
+            )
         }
         {
             let data = vec![
@@ -6761,14 +10681,16 @@ mod tests {
                 create_token(Token::LeftParen, "(".to_string()),
                 create_token(Token::RightParen, ")".to_string()),
                 create_token(Token::LeftBrace, "{".to_string()),
+                create_token(Token::IntPointer, "int*".to_string()),
+                create_token(Token::Variable, "p".to_string()),
+                create_token(Token::SemiColon, ";".to_string()),
                 create_token(Token::Return, "return".to_string()),
                 create_token(Token::SizeOf, "sizeof".to_string()),
-                create_token(Token::LeftParen, "(".to_string()),
-                create_token(Token::Char, "char".to_string()),
-                create_token(Token::RightParen, "(".to_string()),
+                create_token(Token::Multi, "*".to_string()),
+                create_token(Token::Variable, "p".to_string()),
                 create_token(Token::SemiColon, ";".to_string()),
                 create_token(Token::RightBrace, "}".to_string()),
-                create_token(Token::End, "end".to_string()),
+                create_token(Token::End, "End".to_string()),
             ];
             let mut ast = AstGen::new(&data);
             let result = ast.parse();
@@ -6782,10 +10704,11 @@ mod tests {
                     "main".to_string(),
                     Box::new(AstType::Argment(vec![])),
                     Box::new(AstType::Statement(vec![
-                        AstType::Return(Box::new(AstType::SizeOf(1)),)
-                    ]))
+                        AstType::Variable(Type::Int, Structure::Pointer, "p".to_string()),
+                        AstType::Return(Box::new(AstType::SizeOf(4))),
+                    ])),
                 )
-            );
+            )
         }
         {
             let data = vec![
@@ -6794,14 +10717,18 @@ mod tests {
                 create_token(Token::LeftParen, "(".to_string()),
                 create_token(Token::RightParen, ")".to_string()),
                 create_token(Token::LeftBrace, "{".to_string()),
+                create_token(Token::CharPointer, "char*".to_string()),
+                create_token(Token::Variable, "p".to_string()),
+                create_token(Token::SemiColon, ";".to_string()),
                 create_token(Token::Return, "return".to_string()),
                 create_token(Token::SizeOf, "sizeof".to_string()),
-                create_token(Token::LeftParen, "(".to_string()),
-                create_token(Token::IntPointer, "int*".to_string()),
-                create_token(Token::RightParen, "(".to_string()),
+                create_token(Token::Multi, "*".to_string()),
+                create_token(Token::Variable, "p".to_string()),
+                create_token(Token::Plus, "+".to_string()),
+                create_token(Token::Number, "1".to_string()),
                 create_token(Token::SemiColon, ";".to_string()),
                 create_token(Token::RightBrace, "}".to_string()),
-                create_token(Token::End, "end".to_string()),
+                create_token(Token::End, "End".to_string()),
             ];
             let mut ast = AstGen::new(&data);
             let result = ast.parse();
@@ -6815,11 +10742,167 @@ mod tests {
                     "main".to_string(),
                     Box::new(AstType::Argment(vec![])),
                     Box::new(AstType::Statement(vec![
-                        AstType::Return(Box::new(AstType::SizeOf(8)),)
-                    ]))
+                        AstType::Variable(Type::Char, Structure::Pointer, "p".to_string()),
+                        AstType::Return(Box::new(AstType::Plus(
+                            Box::new(AstType::SizeOf(1)),
+                            Box::new(AstType::Factor(1)),
+                        ))),
+                    ])),
                 )
-            );
+            )
         }
+    }
+
+    #[test]
+    fn test_compound_literal() {
+        let data = vec![
+            create_token(Token::Int, "int".to_string()),
+            create_token(Token::Variable, "main".to_string()),
+            create_token(Token::LeftParen, "(".to_string()),
+            create_token(Token::RightParen, ")".to_string()),
+            create_token(Token::LeftBrace, "{".to_string()),
+            create_token(Token::IntPointer, "int*".to_string()),
+            create_token(Token::Variable, "p".to_string()),
+            create_token(Token::Assign, "=".to_string()),
+            create_token(Token::LeftParen, "(".to_string()),
+            create_token(Token::Int, "int".to_string()),
+            create_token(Token::LeftBracket, "[".to_string()),
+            create_token(Token::RightBracket, "]".to_string()),
+            create_token(Token::RightParen, ")".to_string()),
+            create_token(Token::LeftBrace, "{".to_string()),
+            create_token(Token::Number, "1".to_string()),
+            create_token(Token::Comma, ",".to_string()),
+            create_token(Token::Number, "2".to_string()),
+            create_token(Token::Comma, ",".to_string()),
+            create_token(Token::Number, "3".to_string()),
+            create_token(Token::RightBrace, "}".to_string()),
+            create_token(Token::SemiColon, ";".to_string()),
+            create_token(Token::RightBrace, "}".to_string()),
+            create_token(Token::End, "End".to_string()),
+        ];
+        let mut ast = AstGen::new(&data);
+        let result = ast.parse();
+
+        // 期待値確認.
+        assert_eq!(
+            result.get_tree()[0],
+            AstType::FuncDef(
+                Type::Int,
+                Structure::Identifier,
+                "main".to_string(),
+                Box::new(AstType::Argment(vec![])),
+                Box::new(AstType::Statement(vec![AstType::Assign(
+                    Box::new(AstType::Variable(Type::Int, Structure::Pointer, "p".to_string())),
+                    Box::new(AstType::CompoundLiteral(
+                        Box::new(AstType::Variable(
+                            Type::Int,
+                            Structure::Array(vec![3]),
+                            ".compound0".to_string()
+                        )),
+                        vec![AstType::Factor(1), AstType::Factor(2), AstType::Factor(3)],
+                    )),
+                )]))
+            )
+        );
+    }
+
+    #[test]
+    fn test_type_of_promotion() {
+        let a = || Box::new(AstType::Variable(Type::Char, Structure::Identifier, "a".to_string()));
+        let b = || Box::new(AstType::Variable(Type::Char, Structure::Identifier, "b".to_string()));
+        let l = || Box::new(AstType::Variable(Type::Long, Structure::Identifier, "l".to_string()));
+
+        // char同士の演算結果はint型.
+        assert_eq!(AstType::Plus(a(), b()).type_of(), Type::Int);
+        assert_eq!(AstType::Multiple(a(), b()).type_of(), Type::Int);
+        assert_eq!(AstType::BitAnd(a(), b()).type_of(), Type::Int);
+        assert_eq!(AstType::UnMinus(a()).type_of(), Type::Int);
+
+        // longを含む場合はlong型.
+        assert_eq!(AstType::Plus(a(), l()).type_of(), Type::Long);
+
+        // sizeofはsize_t(unsigned long)で、longとの演算結果も符号なし.
+        let size = || Box::new(AstType::SizeOf(4));
+        assert_eq!(size().type_of(), Type::ULong);
+        assert_eq!(AstType::Minus(size(), Box::new(AstType::Factor(8))).type_of(), Type::ULong);
+        assert_eq!(AstType::Plus(l(), size()).type_of(), Type::ULong);
+
+        // 代入式は左辺の型.
+        assert_eq!(AstType::Assign(a(), Box::new(AstType::Plus(a(), b()))).type_of(), Type::Char);
+        assert_eq!(a().type_of(), Type::Char);
+    }
+
+    #[test]
+    fn test_member_access() {
+        // struct Node { int value; struct Node *next; };
+        // int main() { struct Node *list; list->next->value = 5; }
+        let data = vec![
+            create_token(Token::Struct, "struct".to_string()),
+            create_token(Token::Variable, "Node".to_string()),
+            create_token(Token::LeftBrace, "{".to_string()),
+            create_token(Token::Int, "int".to_string()),
+            create_token(Token::Variable, "value".to_string()),
+            create_token(Token::SemiColon, ";".to_string()),
+            create_token(Token::Struct, "struct".to_string()),
+            create_token(Token::Variable, "Node".to_string()),
+            create_token(Token::Multi, "*".to_string()),
+            create_token(Token::Variable, "next".to_string()),
+            create_token(Token::SemiColon, ";".to_string()),
+            create_token(Token::RightBrace, "}".to_string()),
+            create_token(Token::SemiColon, ";".to_string()),
+            create_token(Token::Int, "int".to_string()),
+            create_token(Token::Variable, "main".to_string()),
+            create_token(Token::LeftParen, "(".to_string()),
+            create_token(Token::RightParen, ")".to_string()),
+            create_token(Token::LeftBrace, "{".to_string()),
+            create_token(Token::Struct, "struct".to_string()),
+            create_token(Token::Variable, "Node".to_string()),
+            create_token(Token::Multi, "*".to_string()),
+            create_token(Token::Variable, "list".to_string()),
+            create_token(Token::SemiColon, ";".to_string()),
+            create_token(Token::Variable, "list".to_string()),
+            create_token(Token::Arrow, "->".to_string()),
+            create_token(Token::Variable, "next".to_string()),
+            create_token(Token::Arrow, "->".to_string()),
+            create_token(Token::Variable, "value".to_string()),
+            create_token(Token::Assign, "=".to_string()),
+            create_token(Token::Number, "5".to_string()),
+            create_token(Token::SemiColon, ";".to_string()),
+            create_token(Token::RightBrace, "}".to_string()),
+            create_token(Token::End, "End".to_string()),
+        ];
+        let mut ast = AstGen::new(&data);
+        let result = ast.parse();
+
+        // list->nextは(*list).nextとなる
+        let node = || Type::Struct("Node".to_string());
+        let list = AstType::Variable(node(), Structure::Pointer, "list".to_string());
+        let next = AstType::MemberAccess(
+            Box::new(AstType::Indirect(Box::new(list.clone()))),
+            Box::new(AstType::Variable(node(), Structure::Pointer, "next".to_string())),
+        );
+        let value = AstType::MemberAccess(
+            Box::new(AstType::Indirect(Box::new(next))),
+            Box::new(AstType::Variable(Type::Int, Structure::Identifier, "value".to_string())),
+        );
+        assert_eq!(
+            result.get_tree()[1],
+            AstType::FuncDef(
+                Type::Int,
+                Structure::Identifier,
+                "main".to_string(),
+                Box::new(AstType::Argment(vec![])),
+                Box::new(AstType::Statement(vec![
+                    list,
+                    AstType::Assign(Box::new(value), Box::new(AstType::Factor(5))),
+                ]))
+            )
+        );
+    }
+
+    #[test]
+    fn test_statement_switch() {
+        // int main() { switch (1) { case 1: return 1; case 2: default: break; } }
         {
             let data = vec![
                 create_token(Token::Int, "int".to_string()),
@@ -6827,14 +10910,27 @@ mod tests {
                 create_token(Token::LeftParen, "(".to_string()),
                 create_token(Token::RightParen, ")".to_string()),
                 create_token(Token::LeftBrace, "{".to_string()),
-                create_token(Token::Return, "return".to_string()),
-                create_token(Token::SizeOf, "sizeof".to_string()),
+                create_token(Token::Switch, "switch".to_string()),
                 create_token(Token::LeftParen, "(".to_string()),
-                create_token(Token::CharPointer, "char*".to_string()),
-                create_token(Token::RightParen, "(".to_string()),
+                create_token(Token::Number, "1".to_string()),
+                create_token(Token::RightParen, ")".to_string()),
+                create_token(Token::LeftBrace, "{".to_string()),
+                create_token(Token::Case, "case".to_string()),
+                create_token(Token::Number, "1".to_string()),
+                create_token(Token::Colon, ":".to_string()),
+                create_token(Token::Return, "return".to_string()),
+                create_token(Token::Number, "1".to_string()),
+                create_token(Token::SemiColon, ";".to_string()),
+                create_token(Token::Case, "case".to_string()),
+                create_token(Token::Number, "2".to_string()),
+                create_token(Token::Colon, ":".to_string()),
+                create_token(Token::Default, "default".to_string()),
+                create_token(Token::Colon, ":".to_string()),
+                create_token(Token::Break, "break".to_string()),
                 create_token(Token::SemiColon, ";".to_string()),
                 create_token(Token::RightBrace, "}".to_string()),
-                create_token(Token::End, "end".to_string()),
+                create_token(Token::RightBrace, "}".to_string()),
+                create_token(Token::End, "End".to_string()),
             ];
             let mut ast = AstGen::new(&data);
             let result = ast.parse();
@@ -6847,12 +10943,21 @@ mod tests {
                     Structure::Identifier,
                     "main".to_string(),
                     Box::new(AstType::Argment(vec![])),
-                    Box::new(AstType::Statement(vec![
-                        AstType::Return(Box::new(AstType::SizeOf(8)),)
-                    ]))
+                    Box::new(AstType::Statement(vec![AstType::Switch(
+                        Box::new(AstType::Factor(1)),
+                        Box::new(AstType::Statement(vec![
+                            AstType::Case(1),
+                            AstType::Return(Box::new(AstType::Factor(1))),
+                            AstType::Case(2),
+                            AstType::Default(),
+                            AstType::Break(1),
+                        ])),
+                    )]))
                 )
             );
+            assert!(ast.get_errors().is_empty());
         }
+        // int main() { switch (1) { case 1: case 1: } }
         {
             let data = vec![
                 create_token(Token::Int, "int".to_string()),
@@ -6860,17 +10965,308 @@ mod tests {
                 create_token(Token::LeftParen, "(".to_string()),
                 create_token(Token::RightParen, ")".to_string()),
                 create_token(Token::LeftBrace, "{".to_string()),
-                create_token(Token::Int, "int".to_string()),
-                create_token(Token::Variable, "a".to_string()),
-                create_token(Token::SemiColon, ";".to_string()),
-                create_token(Token::Return, "return".to_string()),
-                create_token(Token::SizeOf, "sizeof".to_string()),
+                create_token(Token::Switch, "switch".to_string()),
                 create_token(Token::LeftParen, "(".to_string()),
-                create_token(Token::Variable, "a".to_string()),
-                create_token(Token::RightParen, "(".to_string()),
-                create_token(Token::SemiColon, ";".to_string()),
+                create_token(Token::Number, "1".to_string()),
+                create_token(Token::RightParen, ")".to_string()),
+                create_token(Token::LeftBrace, "{".to_string()),
+                create_token(Token::Case, "case".to_string()),
+                create_token(Token::Number, "1".to_string()),
+                create_token(Token::Colon, ":".to_string()),
+                create_token(Token::Case, "case".to_string()),
+                create_token(Token::Number, "1".to_string()),
+                create_token(Token::Colon, ":".to_string()),
                 create_token(Token::RightBrace, "}".to_string()),
-                create_token(Token::End, "end".to_string()),
+                create_token(Token::RightBrace, "}".to_string()),
+                create_token(Token::End, "End".to_string()),
+            ];
+            let mut ast = AstGen::new(&data);
+            ast.parse();
+            assert_eq!(messages(ast.get_errors()), vec![":0:0: duplicate case value: 1".to_string()]);
+        }
+    }
+
+    #[test]
+    fn test_compound_assign_lvalue() {
+        // struct S { int x; };
+        // int main() { struct S s; int a[3]; int i; s.x += 1; a[i] -= 3; }
+        let data = vec![
+            create_token(Token::Struct, "struct".to_string()),
+            create_token(Token::Variable, "S".to_string()),
+            create_token(Token::LeftBrace, "{".to_string()),
+            create_token(Token::Int, "int".to_string()),
+            create_token(Token::Variable, "x".to_string()),
+            create_token(Token::SemiColon, ";".to_string()),
+            create_token(Token::RightBrace, "}".to_string()),
+            create_token(Token::SemiColon, ";".to_string()),
+            create_token(Token::Int, "int".to_string()),
+            create_token(Token::Variable, "main".to_string()),
+            create_token(Token::LeftParen, "(".to_string()),
+            create_token(Token::RightParen, ")".to_string()),
+            create_token(Token::LeftBrace, "{".to_string()),
+            create_token(Token::Struct, "struct".to_string()),
+            create_token(Token::Variable, "S".to_string()),
+            create_token(Token::Variable, "s".to_string()),
+            create_token(Token::SemiColon, ";".to_string()),
+            create_token(Token::Int, "int".to_string()),
+            create_token(Token::Variable, "a".to_string()),
+            create_token(Token::LeftBracket, "[".to_string()),
+            create_token(Token::Number, "3".to_string()),
+            create_token(Token::RightBracket, "]".to_string()),
+            create_token(Token::SemiColon, ";".to_string()),
+            create_token(Token::Int, "int".to_string()),
+            create_token(Token::Variable, "i".to_string()),
+            create_token(Token::SemiColon, ";".to_string()),
+            create_token(Token::Variable, "s".to_string()),
+            create_token(Token::Dot, ".".to_string()),
+            create_token(Token::Variable, "x".to_string()),
+            create_token(Token::PlusAssign, "+=".to_string()),
+            create_token(Token::Number, "1".to_string()),
+            create_token(Token::SemiColon, ";".to_string()),
+            create_token(Token::Variable, "a".to_string()),
+            create_token(Token::LeftBracket, "[".to_string()),
+            create_token(Token::Variable, "i".to_string()),
+            create_token(Token::RightBracket, "]".to_string()),
+            create_token(Token::MinusAssign, "-=".to_string()),
+            create_token(Token::Number, "3".to_string()),
+            create_token(Token::SemiColon, ";".to_string()),
+            create_token(Token::RightBrace, "}".to_string()),
+            create_token(Token::End, "End".to_string()),
+        ];
+        let mut ast = AstGen::new(&data);
+        let result = ast.parse();
+
+        // 左辺はメンバーアクセス、配列要素(*(a + i))となる
+        let s = AstType::Variable(Type::Struct("S".to_string()), Structure::Struct, "s".to_string());
+        let a = AstType::Variable(Type::Int, Structure::Array(vec![3]), "a".to_string());
+        let i = AstType::Variable(Type::Int, Structure::Identifier, "i".to_string());
+        assert_eq!(
+            result.get_tree()[1],
+            AstType::FuncDef(
+                Type::Int,
+                Structure::Identifier,
+                "main".to_string(),
+                Box::new(AstType::Argment(vec![])),
+                Box::new(AstType::Statement(vec![
+                    s.clone(),
+                    a.clone(),
+                    i.clone(),
+                    AstType::PlusAssign(
+                        Box::new(AstType::MemberAccess(
+                            Box::new(s),
+                            Box::new(AstType::Variable(Type::Int, Structure::Identifier, "x".to_string())),
+                        )),
+                        Box::new(AstType::Factor(1)),
+                    ),
+                    AstType::MinusAssign(
+                        Box::new(AstType::Indirect(Box::new(AstType::Plus(Box::new(a), Box::new(i))))),
+                        Box::new(AstType::Factor(3)),
+                    ),
+                ]))
+            )
+        );
+    }
+
+    #[test]
+    fn test_designated_initializer() {
+        // int main() { int a[5] = {[2] = 7, [4] = 9}; }
+        let data = vec![
+            create_token(Token::Int, "int".to_string()),
+            create_token(Token::Variable, "main".to_string()),
+            create_token(Token::LeftParen, "(".to_string()),
+            create_token(Token::RightParen, ")".to_string()),
+            create_token(Token::LeftBrace, "{".to_string()),
+            create_token(Token::Int, "int".to_string()),
+            create_token(Token::Variable, "a".to_string()),
+            create_token(Token::LeftBracket, "[".to_string()),
+            create_token(Token::Number, "5".to_string()),
+            create_token(Token::RightBracket, "]".to_string()),
+            create_token(Token::Assign, "=".to_string()),
+            create_token(Token::LeftBrace, "{".to_string()),
+            create_token(Token::LeftBracket, "[".to_string()),
+            create_token(Token::Number, "2".to_string()),
+            create_token(Token::RightBracket, "]".to_string()),
+            create_token(Token::Assign, "=".to_string()),
+            create_token(Token::Number, "7".to_string()),
+            create_token(Token::Comma, ",".to_string()),
+            create_token(Token::LeftBracket, "[".to_string()),
+            create_token(Token::Number, "4".to_string()),
+            create_token(Token::RightBracket, "]".to_string()),
+            create_token(Token::Assign, "=".to_string()),
+            create_token(Token::Number, "9".to_string()),
+            create_token(Token::RightBrace, "}".to_string()),
+            create_token(Token::SemiColon, ";".to_string()),
+            create_token(Token::RightBrace, "}".to_string()),
+            create_token(Token::End, "End".to_string()),
+        ];
+        let mut ast = AstGen::new(&data);
+        let result = ast.parse();
+
+        // 初期化子の無い要素は0
+        let a = AstType::Variable(Type::Int, Structure::Array(vec![5]), "a".to_string());
+        assert_eq!(
+            result.get_tree()[0],
+            AstType::FuncDef(
+                Type::Int,
+                Structure::Identifier,
+                "main".to_string(),
+                Box::new(AstType::Argment(vec![])),
+                Box::new(AstType::Statement(vec![AstType::ArrayInit(
+                    Box::new(a),
+                    vec![
+                        AstType::Factor(0),
+                        AstType::Factor(0),
+                        AstType::Factor(7),
+                        AstType::Factor(0),
+                        AstType::Factor(9),
+                    ],
+                )]))
+            )
+        );
+        assert!(ast.get_errors().is_empty());
+    }
+
+    #[test]
+    fn test_invalid_designated_initializer() {
+        {
+            let data = vec![
+            create_token(Token::Int, "int".to_string()),
+            create_token(Token::Variable, "main".to_string()),
+            create_token(Token::LeftParen, "(".to_string()),
+            create_token(Token::RightParen, ")".to_string()),
+            create_token(Token::LeftBrace, "{".to_string()),
+            create_token(Token::Int, "int".to_string()),
+            create_token(Token::Variable, "a".to_string()),
+            create_token(Token::LeftBracket, "[".to_string()),
+            create_token(Token::Number, "5".to_string()),
+            create_token(Token::RightBracket, "]".to_string()),
+            create_token(Token::Assign, "=".to_string()),
+            create_token(Token::LeftBrace, "{".to_string()),
+            create_token(Token::LeftBracket, "[".to_string()),
+            create_token(Token::Number, "5".to_string()),
+            create_token(Token::RightBracket, "]".to_string()),
+            create_token(Token::Assign, "=".to_string()),
+            create_token(Token::Number, "7".to_string()),
+            create_token(Token::RightBrace, "}".to_string()),
+            create_token(Token::SemiColon, ";".to_string()),
+            create_token(Token::RightBrace, "}".to_string()),
+            create_token(Token::End, "End".to_string()),
+            ];
+            let mut ast = AstGen::new(&data);
+            ast.parse();
+            assert_eq!(messages(ast.get_errors()), vec![":0:0: array index in initializer exceeds array bounds: a[5]".to_string()]);
+        }
+        {
+            let data = vec![
+            create_token(Token::Int, "int".to_string()),
+            create_token(Token::Variable, "main".to_string()),
+            create_token(Token::LeftParen, "(".to_string()),
+            create_token(Token::RightParen, ")".to_string()),
+            create_token(Token::LeftBrace, "{".to_string()),
+            create_token(Token::Int, "int".to_string()),
+            create_token(Token::Variable, "a".to_string()),
+            create_token(Token::LeftBracket, "[".to_string()),
+            create_token(Token::Number, "5".to_string()),
+            create_token(Token::RightBracket, "]".to_string()),
+            create_token(Token::Assign, "=".to_string()),
+            create_token(Token::LeftBrace, "{".to_string()),
+            create_token(Token::LeftBracket, "[".to_string()),
+            create_token(Token::Number, "1".to_string()),
+            create_token(Token::RightBracket, "]".to_string()),
+            create_token(Token::Assign, "=".to_string()),
+            create_token(Token::Number, "7".to_string()),
+            create_token(Token::Comma, ",".to_string()),
+            create_token(Token::LeftBracket, "[".to_string()),
+            create_token(Token::Number, "1".to_string()),
+            create_token(Token::RightBracket, "]".to_string()),
+            create_token(Token::Assign, "=".to_string()),
+            create_token(Token::Number, "9".to_string()),
+            create_token(Token::RightBrace, "}".to_string()),
+            create_token(Token::SemiColon, ";".to_string()),
+            create_token(Token::RightBrace, "}".to_string()),
+            create_token(Token::End, "End".to_string()),
+            ];
+            let mut ast = AstGen::new(&data);
+            ast.parse();
+            assert_eq!(messages(ast.get_errors()), vec![":0:0: duplicate initializer: a[1]".to_string()]);
+        }
+    }
+
+    #[test]
+    fn test_function_address() {
+        // int foo() { return 1; } int main() { int* p = foo; int* q = &foo; }
+        let data = vec![
+            create_token(Token::Int, "int".to_string()),
+            create_token(Token::Variable, "foo".to_string()),
+            create_token(Token::LeftParen, "(".to_string()),
+            create_token(Token::RightParen, ")".to_string()),
+            create_token(Token::LeftBrace, "{".to_string()),
+            create_token(Token::Return, "return".to_string()),
+            create_token(Token::Number, "1".to_string()),
+            create_token(Token::SemiColon, ";".to_string()),
+            create_token(Token::RightBrace, "}".to_string()),
+            create_token(Token::Int, "int".to_string()),
+            create_token(Token::Variable, "main".to_string()),
+            create_token(Token::LeftParen, "(".to_string()),
+            create_token(Token::RightParen, ")".to_string()),
+            create_token(Token::LeftBrace, "{".to_string()),
+            create_token(Token::IntPointer, "int*".to_string()),
+            create_token(Token::Variable, "p".to_string()),
+            create_token(Token::Assign, "=".to_string()),
+            create_token(Token::Variable, "foo".to_string()),
+            create_token(Token::SemiColon, ";".to_string()),
+            create_token(Token::IntPointer, "int*".to_string()),
+            create_token(Token::Variable, "q".to_string()),
+            create_token(Token::Assign, "=".to_string()),
+            create_token(Token::And, "&".to_string()),
+            create_token(Token::Variable, "foo".to_string()),
+            create_token(Token::SemiColon, ";".to_string()),
+            create_token(Token::RightBrace, "}".to_string()),
+            create_token(Token::End, "End".to_string()),
+        ];
+        let mut ast = AstGen::new(&data);
+        let result = ast.parse();
+
+        // 関数名、&関数名はいずれも関数のアドレス
+        let foo = || Box::new(AstType::Address(Box::new(AstType::Variable(Type::Int, Structure::Identifier, "foo".to_string()))));
+        let var = |n: &str| Box::new(AstType::Variable(Type::Int, Structure::Pointer, n.to_string()));
+        assert_eq!(
+            result.get_tree()[1],
+            AstType::FuncDef(
+                Type::Int,
+                Structure::Identifier,
+                "main".to_string(),
+                Box::new(AstType::Argment(vec![])),
+                Box::new(AstType::Statement(vec![
+                    AstType::Assign(var("p"), foo()),
+                    AstType::Assign(var("q"), foo()),
+                ]))
+            )
+        );
+    }
+
+    #[test]
+    fn test_multi_declarator() {
+        // int *p, a[3], x;
+        {
+            let data = vec![
+            create_token(Token::Int, "int".to_string()),
+            create_token(Token::Variable, "main".to_string()),
+            create_token(Token::LeftParen, "(".to_string()),
+            create_token(Token::RightParen, ")".to_string()),
+            create_token(Token::LeftBrace, "{".to_string()),
+            create_token(Token::IntPointer, "int*".to_string()),
+            create_token(Token::Variable, "p".to_string()),
+            create_token(Token::Comma, ",".to_string()),
+            create_token(Token::Variable, "a".to_string()),
+            create_token(Token::LeftBracket, "[".to_string()),
+            create_token(Token::Number, "3".to_string()),
+            create_token(Token::RightBracket, "]".to_string()),
+            create_token(Token::Comma, ",".to_string()),
+            create_token(Token::Variable, "x".to_string()),
+            create_token(Token::SemiColon, ";".to_string()),
+            create_token(Token::RightBrace, "}".to_string()),
+            create_token(Token::End, "End".to_string()),
             ];
             let mut ast = AstGen::new(&data);
             let result = ast.parse();
@@ -6884,33 +11280,29 @@ mod tests {
                     "main".to_string(),
                     Box::new(AstType::Argment(vec![])),
                     Box::new(AstType::Statement(vec![
-                        AstType::Variable(Type::Int, Structure::Identifier, "a".to_string()),
-                        AstType::Return(Box::new(AstType::SizeOf(4)),)
-                    ]))
+                        AstType::Variable(Type::Int, Structure::Pointer, "p".to_string()),
+                        AstType::Variable(Type::Int, Structure::Array(vec![3]), "a".to_string()),
+                        AstType::Variable(Type::Int, Structure::Identifier, "x".to_string()),
+                    ])),
                 )
             );
         }
+        // char c, *s;
         {
             let data = vec![
-                create_token(Token::Int, "int".to_string()),
-                create_token(Token::Variable, "main".to_string()),
-                create_token(Token::LeftParen, "(".to_string()),
-                create_token(Token::RightParen, ")".to_string()),
-                create_token(Token::LeftBrace, "{".to_string()),
-                create_token(Token::Int, "int".to_string()),
-                create_token(Token::Variable, "a".to_string()),
-                create_token(Token::LeftBracket, " [".to_string()),
-                create_token(Token::Number, "3".to_string()),
-                create_token(Token::RightBracket, "]".to_string()),
-                create_token(Token::SemiColon, ";".to_string()),
-                create_token(Token::Return, "return".to_string()),
-                create_token(Token::SizeOf, "sizeof".to_string()),
-                create_token(Token::LeftParen, "(".to_string()),
-                create_token(Token::Variable, "a".to_string()),
-                create_token(Token::RightParen, ")".to_string()),
-                create_token(Token::SemiColon, ";".to_string()),
-                create_token(Token::RightBrace, "}".to_string()),
-                create_token(Token::End, "End".to_string()),
+            create_token(Token::Int, "int".to_string()),
+            create_token(Token::Variable, "main".to_string()),
+            create_token(Token::LeftParen, "(".to_string()),
+            create_token(Token::RightParen, ")".to_string()),
+            create_token(Token::LeftBrace, "{".to_string()),
+            create_token(Token::Char, "char".to_string()),
+            create_token(Token::Variable, "c".to_string()),
+            create_token(Token::Comma, ",".to_string()),
+            create_token(Token::Multi, "*".to_string()),
+            create_token(Token::Variable, "s".to_string()),
+            create_token(Token::SemiColon, ";".to_string()),
+            create_token(Token::RightBrace, "}".to_string()),
+            create_token(Token::End, "End".to_string()),
             ];
             let mut ast = AstGen::new(&data);
             let result = ast.parse();
@@ -6924,9 +11316,9 @@ mod tests {
                     "main".to_string(),
                     Box::new(AstType::Argment(vec![])),
                     Box::new(AstType::Statement(vec![
-                        AstType::Variable(Type::Int, Structure::Array(vec![3]), "a".to_string()),
-                        AstType::Return(Box::new(AstType::SizeOf(24)),)
-                    ]))
+                        AstType::Variable(Type::Char, Structure::Identifier, "c".to_string()),
+                        AstType::Variable(Type::Char, Structure::Pointer, "s".to_string()),
+                    ])),
                 )
             );
         }
@@ -7182,6 +11574,83 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_struct_declaration() {
+        {
+            // struct node; struct node { int v; struct node* next; }; int main() {}
+            let data = vec![
+                create_token(Token::Struct, "struct".to_string()),
+                create_token(Token::Variable, "node".to_string()),
+                create_token(Token::SemiColon, ";".to_string()),
+                create_token(Token::Struct, "struct".to_string()),
+                create_token(Token::Variable, "node".to_string()),
+                create_token(Token::LeftBrace, "{".to_string()),
+                create_token(Token::Int, "int".to_string()),
+                create_token(Token::Variable, "v".to_string()),
+                create_token(Token::SemiColon, ";".to_string()),
+                create_token(Token::Struct, "struct".to_string()),
+                create_token(Token::Variable, "node".to_string()),
+                create_token(Token::Multi, "*".to_string()),
+                create_token(Token::Variable, "next".to_string()),
+                create_token(Token::SemiColon, ";".to_string()),
+                create_token(Token::RightBrace, "}".to_string()),
+                create_token(Token::SemiColon, ";".to_string()),
+                create_token(Token::Int, "int".to_string()),
+                create_token(Token::Variable, "main".to_string()),
+                create_token(Token::LeftParen, "(".to_string()),
+                create_token(Token::RightParen, ")".to_string()),
+                create_token(Token::LeftBrace, "{".to_string()),
+                create_token(Token::RightBrace, "}".to_string()),
+                create_token(Token::End, "End".to_string()),
+                ];
+            let mut ast = AstGen::new(&data);
+            let result = ast.parse();
+
+            // 期待値確認.
+            assert!(ast.get_errors().is_empty());
+            match result.get_tree()[0] {
+                AstType::Global(ref g) => assert_eq!(
+                    g[0],
+                    AstType::Struct(
+                        Box::new(AstType::Variable(Type::Struct("node".to_string()), Structure::Incomplete, "node".to_string())),
+                        vec![]
+                    )
+                ),
+                _ => panic!("not global"),
+            }
+            let sym = ast.get_symbol().search(&Scope::Global, "node").unwrap();
+            assert_eq!(sym.strt, Structure::Struct);
+            assert_eq!(sym.members.len(), 2);
+            assert_eq!(sym.members[1].strt, Structure::Pointer);
+            assert_eq!(sym.members[1].t, Type::Struct("node".to_string()));
+            assert_eq!(sym.type_size(), 16);
+        }
+        {
+            // struct node; int main() { struct node a; }
+            let data = vec![
+                create_token(Token::Struct, "struct".to_string()),
+                create_token(Token::Variable, "node".to_string()),
+                create_token(Token::SemiColon, ";".to_string()),
+                create_token(Token::Int, "int".to_string()),
+                create_token(Token::Variable, "main".to_string()),
+                create_token(Token::LeftParen, "(".to_string()),
+                create_token(Token::RightParen, ")".to_string()),
+                create_token(Token::LeftBrace, "{".to_string()),
+                create_token(Token::Struct, "struct".to_string()),
+                create_token(Token::Variable, "node".to_string()),
+                create_token(Token::Variable, "a".to_string()),
+                create_token(Token::SemiColon, ";".to_string()),
+                create_token(Token::RightBrace, "}".to_string()),
+                create_token(Token::End, "End".to_string()),
+            ];
+            let mut ast = AstGen::new(&data);
+            ast.parse();
+
+            // 期待値確認.
+            assert_eq!(messages(ast.get_errors()), vec![":0:0: variable 'a' has incomplete type 'struct node'".to_string()]);
+        }
+    }
+
     #[test]
     fn test_struct() {
         {
@@ -7490,4 +11959,511 @@ mod tests {
             )
         }
     }
+
+    #[test]
+    fn test_statement_expression() {
+        let parse = |src: &str, gnu: bool| {
+            let mut lexer = LexicalAnalysis::new("test.c".to_string(), src);
+            lexer.read_token();
+            let mut ast = AstGen::new(lexer.get_tokens());
+            ast.set_config(&Config { gnu, ..Default::default() });
+            let result = ast.parse();
+            (result.get_tree()[0].clone(), messages(ast.get_errors()))
+        };
+        let var = |n: &str| Box::new(AstType::Variable(Type::Int, Structure::Identifier, n.to_string()));
+        let src = "int main() { int x = ({ int t = 3; t + 1; }); return x; }";
+
+        // ブロック内の文を保持し、最後の式文の型を結果の型とする(ブロック内の変数は番号付きのシンボル名)
+        let (tree, errors) = parse(src, true);
+        assert!(errors.is_empty());
+        let expr = AstType::StmtExpr(Box::new(AstType::Statement(vec![
+            AstType::Assign(var("t.1"), Box::new(AstType::Factor(3))),
+            AstType::Plus(var("t.1"), Box::new(AstType::Factor(1))),
+        ])));
+        assert_eq!(expr.type_of(), Type::Int);
+        assert_eq!(
+            tree,
+            AstType::FuncDef(
+                Type::Int,
+                Structure::Identifier,
+                "main".to_string(),
+                Box::new(AstType::Argment(vec![])),
+                Box::new(AstType::Statement(vec![
+                    AstType::Assign(var("x"), Box::new(expr)),
+                    AstType::Return(var("x")),
+                ])),
+            )
+        );
+
+        // GNU拡張でない場合はエラー
+        let (_, errors) = parse(src, false);
+        assert_eq!(errors, vec!["test.c:1:22: statement expression requires --gnu".to_string()]);
+    }
+
+    #[test]
+    fn test_check_brackets() {
+        let errors = |src: &str| {
+            let mut lexer = LexicalAnalysis::new("test.c".to_string(), src);
+            lexer.read_token();
+            let mut ast = AstGen::new(lexer.get_tokens());
+            let result = ast.parse();
+            assert!(result.get_tree().is_empty());
+            messages(ast.get_errors())
+        };
+
+        // 閉じられていない括弧は、開き括弧の位置をエラーとする
+        assert_eq!(
+            errors("int main() {\n  if (1) {\n    return 0;\n  }\n"),
+            vec!["test.c:1:12: unclosed '{'".to_string()]
+        );
+        // 対応する開き括弧がない閉じ括弧
+        assert_eq!(
+            errors("int main() {\n  return (1 + 2));\n}"),
+            vec!["test.c:2:17: unmatched ')'".to_string()]
+        );
+        // 種類の異なる括弧
+        assert_eq!(
+            errors("int main() {\n  int a[2};\n}"),
+            vec!["test.c:2:10: mismatched '}' (opening '[' at test.c:2:8)".to_string()]
+        );
+    }
+
+    #[test]
+    fn test_eval_constant() {
+        let n = |v: i64| Box::new(AstType::Factor(v));
+
+        // (1+2)*3
+        assert_eq!(AstType::Multiple(Box::new(AstType::Plus(n(1), n(2))), n(3)).eval_constant(), Some(9));
+        // 5 > 3 ? 10 : 20
+        assert_eq!(
+            AstType::Condition(Box::new(AstType::GreaterThan(n(5), n(3))), n(10), n(20)).eval_constant(),
+            Some(10)
+        );
+        // 単項演算、シフト
+        assert_eq!(AstType::UnMinus(Box::new(AstType::BitReverse(n(0)))).eval_constant(), Some(1));
+        assert_eq!(AstType::LeftShift(n(1), n(4)).eval_constant(), Some(16));
+        assert_eq!(AstType::RightShift(n(-16), n(2)).eval_constant(), Some(-4));
+        assert_eq!(AstType::LeftShift(n(1), n(-1)).eval_constant(), None);
+        assert_eq!(AstType::Division(n(1), n(0)).eval_constant(), None);
+
+        // 変数を含む場合は定数ではない
+        let var = Box::new(AstType::Variable(Type::Int, Structure::Identifier, "a".to_string()));
+        assert_eq!(AstType::Plus(n(1), var).eval_constant(), None);
+    }
+
+    #[test]
+    fn test_goto() {
+        let src = "int main() { goto end; a: return 1; end: goto a; }";
+        let mut lexer = LexicalAnalysis::new("test.c".to_string(), src);
+        lexer.read_token();
+        let mut ast = AstGen::new(lexer.get_tokens());
+        let result = ast.parse();
+        let body = AstType::Statement(vec![
+            AstType::Goto("end".to_string()),
+            AstType::Label("a".to_string()),
+            AstType::Return(Box::new(AstType::Factor(1))),
+            AstType::Label("end".to_string()),
+            AstType::Goto("a".to_string()),
+        ]);
+        assert_eq!(
+            result.get_tree()[0],
+            AstType::FuncDef(
+                Type::Int,
+                Structure::Identifier,
+                "main".to_string(),
+                Box::new(AstType::Argment(vec![])),
+                Box::new(body.clone())
+            )
+        );
+        assert_eq!(body.labels(), vec!["a", "end"]);
+        assert_eq!(body.gotos(), vec!["end", "a"]);
+
+        // 最後のラベル以降でreturnしない場合、returnするとはみなさない
+        assert!(!body.always_returns());
+    }
+
+    #[test]
+    fn test_func_name() {
+        // 関数内の__func__は、関数名の文字列リテラル
+        let src = "int main() { return __func__; }";
+        let mut lexer = LexicalAnalysis::new("test.c".to_string(), src);
+        lexer.read_token();
+        let mut ast = AstGen::new(lexer.get_tokens());
+        let result = ast.parse();
+        assert_eq!(
+            result.get_tree()[0],
+            AstType::FuncDef(
+                Type::Int,
+                Structure::Identifier,
+                "main".to_string(),
+                Box::new(AstType::Argment(vec![])),
+                Box::new(AstType::Statement(vec![AstType::Return(Box::new(AstType::StringLiteral(
+                    "main".to_string(),
+                    0
+                )))]))
+            )
+        );
+
+        // 関数外ではエラー
+        let src = "char* s = __func__; int main() { return 0; }";
+        let mut lexer = LexicalAnalysis::new("test.c".to_string(), src);
+        lexer.read_token();
+        let mut ast = AstGen::new(lexer.get_tokens());
+        ast.parse();
+        assert_eq!(
+            messages(ast.get_errors()),
+            vec!["test.c:1:11: '__func__' is not defined outside of function scope".to_string()]
+        );
+    }
+
+    #[test]
+    fn test_error_recovery() {
+        // 構文エラーの後も、次の文から解析を続け、全てのエラーを報告する
+        let src = "int main() {\n  int a = 1 + ;\n  a = 2 * / 3;\n  if (a == ) { a = 3; }\n  do { a--; } (a > 0);\n  return a;\n}";
+        let mut lexer = LexicalAnalysis::new("test.c".to_string(), src);
+        lexer.read_token();
+        let mut ast = AstGen::new(lexer.get_tokens());
+        let result = ast.parse();
+        assert_eq!(
+            messages(ast.get_errors()),
+            vec![
+                "test.c:2:15: expected expression before ';'".to_string(),
+                "test.c:3:11: expected expression before '/'".to_string(),
+                "test.c:4:12: expected expression before ')'".to_string(),
+                "test.c:5:15: expected 'while' before '('".to_string(),
+            ]
+        );
+
+        // エラー以降の文も構文木に含まれる
+        match result.get_tree()[0] {
+            AstType::FuncDef(_, _, _, _, ref body) => {
+                assert!(matches!(**body, AstType::Statement(ref v) if matches!(v.last(), Some(AstType::Return(_)))))
+            }
+            ref a => panic!("not funcdef {:?}", a),
+        }
+    }
+
+    #[test]
+    fn test_global_error_recovery() {
+        // トップレベルの構文エラーも、次の宣言から解析を続け、全てのエラーを報告する
+        let src = "int a = 1 + ;\nint b = 2 3;\nstruct A { int x; int y * ; int z; };\ntypedef int t u;\nint main() {\n  return b;\n}";
+        let mut lexer = LexicalAnalysis::new("test.c".to_string(), src);
+        lexer.read_token();
+        let mut ast = AstGen::new(lexer.get_tokens());
+        let result = ast.parse();
+        assert_eq!(
+            messages(ast.get_errors()),
+            vec![
+                "test.c:1:13: expected expression before ';'".to_string(),
+                "test.c:2:11: expected ';' before '3'".to_string(),
+                "test.c:3:27: expected expression before ';'".to_string(),
+                "test.c:4:15: expected ';' before 'u'".to_string(),
+            ]
+        );
+
+        // エラー以降の宣言、関数も構文木に含まれる
+        assert!(ast.get_symbol().search(&Scope::Global, "A").is_some());
+        assert!(matches!(result.get_tree().last(), Some(AstType::FuncDef(_, _, ref n, _, _)) if n == "main"));
+    }
+
+    #[test]
+    fn test_for_multiple_declaration() {
+        let src = "int main() { int s = 0; for (int i = 0, *p = &s, j = 3; i < j; i++) { s = s + j; } int a = 1, b = a; return s + b; }";
+        let mut lexer = LexicalAnalysis::new("test.c".to_string(), src);
+        lexer.read_token();
+        let mut ast = AstGen::new(lexer.get_tokens());
+        let result = ast.parse();
+        let var = |n: &str| Box::new(AstType::Variable(Type::Int, Structure::Identifier, n.to_string()));
+        let ptr = Box::new(AstType::Variable(Type::Int, Structure::Pointer, "p".to_string()));
+
+        // 初期化部の型付きの宣言子は、カンマ区切りで複数指定できる
+        let stmts = match result.get_tree()[0] {
+            AstType::FuncDef(_, _, _, _, ref body) => match **body {
+                AstType::Statement(ref s) => s.clone(),
+                _ => panic!("not statement"),
+            },
+            _ => panic!("not funcdef"),
+        };
+        match stmts[1] {
+            AstType::For(ref begin, _, _, _) => assert_eq!(
+                **begin,
+                Some(AstType::Statement(vec![
+                    AstType::Assign(var("i"), Box::new(AstType::Factor(0))),
+                    AstType::Assign(ptr, Box::new(AstType::Address(var("s")))),
+                    AstType::Assign(var("j"), Box::new(AstType::Factor(3))),
+                ]))
+            ),
+            ref s => panic!("not for {:?}", s),
+        }
+
+        // ブロック内の宣言も、宣言子ごとに初期化できる
+        assert_eq!(stmts[2], AstType::Assign(var("a"), Box::new(AstType::Factor(1))));
+        assert_eq!(stmts[3], AstType::Assign(var("b"), var("a")));
+        assert!(ast.get_errors().is_empty());
+    }
+
+    #[test]
+    fn test_function_declaration() {
+        let src = "extern int g; int h = 1; int f(int a); extern int k(); int main() { return f(g) + k() + h; } int f(int a) { return a; }";
+        let mut lexer = LexicalAnalysis::new("test.c".to_string(), src);
+        lexer.read_token();
+        let mut ast = AstGen::new(lexer.get_tokens());
+        let result = ast.parse();
+        let var = |n: &str| AstType::Variable(Type::Int, Structure::Identifier, n.to_string());
+
+        // externの変数は領域を確保せず、シンボルの登録のみ
+        assert_eq!(result.get_tree()[0], AstType::Global(vec![AstType::Assign(Box::new(var("h")), Box::new(AstType::Factor(1)))]));
+        assert!(ast.get_symbol().search(&Scope::Global, "g").is_some());
+
+        // 本体のない関数は宣言とし、後の定義を許容する
+        assert_eq!(
+            result.get_tree()[1],
+            AstType::FuncDecl(Type::Int, Structure::Identifier, "f".to_string(), Box::new(AstType::Argment(vec![var("a")])))
+        );
+        assert_eq!(
+            result.get_tree()[2],
+            AstType::FuncDecl(Type::Int, Structure::Identifier, "k".to_string(), Box::new(AstType::Argment(vec![])))
+        );
+        assert!(matches!(result.get_tree()[4], AstType::FuncDef(_, _, ref n, _, _) if n == "f"));
+        assert!(ast.get_errors().is_empty());
+    }
+
+    #[test]
+    fn test_function_redeclaration() {
+        let errors = |src: &str| {
+            let mut lexer = LexicalAnalysis::new("test.c".to_string(), src);
+            lexer.read_token();
+            let mut ast = AstGen::new(lexer.get_tokens());
+            ast.parse();
+            messages(ast.get_errors())
+        };
+
+        // 定義後の同じ型の宣言、引数を省略した宣言は許容する
+        assert!(errors("int f(int a) { return a; } int f(int b); int f(); int main() { return f(1); }").is_empty());
+
+        // 戻り値、引数の型が異なる宣言はエラー
+        assert_eq!(
+            errors("int f(int a) { return a; }\nint f(char *a);\nchar f(int a);"),
+            vec![
+                "test.c:2:5: conflicting types for 'f'".to_string(),
+                "test.c:3:6: conflicting types for 'f'".to_string(),
+            ]
+        );
+
+        // 定義後の宣言の後でも、再定義はエラー
+        assert_eq!(
+            errors("int f(int a) { return a; } int f(int a);\nint f(int a) { return a; }"),
+            vec!["test.c:2:5: redefinition of 'f'".to_string()]
+        );
+    }
+
+    #[test]
+    fn test_zero_divisor() {
+        let errors = |src: &str| {
+            let mut lexer = LexicalAnalysis::new("test.c".to_string(), src);
+            lexer.read_token();
+            let mut ast = AstGen::new(lexer.get_tokens());
+            ast.parse();
+            messages(ast.get_errors())
+        };
+
+        // 定数0による整数の除算、剰余はエラー
+        assert_eq!(
+            errors("int main() { int x = 4; return x / 0; }"),
+            vec!["test.c:1:34: division by zero".to_string()]
+        );
+        assert_eq!(
+            errors("int main() { int x = 4; return x % (1 - 1); }"),
+            vec!["test.c:1:34: division by zero".to_string()]
+        );
+        assert_eq!(
+            errors("int main() { int x = 4; x /= 0; x %= 0; return x; }"),
+            vec!["test.c:1:27: division by zero".to_string(), "test.c:1:35: division by zero".to_string()]
+        );
+
+        // 0以外の定数、変数、浮動小数点数の除算は対象外
+        assert!(errors("int main() { int x = 4; int y = 0; return x / 2 + x % y; }").is_empty());
+        assert!(errors("int main() { double d = 1.0; d = d / 0; return 0; }").is_empty());
+    }
+
+    #[test]
+    fn test_signed() {
+        let parse = |src: &str| {
+            let mut lexer = LexicalAnalysis::new("test.c".to_string(), src);
+            lexer.read_token();
+            let mut ast = AstGen::new(lexer.get_tokens());
+            let result = ast.parse();
+            (result.get_tree()[0].clone(), messages(ast.get_errors()))
+        };
+
+        // signed char、signed（int省略）は符号付きの型と同じ構文木とする
+        let (plain, _) = parse("int main() { char c; int x; int y; c = 1; return x + y + c; }");
+        let (tree, errors) = parse("int main() { signed char c; signed x; signed int y; c = 1; return x + y + c; }");
+        assert!(errors.is_empty());
+        assert_eq!(tree, plain);
+
+        // 関数の戻り値、引数の型
+        let (plain, _) = parse("char f(int a, char* p) { return a; }");
+        let (tree, errors) = parse("signed char f(signed a, signed char* p) { return a; }");
+        assert!(errors.is_empty());
+        assert_eq!(tree, plain);
+    }
+
+    #[test]
+    fn test_storage_class() {
+        let parse = |src: &str| {
+            let mut lexer = LexicalAnalysis::new("test.c".to_string(), src);
+            lexer.read_token();
+            let mut ast = AstGen::new(lexer.get_tokens());
+            let result = ast.parse();
+            (result.get_tree()[0].clone(), messages(ast.get_errors()))
+        };
+
+        // register、autoは無視し、int宣言と同じ構文木とする
+        let (plain, _) = parse("int main() { int i; int x = 3; i = x; return i; }");
+        let (tree, errors) = parse("int main() { register int i; auto int x = 3; i = x; return i; }");
+        assert!(errors.is_empty());
+        assert_eq!(tree, plain);
+        let (plain, _) = parse("int f(int a) { return a; }");
+        let (tree, errors) = parse("int f(register int a) { return a; }");
+        assert!(errors.is_empty());
+        assert_eq!(tree, plain);
+
+        // 型名がない場合はエラー
+        let (_, errors) = parse("int main() { auto x = 3; return x; }");
+        assert_eq!(errors, vec!["test.c:1:14: expected type name after 'auto'".to_string()]);
+    }
+
+    #[test]
+    fn test_call_non_function() {
+        let errors = |src: &str| {
+            let mut lexer = LexicalAnalysis::new("test.c".to_string(), src);
+            lexer.read_token();
+            let mut ast = AstGen::new(lexer.get_tokens());
+            ast.parse();
+            messages(ast.get_errors())
+        };
+
+        // 関数ではない変数の呼び出しはエラー
+        assert_eq!(
+            errors("int main() { int x; x(); return 0; }"),
+            vec!["test.c:1:21: called object 'x' is not a function or function pointer".to_string()]
+        );
+        assert_eq!(
+            errors("int f; int main() { return f(1, 2) + 3; }"),
+            vec!["test.c:1:28: called object 'f' is not a function or function pointer".to_string()]
+        );
+        assert!(errors("int f() { return 1; } int main() { return f(); }").is_empty());
+    }
+
+    #[test]
+    fn test_pointer_to_pointer() {
+        let mut lexer = LexicalAnalysis::new("test.c".to_string(), "int main(int argc, char** argv) { return argv[1][0]; }");
+        lexer.read_token();
+        let mut ast = AstGen::new(lexer.get_tokens());
+        let result = ast.parse();
+
+        // char**はポインタを要素とするポインタとし、添字参照を重ねる
+        let argv = Box::new(AstType::Variable(Type::Pointer(Box::new(Type::Char)), Structure::Pointer, "argv".to_string()));
+        let elem = AstType::Indirect(Box::new(AstType::Plus(argv.clone(), Box::new(AstType::Factor(1)))));
+        let ch = AstType::Indirect(Box::new(AstType::Plus(Box::new(elem.clone()), Box::new(AstType::Factor(0)))));
+        assert_eq!(
+            result.get_tree()[0],
+            AstType::FuncDef(
+                Type::Int,
+                Structure::Identifier,
+                "main".to_string(),
+                Box::new(AstType::Argment(vec![
+                    AstType::Variable(Type::Int, Structure::Identifier, "argc".to_string()),
+                    *argv,
+                ])),
+                Box::new(AstType::Statement(vec![AstType::Return(Box::new(ch.clone()))])),
+            )
+        );
+        assert!(elem.is_pointer());
+        assert_eq!(elem.type_of(), Type::Char);
+        assert!(!ch.is_pointer());
+        assert_eq!(ch.type_of(), Type::Char);
+    }
+
+    #[test]
+    fn test_pointer_index() {
+        let mut lexer = LexicalAnalysis::new("test.c".to_string(), "int main() { int* p; p[2] = 7; }");
+        lexer.read_token();
+        let mut ast = AstGen::new(lexer.get_tokens());
+        let result = ast.parse();
+
+        // ポインタへの添字参照は*(p + 2)とする（要素サイズ倍はコード生成で行う）
+        let p = Box::new(AstType::Variable(Type::Int, Structure::Pointer, "p".to_string()));
+        assert_eq!(
+            result.get_tree()[0],
+            AstType::FuncDef(
+                Type::Int,
+                Structure::Identifier,
+                "main".to_string(),
+                Box::new(AstType::Argment(vec![])),
+                Box::new(AstType::Statement(vec![
+                    *p.clone(),
+                    AstType::Assign(
+                        Box::new(AstType::Indirect(Box::new(AstType::Plus(p, Box::new(AstType::Factor(2)))))),
+                        Box::new(AstType::Factor(7)),
+                    ),
+                ])),
+            )
+        );
+    }
+
+    #[test]
+    fn test_chained_relation() {
+        let warnings = |src: &str| {
+            let mut lexer = LexicalAnalysis::new("test.c".to_string(), src);
+            lexer.read_token();
+            let mut ast = AstGen::new(lexer.get_tokens());
+            ast.parse();
+            messages(ast.get_warnings())
+        };
+        let msg = "comparisons like 'X<=Y<=Z' do not have their mathematical meaning; did you mean 'X<=Y && Y<=Z'?";
+
+        // 比較結果を、さらに比較する場合は警告
+        assert_eq!(
+            warnings("int f(int a, int b, int c) { return a < b < c; }"),
+            vec![format!("test.c:1:43: {}", msg)]
+        );
+        assert_eq!(
+            warnings("int f(int a, int b, int c) { return (a == b) >= c; }"),
+            vec![format!("test.c:1:46: {}", msg)]
+        );
+
+        // 論理積で結合した比較、算術演算の結果の比較は警告しない
+        assert!(warnings("int f(int a, int b, int c) { return a < b && b < c; }").is_empty());
+        assert!(warnings("int f(int a, int b, int c) { return a + b < c; }").is_empty());
+    }
+
+    #[test]
+    fn test_sizeof_array_param() {
+        let parse = |src: &str| {
+            let mut lexer = LexicalAnalysis::new("test.c".to_string(), src);
+            lexer.read_token();
+            let mut ast = AstGen::new(lexer.get_tokens());
+            ast.parse();
+            (messages(ast.get_warnings()), ast.get_symbol().search(&Scope::Local("f".to_string()), "a"))
+        };
+        let msg = "sizeof on array function parameter 'a' will return size of pointer";
+
+        // 配列として宣言された引数はポインタとなり、sizeofは警告
+        let (warnings, sym) = parse("int f(int a[]) { return sizeof(a); }");
+        assert_eq!(warnings, vec![format!("test.c:1:32: {}", msg)]);
+        let sym = sym.unwrap();
+        assert_eq!(sym.strt, Structure::Pointer);
+        assert!(sym.is_array_param);
+        let (warnings, _) = parse("int f(int a[3]) { return sizeof a; }");
+        assert_eq!(warnings, vec![format!("test.c:1:33: {}", msg)]);
+
+        // ローカル変数の配列、ポインタとして宣言された引数は警告しない
+        let (warnings, sym) = parse("int f() { int a[3]; return sizeof(a); }");
+        assert!(warnings.is_empty());
+        assert!(!sym.unwrap().is_array_param);
+        let (warnings, _) = parse("int f(int* a) { return sizeof(a); }");
+        assert!(warnings.is_empty());
+    }
 }
@@ -0,0 +1,12 @@
+use ast::AstTree;
+
+// コード生成部の共通インターフェース.
+//
+// 抽象構文木から出力形式(アセンブラ、LLVM IR)のテキストを生成する
+pub trait CodeGen {
+    // コード生成開始
+    fn exec(&mut self, tree: &AstTree);
+
+    // 生成結果取得
+    fn get_inst(&self) -> String;
+}
@@ -1,15 +1,54 @@
 use std::process::Command;
 
+// 最適化レベル.
+#[derive(Debug, Clone, Copy, Default, PartialEq, PartialOrd)]
+pub enum OptLevel {
+    #[default]
+    O0, // 最適化なし
+    O1, // 定数畳み込み、不要コード削除、のぞき穴最適化
+    O2, // O1に加え、強度削減、自己末尾呼び出しの除去、レジスタ割当
+}
+
+// 準拠する規格.
+#[derive(Debug, Clone, Copy, Default, PartialEq)]
+pub enum Std {
+    C89, // C99以降の構文はエラー
+    #[default]
+    C99,
+}
+
 // 設定データ.
-pub struct Config {}
+#[derive(Debug, Clone, Default)]
+pub struct Config {
+    pub gnu: bool,                      // GNU拡張を許可するか
+    pub emit_tokens: bool,              // プリプロセス後のトークン列を出力するか
+    pub emit_symbols: bool,             // シンボルテーブルを出力するか
+    pub emit_callgraph: bool,           // コールグラフを出力するか
+    pub emit_ast_json: bool,            // 抽象構文木をJSONで出力するか
+    pub emit_stack_usage: bool,         // 関数ごとのスタック使用量を出力するか
+    pub gc_functions: bool,             // 未使用の関数を全て削除するか
+    pub trapv: bool,                    // 符号付き演算のオーバーフローを検査するか
+    pub bounds_check: bool,             // 配列の添字の範囲を検査するか
+    pub defines: Vec<(String, String)>, // コマンドラインで定義するマクロ(-D)
+    pub opt_level: OptLevel,            // 最適化レベル(--O0/--O1/--O2)
+    pub annotate_ast: bool,             // 生成元の構文木のノード種別をコメント出力するか
+    pub std: Std,                       // 準拠する規格(--std=c89/--std=c99)
+    pub emit_llvm: bool,                // アセンブラの代わりにLLVM IRを出力するか
+}
 
 impl Config {
+    // -DNAME=value形式のマクロ定義を追加(値省略時は1).
+    pub fn add_define(&mut self, arg: &str) {
+        let (name, value) = match arg.split_once('=') {
+            Some((n, v)) => (n, v),
+            None => (arg, "1"),
+        };
+        self.defines.push((name.to_string(), value.to_string()));
+    }
+
     pub fn is_mac() -> bool {
         // macで動作しているかチェック.
         let uname = Command::new("uname").output().expect("uname is error");
-        match String::from_utf8_lossy(&uname.stdout).find("Darwin") {
-            Some(_) => true,
-            _ => false,
-        }
+        String::from_utf8_lossy(&uname.stdout).contains("Darwin")
     }
 }
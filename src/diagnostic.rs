@@ -0,0 +1,63 @@
+use std::fmt;
+use token::PosInfo;
+
+// 診断の重大度.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Severity {
+    Error,   // コンパイルを継続できない
+    Warning, // コンパイルは継続する
+}
+
+// 診断データ.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Diagnostic {
+    pub severity: Severity,   // 重大度
+    pub message: String,      // メッセージ（位置情報を除く）
+    pub pos: Option<PosInfo>, // 位置情報（位置を特定できない場合はNone）
+}
+
+impl Diagnostic {
+    // エラー生成(位置を特定できない場合はNone).
+    pub fn error(pos: Option<PosInfo>, message: String) -> Self {
+        Diagnostic { severity: Severity::Error, message, pos }
+    }
+
+    // 警告生成(位置を特定できない場合はNone).
+    pub fn warning(pos: Option<PosInfo>, message: String) -> Self {
+        Diagnostic { severity: Severity::Warning, message, pos }
+    }
+
+    // エラーであるか
+    pub fn is_error(&self) -> bool {
+        self.severity == Severity::Error
+    }
+}
+
+impl fmt::Display for Diagnostic {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self.pos {
+            Some(ref pos) => write!(f, "{}: {}", pos, self.message),
+            None => write!(f, "{}", self.message),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_display() {
+        // 位置情報付きの診断は、位置とメッセージを出力する
+        let d = Diagnostic::error(Some(PosInfo::new("stdin".to_string(), 2, 14)), "division by zero".to_string());
+        assert_eq!(d.severity, Severity::Error);
+        assert!(d.is_error());
+        assert_eq!(d.to_string(), "stdin:2:14: division by zero");
+
+        // 位置情報がない場合は、メッセージのみ
+        let d = Diagnostic::warning(None, "control reaches end of non-void function: f".to_string());
+        assert_eq!(d.pos, None);
+        assert!(!d.is_error());
+        assert_eq!(d.to_string(), "control reaches end of non-void function: f");
+    }
+}
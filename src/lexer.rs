@@ -1,33 +1,67 @@
-use token::{Token, TokenInfo};
+use std::borrow::Cow;
+use token::{PosInfo, Token, TokenInfo};
 
 #[doc = " 字句解析"]
 pub struct LexicalAnalysis<'a> {
     name: String,
-    input: &'a str,
+    input: Cow<'a, str>,
     row: usize,
     col: usize,
     pos: usize,
     tokens: Vec<TokenInfo>,
+    line_comments: Vec<PosInfo>, // //コメントの位置
+    splices: Vec<usize>,         // 行継続(\+改行)を取り除いた位置
 }
 
 impl<'a> LexicalAnalysis<'a> {
     // コンストラクタ.
-    pub fn new(n: String, i: &'a str) -> LexicalAnalysis {
+    pub fn new(n: String, i: &'a str) -> LexicalAnalysis<'a> {
+        let (input, splices) = LexicalAnalysis::splice_lines(i);
         LexicalAnalysis {
             name: n,
-            input: i,
+            input,
             row: 1,
             col: 0,
             pos: 0,
             tokens: vec![],
+            line_comments: vec![],
+            splices,
         }
     }
 
+    // 行継続.
+    //
+    // 行末の\と改行を取り除き、次の行と連結する（取り除いた位置は行番号の補正に使う）
+    fn splice_lines(i: &'a str) -> (Cow<'a, str>, Vec<usize>) {
+        if !i.contains("\\\n") {
+            return (Cow::Borrowed(i), vec![]);
+        }
+        let mut s = String::new();
+        let mut splices = vec![];
+        let mut chars = i.chars().peekable();
+        let mut n = 0;
+        while let Some(c) = chars.next() {
+            if c == '\\' && chars.peek() == Some(&'\n') {
+                chars.next();
+                splices.push(n);
+            } else {
+                s.push(c);
+                n += 1;
+            }
+        }
+        (Cow::Owned(s), splices)
+    }
+
     // トークン群取得.
     pub fn get_tokens(&self) -> &Vec<TokenInfo> {
         &self.tokens
     }
 
+    // //コメントの位置取得.
+    pub fn get_line_comments(&self) -> &Vec<PosInfo> {
+        &self.line_comments
+    }
+
     // トークン読み込み.
     pub fn read_token(&mut self) {
         // 終了まで読み込み、字句解析を行う.
@@ -50,6 +84,12 @@ impl<'a> LexicalAnalysis<'a> {
                             t
                         } else if let Some(t) = self.generate_struct(s) {
                             t
+                        } else if let Some(t) = self.generate_static(s) {
+                            t
+                        } else if let Some(t) = self.generate_storage_class(s) {
+                            t
+                        } else if let Some(t) = self.generate_typedef(s) {
+                            t
                         } else {
                             self.generate_variable_token(s)
                         }
@@ -137,6 +177,10 @@ impl<'a> LexicalAnalysis<'a> {
                             let token = self.create_token(Token::MinusAssign, "-=".to_string());
                             self.skip(1);
                             token
+                        } else if self.is_arrow(v) {
+                            let token = self.create_token(Token::Arrow, "->".to_string());
+                            self.skip(1);
+                            token
                         } else {
                             self.create_token(Token::Minus, v.to_string())
                         }
@@ -181,6 +225,8 @@ impl<'a> LexicalAnalysis<'a> {
                     ':' => self.create_token(Token::Colon, v.to_string()),
                     ';' => self.create_token(Token::SemiColon, v.to_string()),
                     ',' => self.create_token(Token::Comma, v.to_string()),
+                    '#' => self.create_token(Token::Sharp, v.to_string()),
+                    '.' => self.create_token(Token::Dot, v.to_string()),
                     '0' | '1' | '2' | '3' | '4' | '5' | '6' | '7' | '8' | '9' => {
                         self.generate_number_token(v)
                     }
@@ -238,12 +284,10 @@ impl<'a> LexicalAnalysis<'a> {
     fn skip_ascii_whitespace(&mut self) {
         while !self.is_eof() && self.read().is_ascii_whitespace() {
             let next = self.read_string(1);
+            self.skip(1);
             if self.is_linefeed(&next) {
-                // 行とカラムを更新
-                self.row += 1;
-                self.col = 0;
+                self.new_line();
             }
-            self.skip(1);
         }
     }
 
@@ -251,6 +295,7 @@ impl<'a> LexicalAnalysis<'a> {
     fn skip_comment(&mut self) {
         if self.read_string(2) == "//" {
             // 改行コードまで読み飛ばし
+            self.line_comments.push(PosInfo::new(self.name.clone(), self.row, self.col + 1));
             self.skip(2);
             let mut next = self.read_string(1);
             while !self.is_eof() && !self.is_linefeed(&next) {
@@ -259,8 +304,7 @@ impl<'a> LexicalAnalysis<'a> {
             }
             if self.is_linefeed(&next) {
                 self.skip(1);
-                self.row += 1;
-                self.col = 0;
+                self.new_line();
 
                 // 改行後、先頭に空白がある可能性を考慮
                 self.skip_ascii_whitespace();
@@ -268,6 +312,16 @@ impl<'a> LexicalAnalysis<'a> {
         }
     }
 
+    // 行とカラムを更新.
+    //
+    // 論理行は継続した行を含むため、連結した行数分も進める
+    fn new_line(&mut self) {
+        let n = self.splices.iter().take_while(|p| **p < self.pos).count();
+        self.splices.drain(..n);
+        self.row += 1 + n;
+        self.col = 0;
+    }
+
     // 文字をスキップ.
     fn skip(&mut self, i: usize) {
         self.pos += i;
@@ -287,7 +341,7 @@ impl<'a> LexicalAnalysis<'a> {
 
     // 変数候補チェック.
     fn is_variable(&self, c: char) -> bool {
-        c.is_alphabetic() || c == '_' || c.is_digit(10)
+        c.is_alphabetic() || c == '_' || c.is_ascii_digit()
     }
 
     // 文字列トークン生成
@@ -309,15 +363,109 @@ impl<'a> LexicalAnalysis<'a> {
     }
 
     // 数値トークン生成.
+    //
+    // 小数点、指数部を含む場合は浮動小数点数とする
     fn generate_number_token(&mut self, v: char) -> TokenInfo {
+        if v == '0' && matches!(self.read_string(2).as_bytes(), [b'x' | b'X', c] if c.is_ascii_hexdigit() || *c == b'.') {
+            self.skip(1);
+            return self.generate_hex_number_token();
+        }
         let mut s = String::new();
         s.push(v);
+        self.read_digits(&mut s);
+
+        // 小数部
+        let mut is_float = false;
+        if !self.is_eof() && self.read() == '.' {
+            is_float = true;
+            self.read_digits_with_prefix(&mut s, 1);
+        }
+
+        // 指数部(e10, e-3等)
+        let n = self.exponent_prefix_len();
+        if n > 0 {
+            is_float = true;
+            self.read_digits_with_prefix(&mut s, n);
+        }
+
+        if is_float {
+            self.create_token(Token::FloatNumber, s)
+        } else {
+            self.create_token(Token::Number, s)
+        }
+    }
 
-        while !self.is_eof() && self.read().is_digit(10) {
+    // 16進数の数値トークン生成(0xの後から読み出す).
+    //
+    // 2進指数部(p)を含む場合は浮動小数点数とし、値は10進数の文字列とする
+    fn generate_hex_number_token(&mut self) -> TokenInfo {
+        let mut digits = String::new();
+        self.read_hex_digits(&mut digits);
+
+        // 小数部は、仮数の桁として読み出し、指数で桁をずらす
+        let mut frac_len = 0;
+        if !self.is_eof() && self.read() == '.' {
+            self.skip(1);
+            let l = digits.len();
+            self.read_hex_digits(&mut digits);
+            frac_len = digits.len() - l;
+        }
+        let mantissa = u64::from_str_radix(&digits, 16).unwrap_or(0);
+
+        // 2進指数部(p10, p-3等)
+        let n = self.binary_exponent_prefix_len();
+        if n == 0 {
+            return self.create_token(Token::Number, mantissa.to_string());
+        }
+        let mut exp = self.read_string(n);
+        self.skip(n);
+        exp.remove(0);
+        self.read_digits(&mut exp);
+        let e = exp.parse::<i32>().unwrap_or(0) - 4 * frac_len as i32;
+        self.create_token(Token::FloatNumber, format!("{:?}", mantissa as f64 * 2f64.powi(e)))
+    }
+
+    // 連続する16進数字を読み出す
+    fn read_hex_digits(&mut self, s: &mut String) {
+        while !self.is_eof() && self.read().is_ascii_hexdigit() {
             let n = self.next();
-            s.push(n.expect("lexer.rs(generate_number_token): cannot read next char"));
+            s.push(n.expect("lexer.rs(read_hex_digits): cannot read next char"));
+        }
+    }
+
+    // 2進指数部の接頭辞(p、p+、p-)の文字数取得(指数部でなければ0)
+    fn binary_exponent_prefix_len(&mut self) -> usize {
+        let s: Vec<char> = self.read_string(3).chars().collect();
+        match s.as_slice() {
+            ['p' | 'P', d, ..] if d.is_ascii_digit() => 1,
+            ['p' | 'P', '+' | '-', d] if d.is_ascii_digit() => 2,
+            _ => 0,
+        }
+    }
+
+    // 連続する数字を読み出す
+    fn read_digits(&mut self, s: &mut String) {
+        while !self.is_eof() && self.read().is_ascii_digit() {
+            let n = self.next();
+            s.push(n.expect("lexer.rs(read_digits): cannot read next char"));
+        }
+    }
+
+    // 指定文字数の接頭辞に続く数字を読み出す
+    fn read_digits_with_prefix(&mut self, s: &mut String, n: usize) {
+        s.push_str(&self.read_string(n));
+        self.skip(n);
+        self.read_digits(s);
+    }
+
+    // 指数部の接頭辞(e、e+、e-)の文字数取得(指数部でなければ0)
+    fn exponent_prefix_len(&mut self) -> usize {
+        let s: Vec<char> = self.read_string(3).chars().collect();
+        match s.as_slice() {
+            ['e' | 'E', d, ..] if d.is_ascii_digit() => 1,
+            ['e' | 'E', '+' | '-', d] if d.is_ascii_digit() => 2,
+            _ => 0,
         }
-        self.create_token(Token::Number, s)
     }
 
     // 変数トークン生成.
@@ -394,6 +542,11 @@ impl<'a> LexicalAnalysis<'a> {
         v == '-' && self.read() == '='
     }
 
+    // アロー演算子チェック
+    fn is_arrow(&self, v: char) -> bool {
+        v == '-' && self.read() == '>'
+    }
+
     // multiple assign演算子
     fn is_multiple_assign(&self, v: char) -> bool {
         v == '*' && self.read() == '='
@@ -455,6 +608,30 @@ impl<'a> LexicalAnalysis<'a> {
             Some(self.generate_type_int())
         } else if self.is_type_char(c) {
             Some(self.generate_type_char())
+        } else if self.is_type_unsigned(c) {
+            let t = Some(self.create_token(Token::Unsigned, "unsigned".to_string()));
+            self.skip(7);
+            t
+        } else if self.is_keyword(c, "signed") {
+            let t = Some(self.create_token(Token::Signed, "signed".to_string()));
+            self.skip(5);
+            t
+        } else if self.is_type_void(c) {
+            let t = Some(self.create_token(Token::Void, "void".to_string()));
+            self.skip(3);
+            t
+        } else if self.is_type_double(c) {
+            let t = Some(self.create_token(Token::Double, "double".to_string()));
+            self.skip(5);
+            t
+        } else if self.is_keyword(c, "float") {
+            let t = Some(self.create_token(Token::Float, "float".to_string()));
+            self.skip(4);
+            t
+        } else if self.is_keyword(c, "short") {
+            let t = Some(self.create_token(Token::Short, "short".to_string()));
+            self.skip(4);
+            t
         } else {
             None
         }
@@ -472,6 +649,36 @@ impl<'a> LexicalAnalysis<'a> {
             && !self.is_variable(l.expect("lexer.rs(is_type_char): read error"))
     }
 
+    // unsigned修飾子チェック
+    fn is_type_unsigned(&mut self, c: char) -> bool {
+        let s = self.read_string(8);
+        let l = s.chars().last();
+        c == 'u'
+            && s.len() == 8
+            && &s[0..7] == "nsigned"
+            && !self.is_variable(l.expect("lexer.rs(is_type_unsigned): read error"))
+    }
+
+    // void型チェック
+    fn is_type_void(&mut self, c: char) -> bool {
+        let s = self.read_string(4);
+        let l = s.chars().last();
+        c == 'v'
+            && s.len() == 4
+            && &s[0..3] == "oid"
+            && !self.is_variable(l.expect("lexer.rs(is_type_void): read error"))
+    }
+
+    // double型チェック
+    fn is_type_double(&mut self, c: char) -> bool {
+        let s = self.read_string(6);
+        let l = s.chars().last();
+        c == 'd'
+            && s.len() == 6
+            && &s[0..5] == "ouble"
+            && !self.is_variable(l.expect("lexer.rs(is_type_double): read error"))
+    }
+
     // int型チェック
     fn is_type_int(&mut self, c: char) -> bool {
         let s = self.read_string(3);
@@ -506,12 +713,16 @@ impl<'a> LexicalAnalysis<'a> {
             && !self.is_variable(l.expect("lexer.rs(generate_struct): read error"))
     }
 
-    // structトークン作成
+    // struct/unionトークン作成
     fn generate_struct(&mut self, c: char) -> Option<TokenInfo> {
         if self.is_struct(c) {
             let t = Some(self.create_token(Token::Struct, "struct".to_string()));
             self.skip(5);
             t
+        } else if self.is_keyword(c, "union") {
+            let t = Some(self.create_token(Token::Union, "union".to_string()));
+            self.skip(4);
+            t
         } else {
             None
         }
@@ -527,6 +738,70 @@ impl<'a> LexicalAnalysis<'a> {
             && !self.is_variable(l.expect("lexer.rs(is_struct): read error"))
     }
 
+    // staticトークン作成
+    fn generate_static(&mut self, c: char) -> Option<TokenInfo> {
+        if self.is_static(c) {
+            let t = Some(self.create_token(Token::Static, "static".to_string()));
+            self.skip(5);
+            t
+        } else {
+            None
+        }
+    }
+
+    // static文字列判定
+    fn is_static(&mut self, c: char) -> bool {
+        let s = self.read_string(6);
+        let l = s.chars().last();
+        c == 's'
+            && s.len() == 6
+            && "tatic" == &s[0..5]
+            && !self.is_variable(l.expect("lexer.rs(is_static): read error"))
+    }
+
+    // register/autoトークン作成
+    fn generate_storage_class(&mut self, c: char) -> Option<TokenInfo> {
+        for (t, k) in [(Token::Register, "register"), (Token::Auto, "auto"), (Token::Extern, "extern")] {
+            if self.is_keyword(c, k) {
+                let token = self.create_token(t, k.to_string());
+                self.skip(k.len() - 1);
+                return Some(token);
+            }
+        }
+        None
+    }
+
+    // キーワード文字列判定(キーワードの後に識別子の文字が続かないこと)
+    fn is_keyword(&mut self, c: char, k: &str) -> bool {
+        let s = self.read_string(k.len());
+        let l = s.chars().last();
+        k.starts_with(c)
+            && s.len() == k.len()
+            && k[1..] == s[0..k.len() - 1]
+            && !self.is_variable(l.expect("lexer.rs(is_keyword): read error"))
+    }
+
+    // typedefトークン作成
+    fn generate_typedef(&mut self, c: char) -> Option<TokenInfo> {
+        if self.is_typedef(c) {
+            let t = Some(self.create_token(Token::Typedef, "typedef".to_string()));
+            self.skip(6);
+            t
+        } else {
+            None
+        }
+    }
+
+    // typedef文字列判定
+    fn is_typedef(&mut self, c: char) -> bool {
+        let s = self.read_string(7);
+        let l = s.chars().last();
+        c == 't'
+            && s.len() == 7
+            && "ypedef" == &s[0..6]
+            && !self.is_variable(l.expect("lexer.rs(is_typedef): read error"))
+    }
+
     // ポインタ演算子が存在するか.
     fn is_pointer(&mut self) -> bool {
         // 空白は読み飛ばして、ポインタ型があるかチェック.
@@ -568,6 +843,22 @@ impl<'a> LexicalAnalysis<'a> {
             let s = Some(self.create_token(Token::Return, "return".to_string()));
             self.skip(5);
             s
+        } else if self.is_statement_switch(c) {
+            let s = Some(self.create_token(Token::Switch, "switch".to_string()));
+            self.skip(5);
+            s
+        } else if self.is_statement_case(c) {
+            let s = Some(self.create_token(Token::Case, "case".to_string()));
+            self.skip(3);
+            s
+        } else if self.is_statement_default(c) {
+            let s = Some(self.create_token(Token::Default, "default".to_string()));
+            self.skip(6);
+            s
+        } else if self.is_keyword(c, "goto") {
+            let s = Some(self.create_token(Token::Goto, "goto".to_string()));
+            self.skip(3);
+            s
         } else {
             None
         }
@@ -652,6 +943,36 @@ impl<'a> LexicalAnalysis<'a> {
             && "eturn" == &s[0..5]
             && !self.is_variable(l.expect("lexer.rs(is_statement_return): read error"))
     }
+
+    // switch statementチェック.
+    fn is_statement_switch(&mut self, v: char) -> bool {
+        let s = self.read_string(6);
+        let l = s.chars().last();
+        v == 's'
+            && s.len() == 6
+            && "witch" == &s[0..5]
+            && !self.is_variable(l.expect("lexer.rs(is_statement_switch): read error"))
+    }
+
+    // caseラベルチェック.
+    fn is_statement_case(&mut self, v: char) -> bool {
+        let s = self.read_string(4);
+        let l = s.chars().last();
+        v == 'c'
+            && s.len() == 4
+            && "ase" == &s[0..3]
+            && !self.is_variable(l.expect("lexer.rs(is_statement_case): read error"))
+    }
+
+    // defaultラベルチェック.
+    fn is_statement_default(&mut self, v: char) -> bool {
+        let s = self.read_string(7);
+        let l = s.chars().last();
+        v == 'd'
+            && s.len() == 7
+            && "efault" == &s[0..6]
+            && !self.is_variable(l.expect("lexer.rs(is_statement_default): read error"))
+    }
 }
 
 #[cfg(test)]
@@ -1579,6 +1900,144 @@ mod tests {
                 lexer.get_tokens()[5]
             );
         }
+        {
+            let input = "void f(); voids".to_string();
+            let mut lexer = LexicalAnalysis::new("test.c".to_string(), &input);
+
+            lexer.read_token();
+            let expected = [
+                (Token::Void, "void", 1),
+                (Token::Variable, "f", 6),
+                (Token::LeftParen, "(", 7),
+                (Token::RightParen, ")", 8),
+                (Token::SemiColon, ";", 9),
+                (Token::Variable, "voids", 11),
+            ];
+            expected.iter().enumerate().for_each(|(i, (t, v, col))| {
+                assert_eq!(
+                    TokenInfo::new(t.clone(), v.to_string(), ("test.c".to_string(), 1, *col)),
+                    lexer.get_tokens()[i]
+                );
+            });
+        }
+        {
+            let input = "signed char c; signed x; signedx".to_string();
+            let mut lexer = LexicalAnalysis::new("test.c".to_string(), &input);
+
+            lexer.read_token();
+            let expected = [
+                (Token::Signed, "signed", 1),
+                (Token::Char, "char", 8),
+                (Token::Variable, "c", 13),
+                (Token::SemiColon, ";", 14),
+                (Token::Signed, "signed", 16),
+                (Token::Variable, "x", 23),
+                (Token::SemiColon, ";", 24),
+                (Token::Variable, "signedx", 26),
+            ];
+            expected.iter().enumerate().for_each(|(i, (t, v, col))| {
+                assert_eq!(
+                    TokenInfo::new(t.clone(), v.to_string(), ("test.c".to_string(), 1, *col)),
+                    lexer.get_tokens()[i]
+                );
+            });
+        }
+        {
+            let input = "unsigned int a; unsigned b; unsignedc".to_string();
+            let mut lexer = LexicalAnalysis::new("test.c".to_string(), &input);
+
+            lexer.read_token();
+            let expected = [
+                (Token::Unsigned, "unsigned", 1),
+                (Token::Int, "int", 10),
+                (Token::Variable, "a", 14),
+                (Token::SemiColon, ";", 15),
+                (Token::Unsigned, "unsigned", 17),
+                (Token::Variable, "b", 26),
+                (Token::SemiColon, ";", 27),
+                (Token::Variable, "unsignedc", 29),
+            ];
+            expected.iter().enumerate().for_each(|(i, (t, v, col))| {
+                assert_eq!(
+                    TokenInfo::new(t.clone(), v.to_string(), ("test.c".to_string(), 1, *col)),
+                    lexer.get_tokens()[i]
+                );
+            });
+        }
+        {
+            let input = "short s; shorts".to_string();
+            let mut lexer = LexicalAnalysis::new("test.c".to_string(), &input);
+
+            lexer.read_token();
+            let expected = [
+                (Token::Short, "short", 1),
+                (Token::Variable, "s", 7),
+                (Token::SemiColon, ";", 8),
+                (Token::Variable, "shorts", 10),
+            ];
+            expected.iter().enumerate().for_each(|(i, (t, v, col))| {
+                assert_eq!(
+                    TokenInfo::new(t.clone(), v.to_string(), ("test.c".to_string(), 1, *col)),
+                    lexer.get_tokens()[i]
+                );
+            });
+        }
+    }
+
+    #[test]
+    fn test_storage_class() {
+        let input = "register int a; auto int b; registers autox extern externs".to_string();
+        let mut lexer = LexicalAnalysis::new("test.c".to_string(), &input);
+
+        lexer.read_token();
+        let expected = [
+            (Token::Register, "register", 1),
+            (Token::Int, "int", 10),
+            (Token::Variable, "a", 14),
+            (Token::SemiColon, ";", 15),
+            (Token::Auto, "auto", 17),
+            (Token::Int, "int", 22),
+            (Token::Variable, "b", 26),
+            (Token::SemiColon, ";", 27),
+            (Token::Variable, "registers", 29),
+            (Token::Variable, "autox", 39),
+            (Token::Extern, "extern", 45),
+            (Token::Variable, "externs", 52),
+        ];
+        expected.iter().enumerate().for_each(|(i, (t, v, col))| {
+            assert_eq!(
+                TokenInfo::new(t.clone(), v.to_string(), ("test.c".to_string(), 1, *col)),
+                lexer.get_tokens()[i]
+            );
+        });
+    }
+
+    #[test]
+    fn test_line_continuation() {
+        let input = "int a = 1 + \\\n  2;\nreturn \"ab\\\ncd\";\nb;".to_string();
+        let mut lexer = LexicalAnalysis::new("test.c".to_string(), &input);
+
+        // 行末の\で連結した行は、一つの論理行として字句解析する
+        lexer.read_token();
+        let expected = [
+            (Token::Int, "int", 1, 1),
+            (Token::Variable, "a", 1, 5),
+            (Token::Assign, "=", 1, 7),
+            (Token::Number, "1", 1, 9),
+            (Token::Plus, "+", 1, 11),
+            (Token::Number, "2", 1, 15),
+            (Token::SemiColon, ";", 1, 16),
+            (Token::Return, "return", 3, 1),
+            (Token::StringLiteral, "abcd", 3, 8),
+            (Token::SemiColon, ";", 3, 14),
+            (Token::Variable, "b", 5, 1),
+        ];
+        expected.iter().enumerate().for_each(|(i, (t, v, row, col))| {
+            assert_eq!(
+                TokenInfo::new(t.clone(), v.to_string(), ("test.c".to_string(), *row, *col)),
+                lexer.get_tokens()[i]
+            );
+        });
     }
 
     #[test]
@@ -2141,6 +2600,117 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_member_access() {
+        let input = "a->b.c".to_string();
+        let mut lexer = LexicalAnalysis::new("test.c".to_string(), &input);
+
+        lexer.read_token();
+        let expected = [
+            (Token::Variable, "a", 1),
+            (Token::Arrow, "->", 2),
+            (Token::Variable, "b", 4),
+            (Token::Dot, ".", 5),
+            (Token::Variable, "c", 6),
+        ];
+        expected.iter().enumerate().for_each(|(i, (t, v, col))| {
+            assert_eq!(
+                TokenInfo::new(t.clone(), v.to_string(), ("test.c".to_string(), 1, *col)),
+                lexer.get_tokens()[i]
+            );
+        });
+    }
+
+    #[test]
+    fn test_switch() {
+        let input = "switch (a) { case 1: default: } cases".to_string();
+        let mut lexer = LexicalAnalysis::new("test.c".to_string(), &input);
+
+        lexer.read_token();
+        let expected = [
+            (Token::Switch, "switch", 1),
+            (Token::LeftParen, "(", 8),
+            (Token::Variable, "a", 9),
+            (Token::RightParen, ")", 10),
+            (Token::LeftBrace, "{", 12),
+            (Token::Case, "case", 14),
+            (Token::Number, "1", 19),
+            (Token::Colon, ":", 20),
+            (Token::Default, "default", 22),
+            (Token::Colon, ":", 29),
+            (Token::RightBrace, "}", 31),
+            (Token::Variable, "cases", 33),
+        ];
+        expected.iter().enumerate().for_each(|(i, (t, v, col))| {
+            assert_eq!(
+                TokenInfo::new(t.clone(), v.to_string(), ("test.c".to_string(), 1, *col)),
+                lexer.get_tokens()[i]
+            );
+        });
+    }
+
+    #[test]
+    fn test_goto() {
+        let input = "goto end; end: gotos".to_string();
+        let mut lexer = LexicalAnalysis::new("test.c".to_string(), &input);
+
+        lexer.read_token();
+        let expected = [
+            (Token::Goto, "goto", 1),
+            (Token::Variable, "end", 6),
+            (Token::SemiColon, ";", 9),
+            (Token::Variable, "end", 11),
+            (Token::Colon, ":", 14),
+            (Token::Variable, "gotos", 16),
+        ];
+        expected.iter().enumerate().for_each(|(i, (t, v, col))| {
+            assert_eq!(
+                TokenInfo::new(t.clone(), v.to_string(), ("test.c".to_string(), 1, *col)),
+                lexer.get_tokens()[i]
+            );
+        });
+    }
+
+    #[test]
+    fn test_shift_and_relational() {
+        // 隣接する>>、<<はシフト演算子、空白を挟む場合は比較演算子二つ
+        let data = [
+            ("a >> b", Token::RightShift, ">>", Token::GreaterThan, ">"),
+            ("a << b", Token::LeftShift, "<<", Token::LessThan, "<"),
+        ];
+        data.iter().for_each(|(input, shift, shift_v, rel, rel_v)| {
+            let mut lexer = LexicalAnalysis::new("test.c".to_string(), input);
+            lexer.read_token();
+            let expected = [
+                (Token::Variable, "a", 1),
+                (shift.clone(), shift_v, 3),
+                (Token::Variable, "b", 6),
+            ];
+            expected.iter().enumerate().for_each(|(i, (t, v, col))| {
+                assert_eq!(
+                    TokenInfo::new(t.clone(), v.to_string(), ("test.c".to_string(), 1, *col)),
+                    lexer.get_tokens()[i]
+                );
+            });
+
+            let input = input.replace(shift_v, &format!("{} {}", rel_v, rel_v));
+            let mut lexer = LexicalAnalysis::new("test.c".to_string(), &input);
+            lexer.read_token();
+            let expected = [
+                (Token::Variable, "a", 1),
+                (rel.clone(), rel_v, 3),
+                (rel.clone(), rel_v, 5),
+                (Token::Variable, "b", 7),
+            ];
+            expected.iter().enumerate().for_each(|(i, (t, v, col))| {
+                assert_eq!(
+                    TokenInfo::new(t.clone(), v.to_string(), ("test.c".to_string(), 1, *col)),
+                    lexer.get_tokens()[i]
+                );
+            });
+        });
+    }
+
     #[test]
     fn test_comment() {
         {
@@ -2186,7 +2756,7 @@ mod tests {
                 lexer.get_tokens()[6]
             );
             assert_eq!(
-                TokenInfo::new(Token::End, "End".to_string(), ("test.c".to_string(), 2, 13)),
+                TokenInfo::new(Token::End, "End".to_string(), ("test.c".to_string(), 2, 12)),
                 lexer.get_tokens()[7]
             );
         }
@@ -2563,6 +3133,109 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_typedef() {
+        let input = "typedef int t; typedefs".to_string();
+        let mut lexer = LexicalAnalysis::new("test.c".to_string(), &input);
+
+        lexer.read_token();
+        let expected = [
+            (Token::Typedef, "typedef", 1),
+            (Token::Int, "int", 9),
+            (Token::Variable, "t", 13),
+            (Token::SemiColon, ";", 14),
+            (Token::Variable, "typedefs", 16),
+        ];
+        expected.iter().enumerate().for_each(|(i, (t, v, col))| {
+            assert_eq!(
+                TokenInfo::new(t.clone(), v.to_string(), ("test.c".to_string(), 1, *col)),
+                lexer.get_tokens()[i]
+            );
+        });
+    }
+
+    #[test]
+    fn test_double() {
+        let input = "double d = 3.14 + 1e-3 + 2.5E+2 + 10; do doubles 2e s.x".to_string();
+        let mut lexer = LexicalAnalysis::new("test.c".to_string(), &input);
+
+        lexer.read_token();
+        let expected = [
+            (Token::Double, "double"),
+            (Token::Variable, "d"),
+            (Token::Assign, "="),
+            (Token::FloatNumber, "3.14"),
+            (Token::Plus, "+"),
+            (Token::FloatNumber, "1e-3"),
+            (Token::Plus, "+"),
+            (Token::FloatNumber, "2.5E+2"),
+            (Token::Plus, "+"),
+            (Token::Number, "10"),
+            (Token::SemiColon, ";"),
+            (Token::Do, "do"),
+            (Token::Variable, "doubles"),
+            (Token::Number, "2"),
+            (Token::Variable, "e"),
+            (Token::Variable, "s"),
+            (Token::Dot, "."),
+            (Token::Variable, "x"),
+        ];
+        expected.iter().enumerate().for_each(|(i, (t, v))| {
+            assert_eq!(*t, lexer.get_tokens()[i].get_token_type());
+            assert_eq!(*v, lexer.get_tokens()[i].get_token_value());
+        });
+    }
+
+    #[test]
+    fn test_float() {
+        let input = "float f = 0x1.8p1 + 0x10P-4 + 0xAp+0 + 0x1F + 0x.8p0; floats".to_string();
+        let mut lexer = LexicalAnalysis::new("test.c".to_string(), &input);
+
+        lexer.read_token();
+        let expected = [
+            (Token::Float, "float"),
+            (Token::Variable, "f"),
+            (Token::Assign, "="),
+            (Token::FloatNumber, "3.0"),
+            (Token::Plus, "+"),
+            (Token::FloatNumber, "1.0"),
+            (Token::Plus, "+"),
+            (Token::FloatNumber, "10.0"),
+            (Token::Plus, "+"),
+            (Token::Number, "31"),
+            (Token::Plus, "+"),
+            (Token::FloatNumber, "0.5"),
+            (Token::SemiColon, ";"),
+            (Token::Variable, "floats"),
+        ];
+        expected.iter().enumerate().for_each(|(i, (t, v))| {
+            assert_eq!(*t, lexer.get_tokens()[i].get_token_type());
+            assert_eq!(*v, lexer.get_tokens()[i].get_token_value());
+        });
+    }
+
+    #[test]
+    fn test_union() {
+        let input = "union U { int i; } unions".to_string();
+        let mut lexer = LexicalAnalysis::new("test.c".to_string(), &input);
+
+        lexer.read_token();
+        let expected = [
+            (Token::Union, "union"),
+            (Token::Variable, "U"),
+            (Token::LeftBrace, "{"),
+            (Token::Int, "int"),
+            (Token::Variable, "i"),
+            (Token::SemiColon, ";"),
+            (Token::RightBrace, "}"),
+            (Token::Variable, "unions"),
+        ];
+        expected.iter().enumerate().for_each(|(i, (t, v))| {
+            assert_eq!(*t, lexer.get_tokens()[i].get_token_type());
+            assert_eq!(*v, lexer.get_tokens()[i].get_token_value());
+        });
+    }
+
     #[test]
     fn test_struct() {
         {
@@ -0,0 +1,793 @@
+use ast::{AstTree, AstType};
+use codegen::CodeGen;
+use symbol::{Scope, Structure, SymbolTable, Type};
+
+// 値(オペランド、型、符号なしか).
+#[derive(Debug, Clone)]
+struct Value {
+    v: String,
+    ty: String,
+    unsigned: bool,
+}
+
+impl Value {
+    fn new(v: &str, ty: &str, unsigned: bool) -> Self {
+        Value { v: v.to_string(), ty: ty.to_string(), unsigned }
+    }
+}
+
+// 関数シグネチャ(関数名、戻り値の型、引数の型、定義済みか).
+#[derive(Debug, Clone)]
+struct Signature {
+    name: String,
+    ret: String,
+    args: Vec<String>,
+    defined: bool,
+}
+
+#[doc = "LLVM IR生成部"]
+pub struct Llvm<'a> {
+    inst: String,
+    sym_table: &'a SymbolTable,
+    cur_scope: Scope,
+    funcs: Vec<Signature>,        // 関数シグネチャ
+    reg_no: usize,                // 仮想レジスタ番号
+    label_no: usize,              // ラベル番号
+    block: String,                // 現在の基本ブロック
+    terminated: bool,             // 現在の基本ブロックが終端命令で終わっているか
+    break_labels: Vec<String>,    // breakのジャンプ先
+    continue_labels: Vec<String>, // continueのジャンプ先
+    ret_type: String,             // 生成中の関数の戻り値の型
+}
+
+impl<'a> CodeGen for Llvm<'a> {
+    // LLVM IR生成開始.
+    //
+    // 前方参照する関数を呼び出せるよう、先に関数シグネチャを収集する
+    fn exec(&mut self, tree: &AstTree) {
+        self.funcs = tree.get_tree().iter().filter_map(|a| self.signature(a)).collect();
+        tree.get_tree().iter().for_each(|a| match *a {
+            AstType::Global(ref v) => v.iter().for_each(|g| self.generate_global(g)),
+            AstType::FuncDef(ref t, ref s, ref n, ref a, ref b) => self.generate_funcdef(t, s, n, a, b),
+            _ => {}
+        });
+
+        // 定義されていない関数は、外部関数として宣言
+        let declares: String = self
+            .funcs
+            .iter()
+            .filter(|f| !f.defined)
+            .map(|f| format!("declare {} @{}({})\n", f.ret, f.name, f.args.join(", ")))
+            .collect();
+        self.inst = format!("{}{}", self.inst, declares);
+    }
+
+    // LLVM IR取得.
+    fn get_inst(&self) -> String {
+        self.inst.clone()
+    }
+}
+
+impl<'a> Llvm<'a> {
+    // コンストラクタ.
+    pub fn new(table: &'a SymbolTable) -> Self {
+        Llvm {
+            inst: "".to_string(),
+            sym_table: table,
+            cur_scope: Scope::Global,
+            funcs: vec![],
+            reg_no: 0,
+            label_no: 0,
+            block: "".to_string(),
+            terminated: false,
+            break_labels: vec![],
+            continue_labels: vec![],
+            ret_type: "".to_string(),
+        }
+    }
+
+    // 型、構造に応じたLLVM IRの型
+    fn ir_type(t: &Type, s: &Structure) -> String {
+        let base = Llvm::base_type(t);
+        match *s {
+            Structure::Pointer => format!("{}*", base),
+            Structure::Array(ref v) => format!("[{} x {}]", v.iter().product::<usize>(), base),
+            Structure::Identifier => base,
+            _ => panic!("{} {}: LLVM IR does not support structure {:?}", file!(), line!(), s),
+        }
+    }
+
+    // 基本型のLLVM IRの型
+    fn base_type(t: &Type) -> String {
+        match *t {
+            Type::Int | Type::UInt => "i32".to_string(),
+            Type::Char | Type::UChar => "i8".to_string(),
+            Type::Short => "i16".to_string(),
+            Type::Long | Type::ULong => "i64".to_string(),
+            Type::Void => "void".to_string(),
+            Type::Pointer(ref t) => format!("{}*", Llvm::base_type(t)),
+            _ => panic!("{} {}: LLVM IR does not support type {:?}", file!(), line!(), t),
+        }
+    }
+
+    // 符号なしの型か
+    fn is_unsigned(t: &Type) -> bool {
+        matches!(*t, Type::UInt | Type::UChar | Type::ULong)
+    }
+
+    // 整数型のビット幅(ポインタはNone)
+    fn int_width(ty: &str) -> Option<usize> {
+        ty.strip_prefix('i').and_then(|w| w.parse::<usize>().ok())
+    }
+
+    // 関数シグネチャ取得
+    fn signature(&self, a: &AstType) -> Option<Signature> {
+        let (t, s, name, args, defined) = match *a {
+            AstType::FuncDef(ref t, ref s, ref n, ref a, _) => (t, s, n, a, true),
+            AstType::FuncDecl(ref t, ref s, ref n, ref a) => (t, s, n, a, false),
+            _ => return None,
+        };
+        let args = match **args {
+            AstType::Argment(ref v) => v
+                .iter()
+                .filter_map(|a| match *a {
+                    AstType::Variable(ref t, ref s, _) => Some(Llvm::ir_type(t, s)),
+                    _ => None,
+                })
+                .collect(),
+            _ => vec![],
+        };
+        Some(Signature { name: name.clone(), ret: Llvm::ir_type(t, s), args, defined })
+    }
+
+    // 仮想レジスタ取得
+    fn next_reg(&mut self) -> String {
+        self.reg_no += 1;
+        format!("%.t{}", self.reg_no)
+    }
+
+    // ラベル取得
+    fn next_label(&mut self) -> String {
+        self.label_no += 1;
+        format!(".L{}", self.label_no)
+    }
+
+    // 命令出力(終端命令の後は、新たな基本ブロックを開始する)
+    fn emit(&mut self, s: &str) {
+        if self.terminated {
+            let l = self.next_label();
+            self.start_block(&l);
+        }
+        self.inst = format!("{}  {}\n", self.inst, s);
+    }
+
+    // 終端命令(br、ret)出力
+    fn terminate(&mut self, s: &str) {
+        self.emit(s);
+        self.terminated = true;
+    }
+
+    // 結果を持つ命令を出力し、結果のレジスタを返す
+    fn emit_reg(&mut self, s: &str) -> String {
+        let reg = self.next_reg();
+        self.emit(&format!("{} = {}", reg, s));
+        reg
+    }
+
+    // 基本ブロック開始(直前のブロックが終端していなければ、フォールスルーの分岐を出力)
+    fn start_block(&mut self, l: &str) {
+        if !self.terminated {
+            self.inst = format!("{}  br label %{}\n", self.inst, l);
+        }
+        self.inst = format!("{}{}:\n", self.inst, l);
+        self.block = l.to_string();
+        self.terminated = false;
+    }
+
+    // 無条件分岐
+    fn br(&mut self, l: &str) {
+        self.terminate(&format!("br label %{}", l));
+    }
+
+    // 条件分岐
+    fn cond_br(&mut self, c: &str, t: &str, f: &str) {
+        self.terminate(&format!("br i1 {}, label %{}, label %{}", c, t, f));
+    }
+
+    // グローバル変数生成
+    fn generate_global(&mut self, a: &AstType) {
+        let (var, init) = match *a {
+            AstType::Assign(ref v, ref e) => (&**v, e.eval_constant()),
+            AstType::Variable(_, _, _) => (a, None),
+            _ => panic!("{} {}: LLVM IR does not support global {:?}", file!(), line!(), a),
+        };
+        if let AstType::Variable(ref t, ref s, ref n) = *var {
+            let ty = Llvm::ir_type(t, s);
+            let init = match (s, init) {
+                (Structure::Array(_), _) => "zeroinitializer".to_string(),
+                (Structure::Pointer, _) => "null".to_string(),
+                (_, n) => n.unwrap_or(0).to_string(),
+            };
+            self.inst = format!("{}@{} = global {} {}\n", self.inst, n, ty, init);
+        }
+    }
+
+    // 関数定義生成.
+    //
+    // 引数、ローカル変数は、関数の先頭でallocaした領域へ格納する
+    fn generate_funcdef(&mut self, t: &Type, s: &Structure, name: &str, args: &AstType, body: &AstType) {
+        self.cur_scope = Scope::Local(name.to_string());
+        self.ret_type = Llvm::ir_type(t, s);
+        let args = match *args {
+            AstType::Argment(ref v) => v.clone(),
+            _ => vec![],
+        };
+        let params: Vec<String> = args
+            .iter()
+            .filter_map(|a| match *a {
+                AstType::Variable(ref t, ref s, ref n) => Some(format!("{} %{}.param", Llvm::ir_type(t, s), n)),
+                _ => None,
+            })
+            .collect();
+        let is_static = self.sym_table.search(&Scope::Func, name).is_some_and(|s| s.is_static);
+        let linkage = if is_static { "internal " } else { "" };
+        self.inst = format!("{}\ndefine {}{} @{}({}) {{\n", self.inst, linkage, self.ret_type, name, params.join(", "));
+        self.terminated = true;
+        self.start_block("entry");
+
+        // 引数、ローカル変数の領域確保
+        let mut locals: Vec<(String, String)> = vec![];
+        args.iter().chain(std::iter::once(body)).for_each(|a| self.collect_locals(a, &mut locals));
+        locals.iter().for_each(|(n, ty)| self.emit(&format!("%{}.addr = alloca {}", n, ty)));
+        args.iter().for_each(|a| {
+            if let AstType::Variable(ref t, ref s, ref n) = *a {
+                let ty = Llvm::ir_type(t, s);
+                self.emit(&format!("store {} %{}.param, {}* %{}.addr", ty, n, ty, n));
+            }
+        });
+
+        self.generate_statement(body);
+
+        // 末尾に到達した場合は、0(void型はreturnのみ)を返す
+        if !self.terminated {
+            let ret = match &*self.ret_type {
+                "void" => "ret void".to_string(),
+                ty if ty.ends_with('*') => format!("ret {} null", ty),
+                ty => format!("ret {} 0", ty),
+            };
+            self.terminate(&ret);
+        }
+        self.inst = format!("{}}}\n", self.inst);
+    }
+
+    // ローカル変数の収集
+    fn collect_locals(&self, a: &AstType, locals: &mut Vec<(String, String)>) {
+        if let AstType::Variable(ref t, ref s, ref n) = *a {
+            let is_local = self.sym_table.search(&self.cur_scope, n).is_some();
+            if is_local && !locals.iter().any(|(l, _)| l == n) {
+                locals.push((n.clone(), Llvm::ir_type(t, s)));
+            }
+        }
+        a.children().iter().for_each(|c| self.collect_locals(c, locals));
+    }
+
+    // 文生成
+    fn generate_statement(&mut self, a: &AstType) {
+        match *a {
+            AstType::Statement(ref v) => v.iter().for_each(|s| self.generate_statement(s)),
+            AstType::If(ref c, ref t, ref e) => self.generate_if(c, t, e),
+            AstType::While(ref c, ref b) => self.generate_while(c, b),
+            AstType::Do(ref b, ref c) => self.generate_do(b, c),
+            AstType::For(ref i, ref c, ref u, ref b) => self.generate_for(i, c, u, b),
+            AstType::Break(level) => {
+                let l = self.break_labels[self.break_labels.len() - level].clone();
+                self.br(&l);
+            }
+            AstType::Continue() => {
+                let l = self.continue_labels.last().cloned().expect("llvm.rs(generate_statement): invalid continue");
+                self.br(&l);
+            }
+            AstType::Goto(ref n) => self.br(&format!("{}.label", n)),
+            AstType::Label(ref n) => self.start_block(&format!("{}.label", n)),
+            AstType::Return(ref e) => self.generate_return(e),
+            // 宣言のみの変数、構造体定義は、値を生成しない
+            AstType::Variable(_, _, _) | AstType::Struct(_, _) => {}
+            _ if a.is_expr() => {
+                self.generate_expr(a);
+            }
+            _ => panic!("{} {}: LLVM IR does not support statement {:?}", file!(), line!(), a),
+        }
+    }
+
+    // if文生成
+    fn generate_if(&mut self, c: &AstType, t: &AstType, e: &Option<AstType>) {
+        let label_then = self.next_label();
+        let label_else = self.next_label();
+        let label_end = self.next_label();
+        let cond = self.generate_cond(c);
+        let label_false = if e.is_some() { &label_else } else { &label_end };
+        self.cond_br(&cond, &label_then, label_false);
+
+        self.start_block(&label_then);
+        self.generate_statement(t);
+        self.br(&label_end);
+        if let Some(ref e) = *e {
+            self.start_block(&label_else);
+            self.generate_statement(e);
+            self.br(&label_end);
+        }
+        self.start_block(&label_end);
+    }
+
+    // while文生成
+    fn generate_while(&mut self, c: &AstType, b: &AstType) {
+        let label_cond = self.next_label();
+        let label_body = self.next_label();
+        let label_end = self.next_label();
+        self.start_block(&label_cond);
+        let cond = self.generate_cond(c);
+        self.cond_br(&cond, &label_body, &label_end);
+
+        self.start_block(&label_body);
+        self.generate_loop_body(b, &label_cond, &label_end);
+        self.br(&label_cond);
+        self.start_block(&label_end);
+    }
+
+    // do-while文生成
+    fn generate_do(&mut self, b: &AstType, c: &AstType) {
+        let label_body = self.next_label();
+        let label_cond = self.next_label();
+        let label_end = self.next_label();
+        self.start_block(&label_body);
+        self.generate_loop_body(b, &label_cond, &label_end);
+
+        self.start_block(&label_cond);
+        let cond = self.generate_cond(c);
+        self.cond_br(&cond, &label_body, &label_end);
+        self.start_block(&label_end);
+    }
+
+    // for文生成
+    fn generate_for(&mut self, i: &Option<AstType>, c: &Option<AstType>, u: &Option<AstType>, b: &AstType) {
+        let label_cond = self.next_label();
+        let label_body = self.next_label();
+        let label_update = self.next_label();
+        let label_end = self.next_label();
+        if let Some(ref i) = *i {
+            self.generate_statement(i);
+        }
+        self.start_block(&label_cond);
+        match *c {
+            Some(ref c) => {
+                let cond = self.generate_cond(c);
+                self.cond_br(&cond, &label_body, &label_end);
+            }
+            None => self.br(&label_body),
+        }
+
+        self.start_block(&label_body);
+        self.generate_loop_body(b, &label_update, &label_end);
+        self.start_block(&label_update);
+        if let Some(ref u) = *u {
+            self.generate_statement(u);
+        }
+        self.br(&label_cond);
+        self.start_block(&label_end);
+    }
+
+    // ループ本体生成(continue、breakのジャンプ先を登録)
+    fn generate_loop_body(&mut self, b: &AstType, label_continue: &str, label_break: &str) {
+        self.continue_labels.push(label_continue.to_string());
+        self.break_labels.push(label_break.to_string());
+        self.generate_statement(b);
+        self.continue_labels.pop();
+        self.break_labels.pop();
+    }
+
+    // return文生成
+    fn generate_return(&mut self, e: &AstType) {
+        if let AstType::Void() = *e {
+            self.terminate("ret void");
+            return;
+        }
+        let v = self.generate_expr(e);
+        let ty = self.ret_type.clone();
+        let v = self.convert(&v, &ty);
+        self.terminate(&format!("ret {} {}", ty, v.v));
+    }
+
+    // 条件式生成(i1の値)
+    fn generate_cond(&mut self, a: &AstType) -> String {
+        let v = self.generate_expr(a);
+        let zero = if v.ty.ends_with('*') { "null" } else { "0" };
+        self.emit_reg(&format!("icmp ne {} {}, {}", v.ty, v.v, zero))
+    }
+
+    // 式生成.
+    //
+    // intより小さい整数はintへ拡張した値とする
+    fn generate_expr(&mut self, a: &AstType) -> Value {
+        match *a {
+            AstType::Factor(n) if n >= i32::MIN as i64 && n <= i32::MAX as i64 => Value::new(&n.to_string(), "i32", false),
+            AstType::Factor(n) => Value::new(&n.to_string(), "i64", false),
+            AstType::SizeOf(n) => Value::new(&n.to_string(), "i64", true),
+            AstType::Variable(_, Structure::Array(_), _) => self.generate_array_decay(a),
+            AstType::Variable(_, _, _) | AstType::Indirect(_) => {
+                let (ptr, ty) = self.generate_address(a);
+                let v = self.emit_reg(&format!("load {}, {}* {}", ty, ty, ptr));
+                self.promote(&Value::new(&v, &ty, Llvm::is_unsigned(&a.type_of())))
+            }
+            AstType::Address(ref e) => {
+                let (ptr, ty) = self.generate_address(e);
+                Value::new(&ptr, &format!("{}*", ty), false)
+            }
+            AstType::Assign(ref l, ref r) => {
+                let v = self.generate_expr(r);
+                self.generate_store(l, &v)
+            }
+            AstType::Plus(ref l, ref r) if l.is_pointer() => self.generate_pointer_offset(l, r, false),
+            AstType::Minus(ref l, ref r) if l.is_pointer() && !r.is_pointer() => self.generate_pointer_offset(l, r, true),
+            AstType::Minus(ref l, ref r) if l.is_pointer() => self.generate_pointer_diff(l, r),
+            AstType::Plus(ref l, ref r)
+            | AstType::Minus(ref l, ref r)
+            | AstType::Multiple(ref l, ref r)
+            | AstType::Division(ref l, ref r)
+            | AstType::Remainder(ref l, ref r)
+            | AstType::LeftShift(ref l, ref r)
+            | AstType::RightShift(ref l, ref r)
+            | AstType::BitAnd(ref l, ref r)
+            | AstType::BitOr(ref l, ref r)
+            | AstType::BitXor(ref l, ref r) => {
+                let lv = self.generate_expr(l);
+                let rv = self.generate_expr(r);
+                self.generate_binary(a, &lv, &rv)
+            }
+            AstType::Equal(ref l, ref r)
+            | AstType::NotEqual(ref l, ref r)
+            | AstType::LessThan(ref l, ref r)
+            | AstType::GreaterThan(ref l, ref r)
+            | AstType::LessThanEqual(ref l, ref r)
+            | AstType::GreaterThanEqual(ref l, ref r) => self.generate_compare(a, l, r),
+            AstType::LogicalAnd(ref l, ref r) => self.generate_logical(l, r, true),
+            AstType::LogicalOr(ref l, ref r) => self.generate_logical(l, r, false),
+            AstType::Not(ref e) => {
+                let c = self.generate_cond(e);
+                let v = self.emit_reg(&format!("xor i1 {}, true", c));
+                self.generate_zext(&v)
+            }
+            AstType::UnPlus(ref e) => self.generate_expr(e),
+            AstType::UnMinus(ref e) => {
+                let v = self.generate_expr(e);
+                let r = self.emit_reg(&format!("sub {} 0, {}", v.ty, v.v));
+                Value::new(&r, &v.ty, v.unsigned)
+            }
+            AstType::BitReverse(ref e) => {
+                let v = self.generate_expr(e);
+                let r = self.emit_reg(&format!("xor {} {}, -1", v.ty, v.v));
+                Value::new(&r, &v.ty, v.unsigned)
+            }
+            AstType::Condition(ref c, ref l, ref r) => self.generate_condition(c, l, r),
+            AstType::PreInc(ref e) => self.generate_inc_dec(e, "1", true),
+            AstType::PreDec(ref e) => self.generate_inc_dec(e, "-1", true),
+            AstType::PostInc(ref e) => self.generate_inc_dec(e, "1", false),
+            AstType::PostDec(ref e) => self.generate_inc_dec(e, "-1", false),
+            AstType::PlusAssign(ref l, ref r) => self.generate_compound_assign(l, AstType::Plus(l.clone(), r.clone())),
+            AstType::MinusAssign(ref l, ref r) => self.generate_compound_assign(l, AstType::Minus(l.clone(), r.clone())),
+            AstType::MultipleAssign(ref l, ref r) => {
+                self.generate_compound_assign(l, AstType::Multiple(l.clone(), r.clone()))
+            }
+            AstType::DivisionAssign(ref l, ref r) => {
+                self.generate_compound_assign(l, AstType::Division(l.clone(), r.clone()))
+            }
+            AstType::RemainderAssign(ref l, ref r) => {
+                self.generate_compound_assign(l, AstType::Remainder(l.clone(), r.clone()))
+            }
+            AstType::FuncCall(ref f, ref args) => self.generate_call(f, args),
+            AstType::Cast(Type::Void, ref e) => {
+                self.generate_expr(e);
+                Value::new("0", "i32", false)
+            }
+            _ => panic!("{} {}: LLVM IR does not support expression {:?}", file!(), line!(), a),
+        }
+    }
+
+    // 左辺値のアドレス生成(アドレス、格納する値の型)
+    fn generate_address(&mut self, a: &AstType) -> (String, String) {
+        match *a {
+            AstType::Variable(ref t, ref s, ref n) => {
+                let ptr = if self.sym_table.search(&self.cur_scope, n).is_some() {
+                    format!("%{}.addr", n)
+                } else {
+                    format!("@{}", n)
+                };
+                (ptr, Llvm::ir_type(t, s))
+            }
+            AstType::Indirect(ref p) => {
+                let v = self.generate_expr(p);
+                let ty = v.ty.strip_suffix('*').expect("llvm.rs(generate_address): not pointer").to_string();
+                (v.v, ty)
+            }
+            _ => panic!("{} {}: LLVM IR does not support lvalue {:?}", file!(), line!(), a),
+        }
+    }
+
+    // 配列を先頭要素へのポインタへ変換
+    fn generate_array_decay(&mut self, a: &AstType) -> Value {
+        let (ptr, ty) = self.generate_address(a);
+        let elem = Llvm::base_type(&a.type_of());
+        let v = self.emit_reg(&format!("getelementptr inbounds {}, {}* {}, i64 0, i64 0", ty, ty, ptr));
+        Value::new(&v, &format!("{}*", elem), false)
+    }
+
+    // 左辺へ格納し、格納した値を返す
+    fn generate_store(&mut self, l: &AstType, v: &Value) -> Value {
+        let (ptr, ty) = self.generate_address(l);
+        let v = self.convert(v, &ty);
+        self.emit(&format!("store {} {}, {}* {}", ty, v.v, ty, ptr));
+        self.promote(&Value::new(&v.v, &ty, Llvm::is_unsigned(&l.type_of())))
+    }
+
+    // 複合代入生成(左辺の評価は一度のみ)
+    fn generate_compound_assign(&mut self, l: &AstType, ope: AstType) -> Value {
+        let (ptr, ty) = self.generate_address(l);
+        let cur = self.emit_reg(&format!("load {}, {}* {}", ty, ty, ptr));
+        let cur = self.promote(&Value::new(&cur, &ty, Llvm::is_unsigned(&l.type_of())));
+        let r = match ope {
+            AstType::Plus(_, ref r)
+            | AstType::Minus(_, ref r)
+            | AstType::Multiple(_, ref r)
+            | AstType::Division(_, ref r)
+            | AstType::Remainder(_, ref r) => self.generate_expr(r),
+            _ => panic!("{} {}: LLVM IR does not support compound assign {:?}", file!(), line!(), ope),
+        };
+        let v = if ty.ends_with('*') {
+            let r = if let AstType::Minus(_, _) = ope { self.generate_negate(&r) } else { r };
+            self.generate_gep(&cur, &r)
+        } else {
+            self.generate_binary(&ope, &cur, &r)
+        };
+        let v = self.convert(&v, &ty);
+        self.emit(&format!("store {} {}, {}* {}", ty, v.v, ty, ptr));
+        self.promote(&v)
+    }
+
+    // 前置、後置のインクリメント、デクリメント生成
+    fn generate_inc_dec(&mut self, e: &AstType, n: &str, is_pre: bool) -> Value {
+        let (ptr, ty) = self.generate_address(e);
+        let cur = self.emit_reg(&format!("load {}, {}* {}", ty, ty, ptr));
+        let cur = Value::new(&cur, &ty, Llvm::is_unsigned(&e.type_of()));
+        let next = if ty.ends_with('*') {
+            self.generate_gep(&cur, &Value::new(n, "i64", false))
+        } else {
+            Value::new(&self.emit_reg(&format!("add {} {}, {}", ty, cur.v, n)), &ty, cur.unsigned)
+        };
+        self.emit(&format!("store {} {}, {}* {}", ty, next.v, ty, ptr));
+        if is_pre {
+            self.promote(&next)
+        } else {
+            self.promote(&cur)
+        }
+    }
+
+    // ポインタへの整数の加減算
+    fn generate_pointer_offset(&mut self, p: &AstType, n: &AstType, is_minus: bool) -> Value {
+        let p = self.generate_expr(p);
+        let n = self.generate_expr(n);
+        let n = if is_minus { self.generate_negate(&n) } else { n };
+        self.generate_gep(&p, &n)
+    }
+
+    // ポインタ同士の減算(要素数の差)
+    fn generate_pointer_diff(&mut self, l: &AstType, r: &AstType) -> Value {
+        let lv = self.generate_expr(l);
+        let rv = self.generate_expr(r);
+        let ptr_ty = lv.ty.clone();
+        let lv = self.convert(&lv, "i64");
+        let rv = self.convert(&rv, "i64");
+        let diff = self.emit_reg(&format!("sub i64 {}, {}", lv.v, rv.v));
+
+        // 要素サイズは、nullから1要素進めたアドレスで求める
+        let size = self.generate_gep(&Value::new("null", &ptr_ty, false), &Value::new("1", "i64", false));
+        let size = self.convert(&size, "i64");
+        let v = self.emit_reg(&format!("sdiv exact i64 {}, {}", diff, size.v));
+        Value::new(&v, "i64", false)
+    }
+
+    // 符号反転
+    fn generate_negate(&mut self, v: &Value) -> Value {
+        let r = self.emit_reg(&format!("sub {} 0, {}", v.ty, v.v));
+        Value::new(&r, &v.ty, v.unsigned)
+    }
+
+    // 要素数分のオフセットを加算したポインタ
+    fn generate_gep(&mut self, p: &Value, n: &Value) -> Value {
+        let n = self.convert(n, "i64");
+        let elem = p.ty.strip_suffix('*').expect("llvm.rs(generate_gep): not pointer");
+        let r = self.emit_reg(&format!("getelementptr inbounds {}, {} {}, i64 {}", elem, p.ty, p.v, n.v));
+        Value::new(&r, &p.ty, false)
+    }
+
+    // 二項演算生成(通常の算術変換を行う)
+    fn generate_binary(&mut self, ope: &AstType, l: &Value, r: &Value) -> Value {
+        let unsigned = Llvm::is_unsigned(&ope.type_of());
+        let ty = if l.ty == "i64" || r.ty == "i64" { "i64" } else { "i32" };
+        let l = self.convert(l, ty);
+        let r = self.convert(r, ty);
+        let inst = match (ope, unsigned) {
+            (AstType::Plus(_, _), _) => "add",
+            (AstType::Minus(_, _), _) => "sub",
+            (AstType::Multiple(_, _), _) => "mul",
+            (AstType::Division(_, _), false) => "sdiv",
+            (AstType::Division(_, _), true) => "udiv",
+            (AstType::Remainder(_, _), false) => "srem",
+            (AstType::Remainder(_, _), true) => "urem",
+            (AstType::LeftShift(_, _), _) => "shl",
+            (AstType::RightShift(_, _), false) => "ashr",
+            (AstType::RightShift(_, _), true) => "lshr",
+            (AstType::BitAnd(_, _), _) => "and",
+            (AstType::BitOr(_, _), _) => "or",
+            (AstType::BitXor(_, _), _) => "xor",
+            _ => panic!("{} {}: LLVM IR does not support operator {:?}", file!(), line!(), ope),
+        };
+        let v = self.emit_reg(&format!("{} {} {}, {}", inst, ty, l.v, r.v));
+        Value::new(&v, ty, unsigned)
+    }
+
+    // 比較演算生成(結果はint型の0/1)
+    fn generate_compare(&mut self, ope: &AstType, l: &AstType, r: &AstType) -> Value {
+        let lv = self.generate_expr(l);
+        let rv = self.generate_expr(r);
+        let unsigned = l.is_pointer() || Llvm::is_unsigned(&AstType::promote(&l.type_of(), &r.type_of()));
+        let ty = if lv.ty.ends_with('*') {
+            lv.ty.clone()
+        } else if rv.ty.ends_with('*') {
+            rv.ty.clone()
+        } else if lv.ty == "i64" || rv.ty == "i64" {
+            "i64".to_string()
+        } else {
+            "i32".to_string()
+        };
+        let lv = self.convert(&lv, &ty);
+        let rv = self.convert(&rv, &ty);
+        let cond = match (ope, unsigned) {
+            (AstType::Equal(_, _), _) => "eq",
+            (AstType::NotEqual(_, _), _) => "ne",
+            (AstType::LessThan(_, _), false) => "slt",
+            (AstType::LessThan(_, _), true) => "ult",
+            (AstType::GreaterThan(_, _), false) => "sgt",
+            (AstType::GreaterThan(_, _), true) => "ugt",
+            (AstType::LessThanEqual(_, _), false) => "sle",
+            (AstType::LessThanEqual(_, _), true) => "ule",
+            (AstType::GreaterThanEqual(_, _), false) => "sge",
+            (AstType::GreaterThanEqual(_, _), true) => "uge",
+            _ => panic!("{} {}: LLVM IR does not support operator {:?}", file!(), line!(), ope),
+        };
+        let c = self.emit_reg(&format!("icmp {} {} {}, {}", cond, ty, lv.v, rv.v));
+        self.generate_zext(&c)
+    }
+
+    // i1の値をint型へ拡張
+    fn generate_zext(&mut self, c: &str) -> Value {
+        let v = self.emit_reg(&format!("zext i1 {} to i32", c));
+        Value::new(&v, "i32", false)
+    }
+
+    // 論理演算生成(右辺は短絡評価)
+    fn generate_logical(&mut self, l: &AstType, r: &AstType, is_and: bool) -> Value {
+        let label_rhs = self.next_label();
+        let label_end = self.next_label();
+        let lc = self.generate_cond(l);
+        let from = self.block.clone();
+        if is_and {
+            self.cond_br(&lc, &label_rhs, &label_end);
+        } else {
+            self.cond_br(&lc, &label_end, &label_rhs);
+        }
+
+        self.start_block(&label_rhs);
+        let rc = self.generate_cond(r);
+        let rhs_end = self.block.clone();
+        self.br(&label_end);
+
+        self.start_block(&label_end);
+        let short = if is_and { "false" } else { "true" };
+        let c = self.emit_reg(&format!("phi i1 [ {}, %{} ], [ {}, %{} ]", short, from, rc, rhs_end));
+        self.generate_zext(&c)
+    }
+
+    // 条件演算子生成
+    fn generate_condition(&mut self, c: &AstType, l: &AstType, r: &AstType) -> Value {
+        let label_then = self.next_label();
+        let label_else = self.next_label();
+        let label_end = self.next_label();
+        let cond = self.generate_cond(c);
+        self.cond_br(&cond, &label_then, &label_else);
+
+        self.start_block(&label_then);
+        let lv = self.generate_expr(l);
+        let lv = if l.is_pointer() {
+            lv
+        } else {
+            let ty = Llvm::base_type(&AstType::promote(&l.type_of(), &r.type_of()));
+            self.convert(&lv, &ty)
+        };
+        let then_end = self.block.clone();
+        self.br(&label_end);
+
+        self.start_block(&label_else);
+        let rv = self.generate_expr(r);
+        let rv = self.convert(&rv, &lv.ty);
+        let else_end = self.block.clone();
+        self.br(&label_end);
+
+        self.start_block(&label_end);
+        let v = self.emit_reg(&format!("phi {} [ {}, %{} ], [ {}, %{} ]", lv.ty, lv.v, then_end, rv.v, else_end));
+        Value::new(&v, &lv.ty, lv.unsigned)
+    }
+
+    // 関数呼び出し生成
+    fn generate_call(&mut self, f: &AstType, args: &AstType) -> Value {
+        let name = match *f {
+            AstType::Variable(_, _, ref n) => n.clone(),
+            _ => panic!("{} {}: LLVM IR does not support call {:?}", file!(), line!(), f),
+        };
+        let args = match *args {
+            AstType::Argment(ref v) => v.iter().map(|a| self.generate_expr(a)).collect::<Vec<Value>>(),
+            _ => vec![],
+        };
+
+        // 宣言の無い関数は、実引数の型でint型を返す外部関数として宣言する
+        let sig = match self.funcs.iter().find(|s| s.name == name) {
+            Some(s) => s.clone(),
+            None => {
+                let sig = Signature {
+                    name: name.clone(),
+                    ret: "i32".to_string(),
+                    args: args.iter().map(|a| a.ty.clone()).collect(),
+                    defined: false,
+                };
+                self.funcs.push(sig.clone());
+                sig
+            }
+        };
+        let params: Vec<String> = args
+            .iter()
+            .zip(sig.args.iter())
+            .map(|(a, ty)| format!("{} {}", ty, self.convert(a, ty).v))
+            .collect();
+        let call = format!("call {} @{}({})", sig.ret, name, params.join(", "));
+        if sig.ret == "void" {
+            self.emit(&call);
+            return Value::new("0", "i32", false);
+        }
+        let v = self.emit_reg(&call);
+        self.promote(&Value::new(&v, &sig.ret, false))
+    }
+
+    // intより小さい整数型をintへ拡張
+    fn promote(&mut self, v: &Value) -> Value {
+        match Llvm::int_width(&v.ty) {
+            Some(w) if w < 32 => self.convert(v, "i32"),
+            _ => v.clone(),
+        }
+    }
+
+    // 型変換(整数間は拡張、切り詰め、整数とポインタ間はinttoptr/ptrtoint)
+    fn convert(&mut self, v: &Value, ty: &str) -> Value {
+        if v.ty == ty {
+            return v.clone();
+        }
+        let inst = match (Llvm::int_width(&v.ty), Llvm::int_width(ty)) {
+            (Some(from), Some(to)) if from < to && v.unsigned => "zext",
+            (Some(from), Some(to)) if from < to => "sext",
+            (Some(_), Some(_)) => "trunc",
+            (Some(_), None) if v.v == "0" => return Value::new("null", ty, false),
+            (Some(_), None) => "inttoptr",
+            (None, Some(_)) => "ptrtoint",
+            (None, None) => "bitcast",
+        };
+        let r = self.emit_reg(&format!("{} {} {} to {}", inst, v.ty, v.v, ty));
+        Value::new(&r, ty, v.unsigned)
+    }
+}
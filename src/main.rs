@@ -1,41 +1,167 @@
 mod arch;
 mod asm;
 mod ast;
+mod codegen;
 mod config;
+mod diagnostic;
 mod lexer;
+mod llvm;
+mod optimize;
+mod preprocess;
 mod semantic;
 mod symbol;
 mod token;
 
 use asm::Asm;
 use ast::AstGen;
+use codegen::CodeGen;
+use config::{Config, OptLevel, Std};
+use diagnostic::Diagnostic;
 use lexer::LexicalAnalysis;
+use llvm::Llvm;
+use optimize::Optimizer;
+use preprocess::Preprocessor;
 use semantic::Semantic;
 use std::env;
+use std::fs;
+use std::process;
+use std::process::Command;
+use symbol::Scope;
+use token::dump_tokens;
 use std::fs::File;
 use std::io::Read;
 
 /// コンパイルスタート
 ///
-/// 成功時、アセンブリを返す。失敗時はエラーのVecを返す
-fn compile(inst: &str) -> Result<String, Vec<String>> {
+/// 成功時、アセンブリを返す。失敗時はエラーのVecを返す(警告は標準エラーへ出力)
+fn compile(inst: &str, config: &Config) -> Result<String, Vec<String>> {
+    let (asm, diags) = compile_with_diagnostics(inst, config);
+    diags.iter().filter(|d| !d.is_error()).for_each(|w| eprintln!("warning: {}", w));
+    asm.ok_or_else(|| diags.iter().filter(|d| d.is_error()).map(|e| e.to_string()).collect())
+}
+
+/// 診断付きコンパイル
+///
+/// 成功時はアセンブリを返す。字句解析、構文解析、意味解析のエラー、警告は、発生順に診断として返す
+fn compile_with_diagnostics(inst: &str, config: &Config) -> (Option<String>, Vec<Diagnostic>) {
     // 字句解析
-    let mut p = LexicalAnalysis::new("stdin".to_string(), &inst);
+    let mut p = LexicalAnalysis::new("stdin".to_string(), inst);
     p.read_token();
 
+    // C89では//コメントを許可しない
+    if config.std == Std::C89 && !p.get_line_comments().is_empty() {
+        let errs = p
+            .get_line_comments()
+            .iter()
+            .map(|pos| Diagnostic::error(Some(pos.clone()), "C++ style comments are not allowed in ISO C90".to_string()))
+            .collect();
+        return (None, errs);
+    }
+
+    // プリプロセス
+    let mut pre = Preprocessor::new();
+    config.defines.iter().for_each(|(n, v)| pre.define_value(n, v));
+    let tokens = pre.exec(p.get_tokens());
+    if !pre.get_errors().is_empty() {
+        return (None, pre.get_errors().clone());
+    }
+
+    // トークン列出力
+    if config.emit_tokens {
+        eprint!("{}", dump_tokens(&tokens));
+    }
+
     // AST作成
-    let mut ast_gen = AstGen::new(p.get_tokens());
-    let ast_tree = ast_gen.parse();
+    let mut ast_gen = AstGen::new(&tokens);
+    ast_gen.set_config(config);
+    let mut ast_tree = ast_gen.parse();
+    let mut diags = ast_gen.get_warnings().clone();
+    if !ast_gen.get_errors().is_empty() {
+        diags.extend(ast_gen.get_errors().iter().cloned());
+        return (None, diags);
+    }
 
     // 意味解析
     let sym = ast_gen.get_symbol();
-    let mut sem = Semantic::new(&ast_tree, &sym);
-    sem.exec()?;
+    let mut sem = Semantic::new(&ast_tree, sym);
+    let result = sem.exec();
+    diags.extend(sem.get_warnings().iter().cloned());
+    if let Err(errs) = result {
+        diags.extend(errs);
+        return (None, diags);
+    }
+
+    // シンボルテーブル出力
+    if config.emit_symbols {
+        eprint!("{}", sym.dump());
+    }
+
+    // コールグラフ出力
+    if config.emit_callgraph {
+        eprint!("{}", ast_tree.dump_call_graph());
+    }
+
+    // 抽象構文木出力
+    if config.emit_ast_json {
+        eprintln!("{}", ast_tree.dump_json());
+    }
+
+    // スタック使用量出力
+    if config.emit_stack_usage {
+        eprint!("{}", ast_tree.dump_stack_usage(sym));
+    }
+
+    // 未使用関数削除(static関数以外は--gc-functions指定時のみ対象)
+    let roots: Vec<String> = ast_tree
+        .call_graph()
+        .into_keys()
+        .filter(|f| {
+            let is_static = sym.search(&Scope::Func, f).is_some_and(|s| s.is_static);
+            f == "main" || !(is_static || config.gc_functions)
+        })
+        .collect();
+    ast_tree.remove_unreachable_functions(&roots);
+
+    // 最適化
+    let optimizer = Optimizer::new(config);
+    optimizer.exec(&mut ast_tree);
+
+    // LLVM IRへ変換(--emit-llvm指定時)
+    if config.emit_llvm {
+        let mut llvm = Llvm::new(sym);
+        llvm.exec(&ast_tree);
+        return (Some(llvm.get_inst()), diags);
+    }
 
     // アセンブラへ変換.
-    let mut asm = Asm::new(&sym);
+    let mut asm = Asm::new(sym);
+    asm.set_config(config);
     asm.exec(&ast_tree);
-    Ok(asm.get_inst())
+    (Some(optimizer.exec_asm(&asm.get_inst())), diags)
+}
+
+// アセンブル、リンクして実行し、終了コードを取得
+fn run(inst: &str) -> Result<i32, String> {
+    let dir = env::temp_dir();
+    let asm = dir.join(format!("rcc_run_{}.s", process::id()));
+    let bin = dir.join(format!("rcc_run_{}", process::id()));
+    fs::write(&asm, format!("{}\n", inst)).map_err(|e| format!("cannot write {}: {}", asm.display(), e))?;
+
+    // アセンブラ、リンカはgccから呼び出す
+    let mut gcc = Command::new("gcc");
+    if !Config::is_mac() {
+        gcc.arg("-no-pie");
+    }
+    let result = gcc.arg(&asm).arg("-o").arg(&bin).output().map_err(|e| format!("cannot execute gcc: {}", e));
+    let _ = fs::remove_file(&asm);
+    let result = result?;
+    if !result.status.success() {
+        return Err(format!("assemble/link failed:\n{}", String::from_utf8_lossy(&result.stderr)));
+    }
+
+    let status = Command::new(&bin).status().map_err(|e| format!("cannot execute {}: {}", bin.display(), e));
+    let _ = fs::remove_file(&bin);
+    status?.code().ok_or_else(|| "program terminated by signal".to_string())
 }
 
 #[doc = "メイン関数"]
@@ -45,24 +171,64 @@ fn main() {
 
     // 引数チェック
     if args.len() < 2 {
-        panic!("Usage: rcc [--input] [filename]")
+        panic!("Usage: rcc [--gnu] [--emit-tokens] [--emit-symbols] [--emit-callgraph] [--emit-ast-json] [--emit-stack-usage] [--gc-functions] [-ftrapv] [-fbounds-check] [--O0|--O1|--O2] [--annotate-ast] [--emit-llvm] [--std=c89|--std=c99] [-o file] [--run] [-DNAME[=value]] [--input] [filename]")
     }
 
-    // 入力ソースを決定
+    // オプションと入力ソースを決定
+    let mut config = Config::default();
     let mut s = String::new();
-    match &*args[1] {
-        "--input" => {
-            std::io::stdin().read_line(&mut s).unwrap();
-        }
-        _ => {
-            let mut f = File::open(&args[1]).unwrap_or_else(|_| panic!("not found file {}", args[1]));
-            f.read_to_string(&mut s).expect("read file error");
+    let mut output = None;
+    let mut run_output = false;
+    let mut iter = args.iter().skip(1);
+    while let Some(a) = iter.next() {
+        match &**a {
+            "--gnu" => config.gnu = true,
+            "--emit-tokens" => config.emit_tokens = true,
+            "--emit-symbols" => config.emit_symbols = true,
+            "--emit-callgraph" => config.emit_callgraph = true,
+            "--emit-ast-json" => config.emit_ast_json = true,
+            "--emit-stack-usage" => config.emit_stack_usage = true,
+            "--gc-functions" => config.gc_functions = true,
+            "--O0" => config.opt_level = OptLevel::O0,
+            "--O1" => config.opt_level = OptLevel::O1,
+            "--O2" => config.opt_level = OptLevel::O2,
+            "--annotate-ast" => config.annotate_ast = true,
+            "--emit-llvm" => config.emit_llvm = true,
+            "--std=c89" => config.std = Std::C89,
+            "--std=c99" => config.std = Std::C99,
+            "-ftrapv" => config.trapv = true,
+            "-fbounds-check" => config.bounds_check = true,
+            "-o" => output = Some(iter.next().expect("missing filename after '-o'")),
+            "--run" => run_output = true,
+            d if d.starts_with("-D") => config.add_define(&d[2..]),
+            "--input" => {
+                std::io::stdin().read_line(&mut s).unwrap();
+            }
+            _ => {
+                let mut f = File::open(a).unwrap_or_else(|_| panic!("not found file {}", a));
+                f.read_to_string(&mut s).expect("read file error");
+            }
         }
-    };
+    }
 
-    // コンパイル実行
-    match compile(&s) {
-        Ok(inst) => println!("{}", inst),
+    // コンパイル実行(-o指定時はファイルへ、--run指定時は実行し、それ以外は標準出力へ出力)
+    match compile(&s, &config) {
+        Ok(inst) if run_output => match run(&inst) {
+            Ok(code) => process::exit(code),
+            Err(e) => {
+                eprintln!("{}", e);
+                process::exit(1);
+            }
+        },
+        Ok(inst) => match output {
+            Some(path) => {
+                if let Err(e) = fs::write(path, format!("{}\n", inst)) {
+                    eprintln!("cannot write {}: {}", path, e);
+                    process::exit(1);
+                }
+            }
+            None => println!("{}", inst),
+        },
         Err(errs) => errs.iter().for_each(|e| println!("{:?}", e)),
     }
 }
@@ -70,7 +236,8 @@ fn main() {
 #[cfg(test)]
 mod test {
     use super::*;
-    use config::Config;
+    use ast::StackUsage;
+    use diagnostic::Severity;
     use std::fs;
     use std::io::Write;
     use std::process::Command;
@@ -93,20 +260,25 @@ mod test {
     //
     // 引数で指定された文字列をコンパイル→実行、exitコードを返す
     fn eval(inst: &str) -> i32 {
-        match compile(inst) {
+        eval_with_config(inst, &Config::default())
+    }
+
+    // 設定データを指定して評価
+    fn eval_with_config(inst: &str, config: &Config) -> i32 {
+        match compile(inst, config) {
             Err(_) => -1,
             Ok(inst) => {
                 // gccを使用して実行.
                 let _ = create_asm_file(&inst);
-                match Command::new("gcc").args(&["-g3", "-no-pie", "./test.s", "-o", "test"]).output() {
-                    Err(e) => panic!(e),
+                match Command::new("gcc").args(["-g3", "-no-pie", "./test.s", "-o", "test"]).output() {
+                    Err(e) => panic!("{}", e),
                     Ok(_) => {
                         match Command::new("./test").status() {
                             Ok(r) => match r.code() {
                                 Some(r) => r,
                                 None => panic!("code() is failed"),
                             },
-                            Err(e) => panic!(e),
+                            Err(e) => panic!("{}", e),
                         }
                     }
                 }
@@ -184,6 +356,9 @@ mod test {
             TestData { inst: "int main() { return 1 != 1 || 2 < 1; }", ex_ret: 0 },
             TestData { inst: "int main() { return 4 / 2 == 0 + 2 || 2 < 1; }", ex_ret: 1 },
             TestData { inst: "int main() { return (1 == 0 && 1) && (2 < 1 || 0); }", ex_ret: 0 },
+            TestData { inst: "int main() { int b = 5 && 3; int c = 0 || 0; return b * 10 + c; }", ex_ret: 10 },
+            TestData { inst: "int main() { int x = 5; int y = 3; int z = 0; int b = x && y; int c = z || z; int d = z || y; int e = x && z; return b * 1000 + c * 100 + d * 10 + e; }", ex_ret: 1010 % 256 },
+            TestData { inst: "int main() { double d = 0.5; int x = 4; return (d && x) + (x || d) + (0.0 || d); }", ex_ret: 3 },
             TestData { inst: "int main() { return 2 ? 1 : 3; }", ex_ret: 1 },
             TestData { inst: "int main() { return 2 > 1 ? 1 : 3; }", ex_ret: 1 },
             TestData { inst: "int main() { return 2 < 1 ? 1 : 3; }", ex_ret: 3 },
@@ -339,8 +514,54 @@ mod test {
             TestData { inst: "int main() { char a = 1; return sizeof(a); }", ex_ret: 1 },
             TestData { inst: "int main() { int* a; return sizeof(a); }", ex_ret: 8 },
             TestData { inst: "int main() { char* a; return sizeof(a); }", ex_ret: 8 },
-            TestData { inst: "int main() { int a[2]; return sizeof(a); }", ex_ret: 16 },
-            TestData { inst: "int main() { int a[2][10]; return sizeof(a); }", ex_ret: 160 },
+            TestData { inst: "int main() { int a[2]; return sizeof(a); }", ex_ret: 8 },
+            TestData { inst: "int main() { int a[2][10]; return sizeof(a); }", ex_ret: 80 },
+            TestData { inst: "int f(register int a) { return a * 2; } int main() { register int i; auto int x = 3; i = x; return f(i); }", ex_ret: 6 },
+            TestData { inst: "int main() { auto x = 3; return x; }", ex_ret: -1 },
+            TestData { inst: "int main() { int x = 1; return x(); }", ex_ret: -1 },
+            TestData { inst: "int main() { int x = 4; return x / 0; }", ex_ret: -1 },
+            TestData { inst: "int main() { int x = 4; x %= 0; return x; }", ex_ret: -1 },
+            TestData { inst: "int main() { int a[4]; int* p = a; a[1] = 3; p[2] = 7; p[2] += 1; return p[2] * 10 + a[2] + p[1]; }", ex_ret: 91 },
+            TestData { inst: "int f(int* p, int i) { return p[i]; } int main() { int a[4]; a[3] = 9; return f(a, 3); }", ex_ret: 9 },
+            TestData { inst: "int main() { int i = 0; while (1) { { if (i == 3) break; } i++; } return i; }", ex_ret: 3 },
+            TestData { inst: "int main() { int i = 0; int n = 0; for (i = 0; i < 5; i++) { { if (i == 2) { continue; } } n += 10; } return n + i; }", ex_ret: 45 },
+            TestData { inst: "int main() { int n = 1; { int m = 2; { n = n + m; } n = n * 10; } return n; }", ex_ret: 30 },
+            TestData { inst: "int n; int next() { n = n - 1; return n; } int main() { int c; int s = 0; n = 5; while ((c = next()) != 0) { s = s + c; } return s * 10 + c; }", ex_ret: 100 },
+            TestData { inst: "int g; struct S { int x; int y; }; int main() { int a; int b; struct S s; a = b = (g = 4) + 1; return a + b + (s.y = 3) * 10 + g; }", ex_ret: 44 },
+            TestData { inst: "int main() { int a = 7; int b; int c[2]; c[1] = 1; b = (a -= 2) + (a *= 2) + (c[1] += 4); return b * 10 + a; }", ex_ret: 210 },
+            TestData { inst: "int main() { char c; int a; a = (c = 300) + 1; return a; }", ex_ret: 45 },
+            TestData { inst: "int main() { char a = 1; char c; char b = 2; c = 300; return c + a * 100 + b * 10; }", ex_ret: 164 },
+            TestData { inst: "int main() { char a[3]; a[0] = 1; a[2] = 2; a[1] = 300; return a[1] + a[0] * 100 + a[2] * 10; }", ex_ret: 164 },
+            TestData { inst: "struct S { char a; char c; char b; }; int main() { struct S s; s.a = 1; s.b = 2; s.c = 300; return s.c + s.a * 100 + s.b * 10; }", ex_ret: 164 },
+            TestData { inst: "int main() { char a = 1; char c; char* p = &c; char b = 2; *p = 300; c += 256; return c + a * 100 + b * 10; }", ex_ret: 164 },
+            TestData { inst: "char g1 = 1; char g; char g2 = 2; int main() { g = 300; return g + g1 * 100 + g2 * 10; }", ex_ret: 164 },
+            TestData { inst: "int main() { short a = 1; short c; short b = 2; c = 65836; return (c == 300) + a * 100 + b * 10; }", ex_ret: 121 },
+            TestData { inst: "int main() { short a[3]; a[0] = 1; a[2] = 2; a[1] = 65836; return (a[1] == 300) + a[0] * 100 + a[2] * 10; }", ex_ret: 121 },
+            TestData { inst: "struct S { short a; short c; short b; }; int main() { struct S s; s.a = 1; s.b = 2; s.c = 65836; return (s.c == 300) + s.a * 100 + s.b * 10 + sizeof(struct S); }", ex_ret: 127 },
+            TestData { inst: "short g1 = 1; short g; short g2 = 2; int main() { g = 65836; g += 65536; return (g == 300) + g1 * 100 + g2 * 10 + sizeof(g); }", ex_ret: 123 },
+            TestData { inst: "int main() { short s = 32767; s++; return s == -32768; }", ex_ret: 1 },
+            TestData { inst: "int g; int h; int main() { h = 5; int *p = &g; *p = 1; return h * 10 + g; }", ex_ret: 51 },
+            TestData { inst: "int g; int h; int main() { h = 5; int *p = &g; *p = -1; *p += 3; ++*p; return h * 10 + g; }", ex_ret: 53 },
+            TestData { inst: "struct S { int x; int y; }; int main() { struct S s; s.y = 3; int *p = &s.x; *p = -1; struct S *q = &s; s.x++; q->x--; return s.y * 10 + (s.x == -1); }", ex_ret: 31 },
+            TestData { inst: "int g[2]; int h = 5; int main() { int *p = g; p[1] = -2; g[1]++; return h * 10 + (g[1] == -1) + g[0]; }", ex_ret: 51 },
+            TestData { inst: "int main() { char *s = \"hi\"; return s[1]; }", ex_ret: 105 },
+            TestData { inst: "int main() { char *s = \"hello\"; int n = 0; while (*s) { n++; s++; } return n; }", ex_ret: 5 },
+            TestData { inst: "int main() { char a[3] = {1, 2, 3}; char *p = &a[1]; return *p * 10 + *(p + 1) + (p - a); }", ex_ret: 24 },
+            TestData { inst: "int main() { int a[3]; int *p = a; p++; *p = 4; p += 1; *p = 5; return a[1] + a[2] + (p - a); }", ex_ret: 11 },
+            TestData { inst: "int main() { int a[3] = {1, 2, 3}; int *p = a + 2; p -= 1; return *p * 10 + *(p - 1) + sizeof(a); }", ex_ret: 33 },
+            TestData { inst: "int main() { int a[3] = {1, 2, 3}; int s = 0; int *p = a; while (p < a + 3) { s = s * 10 + *p; p++; } return s; }", ex_ret: 123 },
+            TestData { inst: "int fib(int n) { int a = n; int b; if (n < 2) { return n; } b = fib(n - 1); return b + fib(n - 2) + a - n; } int main() { return fib(10); }", ex_ret: 55 },
+            TestData { inst: "int main() { int a = 7; int b = 3; int c = 0; int d = 1; int e = 2; int f = 5; a %= b; b *= f; c -= e; d /= 1; f = a + b + c + d + e + f-- + --e; return f + e; }", ex_ret: 24 },
+            TestData { inst: "char g() { return 300; } int f() { char c = 5; return c; } int main() { return f() + g(); }", ex_ret: 49 },
+            TestData { inst: "unsigned char g(int n) { return n; } int main() { return g(-1) == 255; }", ex_ret: 1 },
+            TestData { inst: "int main() { return sizeof(int) - 8 > 0; }", ex_ret: 1 },
+            TestData { inst: "struct S { int a[3]; char c; }; int main() { struct S s; return sizeof(s.a) * 10 + sizeof(s.a[1]); }", ex_ret: 124 },
+            TestData { inst: "struct S { char a[5]; int c; }; int main() { struct S s; struct S* p = &s; return sizeof p->a * 10 + sizeof(s.c); }", ex_ret: 54 },
+            TestData { inst: "struct S { int a; }; int main() { struct S s; return sizeof(s.b); }", ex_ret: -1 },
+            TestData { inst: "int main() { int x; return x.b; }", ex_ret: -1 },
+            TestData { inst: "int main() { int b = 3; return sizeof(int) - 8 < b; }", ex_ret: 0 },
+            TestData { inst: "int main() { return sizeof(char) - 2 >= 1 && sizeof(int) <= 4; }", ex_ret: 1 },
+            TestData { inst: "int main() { unsigned int u = 1; int i = -1; return u < i; }", ex_ret: 1 },
             TestData { inst: "int main() { int a = 0; a += 102; return a; }", ex_ret: 102 },
             TestData { inst: "int main() { int a = 0; a += (10 * 2); return a; }", ex_ret: 20 },
             TestData { inst: "int main() { int a = 100; a -= 81; return a; }", ex_ret: 19 },
@@ -407,10 +628,276 @@ mod test {
             TestData { inst: "int main() { struct A { char a; char b; }; struct A c; return sizeof(c); }", ex_ret: 2 },
             TestData { inst: "int main() { struct A { char a; int b; int c; }; struct A d; return sizeof(d); }", ex_ret: 12 },
             TestData { inst: "struct A { char a; char b; char c; char d; char e; }; int main() { struct A x; return sizeof(x); }", ex_ret: 5 },
+            TestData { inst: "struct node { int v; struct node* next; }; int main() { struct node a; struct node b; a.v = 3; b.v = 4; a.next = &b; b.next = 0; struct node* p; p = &a; int s = 0; while (p) { s = s + p->v; p = p->next; } return s; }", ex_ret: 7 },
+            TestData { inst: "struct node; struct node { int v; struct node* next; }; int main() { struct node a; struct node* head; a.v = 5; a.next = &a; head = &a; return head->next->next->v + sizeof(struct node); }", ex_ret: 21 },
+            TestData { inst: "struct node; int main() { struct node a; return 1; }", ex_ret: -1 },
+            TestData { inst: "struct node { int v; struct node n; }; int main() { return 1; }", ex_ret: -1 },
+            TestData { inst: "int a = 1; int b = 2; int main() { a = 5; return a + b; }", ex_ret: 7 },
+            TestData { inst: "char c; int a; int b; int main() { b = 7; a = -1; a += 3; a++; ++a; c = 300; return a + b + c; }", ex_ret: 55 },
+            TestData { inst: "char c1; char c2; int main() { c2 = 3; c1 = 1; return c1 + c2; }", ex_ret: 4 },
+            TestData { inst: "int main() { int a[10]; return &a[5] - &a[2]; }", ex_ret: 3 },
+            TestData { inst: "int main() { int a[10]; int* p = &a[7]; int* q = a; int n = p - q; return n * 2; }", ex_ret: 14 },
+            TestData { inst: "int main() { int a[10]; int* p = a; int* q = &a[4]; return (p - q) + 10; }", ex_ret: 6 },
+            TestData { inst: "int main() { int a[10]; a[3] = 4; int* p = &a[3]; return *p; }", ex_ret: 4 },
+            TestData { inst: "int main() { int a[3]; char b[3]; return &a[2] - &b[0]; }", ex_ret: -1 },
+            TestData { inst: "int main() { int a[2 > 1 ? 3 : 5]; a[0] = 1; a[2] = 4; return a[0] + a[2]; }", ex_ret: 5 },
+            TestData { inst: "int g = 2 < 1 ? 1 : 0 ? 4 : 9; int main() { return g; }", ex_ret: 9 },
+            TestData { inst: "int g = 3 == 3 && 1 ? 7 : 0; char c = !0 ? 2 : 5; int main() { return g + c; }", ex_ret: 9 },
+            TestData { inst: "char* msg = \"hi\"; int g = 3; int main() { char* p = msg; return *p + g; }", ex_ret: 107 },
+            TestData { inst: "int main() { int x = 0 ? 2 : 3; int y = 1 && 0; int z = 0 || 2; return x * 10 + y * 5 + z; }", ex_ret: 31 },
+            TestData { inst: "int f(char* s) { return *s; } int main() { return f(\"hello\"); }", ex_ret: 104 },
+            TestData { inst: "int f(int n, char* s, char* t) { return n + *s - *t; } int main() { return f(1, \"b\", \"a\"); }", ex_ret: 2 },
+            TestData { inst: "int main() { char* s = \"foo\" \"bar\"; return s[3] + s[5]; }", ex_ret: 212 },
+            TestData { inst: "int f(char* s) { return s[0] + s[1]; } int main() { char a[3]; a[0] = 7; a[1] = 9; return f(a); }", ex_ret: 16 },
+            TestData { inst: "int main() { int x = 3; int* p = &x; return -p == 0; }", ex_ret: -1 },
+            TestData { inst: "int main() { int x = 3; int* p = &x; return +p == 0; }", ex_ret: -1 },
+            TestData { inst: "int main() { int x = 3; return -x + 10; }", ex_ret: 7 },
+            TestData { inst: "int main() { foo x; return 0; }", ex_ret: -1 },
+            TestData { inst: "typedef int t; int main() { return sizeof(t); }", ex_ret: 4 },
+            TestData { inst: "int main() { typedef char c; typedef int* p; return sizeof(c) + sizeof(p); }", ex_ret: 9 },
+            TestData { inst: "struct A { char a; int b; int c; }; typedef struct A a_t; int main() { return sizeof(a_t); }", ex_ret: 12 },
+            TestData { inst: "int main() { struct A { char a; char b; }; typedef struct A a_t; typedef struct A* ap; return sizeof(a_t) + sizeof(ap); }", ex_ret: 10 },
+            TestData { inst: "typedef int t; int main() { t x = 3; return x; }", ex_ret: 3 },
+            TestData { inst: "typedef int t; t g = 4; t add(t a, t b) { return a + b; } int main() { t* p; t x = 3; t arr[2]; arr[1] = 5; p = &x; return add(*p, g) + arr[1]; }", ex_ret: 12 },
+            TestData { inst: "typedef char* str; int main() { str s = \"ab\"; return *s; }", ex_ret: 97 },
+            TestData { inst: "typedef struct P { int x; int y; } P; int main() { P v; P* q; v.y = 6; q = &v; return q->y + sizeof(P); }", ex_ret: 14 },
+            TestData { inst: "typedef int t; int main() { int t = 2; return t; }", ex_ret: 2 },
+            TestData { inst: "int main() { return y + 1; }", ex_ret: -1 },
+            TestData { inst: "int main() { return 0 == 1 < 0; }", ex_ret: 1 },
+            TestData { inst: "int main() { return 8 >> 2 < 3; }", ex_ret: 1 },
+            TestData { inst: "int main() { return 3 & 2 == 2; }", ex_ret: 1 },
+            TestData { inst: "int main() { return 1 | 6 & 2; }", ex_ret: 3 },
+            TestData { inst: "int main() { return 3 ^ 1 | 4; }", ex_ret: 6 },
+            TestData { inst: "int main() { return 6 ^ 3 & 1; }", ex_ret: 7 },
+            TestData { inst: "int main() { int x; return sizeof x; }", ex_ret: 4 },
+            TestData { inst: "int main() { int* p; return sizeof *p; }", ex_ret: 4 },
+            TestData { inst: "int main() { char* p; return sizeof *p + 1; }", ex_ret: 2 },
+            TestData { inst: "int main() { int a[10]; return sizeof a[1]; }", ex_ret: 4 },
+            TestData { inst: "int main() { char x; return sizeof(x) + sizeof x; }", ex_ret: 2 },
+            TestData { inst: "int main() { char a = 100; char c = a + a; return c == -56; }", ex_ret: 1 },
+            TestData { inst: "int main() { char a = 100; char c; return (c = a + a) == -56; }", ex_ret: 1 },
+            TestData { inst: "int main() { char a = 100; return (a + a) / 2; }", ex_ret: 100 },
+            TestData { inst: "int main() { char a = 100; return sizeof(a + a); }", ex_ret: 4 },
+            TestData { inst: "int main() { char a = 100; char b = 100; char c = a + b; return c == -56; }", ex_ret: 1 },
+            TestData { inst: "int main() { char a; int b; char c; a = 1; b = 300; c = 2; return b - 290 + a + c; }", ex_ret: 13 },
+            TestData { inst: "int main() { int a; int b[3]; int c; a = 1; b[0] = 2; b[1] = 3; b[2] = 4; c = 5; return a + b[0] + b[1] + b[2] + c; }", ex_ret: 15 },
+            TestData { inst: "int f(char a, int b, char c) { return a + b + c; } int main() { return f(1, 20, 3); }", ex_ret: 24 },
+            TestData { inst: "int main() { int* p = (int[]){1, 2, 3}; return p[2]; }", ex_ret: 3 },
+            TestData { inst: "int main() { int* p; p = (int[4]){5, 6}; return p[0] + p[1] + p[3]; }", ex_ret: 11 },
+            TestData { inst: "int main() { char* p = (char[]){7, 8,}; return *(p + 1); }", ex_ret: 8 },
+            TestData { inst: "int main() { int* p; p = 0; if (p == 0) { return 3; } return 4; }", ex_ret: 3 },
+            TestData { inst: "int main() { int a; int* p = &a; if (p != 0) { return 3; } return 4; }", ex_ret: 3 },
+            TestData { inst: "int main() { int* p = 0; return p == 0; }", ex_ret: 1 },
+            TestData { inst: "int main() { int x = 0; do { x = x + 1; } while (0); return x; }", ex_ret: 1 },
+            TestData { inst: "int main() { int x = 3; do { x = x - 1; } while (x); return x + 7; }", ex_ret: 7 },
+            TestData { inst: "int main() { int x = 0; do { } while (0); return 6; }", ex_ret: 6 },
+            TestData { inst: "int main() { int x = 0; while (x) { } return 5; }", ex_ret: 5 },
+            TestData { inst: "int main() { int x; for (x = 0; x < 3; x++); return x; }", ex_ret: 3 },
+            TestData { inst: "int main() { int x = 0; while (x++ < 4); return x; }", ex_ret: 5 },
+            TestData { inst: "int main() { int x = 0; do ; while (x++ < 2); return x; }", ex_ret: 3 },
+            TestData { inst: "int f(int x) { if (x == 1) return 10; else if (x == 2) return 20; else return 30; } int main() { return f(1) + f(2) + f(3); }", ex_ret: 60 },
+            TestData { inst: "int main() { int x = 5; int r; if (x < 3) r = 1; else if (x < 6) r = 2; else r = 3; return r; }", ex_ret: 2 },
+            TestData { inst: "int main() { int x = 9; int r; if (x < 3) { r = 1; } else if (x < 6) r = 2; else if (x < 8) { r = 3; } else r = 4; return r; }", ex_ret: 4 },
+            TestData { inst: "int main() { int n = 0; while (1) { n++; if (n == 4) break; } return n; }", ex_ret: 4 },
+            TestData { inst: "int main() { int n = 3; return 1 << n; }", ex_ret: 8 },
+            TestData { inst: "int main() { int x = 1000; int n = 4; return x >> n; }", ex_ret: 62 },
+            TestData { inst: "int main() { int x = -64; int n = 3; return (x >> n) == -8; }", ex_ret: 1 },
+            TestData { inst: "int main() { int x = -8; return (x >> 1) == -4; }", ex_ret: 1 },
+            TestData { inst: "int main() { int x = 2; if (x) return 1; return 0; }", ex_ret: 1 },
+            TestData { inst: "int main() { int n = 20; int k = 0; for (char c = 0; c < n; c++) { k++; } return k; }", ex_ret: 20 },
+            TestData { inst: "int main() { int n = 0; char c; for (c = 120; c > 0; c++) { n++; } return n * 10 + (c == -128); }", ex_ret: 81 },
+            TestData { inst: "int main() { int n = 0; unsigned char c; for (c = 250; c != 4; c++) { n++; } return n; }", ex_ret: 10 },
+            TestData { inst: "int main() { int n = 0; for (unsigned char c = 200; c < 300; c++) { n++; if (n > 100) return 1; } return 0; }", ex_ret: 1 },
+            TestData { inst: "int main() { int x = 7; char c = 127; c++; ++c; c--; --c; return x * 10 + (c == 127); }", ex_ret: 71 },
+            TestData { inst: "int main() { char c = 127; int x = ++c; return x == -128; }", ex_ret: 1 },
+            TestData { inst: "int main() { unsigned char c = 0; int x = c--; return x * 10 + (c == 255); }", ex_ret: 1 },
+            TestData { inst: "int main() { unsigned char c = 200; int n = 100; return (c > n) + sizeof(unsigned char) + sizeof(c); }", ex_ret: 3 },
+            TestData { inst: "int main() { int i; int n = 0; for (i = 0; i < 6; i++) { switch (i % 3) { case 0: continue; case 1: if (i > 3) continue; n = n + 10; break; default: n++; } n = n + 100; } return n - 300; }", ex_ret: 12 },
+            TestData { inst: "int main() { int i = 0; int n = 0; while (i < 4) { i++; switch (i) { case 2: continue; default: switch (i) { case 3: continue; } } n = n + i; } return n; }", ex_ret: 5 },
+            TestData { inst: "int main() { int i; int n = 0; for (i = 0; i < 5; i++) { if (i == 1) continue; if (i == 3) continue; n = n + i; } return n; }", ex_ret: 6 },
+            TestData { inst: "int main() { int x = 0; switch (x) { case 0: continue; } return 0; }", ex_ret: -1 },
+            TestData { inst: "int main() { int x = 2; if (x) return 1; else return 0; }", ex_ret: 1 },
+            TestData { inst: "int main() { int x = 0; if (x) return 1; else return 2; }", ex_ret: 2 },
+            TestData { inst: "int main() { int x = 0; return (x == 0) * 10 + (0 != x); }", ex_ret: 10 },
+            TestData { inst: "int main() { int x = 4294967296 - 1; return (x == 0) + (x != 0) * 2; }", ex_ret: 2 },
+            TestData { inst: "int main() { int *p = 0; return p == 0; }", ex_ret: 1 },
+            TestData { inst: "int main() { unsigned x = -8; return (x >> 1) == 2147483644; }", ex_ret: 1 },
+            TestData { inst: "int main() { unsigned x = 100; return x / 8 + x % 8; }", ex_ret: 16 },
+            TestData { inst: "int main() { unsigned x = -1; return (x / 16 == 268435455) + (x % 16 == 15) * 2; }", ex_ret: 3 },
+            TestData { inst: "int main() { unsigned x = 77; return (x / 1 == 77) + (x % 1 == 0) * 2 + (x / 6 == 12) * 4 + (x % 6 == 5) * 8; }", ex_ret: 15 },
+            TestData { inst: "int main() { unsigned int x = -8; int n = 28; return x >> n; }", ex_ret: 15 },
+            TestData { inst: "int main() { int x = -8; int n = 28; return (x >> n) == -1; }", ex_ret: 1 },
+            TestData { inst: "int main() { unsigned int x = 1; return (x << 31) >> 31; }", ex_ret: 1 },
+            TestData { inst: "signed char g() { return 300; } int main() { signed char c = 200; signed x = 5; signed int y = 2; return c + x + y + g() + sizeof(signed char) + sizeof(signed); }", ex_ret: 0 },
+            TestData { inst: "int f(unsigned int x) { return x >> 30; } int main() { return f(-1); }", ex_ret: 3 },
+            TestData { inst: "int main() { int n = 31; return (1 << n) < 0; }", ex_ret: 1 },
+            TestData { inst: "int main() { int n = 33; return 1 << n; }", ex_ret: 2 },
+            TestData { inst: "int main() { int a[0]; return 1; }", ex_ret: -1 },
+            TestData { inst: "int main() { int a = 1; int b = 2; (a ? a : b) = 5; return a; }", ex_ret: -1 },
+            TestData { inst: "int main() { int a[-1]; return 1; }", ex_ret: -1 },
+            TestData { inst: "int main() { int a[2][0]; return 1; }", ex_ret: -1 },
+            TestData { inst: "int main() { int n = 3; int a[n]; return 1; }", ex_ret: -1 },
+            TestData { inst: "int g(int x) { return x + 1; } int f(int a, int b, int* c, int d) { return a + b + *c + d; } int main() { int a[2]; int x = 3; int y = 4; a[0] = 10; return f(g(1), a[0], &x, y + 1); }", ex_ret: 20 },
+            TestData { inst: "int g(int x) { return x * 2; } int main() { return g(g(g(1))); }", ex_ret: 8 },
+            TestData { inst: "int f(int a, int b) { return a - b; } int main() { return f((3 + 4) * 2, (1)); }", ex_ret: 13 },
+            TestData { inst: "#define N 5\nint main() { int a[N]; a[N - 1] = N; return a[4] + sizeof(a) / 4; }", ex_ret: 10 },
+            TestData { inst: "#define ONE 1\n#define TWO ONE + ONE\nint main() { return TWO * 3; }", ex_ret: 4 },
+            TestData { inst: "#define X 1\n#undef X\nint main() { int X = 7; return X; }", ex_ret: 7 },
+            TestData { inst: "#define A B\n#define B A\nint main() { int A = 3; return A; }", ex_ret: 3 },
+            TestData { inst: "#define F(x) x\nint main() { return F(1); }", ex_ret: -1 },
+            TestData { inst: "#define\nint main() { return 0; }", ex_ret: -1 },
+            TestData { inst: "#include <stdio.h>\nint main() { return 0; }", ex_ret: -1 },
+            TestData { inst: "#\nint main() { return 3; }", ex_ret: 3 },
+            TestData { inst: "int main() { return sizeof int; }", ex_ret: -1 },
+            TestData { inst: "int main() { return 0; } int 3;", ex_ret: -1 },
+            TestData { inst: "int main() { int 3; return 0; }", ex_ret: -1 },
+            TestData { inst: "int main() { char c = -1; return c < 0; }", ex_ret: 1 },
+            TestData { inst: "int main() { char c = -1; return c > 0; }", ex_ret: 0 },
+            TestData { inst: "int main() { char c = -5; char d = 3; return (c < d) + (c >= d) * 2; }", ex_ret: 1 },
+            TestData { inst: "int main() { char c = -1; return c + 0 < 0; }", ex_ret: 1 },
+            TestData { inst: "int main() { char c = 127; c++; return c == -128; }", ex_ret: 1 },
+            TestData { inst: "int main() { char a[2]; a[1] = -3; return a[1] <= -3; }", ex_ret: 1 },
+            TestData { inst: "int main() { int x = -1; return x < 0; }", ex_ret: 1 },
+            TestData { inst: "struct Node { int value; struct Node *next; }; int main() { struct Node a; struct Node b; struct Node *list = &a; a.value = 1; a.next = &b; b.value = 2; b.next = 0; list->next->value = 5; return a.value * 10 + b.value; }", ex_ret: 15 },
+            TestData { inst: "struct Node { int value; struct Node *next; }; int sum(struct Node *p) { int s = 0; while (p != 0) { s = s + p->value; p = p->next; } return s; } int main() { struct Node a; struct Node b; struct Node c; a.value = 1; a.next = &b; b.value = 2; b.next = &c; c.value = 3; c.next = 0; a.next->next->value = 30; return sum(&a); }", ex_ret: 33 },
+            TestData { inst: "struct P { char c; int x; char d; }; int main() { struct P p; struct P *q = &p; q->c = -1; q->x = -7; q->d = 3; return (p.c < 0) + (q->x == -7) * 2 + p.d * 4; }", ex_ret: 15 },
+            TestData { inst: "int main() { struct S { int x; int y; }; struct S s; s.y = 3; int *p = &s.x; *p = -1; return s.y * 10 + (s.x == -1); }", ex_ret: 31 },
+            TestData { inst: "int main() { struct S { int x; int y; }; struct S s; s.x = 4; int *p = &s.y; *p = 9; return s.x * 10 + s.y; }", ex_ret: 49 },
+            TestData { inst: "struct S { char c; int x; }; int main() { struct S s; struct S* p = &s; int* q = &p->x; *q = 7; return s.x + sizeof(s.x) * 10 + sizeof(p->c) * 100; }", ex_ret: 147 },
+            TestData { inst: "struct S { int x; int y; }; int main() { struct S a; struct S b; a.x = 3; a.y = 4; b = a; a.x = 0; return b.x * 10 + b.y + a.x; }", ex_ret: 34 },
+            TestData { inst: "struct S { char c; int x; int y; }; int main() { struct S a; struct S b; struct S c; struct S* p = &b; a.c = 2; a.x = 3; a.y = 4; c = *p = a; return p->c * 100 + c.x * 10 + c.y; }", ex_ret: 234 },
+            TestData { inst: "struct S { int x; }; struct T { int x; }; int main() { struct S a; struct T b; b = a; return 0; }", ex_ret: -1 },
+            TestData { inst: "static int unused(int x) { return x; } static int twice(int x) { return x * 2; } int main() { return twice(4); }", ex_ret: 8 },
+            TestData { inst: "int main() { int a[5] = {[2] = 7, [4] = 9}; return a[0] + a[1] + a[2] * 10 + a[3] + a[4]; }", ex_ret: 79 },
+            TestData { inst: "int main() { int a[3] = {1, 2}; return a[0] * 100 + a[1] * 10 + a[2]; }", ex_ret: 120 },
+            TestData { inst: "int main() { char s[4] = {[1] = 5, 6}; return s[0] + s[1] * 10 + s[2] + s[3]; }", ex_ret: 56 },
+            TestData { inst: "int main() { int a[2] = {[2] = 1}; return 0; }", ex_ret: -1 },
+            TestData { inst: "int main() { int a[3] = {[1] = 1, [1] = 2}; return 0; }", ex_ret: -1 },
+            TestData { inst: "int foo(int x) { return x + 1; } int main() { int *p = foo; int *q = &foo; return (p == q) + (p != 0) * 2; }", ex_ret: 3 },
+            TestData { inst: "int foo() { return 1; } int bar() { return 2; } int main() { int *p = foo; int *q = bar; return (p == q) + foo() + bar(); }", ex_ret: 3 },
+            TestData { inst: "int main() { int *p, a[3], x; x = 2; a[1] = 3; p = &x; return *p + a[1] * 10; }", ex_ret: 32 },
+            TestData { inst: "int main() { char c, *s, t[2]; c = 4; s = &c; t[1] = 5; return *s + t[1]; }", ex_ret: 9 },
+            TestData { inst: "void set(int *p, int v) { if (v > 5) return; *p = v; } int main() { int x; x = 1; set(&x, 3); set(&x, 9); return x; }", ex_ret: 3 },
+            TestData { inst: "int main() { }", ex_ret: 0 },
+            TestData { inst: "int main() { return 7; }", ex_ret: 7 },
+            TestData { inst: "int main() { int x; x = 9; if (x > 10) return 1; }", ex_ret: 0 },
+            TestData { inst: "int main() { int a = 10; a += 2; a -= 1; a *= 3; return a * 2 - 1 + 3; }", ex_ret: 68 },
+            TestData { inst: "int main() { int a[3]; int i; i = 1; a[0] = 1; a[1] = 10; a[2] = 100; a[i] -= 3; a[i] *= 2; a[2] /= 7; return a[0] + a[1] + a[2]; }", ex_ret: 29 },
+            TestData { inst: "struct S { char c; int x; int y; }; int main() { struct S s; struct S *p = &s; s.c = 1; s.x = 5; s.y = 7; s.x += 1; p->y *= 2; p->c += 2; return s.c + s.x * 10 + s.y; }", ex_ret: 77 },
+            TestData { inst: "int main() { int x; int *p = &x; x = 17; *p %= 5; return x; }", ex_ret: 2 },
+            TestData { inst: "int main() { int a = 3; int b = (a += 4) * 2; return a + b; }", ex_ret: 21 },
+            TestData { inst: "int f(int x) { int r; r = 0; switch (x) { case 0: r = 10; break; case 1: r = 11; break; case 2: r = 12; case 3: r = r + 1; break; case 5: return 15; default: r = 99; } return r; } int main() { return f(0); }", ex_ret: 10 },
+            TestData { inst: "int f(int x) { int r; r = 0; switch (x) { case 0: r = 10; break; case 1: r = 11; break; case 2: r = 12; case 3: r = r + 1; break; case 5: return 15; default: r = 99; } return r; } int main() { return f(1); }", ex_ret: 11 },
+            TestData { inst: "int f(int x) { int r; r = 0; switch (x) { case 0: r = 10; break; case 1: r = 11; break; case 2: r = 12; case 3: r = r + 1; break; case 5: return 15; default: r = 99; } return r; } int main() { return f(2); }", ex_ret: 13 },
+            TestData { inst: "int f(int x) { int r; r = 0; switch (x) { case 0: r = 10; break; case 1: r = 11; break; case 2: r = 12; case 3: r = r + 1; break; case 5: return 15; default: r = 99; } return r; } int main() { return f(3); }", ex_ret: 1 },
+            TestData { inst: "int f(int x) { int r; r = 0; switch (x) { case 0: r = 10; break; case 1: r = 11; break; case 2: r = 12; case 3: r = r + 1; break; case 5: return 15; default: r = 99; } return r; } int main() { return f(4); }", ex_ret: 99 },
+            TestData { inst: "int f(int x) { int r; r = 0; switch (x) { case 0: r = 10; break; case 1: r = 11; break; case 2: r = 12; case 3: r = r + 1; break; case 5: return 15; default: r = 99; } return r; } int main() { return f(5); }", ex_ret: 15 },
+            TestData { inst: "int f(int x) { int r; r = 0; switch (x) { case 0: r = 10; break; case 1: r = 11; break; case 2: r = 12; case 3: r = r + 1; break; case 5: return 15; default: r = 99; } return r; } int main() { return f(6); }", ex_ret: 99 },
+            TestData { inst: "int f(int x) { int r; r = 0; switch (x) { case 0: r = 10; break; case 1: r = 11; break; case 2: r = 12; case 3: r = r + 1; break; case 5: return 15; default: r = 99; } return r; } int main() { return f(-1); }", ex_ret: 99 },
+            TestData { inst: "int f(int x) { switch (x) { case -100: return 1; case 7: return 2; case 1000: return 3; } return 4; } int main() { return f(-100); }", ex_ret: 1 },
+            TestData { inst: "int f(int x) { switch (x) { case -100: return 1; case 7: return 2; case 1000: return 3; } return 4; } int main() { return f(7); }", ex_ret: 2 },
+            TestData { inst: "int f(int x) { switch (x) { case -100: return 1; case 7: return 2; case 1000: return 3; } return 4; } int main() { return f(1000); }", ex_ret: 3 },
+            TestData { inst: "int f(int x) { switch (x) { case -100: return 1; case 7: return 2; case 1000: return 3; } return 4; } int main() { return f(5); }", ex_ret: 4 },
+            TestData { inst: "int main() { int i; int s; s = 0; for (i = 0; i < 5; i = i + 1) { switch (i) { case 1: continue; case 3: break; default: s = s + i; } s = s + 10; } return s; }", ex_ret: 46 },
+            TestData { inst: "int main() { int x; x = 2; switch (x) { case 1: case 2: switch (x + 1) { case 3: x = 7; break; } x = x + 1; break; case 4: x = 0; } return x; }", ex_ret: 8 },
+            TestData { inst: "int main() { switch (1) { case 1: case 1: return 1; } return 0; }", ex_ret: -1 },
+            TestData { inst: "int main() { case 1: return 0; }", ex_ret: -1 },
+            TestData { inst: "int main() { double a = 1.5; double b = 2.5; return a + b; }", ex_ret: 4 },
+            TestData { inst: "int main() { double a = 1.5; double b; b = a * 4 - 0.5; return b / 2; }", ex_ret: 2 },
+            TestData { inst: "int main() { double a = 1e-3; int i = a * 10000; double c = -2.5; int j = c * 2; return i + j + 100; }", ex_ret: 105 },
+            TestData { inst: "int main() { int x = -3; double d = x; unsigned u = 4294967295; double e = u; return (d + 10) + (e - 4294967290.0); }", ex_ret: 12 },
+            TestData { inst: "double half(int x) { return x / 2.0; } int main() { double d = half(7); return d * 2; }", ex_ret: 7 },
+            TestData { inst: "int main() { double d = 2.5E+1; return sizeof(d) + sizeof(double) + d; }", ex_ret: 41 },
+            TestData { inst: "int main() { double a = 1.5; double b = 2.5; return (a < b) + (a > b) * 2 + (a == 1.5) * 4 + (a != b) * 8 + (a <= 1.5) * 16 + (b >= a) * 32; }", ex_ret: 61 },
+            TestData { inst: "int main() { double a = 2.5; double b = 1.5; return (a < b) + (a > b) * 2 + (a == b) * 4 + (a != 2.5) * 8 + (a <= b) * 16 + (b >= 1.5) * 32; }", ex_ret: 34 },
+            TestData { inst: "int main() { double n = 0.0 / 0.0; return (n < 1.0) + (n > 1.0) * 2 + (n == n) * 4 + (n != n) * 8 + (n <= n) * 16 + (n >= 1.0) * 32; }", ex_ret: 8 },
+            TestData { inst: "int main() { double a = 2.0; int i = 2; return (a == i) + (i < 2.5) * 2 + (-1 > a) * 4; }", ex_ret: 3 },
+            TestData { inst: "int main() { double m = -0.0; return (m == 0) + (m != 0) * 2 + (m == 0.0) * 4; }", ex_ret: 5 },
+            TestData { inst: "int main() { int a = 5; int r = 0; if (a) { r = r + 1; } a = 0; if (a) { r = r + 2; } int n = -1; if (n) { r = r + 4; } return r; }", ex_ret: 5 },
+            TestData { inst: "int main() { int x = 3; int *p = &x; int n = 0; while (p) { n = n + *p; p = 0; } return n; }", ex_ret: 3 },
+            TestData { inst: "int main() { int i = 3; int n = 0; for (; i; i--) { n = n + i; } do { n = n + 10; } while (i); return n; }", ex_ret: 16 },
+            TestData { inst: "int main() { int a = 0; int *p = &a; char c = -128; return (a ? 1 : 2) + (p ? 10 : 20) + (c ? 100 : 0); }", ex_ret: 112 },
+            TestData { inst: "int main() { double z = -0.0; double h = 0.5; int r = 0; if (z) { r = r + 1; } if (h) { r = r + 2; } while (h) { r = r + 4; h = 0.0; } return r + (z ? 8 : 16) + !z * 32 + (z || h) * 64; }", ex_ret: 54 },
+            TestData { inst: "int main() { double n = 0.0 / 0.0; return (n ? 1 : 2) + !n * 4; }", ex_ret: 1 },
+            TestData { inst: "int main() { double d = 1.5; d += 2.5; return d; }", ex_ret: 4 },
+            TestData { inst: "int main() { double d = 3; d *= 2.5; d -= 0.5; d /= 2; return d * 2; }", ex_ret: 7 },
+            TestData { inst: "int main() { int i = 3; i += 2.5; i *= 1.5; char c = 10; c -= 1.5; return i * 10 + c; }", ex_ret: 78 },
+            TestData { inst: "int main() { double d = 1.5; d++; return d * 2; }", ex_ret: 5 },
+            TestData { inst: "int main() { double d = 1.5; double e = d++; double f = --d; d--; ++d; return e * 2 + d * 10 + f * 4; }", ex_ret: 24 },
+            TestData { inst: "double g = 1.5; double h = -2; int main() { g += 1; return g * 2 + h; }", ex_ret: 3 },
+            TestData { inst: "int main() { float f = 1.5; float g = 2.25; return (f + g) * 4 + sizeof(f) * 10 + sizeof(float) * 20 + sizeof(f + f) * 20; }", ex_ret: 215 },
+            TestData { inst: "int main() { float f = 16777217; float g = 3; g /= 2; g *= 3; g -= 0.25; g++; return (f - 16777216) + g * 4; }", ex_ret: 21 },
+            TestData { inst: "float g = 2.5; float h = -1; int main() { g += 1; ++g; return g * 2 + h; }", ex_ret: 8 },
+            TestData { inst: "float half(float x) { return x / 2; } int main() { float f = 7.0; return half(f) * 2 + half(3.0) * 4; }", ex_ret: 13 },
+            TestData { inst: "int main() { double d = 0x1.8p1; double e = 0x10p-4; double h = 0xAp0; return d * 100 + e * 10 + h + 0x1F - 300; }", ex_ret: 51 },
+            TestData { inst: "int main() { double d; double *p = &d; *p = 3; *p += 1.5; ++*p; return d * 2; }", ex_ret: 11 },
+            TestData { inst: "int main() { float f = 1.5; float *p = &f; *p = 2.5; *p *= 2; return f * 2 + *p; }", ex_ret: 15 },
+            TestData { inst: "struct P { double x; int y; }; int main() { struct P p; p.x = 2.5; p.y = 1; return p.x * 2 + p.y; }", ex_ret: 6 },
+            TestData { inst: "struct S { float f; double d; }; int main() { struct S s; struct S *p = &s; p->f = 1.5; p->d = 2; p->f += 1; s.d++; return p->f * 2 + s.d + sizeof(struct S); }", ex_ret: 24 },
+            TestData { inst: "int main() { double a[3]; a[0] = 1.5; a[1] = 2.5; a[2] = 4; return a[0] + a[1] + a[2] + sizeof(a); }", ex_ret: 32 },
+            TestData { inst: "int main() { float a[3]; float *p = a; p[2] = 2.5; p += 2; return *p * 2 + (p - a) + sizeof(a); }", ex_ret: 19 },
+            TestData { inst: "int main() { short a[3]; short *p = a; p[2] = -2; p++; return p[1] + 10 + (p - a) + sizeof(short*); }", ex_ret: 17 },
+            TestData { inst: "double g[2]; double *f() { return g + 1; } int main() { double *p = f(); *p = 2.5; double **q = &p; return **q * 2 + sizeof(g); }", ex_ret: 21 },
+            TestData { inst: "int n; int g() { n = 5; return 1; } int main() { (void)g(); return n; }", ex_ret: 5 },
+            TestData { inst: "int main() { int i = 0; int s = 0; for ((void)0; i < 3; (void)i++) { s = s + i; } (void)s; return s; }", ex_ret: 3 },
+            TestData { inst: "int main() { int x = (void)1; return 0; }", ex_ret: -1 },
+            TestData { inst: "int g() { return 1; } int main() { return (void)g(); }", ex_ret: -1 },
+            TestData { inst: "int main() { int x; x = 1 + (void)2; return 0; }", ex_ret: -1 },
+            TestData { inst: "int main() {}", ex_ret: 0 },
+            TestData { inst: "int x; int* get() { return &x; } int main() { *get() = 5; return x; }", ex_ret: 5 },
+            TestData { inst: "int x; int* get() { return &x; } int main() { x = 3; return *get(); }", ex_ret: 3 },
+            TestData { inst: "int x; int* get() { return &x; } int main() { *get() += 4; *get() *= 2; return x; }", ex_ret: 8 },
+            TestData { inst: "char c; char* get() { return &c; } int main() { *get() = 300; return c; }", ex_ret: 44 },
+            TestData { inst: "int f() { return 1; } int main() { *f() = 5; return 0; }", ex_ret: -1 },
+            TestData { inst: "int main() { 42; }", ex_ret: 0 },
+            TestData { inst: "int f() {} void g() {} int main() { f(); g(); return 7; }", ex_ret: 7 },
+            TestData { inst: "int n; int f() { n = n + 40; } int g() { 42; } int main() { f(); g(); return n + 2; }", ex_ret: 42 },
+            TestData { inst: "int main() { int n = 10; int s = 0; for (int i = 0, j = n; i < j; i++) { s = s + j - i; j = j - 1; } return s; }", ex_ret: 30 },
+            TestData { inst: "int main() { int n = 2; int s = 0; for (int k = 1, *p = &n, a[2]; k < 3; k++) { a[1] = k; s = s + *p + a[1]; } return s; }", ex_ret: 7 },
+            TestData { inst: "int main() { int n = 5; int s = 0; for (int i = 0, j = n; i < j; i++, j--) { s = s * 10 + i; } return s; }", ex_ret: 12 },
+            TestData { inst: "int main() { int i; int j; int s = 0; for (i = 0, j = 4; i < j; i++, j--) { s += j - i; } return s * 10 + i + j; }", ex_ret: 64 },
+            TestData { inst: "int main() { int i = 1; int j = 5; i++, j--; i += 2, j *= 2; return i * 10 + j; }", ex_ret: 48 },
+            TestData { inst: "int main() { int a = 1, b = a + 2, *p = &b, c; c = 4; return a + *p + c; }", ex_ret: 8 },
+            TestData { inst: "struct B { unsigned a:3; unsigned b:5; int c:4; int d; }; int main() { struct B x; x.a = 0; x.b = 31; x.c = 0; x.d = 7; x.a = 13; return x.a + x.b * 10 + x.d * 100; }", ex_ret: 1015 % 256 },
+            TestData { inst: "struct B { unsigned a:3; unsigned b:5; }; int main() { struct B x; struct B* p = &x; p->b = 20; p->a = 7; p->a += 2; return (x.a == 1) + (p->b == 20) * 2 + (sizeof(x) == 4) * 4; }", ex_ret: 7 },
+            TestData { inst: "struct B { int a:4; unsigned b:4; }; int main() { struct B x; x.b = 0; return (x.a = 9) == -7 && x.b == 0; }", ex_ret: 1 },
+            TestData { inst: "struct B { int a:3; }; int main() { struct B x; int* p = &x.a; return 0; }", ex_ret: -1 },
+            TestData { inst: "struct B { char c:3; }; int main() { return 0; }", ex_ret: -1 },
+            TestData { inst: "struct B { int a:33; }; int main() { return 0; }", ex_ret: -1 },
+            TestData { inst: "union U { int i; char c; }; int main() { union U u; union U* p = &u; u.i = 4660; int a = u.c; p->c = 1; return (a == 52) + (u.i == 4609) * 2 + (sizeof(u) == 4) * 4 + (sizeof(union U) == 4) * 8; }", ex_ret: 15 },
+            TestData { inst: "int main() { union U { int i; char c; int* p; }; union U u; union U v; u.i = 7; v = u; return v.i + sizeof(v) * 10; }", ex_ret: 87 },
+            TestData { inst: "union u { int i; char c[4]; }; int main() { union u x; x.i = 0x01020304; return sizeof(x) * 10 + x.c[1]; }", ex_ret: 43 },
+            TestData { inst: "union u { int i; char c[4]; }; int main() { union u x; union u* p = &x; x.i = 0; x.c[0] = 1; p->c[2] = 1; x.c[2] += 1; return (x.i == 131073) + (sizeof(union u) == 4) * 2; }", ex_ret: 3 },
+            TestData { inst: "struct S { int a[3]; char c; }; int main() { struct S s; struct S* p = &s; s.a[0] = 1; p->a[1] = 5; s.a[2] = 7; s.c = 2; return s.a[0] + s.a[1] * 10 + p->a[2] + s.c + sizeof(s) * 10; }", ex_ret: 220 },
+            TestData { inst: "struct S { char m[2][3]; int x; }; int main() { struct S s; s.m[1][2] = 4; s.m[0][2] = 3; s.x = 1; return s.m[1][2] + s.m[0][2] * 10 + sizeof(s) + s.x * 100; }", ex_ret: 146 },
+            TestData { inst: "struct point { int x; int y; }; int main() { struct point a[4]; int i; for (i = 0; i < 4; i = i + 1) { a[i].x = i; a[i].y = i * 10; } return a[2].x + a[3].y; }", ex_ret: 32 },
+            TestData { inst: "struct point { int x; int y; char c; }; int main() { int z = 7; struct point a[3]; int w = 9; struct point* p = a; a[1].c = 5; a[2].x = 3; a[0].y = 4; return a[1].c + p[2].x + a[0].y + z + w + sizeof(a); }", ex_ret: 64 },
+            TestData { inst: "struct point { int x; int y; }; int main() { struct point a[2]; a[0].x = 1; a[1].x = 3; a[1].y = 4; struct point* p = a; p = p + 1; struct point q = a[1]; return p->x * 10 + q.y; }", ex_ret: 34 },
+            TestData { inst: "int main() { int a = 65536; return (a * 32768) / 65536 == -32768; }", ex_ret: 1 },
+            TestData { inst: "int main() { int a = 65536; int b = 3; return (a * a + b) / b; }", ex_ret: 1 },
+            TestData { inst: "int main() { int a = 2147483647; return (a + 1) / 2 == -1073741824; }", ex_ret: 1 },
+            TestData { inst: "int main() { int a = -7; int b = -10; b /= 5; return (a / 5 == -1) + (a % 5 == -2) * 2 + (b == -2) * 4; }", ex_ret: 7 },
+            TestData { inst: "int main() { unsigned int a = 1; return (a - 2) / 2 == 2147483647; }", ex_ret: 1 },
+            TestData { inst: "int main() { int a = 1; goto skip; a = 50; skip: a = a + 2; return a; }", ex_ret: 3 },
+            TestData { inst: "int main() { int i = 0; int s = 0; loop: s = s + i; i = i + 1; if (i < 5) { goto loop; } return s; }", ex_ret: 10 },
+            TestData { inst: "int main() { int i; int n = 0; for (i = 0; i < 10; i++) { if (i == 3) { goto out; } n++; } out: return n; }", ex_ret: 3 },
+            TestData { inst: "int main() { goto end; return 1; end: ; }", ex_ret: 0 },
+            TestData { inst: "int main() { goto nowhere; return 1; }", ex_ret: -1 },
+            TestData { inst: "int s(int a[3], int n) { int i; int t = 0; for (i = 0; i < n; i++) { t += a[i]; } return t + sizeof a; } int main() { int b[3]; b[0] = 1; b[1] = 2; b[2] = 3; return s(b, 3); }", ex_ret: 14 },
+            TestData { inst: "int f(int a[]) { return a[1]; } int main() { int b[2]; b[1] = 7; return f(b); }", ex_ret: 7 },
+            TestData { inst: "int main() { a: ; a: return 1; }", ex_ret: -1 },
+            TestData { inst: "int main() { struct S { int x; }; struct S a; struct S b; a.x = 5; b = a; return b.x; }", ex_ret: 5 },
+            TestData { inst: "int main() { int a = 1 + ; a = 2 * / 3; if (a == ) { a = 3; } return a; }", ex_ret: -1 },
+            TestData { inst: "int f() { char* s = __func__; return *s; } int main() { char* s = __func__; return *s + f(); }", ex_ret: 211 },
+            TestData { inst: "char* g = __func__; int main() { return 0; }", ex_ret: -1 },
+            TestData { inst: "int main() { char* s = __func__; return s[1] + *(s + 3); }", ex_ret: 207 },
+            TestData { inst: "int a = 1 + ; int b = 2; int main() { return b; }", ex_ret: -1 },
+            TestData { inst: "int f(int a) { return a + 1; } int f(int a); int main() { return f(2); }", ex_ret: 3 },
+            TestData { inst: "int f(int a) { return a; } int f(char* a); int main() { return f(2); }", ex_ret: -1 },
         ];
 
         // Macの場合、位置独立形式でバイナリを生成できないので、Linux環境下でのみテスト
-        if false == Config::is_mac() {
+        if !Config::is_mac() {
             data.push(
                 TestData { inst: "int main() { char* i; i = \"test\"; return 1; }", ex_ret: 1, }
             );
@@ -431,8 +918,643 @@ mod test {
                 );
             });
 
+        // GNU拡張を有効にしたテスト
+        let gnu = Config { gnu: true, ..Default::default() };
+        let gnu_data = [
+            TestData { inst: "int main() { int x = 3; return x ?: 5; }", ex_ret: 3 },
+            TestData { inst: "int main() { int x = 0; return x ?: 5; }", ex_ret: 5 },
+            TestData { inst: "int main() { int x = 0; x = x++ ?: 9; return x; }", ex_ret: 9 },
+            TestData { inst: "int main() { int x = 1; int y = x++ ?: 9; return x + y; }", ex_ret: 3 },
+            TestData { inst: "int main() { int i; int n; n = 0; for (i = 0; i < 5; i++) { while (1) { if (i == 2) { break 2; } break; } n++; } return n; }", ex_ret: 2 },
+            TestData { inst: "int main() { int i; int n; n = 0; for (i = 0; i < 5; i++) { do { if (i == 3) { break 2; } n = n + 10; break; } while (1); } return n + i; }", ex_ret: 33 },
+            TestData { inst: "int main() { int n; n = 0; while (1) { if (n == 3) { break 1; } n++; } return n; }", ex_ret: 3 },
+            TestData { inst: "int main() { int a = 1; int b = 2; int c = 1; (c ? a : b) = 5; return a * 10 + b; }", ex_ret: 52 },
+            TestData { inst: "int main() { int a = 1; int b = 2; int c = 0; (c ? a : b) = 5; return a * 10 + b; }", ex_ret: 15 },
+            TestData { inst: "int main() { char a = 1; char b = 2; int r; r = ((a > b) ? a : b) = 300; return r == 44 && b == 44 && a == 1; }", ex_ret: 1 },
+            TestData { inst: "int main() { int x[2]; int y = 0; int *p = &y; int c = 0; x[0] = 0; (c ? x[0] : *p) = 7; (p ? x[0] : y) = 3; return x[0] * 10 + y; }", ex_ret: 37 },
+            TestData { inst: "int main() { int a = 1; int c = 1; (c ? a : 3) = 5; return a; }", ex_ret: -1 },
+            TestData { inst: "int main() { int a = 1; char b = 1; int c = 1; (c ? a : b) = 5; return a; }", ex_ret: -1 },
+            TestData { inst: "int main() { int x = ({ int t = 3; t + 1; }); return x; }", ex_ret: 4 },
+            TestData { inst: "int main() { int x = 3; int y = ({ int u = x; if (u > 2) u = u * 2; u; }) + ({ x; }); return x * 10 + y; }", ex_ret: 39 },
+            TestData { inst: "int main() { int x = 0; ({ x = 5; if (x) x++; }); return x; }", ex_ret: 6 },
+            TestData { inst: "int main() { int t = 10; int x = ({ int t = 3; t + 1; }); return t * 10 + x; }", ex_ret: 104 },
+            TestData { inst: "int main() { int t = 10; int x = ({ int t = 3; int a[2]; a[1] = t; ({ int t = 5; a[1] + t; }) + t; }); return t * 10 + x; }", ex_ret: 111 },
+            TestData { inst: "int main() { int s = 0; int i; for (i = 0; i < 3; i++) { s += ({ int i = 10; i; }); } int y = ({ struct P { int i; }; struct P p; p.i = 2; p.i; }); return s + i + y; }", ex_ret: 35 },
+        ];
+        gnu_data.iter()
+            .enumerate()
+            .for_each(|(i, d)| {
+                assert_eq!(
+                    d.ex_ret,
+                    eval_with_config(d.inst, &gnu),
+                    "\tFail Test(gnu): No.{}, inst: {}",
+                    i,
+                    d.inst
+                );
+            });
+
+        // -Dオプション
+        let mut define = Config::default();
+        define.add_define("DEBUG=1");
+        define.add_define("LEVEL=2");
+        define.add_define("LEVEL=3");
+        define.add_define("FLAG");
+        let define_data = [
+            TestData { inst: "int main() { int x = 1;\n#ifdef DEBUG\nx = x + 10;\n#endif\nreturn x; }", ex_ret: 11 },
+            TestData { inst: "int main() { int x = 1;\n#ifndef DEBUG\nx = x + 10;\n#else\nx = x + DEBUG;\n#endif\nreturn x; }", ex_ret: 2 },
+            TestData { inst: "int main() { return LEVEL * FLAG; }", ex_ret: 3 },
+            TestData { inst: "int g = FLAG ? LEVEL : 0; int main() { int a[DEBUG ? 2 : 0]; a[1] = 4; return g + a[1]; }", ex_ret: 7 },
+        ];
+        define_data.iter()
+            .enumerate()
+            .for_each(|(i, d)| {
+                assert_eq!(
+                    d.ex_ret,
+                    eval_with_config(d.inst, &define),
+                    "\tFail Test(define): No.{}, inst: {}",
+                    i,
+                    d.inst
+                );
+            });
+        assert_eq!(1, eval("int main() { int x = 1;\n#ifdef DEBUG\nx = x + 10;\n#endif\nreturn x; }"));
+
+        // 最適化を有効にしても、結果が変わらないこと
+        let o2 = Config { opt_level: OptLevel::O2, ..Default::default() };
+        let opt_data = [
+            TestData { inst: "int main() { if (0) { return 1; } else { return 2 * 3; } }", ex_ret: 6 },
+            TestData { inst: "int main() { int x = 1; while (0) { x = 5; } if (1) x = x + 2; return x; }", ex_ret: 3 },
+            TestData { inst: "int main() { int x = 2; switch (x) { case 1: return 1; x = 9; case 2: x = x * 4; break; x = 7; default: x = 0; } return x; }", ex_ret: 8 },
+            TestData { inst: "int main() { int i; int n = 0; for (i = 0; i < 4; i++) { if (i == 2) { continue; n = 100; } n = n + i * 16; } return n; }", ex_ret: 64 },
+            TestData { inst: "int main() { int x = 3; return 8 * x + x * 2 - (1 << 2) * 5; }", ex_ret: 10 },
+            TestData { inst: "int main() { return 2147483647 + 1 - 2147483647; }", ex_ret: 1 },
+            TestData { inst: "int count(int n, int acc) { if (n == 0) { return acc; } return count(n - 1, acc + 1); } int main() { return count(10000000, 0) % 256; }", ex_ret: 128 },
+            TestData { inst: "int fact(int n, int acc) { if (n <= 1) { return acc; } return fact(n - 1, acc * n); } int main() { return fact(10, 1) == 3628800; }", ex_ret: 1 },
+            TestData { inst: "int f(int n, int* p) { if (n == 0) { return *p; } int x = n; return f(n - 1, &x) + 0; } int g(int n, int* p) { int x = n * 2; if (n == 0) { return *p; } return g(n - 1, &x); } int main() { int a = 5; return f(3, &a) * 10 + g(3, &a); }", ex_ret: 12 },
+            TestData { inst: "int f(int n, int* p) { int x = n; if (n == 0) { return *p; } int* q = &x; return f(n - 1, q); } int main() { int a = 5; return f(3, &a); }", ex_ret: 1 },
+        ];
+        data.iter()
+            .chain(opt_data.iter())
+            .enumerate()
+            .for_each(|(i, d)| {
+                assert_eq!(
+                    d.ex_ret,
+                    eval_with_config(d.inst, &o2),
+                    "\tFail Test(O2): No.{}, inst: {}",
+                    i,
+                    d.inst
+                );
+            });
+
         // ファイル削除
         let _ = fs::remove_file("test.s");
         let _ = fs::remove_file("test");
     }
+
+    #[test]
+    fn test_emit_tokens() {
+        let tokens = |src: &str| {
+            let mut p = LexicalAnalysis::new("stdin".to_string(), src);
+            p.read_token();
+            dump_tokens(&Preprocessor::new().exec(p.get_tokens()))
+        };
+
+        // トークンを、位置情報、種別、内容の順に1行ずつ出力
+        assert_eq!(
+            tokens("int x = 1;"),
+            "stdin:1:1 Int int\n\
+             stdin:1:5 Variable x\n\
+             stdin:1:7 Assign =\n\
+             stdin:1:9 Number 1\n\
+             stdin:1:10 SemiColon ;\n\
+             stdin:1:10 End End\n"
+        );
+
+        // マクロは展開後のトークンとなる
+        let dump = tokens("#define ONE 1\nint x = ONE;");
+        assert!(dump.contains(" Number 1\n"));
+        assert!(!dump.contains("ONE"));
+    }
+
+    #[test]
+    fn test_emit_symbols() {
+        let src = "struct A { char a; int b; int c; }; int g = 3; char h = 1; int main() { struct A d; int y; return y; }";
+        let mut p = LexicalAnalysis::new("stdin".to_string(), src);
+        p.read_token();
+        let mut ast_gen = AstGen::new(p.get_tokens());
+        ast_gen.parse();
+        let dump = ast_gen.get_symbol().dump();
+        let lines: Vec<Vec<&str>> = dump.lines().map(|l| l.split_whitespace().collect()).collect();
+
+        // スコープごとにまとまっていること
+        let scopes: Vec<&str> = dump.lines().filter(|l| l.starts_with('[')).collect();
+        assert_eq!(scopes, vec!["[Global]", "[Func]", "[Local(\"main\")]"]);
+
+        // 名前、型、構造、サイズ、オフセット
+        assert!(lines.contains(&vec!["A", "Struct(\"A\")", "Struct", "12", "0"]));
+        assert!(lines.contains(&vec!["g", "Int", "Identifier", "4", "0"]));
+        assert!(lines.contains(&vec!["h", "Char", "Identifier", "1", "0"]));
+        assert!(lines.contains(&vec!["main", "Int", "Identifier", "8", "0"]));
+        assert!(lines.contains(&vec!["d", "Struct(\"A\")", "Struct", "12", "16"]));
+        assert!(lines.contains(&vec!["y", "Int", "Identifier", "4", "24"]));
+
+        // 構造体メンバーのオフセット
+        assert!(lines.contains(&vec![".a", "Char", "Identifier", "1", "0"]));
+        assert!(lines.contains(&vec![".b", "Int", "Identifier", "4", "4"]));
+        assert!(lines.contains(&vec![".c", "Int", "Identifier", "4", "8"]));
+    }
+
+    #[test]
+    fn test_emit_callgraph() {
+        let src = "int b(int x) { return x + 1; } \
+                   int a(int x) { return b(x) * b(x); } \
+                   int f(int n) { if (n == 0) { return 0; } return f(n - 1) + 1; } \
+                   int main() { int x; x = a(1) + b(2); return f(x); }";
+        let mut p = LexicalAnalysis::new("stdin".to_string(), src);
+        p.read_token();
+        let mut ast_gen = AstGen::new(p.get_tokens());
+        let ast_tree = ast_gen.parse();
+        let graph = ast_tree.call_graph();
+
+        // 呼び出し元→呼び出し先(重複なし、再帰呼び出しを含む)
+        assert_eq!(graph.len(), 4);
+        assert_eq!(graph["main"], vec!["a", "b", "f"]);
+        assert_eq!(graph["a"], vec!["b"]);
+        assert_eq!(graph["b"], Vec::<String>::new());
+        assert_eq!(graph["f"], vec!["f"]);
+        assert_eq!(ast_tree.dump_call_graph(), "a -> b\nb ->\nf -> f\nmain -> a, b, f\n");
+    }
+
+    #[test]
+    fn test_emit_ast_json() {
+        let src = "struct P { int x; }; int f(int a) { return a > 0 ? a : 0 - a; } int main() { return f(2); }";
+        let mut p = LexicalAnalysis::new("stdin".to_string(), src);
+        p.read_token();
+        let mut ast_gen = AstGen::new(p.get_tokens());
+        let json = ast_gen.parse().dump_json();
+
+        // 各ノードの種別とフィールドを出力する
+        [
+            r#"{"kind":"Global","children":[{"kind":"Struct","definition":{"kind":"Variable","type":"Struct(\"P\")","structure":"Struct","name":"P"}"#,
+            r#"{"kind":"FuncDef","type":"Int","structure":"Identifier","name":"f","args":{"kind":"Argment","children":[{"kind":"Variable","type":"Int","structure":"Identifier","name":"a"}]}"#,
+            r#"{"kind":"Return","value":{"kind":"Condition","condition":{"kind":"GreaterThan","left":"#,
+            r#""else":{"kind":"Minus","left":{"kind":"Factor","value":0}"#,
+            r#"{"kind":"FuncCall","func":{"kind":"Variable","type":"Int","structure":"Identifier","name":"f"},"args":{"kind":"Argment","children":[{"kind":"Factor","value":2}]}}"#,
+        ]
+        .iter()
+        .for_each(|e| assert!(json.contains(e), "{}\n{}", e, json));
+        assert!(json.starts_with('[') && json.ends_with(']'));
+    }
+
+    #[test]
+    fn test_global_storage() {
+        // 初期値なしは.bssへ、型のサイズ分を確保
+        let inst = compile("char c; int i; int* p; int a[3]; int main() { return 0; }", &Config::default()).unwrap();
+        assert!(inst.contains("  .bss\n  .balign 1\nc:\n  .zero 1\n"));
+        assert!(inst.contains("  .balign 4\ni:\n  .zero 4\n"));
+        assert!(inst.contains("  .balign 8\np:\n  .zero 8\n"));
+        assert!(inst.contains("  .balign 8\na:\n  .zero 12\n"));
+        assert!(!inst.contains(".data"));
+
+        // 初期値ありは.dataへ、型のサイズに応じたディレクティブで確保
+        let inst = compile("char c = 1; int i = 2; int* p = 0; int main() { return 0; }", &Config::default()).unwrap();
+        assert!(inst.contains("  .data\n  .balign 1\nc:\n  .byte 1\n"));
+        assert!(inst.contains("  .balign 4\ni:\n  .long 2\n"));
+        assert!(inst.contains("  .balign 8\np:\n  .quad 0\n"));
+        assert!(!inst.contains(".bss"));
+        // 文字列リテラルで初期化するポインタは、.rodataの文字列ラベルを指す
+        let inst = compile("char* msg = \"hi\"; int main() { return 0; }", &Config::default()).unwrap();
+        assert!(inst.contains("  .section .rodata\n.LC0:\n  .string \"hi\"\n"));
+        assert!(inst.contains("  .data\n  .balign 8\nmsg:\n  .quad .LC0\n"));
+    }
+
+    #[test]
+    fn test_annotate_ast() {
+        let src = "int f(int a) { return a; } int main() { return f(2) * 3; }";
+
+        // 各ノードの命令の前に、ノード種別のコメントを出力
+        let config = Config { annotate_ast: true, ..Default::default() };
+        let inst = compile(src, &config).unwrap();
+        assert!(inst.contains("  # FuncDef main\n"));
+        assert!(inst.contains("  # Return\n  # Multiple\n  # FuncCall f\n"));
+        assert!(inst.contains("  # Factor\n  mov $3, %rax\n"));
+        assert!(inst.contains("  # Variable a\n"));
+
+        // 未指定時はコメントを出力しない
+        let inst = compile(src, &Config::default()).unwrap();
+        assert!(!inst.contains("  #"));
+    }
+
+    #[test]
+    fn test_emit_llvm() {
+        let src = "int add(int a, int b) { return a + b; } int main() { int s; s = 0; while (s < 3) { s++; } return add(s, 2); }";
+
+        // 関数ごとにdefineを出力し、ローカル変数はallocaした領域へ格納する
+        let config = Config { emit_llvm: true, ..Default::default() };
+        let inst = compile(src, &config).unwrap();
+        assert!(inst.contains("define i32 @add(i32 %a.param, i32 %b.param) {\n"));
+        assert!(inst.contains("  %s.addr = alloca i32\n"));
+        assert!(inst.contains(" = add i32 "));
+        assert!(inst.contains("  ret i32 "));
+        assert!(inst.contains(" = call i32 @add(i32 "));
+        assert!(inst.contains("  br i1 "));
+
+        // lliが利用できる場合は、実行結果を確認
+        let path = env::temp_dir().join(format!("rcc_emit_llvm_{}.ll", process::id()));
+        fs::write(&path, &inst).unwrap();
+        if let Ok(status) = Command::new("lli").arg(&path).status() {
+            assert_eq!(status.code(), Some(5));
+        }
+        let _ = fs::remove_file(&path);
+    }
+
+    #[test]
+    fn test_std() {
+        let c89 = Config { std: Std::C89, ..Default::default() };
+        let c99 = Config { std: Std::C99, ..Default::default() };
+
+        // //コメントはC99以降のみ
+        let src = "int main() {\n  // comment\n  return 1;\n}";
+        assert_eq!(
+            compile(src, &c89),
+            Err(vec!["stdin:2:3: C++ style comments are not allowed in ISO C90".to_string()])
+        );
+        assert!(compile(src, &c99).is_ok());
+
+        // 文の後の宣言はC99以降のみ(ブロック先頭の宣言は可)
+        let src = "int main() {\n  int a = 1;\n  a = a + 1;\n  int b = 2;\n  return a + b;\n}";
+        assert_eq!(
+            compile(src, &c89),
+            Err(vec!["stdin:4:3: ISO C90 forbids mixed declarations and code".to_string()])
+        );
+        assert!(compile(src, &c99).is_ok());
+        assert!(compile("int main() { int a; a = 1; { int c = 2; a = c; } return a; }", &c89).is_ok());
+
+        // for文の初期化部の宣言、複合リテラルはC99以降のみ
+        let src = "int main() { int n = 0; for (int i = 0; i < 3; i++) { n += i; } return n; }";
+        assert!(compile(src, &c89).is_err());
+        assert!(compile(src, &c99).is_ok());
+        let src = "int main() { int* p = (int[]){1, 2}; return p[1]; }";
+        assert!(compile(src, &c89).is_err());
+        assert!(compile(src, &c99).is_ok());
+    }
+
+    #[test]
+    fn test_pointer_index() {
+        // p[2]は*(p + 2)と同じく、添字を要素サイズ(intは4バイト)倍したオフセットを加算する
+        let index = compile("int main() { int a[4]; int* p = a; p[2] = 7; return 0; }", &Config::default()).unwrap();
+        let plus = compile("int main() { int a[4]; int* p = a; *(p + 2) = 7; return 0; }", &Config::default()).unwrap();
+        assert_eq!(index, plus);
+        assert!(index.contains("  mov $2, %rax\n  push %rax\n  pop %rax\n  mov $4, %rcx\n  mul %rcx\n"));
+    }
+
+    #[test]
+    fn test_diagnostics() {
+        // 警告とエラーは、重大度、メッセージ、位置を持つ診断として返す
+        let src = "int main() {\n  int a;\n  if (a = 1) { a = 2; }\n  return a / 0;\n}";
+        let (asm, diags) = compile_with_diagnostics(src, &Config::default());
+        assert!(asm.is_none());
+        assert_eq!(
+            diags,
+            vec![
+                Diagnostic {
+                    severity: Severity::Warning,
+                    message: "suggest parentheses around assignment used as truth value".to_string(),
+                    pos: Some(token::PosInfo::new("stdin".to_string(), 3, 7)),
+                },
+                Diagnostic {
+                    severity: Severity::Error,
+                    message: "division by zero".to_string(),
+                    pos: Some(token::PosInfo::new("stdin".to_string(), 4, 12)),
+                },
+            ]
+        );
+
+        // 意味解析の診断も同様(位置情報のないものはNone)
+        let (asm, diags) = compile_with_diagnostics("int f() { } int main() { return f(); }", &Config::default());
+        assert!(asm.is_some());
+        assert_eq!(diags.len(), 1);
+        assert_eq!(diags[0].severity, Severity::Warning);
+        assert_eq!(diags[0].message, "control reaches end of non-void function: f");
+        assert_eq!(diags[0].pos, None);
+    }
+
+    #[test]
+    fn test_int_arithmetic_width() {
+        // int型は32bitで演算し、結果を符号拡張する
+        let inst = compile("int main() { int a = 3; int b = 4; return a * b - a / b; }", &Config::default()).unwrap();
+        assert!(inst.contains("  imul %ecx\n  cltq\n"));
+        assert!(inst.contains("  cltd\n  idivl %ecx\n  cltq\n"));
+        assert!(inst.contains("  sub %ecx, %eax\n  cltq\n"));
+
+        // ポインタ同士の差(long型)は64bitで演算する
+        let inst = compile("int main() { int a[4]; int* p = a; int* q = a; return (q - p) * 2; }", &Config::default()).unwrap();
+        assert!(inst.contains("  imul %rcx, %rax\n"));
+    }
+
+    #[test]
+    fn test_compare_branch() {
+        // 条件が比較演算子の場合、0/1を作らずにフラグから直接分岐する
+        let inst = compile("int main() { int a = 1; int b = 2; if (a < b) { return 1; } return 0; }", &Config::default()).unwrap();
+        assert!(inst.contains("  cmp %rcx, %rax\n  jge .L2\n"));
+        assert!(!inst.contains("setl"));
+        assert!(!inst.contains("test %rax"));
+
+        // 符号なしの比較、ループ条件も同様(do-whileは真の場合に先頭へ戻る)
+        let inst = compile("int main() { unsigned a = 1; int i = 0; while (a > 5) { a = a - 1; } do { i++; } while (i <= 3); return i; }", &Config::default()).unwrap();
+        assert!(inst.contains("  cmp %rcx, %rax\n  jbe .L"));
+        assert!(inst.contains("  cmp %rcx, %rax\n  jle .L"));
+        assert!(!inst.contains("  set"));
+
+        // 値として使う場合は、0/1を作る
+        let inst = compile("int main() { int a = 1; int b = 2; int c = a < b; return c; }", &Config::default()).unwrap();
+        assert!(inst.contains("  setl %al\n"));
+    }
+
+    #[test]
+    fn test_stack_note() {
+        // 出力の末尾で、スタックを実行不可とする注記セクションを宣言する
+        let inst = compile("int main() { int a = 1; return a; }", &Config::default()).unwrap();
+        assert!(inst.ends_with("  ret\n  .section .note.GNU-stack,\"\",@progbits\n"));
+        let trapv = Config { trapv: true, ..Default::default() };
+        let inst = compile("int main() { int a = 1; return a + 1; }", &trapv).unwrap();
+        assert!(inst.ends_with("  call abort\n  .section .note.GNU-stack,\"\",@progbits\n"));
+    }
+
+    #[test]
+    fn test_member_operand() {
+        // &s.xは構造体の先頭アドレスにオフセットを加算したもの(メンバーの値は読み込まない)
+        let src = "struct S { char c; int x; }; int main() { struct S s; int* q; q = &s.x; return 0; }";
+        let inst = compile(src, &Config::default()).unwrap();
+        assert!(inst.contains("  lea -8(%rbp), %rax\n  push %rax\n  pop %rax\n  add $4, %rax\n  push %rax\n  pop %rcx\n  pop %rax\n"));
+
+        // sizeofはメンバーの型のサイズ
+        let src = "struct S { char c; int x; double d; }; int main() { struct S s; struct S* p = &s; return sizeof(s.x) + sizeof(p->c) * 10 + sizeof s.d * 100; }";
+        let inst = compile(src, &Config::default()).unwrap();
+        assert!(inst.contains("  mov $4, %rax\n"));
+        assert!(inst.contains("  mov $1, %rax\n"));
+        assert!(inst.contains("  mov $8, %rax\n"));
+    }
+
+    #[test]
+    fn test_opt_level() {
+        let o0 = Config::default();
+        let o1 = Config { opt_level: OptLevel::O1, ..Default::default() };
+        let o2 = Config { opt_level: OptLevel::O2, ..Default::default() };
+
+        // O1では定数畳み込みを行い、O0では行わない
+        let src = "int main() { return 2 * 3 + 4; }";
+        let inst = compile(src, &o0).unwrap();
+        assert!(inst.contains("imul"));
+        assert!(!inst.contains("mov $10, %rax"));
+        let inst = compile(src, &o1).unwrap();
+        assert!(!inst.contains("imul"));
+        assert!(inst.contains("mov $10, %rax"));
+
+        // 不要コード削除
+        let inst = compile("int main() { if (0) { return 5; } return 1; return 7; }", &o1).unwrap();
+        assert!(!inst.contains("$5"));
+        assert!(!inst.contains("$7"));
+
+        // のぞき穴最適化
+        let src = "int main() { int x = 1; return x; }";
+        assert!(compile(src, &o0).unwrap().contains("  push %rax\n  pop %rax\n"));
+        assert!(!compile(src, &o1).unwrap().contains("  push %rax\n  pop %rax\n"));
+
+        // O2では、2の累乗による乗算をシフトへ置き換える（-ftrapv指定時は除く）
+        let src = "int main() { int x = 3; return x * 8; }";
+        assert!(compile(src, &o1).unwrap().contains("imul"));
+        let inst = compile(src, &o2).unwrap();
+        assert!(!inst.contains("imul"));
+        assert!(inst.contains("sall"));
+        let trapv = Config { trapv: true, ..o2.clone() };
+        assert!(!compile(src, &trapv).unwrap().contains("sall"));
+
+        // O2では、自己末尾呼び出しをジャンプへ置き換える（ローカル変数のアドレスを取得する関数は除く）
+        let src = "int f(int n) { if (n == 0) { return 0; } return f(n - 1); } int main() { return f(3); }";
+        assert_eq!(compile(src, &o1).unwrap().matches("call f\n").count(), 2);
+        assert_eq!(compile(src, &o2).unwrap().matches("call f\n").count(), 1);
+        let src = "int f(int n, int* p) { int x = n; if (n == 0) { return *p; } return f(n - 1, &x); } int main() { return f(3, 0); }";
+        assert_eq!(compile(src, &o2).unwrap().matches("call f\n").count(), 2);
+        let src = "int f(int n, int* p) { int x = n; if (n == 0) { return *p; } int* q = &x; return f(n - 1, q); } int main() { return f(3, 0); }";
+        assert_eq!(compile(src, &o2).unwrap().matches("call f\n").count(), 2);
+
+        // O2では、アドレスを取らないローカル変数をcallee-savedレジスタへ割り当て、関数の出入口で退避、復帰する
+        let src = "int main() { int s = 0; int i; for (i = 0; i < 10; i++) { s += i; } return s; }";
+        assert!(!compile(src, &o1).unwrap().contains("%rbx"));
+        let inst = compile(src, &o2).unwrap();
+        assert!(inst.contains("  add $1, %rbx\n") || inst.contains("  add $1, %r12\n"));
+        assert!(inst.contains("  mov %rbx, -24(%rbp)\n  mov %r12, -32(%rbp)\n"));
+        assert!(inst.contains("  mov -24(%rbp), %rbx\n  mov -32(%rbp), %r12\n  leave\n"));
+        let inst = compile("int main() { int x = 1; int* p = &x; return *p; }", &o2).unwrap();
+        assert!(!inst.contains("%rbx"));
+    }
+
+    #[test]
+    fn test_stack_usage() {
+        let src = "int f(int a, int b, int c, int d, int e, int g) { return a + g; } \
+                   int g(int x) { return x; } \
+                   int main() { int a; int b; char c; int d[3]; a = g(1); return f(1, 2, 3, 4, 5, 6) + a; }";
+        assert!(compile(src, &Config::default()).is_ok());
+
+        let mut p = LexicalAnalysis::new("stdin".to_string(), src);
+        p.read_token();
+        let mut ast_gen = AstGen::new(p.get_tokens());
+        let tree = ast_gen.parse();
+        let sym = ast_gen.get_symbol();
+        let usage = tree.stack_usage(sym);
+
+        // フレームサイズはローカル変数の配置結果
+        let frame = |n: &str| sym.frame_size(&Scope::Local(n.to_string()));
+        assert_eq!(
+            usage,
+            vec![
+                StackUsage { name: "f".to_string(), frame: frame("f"), is_leaf: true },
+                StackUsage { name: "g".to_string(), frame: frame("g"), is_leaf: true },
+                StackUsage { name: "main".to_string(), frame: frame("main"), is_leaf: false },
+            ]
+        );
+        assert_eq!(frame("f"), 48);
+        assert_eq!(frame("main"), 32);
+        assert_eq!(tree.dump_stack_usage(sym), "f: frame=48 leaf\ng: frame=16 leaf\nmain: frame=32 non-leaf\n");
+    }
+
+    #[test]
+    fn test_remove_unused_functions() {
+        let src = "static int helper(int x) { return x + 1; } \
+                   static int dead_helper(int x) { return x * 2; } \
+                   static int dead(int x) { return dead_helper(x); } \
+                   int visible(int x) { return x; } \
+                   int main() { return helper(1); }";
+
+        // 呼び出されないstatic関数のみ削除
+        let inst = compile(src, &Config::default()).unwrap();
+        assert!(inst.contains("helper:"));
+        assert!(!inst.contains("dead_helper:"));
+        assert!(!inst.contains("dead:"));
+        assert!(inst.contains("visible:"));
+
+        // --gc-functions指定時は、mainから到達しない関数を全て削除
+        let config = Config { gc_functions: true, ..Default::default() };
+        let inst = compile(src, &config).unwrap();
+        assert!(inst.contains("helper:"));
+        assert!(!inst.contains("visible:"));
+        assert!(inst.contains("main:"));
+
+        // アドレスを参照している関数は削除しない
+        let src = "static int f() { return 1; } int main() { int *p = &f; return p != 0; }";
+        let inst = compile(src, &config).unwrap();
+        assert!(inst.contains("f:"));
+    }
+
+    #[test]
+    fn test_bounds_check() {
+        let run = |inst: &str, config: &Config| run_with_signal("test_bounds_check", inst, config);
+        let check = Config { bounds_check: true, ..Default::default() };
+
+        // 範囲外アクセスは-fbounds-check指定時のみabortする
+        let src = "int main() { int a[4]; int b = 9; int i = 4; a[i] = 1; return b; }";
+        assert_eq!(run(src, &Config::default()).0, None);
+        assert_eq!(run(src, &check), (Some(SIGABRT), None));
+
+        // 読み出し、負の添字、多次元配列、複合代入、グローバル配列も検査対象
+        let data = [
+            "int main() { int a[4]; int i = 5; return a[i]; }",
+            "int main() { int a[4]; int i = 0 - 1; a[i] = 1; return 0; }",
+            "int main() { int a[2][3]; int i = 2; int j = 0; a[i][j] = 1; return 0; }",
+            "int main() { int a[4]; int i = 4; a[i] += 1; return 0; }",
+            "int g[3]; int main() { int i = 3; return g[i]; }",
+        ];
+        data.iter().for_each(|src| assert_eq!(run(src, &check), (Some(SIGABRT), None), "{}", src));
+
+        // 範囲内のアクセスとポインタ経由のアクセスはそのまま
+        let src = "int main() { int a[4]; int *p = a; int i; for (i = 0; i < 4; i++) { a[i] = i; } p[1] = 5; return a[3] + a[1] + p[0]; }";
+        assert_eq!(run(src, &check), (None, Some(8)));
+    }
+
+    #[test]
+    fn test_empty_body() {
+        // 空の関数もプロローグ、エピローグを持つ
+        let inst = compile("int f() {} int main() { f(); }", &Config::default()).unwrap();
+        let f = &inst[inst.find("f:\n").unwrap()..inst.find("main:\n").unwrap()];
+        assert!(f.contains("push %rbp") && f.contains("ret\n"));
+
+        // mainの末尾へ到達した場合は0を返す
+        let main = &inst[inst.find("main:\n").unwrap()..];
+        assert!(main.contains("mov $0, %rax"));
+    }
+
+    #[test]
+    fn test_string_concat() {
+        // 隣接する文字列リテラルは、一つのリテラルとして出力される
+        let inst = compile("int main() { char* s; s = \"foo\" \"bar\"; char* t; t = \"baz\"; return 0; }", &Config::default()).unwrap();
+        assert_eq!(inst.matches(".string").count(), 2);
+        assert!(inst.contains(".LC0:\n  .string \"foobar\"\n"));
+        assert!(inst.contains(".LC1:\n  .string \"baz\"\n"));
+    }
+
+    #[test]
+    fn test_string_argment() {
+        // 文字列リテラルは.rodataへ配置し、先頭文字のアドレスを引数として渡す
+        let inst = compile("int f(char* s) { return *s; } int main() { return f(\"hi\"); }", &Config::default()).unwrap();
+        assert!(inst.contains("  .section .rodata\n.LC0:\n  .string \"hi\"\n"));
+        assert!(inst.contains("  lea .LC0(%rip), %rax\n"));
+    }
+
+    #[test]
+    fn test_unsigned_pow2_division() {
+        // unsigned int型の2のべき乗による除算はシフト、剰余はマスクとなる
+        let inst = compile("int main() { unsigned u = 100; return u / 8; }", &Config::default()).unwrap();
+        assert!(inst.contains("shrl $3, %eax"));
+        assert!(!inst.contains("idiv"));
+        let inst = compile("int main() { unsigned u = 100; return u % 8; }", &Config::default()).unwrap();
+        assert!(inst.contains("andl $7, %eax"));
+        assert!(!inst.contains("idiv"));
+
+        // 符号付き、2のべき乗以外は除算命令のまま
+        let inst = compile("int main() { int x = 100; return x / 8; }", &Config::default()).unwrap();
+        assert!(inst.contains("idiv"));
+        let inst = compile("int main() { unsigned u = 100; return u % 6; }", &Config::default()).unwrap();
+        assert!(inst.contains("divl %ecx"));
+    }
+
+    #[test]
+    fn test_zero_compare() {
+        // 0との比較は、cmp $0ではなくtestを使用する
+        let data = [
+            "int main() { int x = 1; if (x) return 2; return 3; }",
+            "int main() { int x = 1; return x == 0; }",
+            "int main() { int x = 1; return 0 != x; }",
+            "int main() { int x = 1; while (x) { x--; } return x; }",
+        ];
+        data.iter().for_each(|src| {
+            let inst = compile(src, &Config::default()).unwrap();
+            assert!(inst.contains("test %rax, %rax"), "{}", src);
+            assert!(!inst.contains("cmp $0,"), "{}", src);
+        });
+    }
+
+    #[test]
+    fn test_unique_labels() {
+        let src = "int f(int x) { int n = 0; while (x > 0) { if (x > 2 && (n < 5 || x == 3)) { n = n + (x > 4 ? 2 : 1); } else if (x && n) { n++; } x--; } return n; } \
+                   int main() { int i; int r = 0; for (i = 0; i < 3; i++) { while (r < 10 && i || r == 0) { if (i) { r = r + f(i); } else { r++; } if (r > 5) break; } \
+                   switch (i) { case 0: r++; break; case 1: r = r + 2; break; default: r = r ? r : 1; } } return r; }";
+        let inst = compile(src, &Config::default()).unwrap();
+
+        // ラベル定義が重複しないこと
+        let labels = inst.lines().filter(|l| l.starts_with(".L") && l.ends_with(':')).collect::<Vec<_>>();
+        let mut uniq = labels.clone();
+        uniq.sort();
+        uniq.dedup();
+        assert!(labels.len() > 10);
+        assert_eq!(labels.len(), uniq.len());
+
+        // ジャンプ先は全て定義されていること
+        inst.lines()
+            .filter(|l| l.trim_start().starts_with('j'))
+            .filter_map(|l| l.split_whitespace().nth(1))
+            .filter(|t| t.starts_with(".L"))
+            .for_each(|t| assert!(labels.contains(&format!("{}:", t).as_str()), "{}", t));
+    }
+
+    // 実行し、終了シグナル(abort時はSIGABRT)と終了コードを返す
+    //
+    // 他のテストと並列に実行されるため、nameで出力ファイル名を分ける
+    fn run_with_signal(name: &str, inst: &str, config: &Config) -> (Option<i32>, Option<i32>) {
+        use std::os::unix::process::ExitStatusExt;
+        let asm = compile(inst, config).unwrap();
+        let (src, bin) = (format!("./{}.s", name), format!("./{}", name));
+        fs::write(&src, asm).unwrap();
+        Command::new("gcc").args(["-g3", "-no-pie", &src, "-o", &bin]).output().unwrap();
+        let r = Command::new(&bin).status().unwrap();
+        let _ = fs::remove_file(&src);
+        let _ = fs::remove_file(&bin);
+        (r.signal(), r.code())
+    }
+
+    const SIGABRT: i32 = 6;
+
+    #[test]
+    fn test_trapv() {
+        let run = |inst: &str, config: &Config| run_with_signal("test_trapv", inst, config);
+        let trapv = Config { trapv: true, ..Default::default() };
+
+        // INT_MAX + 1は-ftrapv指定時のみabortする
+        let src = "int main() { int a = 2147483647; int b = a + 1; return b == 0 - 2147483647 - 1; }";
+        assert_eq!(run(src, &Config::default()), (None, Some(1)));
+        assert_eq!(run(src, &trapv), (Some(SIGABRT), None));
+
+        // 減算、乗算、複合代入も検査対象
+        let data = [
+            "int main() { int a = 0 - 2147483647; return a - 2; }",
+            "int main() { int a = 65536; return a * a; }",
+            "int main() { int a = 2147483647; a += 1; return 0; }",
+            "int main() { int a = 0 - 2147483647; a -= 2; return 0; }",
+            "int main() { int a = 65536; a *= a; return 0; }",
+        ];
+        data.iter().for_each(|src| assert_eq!(run(src, &trapv), (Some(SIGABRT), None), "{}", src));
+
+        // オーバーフローしない演算はそのまま
+        let src = "int main() { int a = 10; a *= 3; return a * 2 - 1 + 3; }";
+        assert_eq!(run(src, &trapv), (None, Some(62)));
+    }
 }
@@ -0,0 +1,162 @@
+use ast::{AstTree, AstType};
+use config::{Config, OptLevel};
+use symbol::Type;
+
+#[doc = "最適化部"]
+pub struct Optimizer<'a> {
+    config: &'a Config,
+}
+
+impl<'a> Optimizer<'a> {
+    // コンストラクタ.
+    pub fn new(config: &'a Config) -> Self {
+        Optimizer { config }
+    }
+
+    // 抽象構文木の最適化.
+    //
+    // O1: 定数畳み込み、不要コード削除
+    // O2: O1に加え、強度削減（-ftrapv指定時は乗算のオーバーフロー検査を残すため行わない）
+    pub fn exec(&self, tree: &mut AstTree) {
+        if self.config.opt_level >= OptLevel::O1 {
+            tree.tree.iter_mut().for_each(|a| {
+                Optimizer::fold_constants(a);
+                Optimizer::eliminate_dead_code(a);
+            });
+        }
+        if self.config.opt_level >= OptLevel::O2 && !self.config.trapv {
+            tree.tree.iter_mut().for_each(Optimizer::reduce_strength);
+        }
+    }
+
+    // アセンブラの最適化（のぞき穴最適化）.
+    //
+    // 隣接するpush/popを、レジスタ間の転送へ置き換える
+    pub fn exec_asm(&self, inst: &str) -> String {
+        if self.config.opt_level < OptLevel::O1 {
+            return inst.to_string();
+        }
+
+        let mut lines: Vec<String> = vec![];
+        for line in inst.lines() {
+            let push_reg = lines.last().and_then(|l| l.strip_prefix("  push ")).map(|r| r.to_string());
+            match (push_reg, line.strip_prefix("  pop ")) {
+                (Some(ref src), Some(dst)) if src.starts_with('%') && dst.starts_with('%') => {
+                    lines.pop();
+                    if src != dst {
+                        lines.push(format!("  mov {}, {}", src, dst));
+                    }
+                }
+                _ => lines.push(line.to_string()),
+            }
+        }
+        lines.iter().map(|l| format!("{}\n", l)).collect()
+    }
+
+    // 定数畳み込み.
+    fn fold_constants(ast: &mut AstType) {
+        match Optimizer::int_constant(ast) {
+            Some(n) if !matches!(ast, AstType::Factor(_)) => *ast = AstType::Factor(n),
+            _ => ast.children_mut().into_iter().for_each(Optimizer::fold_constants),
+        }
+    }
+
+    // int型の範囲で評価できる定数式の値（途中の演算結果もint型に収まる場合のみ）
+    fn int_constant(ast: &AstType) -> Option<i64> {
+        let n = ast.eval_constant()?;
+        let in_range = n >= i32::MIN as i64 && n <= i32::MAX as i64;
+        if in_range && ast.children().iter().all(|c| Optimizer::int_constant(c).is_some()) {
+            Some(n)
+        } else {
+            None
+        }
+    }
+
+    // 不要コード削除.
+    //
+    // 条件が定数のif/whileを展開し、return/break/continue/goto以降の到達しない文を取り除く
+    // （case/defaultラベル、gotoのラベルを含む文は、ジャンプ先となりうるため残す）
+    fn eliminate_dead_code(ast: &mut AstType) {
+        ast.children_mut().into_iter().for_each(Optimizer::eliminate_dead_code);
+
+        if let AstType::Statement(ref mut stmts) = *ast {
+            let mut reachable = true;
+            *stmts = stmts
+                .drain(..)
+                .flat_map(Optimizer::expand_constant_branch)
+                .filter(|s| {
+                    if Optimizer::has_label(s) {
+                        reachable = true;
+                    }
+                    let keep = reachable;
+                    if matches!(s, AstType::Return(_) | AstType::Break(_) | AstType::Continue() | AstType::Goto(_)) {
+                        reachable = false;
+                    }
+                    keep
+                })
+                .collect();
+        }
+    }
+
+    // 条件が定数のif/whileを、実行される文の並びへ展開
+    fn expand_constant_branch(ast: AstType) -> Vec<AstType> {
+        if Optimizer::has_label(&ast) {
+            return vec![ast];
+        }
+        let block = |a: AstType| match a {
+            AstType::Statement(v) => v,
+            a => vec![a],
+        };
+        match ast {
+            AstType::If(ref c, ref t, ref e) if c.is_constant() => match c.eval_constant() {
+                Some(0) => (**e).clone().map_or(vec![], block),
+                _ => block((**t).clone()),
+            },
+            AstType::While(ref c, _) if c.eval_constant() == Some(0) => vec![],
+            a => vec![a],
+        }
+    }
+
+    // case/defaultラベル、gotoのラベルを含むか
+    fn has_label(ast: &AstType) -> bool {
+        matches!(ast, AstType::Case(_) | AstType::Default() | AstType::Label(_))
+            || ast.children().iter().any(|c| Optimizer::has_label(c))
+    }
+
+    // 強度削減.
+    //
+    // 2の累乗による整数の乗算を、左シフトへ置き換える
+    fn reduce_strength(ast: &mut AstType) {
+        ast.children_mut().into_iter().for_each(Optimizer::reduce_strength);
+
+        let shift = match *ast {
+            AstType::Multiple(ref a, ref b) if Optimizer::is_shiftable(ast) => {
+                match (Optimizer::pow2_exponent(b), Optimizer::pow2_exponent(a)) {
+                    (Some(n), _) => Some(AstType::LeftShift(a.clone(), Box::new(AstType::Factor(n)))),
+                    (None, Some(n)) => Some(AstType::LeftShift(b.clone(), Box::new(AstType::Factor(n)))),
+                    _ => None,
+                }
+            }
+            _ => None,
+        };
+        if let Some(s) = shift {
+            *ast = s;
+        }
+    }
+
+    // シフトへ置き換え可能な型の乗算であるか
+    fn is_shiftable(ast: &AstType) -> bool {
+        matches!(ast.type_of(), Type::Int | Type::UInt | Type::Long | Type::ULong)
+            && ast.children().iter().all(|c| !c.is_pointer())
+    }
+
+    // 2の累乗の定数であれば、その指数を取得
+    fn pow2_exponent(ast: &AstType) -> Option<i64> {
+        match *ast {
+            AstType::Factor(n) if n > 0 && n <= i32::MAX as i64 && (n & (n - 1)) == 0 => {
+                Some(n.trailing_zeros() as i64)
+            }
+            _ => None,
+        }
+    }
+}
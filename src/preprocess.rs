@@ -0,0 +1,362 @@
+use std::collections::HashMap;
+use std::collections::HashSet;
+use diagnostic::Diagnostic;
+use lexer::LexicalAnalysis;
+use token::{PosInfo, Token, TokenInfo};
+
+#[doc = "プリプロセッサ"]
+pub struct Preprocessor {
+    macros: HashMap<String, Vec<TokenInfo>>, // マクロ定義.
+    conds: Vec<(bool, PosInfo)>,             // 条件コンパイルの状態と指令の位置.
+    errors: Vec<Diagnostic>,                 // プリプロセス時に検出したエラー.
+}
+
+impl Preprocessor {
+    // コンストラクタ.
+    pub fn new() -> Self {
+        Preprocessor {
+            macros: HashMap::new(),
+            conds: vec![],
+            errors: vec![],
+        }
+    }
+
+    // エラー取得.
+    pub fn get_errors(&self) -> &Vec<Diagnostic> {
+        &self.errors
+    }
+
+    // 文字列からマクロ定義(コマンドライン用).
+    pub fn define_value(&mut self, name: &str, value: &str) {
+        let mut lexer = LexicalAnalysis::new("command line".to_string(), value);
+        lexer.read_token();
+        let tokens = lexer
+            .get_tokens()
+            .iter()
+            .filter(|t| t.get_token_type() != Token::End)
+            .cloned()
+            .collect();
+        self.define(name, tokens);
+    }
+
+    // マクロ定義.
+    pub fn define(&mut self, name: &str, tokens: Vec<TokenInfo>) {
+        self.macros.insert(name.to_string(), tokens);
+    }
+
+    // マクロ定義削除.
+    pub fn undef(&mut self, name: &str) {
+        self.macros.remove(name);
+    }
+
+    // プリプロセス実行.
+    pub fn exec(&mut self, tokens: &[TokenInfo]) -> Vec<TokenInfo> {
+        let mut result = vec![];
+        let mut i = 0;
+        while i < tokens.len() {
+            if tokens[i].get_token_type() == Token::Sharp {
+                // 指令と同じ行のトークンを取り出す.
+                let row = tokens[i].pos.get_row();
+                let start = i + 1;
+                i = start;
+                while i < tokens.len()
+                    && tokens[i].get_token_type() != Token::End
+                    && tokens[i].pos.get_row() == row
+                {
+                    i += 1;
+                }
+                self.directive(&tokens[start - 1], &tokens[start..i]);
+            } else if !self.is_active() {
+                i += 1;
+            } else {
+                result.extend(self.expand(&tokens[i], &mut HashSet::new()));
+                i += 1;
+            }
+        }
+        // 閉じていない条件指令は、指令の位置でエラーとする
+        self.errors.extend(
+            self.conds.drain(..).map(|(_, pos)| Diagnostic::error(Some(pos), "unterminated conditional directive".to_string())),
+        );
+        result
+    }
+
+    // 条件コンパイルで有効な領域か.
+    fn is_active(&self) -> bool {
+        self.conds.iter().all(|(c, _)| *c)
+    }
+
+    // プリプロセッサ指令.
+    fn directive(&mut self, sharp: &TokenInfo, line: &[TokenInfo]) {
+        let directive = line.first().map(|t| t.get_token_value());
+        match directive {
+            Some(ref d) if d == "ifdef" || d == "ifndef" => {
+                let defined = self.macro_name(sharp, line).is_some_and(|n| self.macros.contains_key(&n));
+                self.conds.push((defined == (d == "ifdef"), sharp.pos.clone()))
+            }
+            Some(ref d) if d == "else" || d == "endif" => match self.conds.pop() {
+                Some((c, pos)) if d == "else" => self.conds.push((!c, pos)),
+                Some(_) => {}
+                None => self.errors.push(Diagnostic::error(Some(sharp.pos.clone()), format!("#{} without #ifdef", d))),
+            },
+            _ if !self.is_active() => {}
+            Some(ref d) if d == "define" => {
+                if let Some(name) = self.macro_name(sharp, line) {
+                    // 名前の直後に括弧が続く関数形式マクロは未対応
+                    match line.get(2) {
+                        Some(t) if t.get_token_type() == Token::LeftParen && t.pos.col == line[1].pos.col + name.len() => {
+                            self.errors.push(Diagnostic::error(
+                                Some(line[1].pos.clone()),
+                                format!("function-like macro '{}' is not supported", name),
+                            ))
+                        }
+                        _ => self.define(&name, line[2..].to_vec()),
+                    }
+                }
+            }
+            Some(ref d) if d == "undef" => {
+                if let Some(name) = self.macro_name(sharp, line) {
+                    self.undef(&name)
+                }
+            }
+            // #のみの行(空指令)は何もしない
+            None => {}
+            Some(d) => self.errors.push(Diagnostic::error(Some(line[0].pos.clone()), format!("invalid preprocessing directive #{}", d))),
+        }
+    }
+
+    // 指令のマクロ名取得(マクロ名が無い場合はエラーとする).
+    fn macro_name(&mut self, sharp: &TokenInfo, line: &[TokenInfo]) -> Option<String> {
+        match line.get(1) {
+            Some(t) if t.get_token_type() == Token::Variable => Some(t.get_token_value()),
+            Some(t) => {
+                self.errors.push(Diagnostic::error(Some(t.pos.clone()), "macro names must be identifiers".to_string()));
+                None
+            }
+            None => {
+                let message = format!("no macro name given in #{} directive", line[0].get_token_value());
+                self.errors.push(Diagnostic::error(Some(sharp.pos.clone()), message));
+                None
+            }
+        }
+    }
+
+    // マクロ展開.
+    //
+    // 展開中のマクロは再展開しない(無限ループ防止).
+    fn expand(&self, token: &TokenInfo, expanding: &mut HashSet<String>) -> Vec<TokenInfo> {
+        let name = token.get_token_value();
+        match self.macros.get(&name) {
+            Some(body) if token.get_token_type() == Token::Variable && !expanding.contains(&name) => {
+                expanding.insert(name.clone());
+                let tokens = body.iter().flat_map(|t| self.expand(t, expanding)).collect();
+                expanding.remove(&name);
+                tokens
+            }
+            _ => vec![token.clone()],
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use ast::{AstGen, AstType};
+    use symbol::{Structure, Type};
+
+    fn preprocess(src: &str) -> Vec<(Token, String)> {
+        let mut lexer = LexicalAnalysis::new("test.c".to_string(), src);
+        lexer.read_token();
+        Preprocessor::new()
+            .exec(lexer.get_tokens())
+            .iter()
+            .map(|t| (t.get_token_type(), t.get_token_value()))
+            .collect()
+    }
+
+    #[test]
+    fn test_define() {
+        let tokens = preprocess("#define MAX 100\nMAX + 1");
+        assert_eq!(
+            tokens,
+            vec![
+                (Token::Number, "100".to_string()),
+                (Token::Plus, "+".to_string()),
+                (Token::Number, "1".to_string()),
+                (Token::End, "End".to_string()),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_define_continuation() {
+        // 行末の\で継続した行も、マクロ定義に含む
+        let tokens = preprocess("#define SUM 1 + \\\n 2\nSUM");
+        assert_eq!(
+            tokens,
+            vec![
+                (Token::Number, "1".to_string()),
+                (Token::Plus, "+".to_string()),
+                (Token::Number, "2".to_string()),
+                (Token::End, "End".to_string()),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_undef() {
+        let tokens = preprocess("#define A 1\nA\n#undef A\nA");
+        assert_eq!(
+            tokens,
+            vec![
+                (Token::Number, "1".to_string()),
+                (Token::Variable, "A".to_string()),
+                (Token::End, "End".to_string()),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_recursive_define() {
+        let tokens = preprocess("#define A B + 1\n#define B A\nA");
+        assert_eq!(
+            tokens,
+            vec![
+                (Token::Variable, "A".to_string()),
+                (Token::Plus, "+".to_string()),
+                (Token::Number, "1".to_string()),
+                (Token::End, "End".to_string()),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_ifdef() {
+        let tokens = preprocess("#define A\n#ifdef A\n1\n#else\n2\n#endif\n#ifndef A\n3\n#endif");
+        assert_eq!(
+            tokens,
+            vec![(Token::Number, "1".to_string()), (Token::End, "End".to_string())]
+        );
+    }
+
+    #[test]
+    fn test_ifdef_nested() {
+        let tokens = preprocess("#ifdef A\n#ifndef A\n1\n#endif\n#define B 2\n#else\nB\n#endif");
+        assert_eq!(
+            tokens,
+            vec![(Token::Variable, "B".to_string()), (Token::End, "End".to_string())]
+        );
+    }
+
+    #[test]
+    fn test_conditional_error() {
+        let errors = |src: &str| {
+            let mut lexer = LexicalAnalysis::new("test.c".to_string(), src);
+            lexer.read_token();
+            let mut pre = Preprocessor::new();
+            pre.exec(lexer.get_tokens());
+            pre.get_errors().iter().map(|e| e.to_string()).collect::<Vec<String>>()
+        };
+
+        // 閉じていない条件指令、対応する条件指令の無い#else、#endifは指令の位置でエラーとする
+        assert_eq!(
+            errors("#ifdef A\n#ifndef B\n1\n#endif\n2"),
+            vec!["test.c:1:1: unterminated conditional directive".to_string()]
+        );
+        assert_eq!(
+            errors("1\n#else\n2\n#endif"),
+            vec!["test.c:2:1: #else without #ifdef".to_string(), "test.c:4:1: #endif without #ifdef".to_string()]
+        );
+        assert!(errors("#ifdef A\n1\n#else\n2\n#endif").is_empty());
+    }
+
+    #[test]
+    fn test_define_error() {
+        let errors = |src: &str| {
+            let mut lexer = LexicalAnalysis::new("test.c".to_string(), src);
+            lexer.read_token();
+            let mut pre = Preprocessor::new();
+            pre.exec(lexer.get_tokens());
+            pre.get_errors().iter().map(|e| e.to_string()).collect::<Vec<String>>()
+        };
+
+        // マクロ名の無い指令、関数形式マクロはエラーとする
+        assert_eq!(
+            errors("#define\n#undef 1\n#ifdef\n#endif"),
+            vec![
+                "test.c:1:1: no macro name given in #define directive".to_string(),
+                "test.c:2:8: macro names must be identifiers".to_string(),
+                "test.c:3:1: no macro name given in #ifdef directive".to_string(),
+            ]
+        );
+        assert_eq!(
+            errors("#define F(x) x\nF(1)"),
+            vec!["test.c:1:9: function-like macro 'F' is not supported".to_string()]
+        );
+
+        // 名前と括弧の間に空白があれば、オブジェクト形式マクロとする
+        assert!(errors("#define F (1)\nF").is_empty());
+        assert_eq!(preprocess("#define F (1)\nF")[1], (Token::Number, "1".to_string()));
+    }
+
+    #[test]
+    fn test_invalid_directive() {
+        let mut lexer = LexicalAnalysis::new("test.c".to_string(), "#\n#include <stdio.h>\n1");
+        lexer.read_token();
+        let mut pre = Preprocessor::new();
+        let tokens = pre.exec(lexer.get_tokens());
+
+        // 空指令は無視し、未対応の指令はエラーとする
+        assert_eq!(tokens[0].get_token_type(), Token::Number);
+        assert_eq!(
+            pre.get_errors().iter().map(|e| e.to_string()).collect::<Vec<String>>(),
+            vec!["test.c:2:2: invalid preprocessing directive #include".to_string()]
+        );
+    }
+
+    #[test]
+    fn test_define_value() {
+        let mut lexer = LexicalAnalysis::new("test.c".to_string(), "X");
+        lexer.read_token();
+        let mut pre = Preprocessor::new();
+        pre.define_value("X", "1");
+        pre.define_value("X", "2 + 3");
+        let tokens: Vec<(Token, String)> = pre
+            .exec(lexer.get_tokens())
+            .iter()
+            .map(|t| (t.get_token_type(), t.get_token_value()))
+            .collect();
+        assert_eq!(
+            tokens,
+            vec![
+                (Token::Number, "2".to_string()),
+                (Token::Plus, "+".to_string()),
+                (Token::Number, "3".to_string()),
+                (Token::End, "End".to_string()),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_define_array_size() {
+        let src = "#define N 5\nint main() { int a[N]; return 0; }";
+        let mut lexer = LexicalAnalysis::new("test.c".to_string(), src);
+        lexer.read_token();
+        let tokens = Preprocessor::new().exec(lexer.get_tokens());
+        let mut ast = AstGen::new(&tokens);
+        let result = ast.parse();
+
+        assert_eq!(
+            result.get_tree()[0],
+            AstType::FuncDef(
+                Type::Int,
+                Structure::Identifier,
+                "main".to_string(),
+                Box::new(AstType::Argment(vec![])),
+                Box::new(AstType::Statement(vec![
+                    AstType::Variable(Type::Int, Structure::Array(vec![5]), "a".to_string()),
+                    AstType::Return(Box::new(AstType::Factor(0))),
+                ]))
+            )
+        );
+    }
+}
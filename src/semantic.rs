@@ -1,4 +1,5 @@
 use ast::{AstTree, AstType};
+use diagnostic::Diagnostic;
 use std::result::Result;
 use symbol::{Scope, SymbolTable};
 use symbol::{Structure, Type};
@@ -7,6 +8,7 @@ use symbol::{Structure, Type};
 pub struct Semantic<'a> {
     ast: &'a AstTree,
     sym_table: &'a SymbolTable,
+    warnings: Vec<Diagnostic>,
 }
 
 // 解析結果返却マクロ
@@ -23,15 +25,25 @@ macro_rules! analyzed {
 impl<'a> Semantic<'a> {
     pub fn new(a: &'a AstTree, s: &'a SymbolTable) -> Self {
         Semantic {
-            ast: a, sym_table: s,
+            ast: a, sym_table: s, warnings: vec![],
         }
     }
 
+    // 警告取得
+    pub fn get_warnings(&self) -> &Vec<Diagnostic> {
+        &self.warnings
+    }
+
     // 解析開始
-    pub fn exec(&mut self) -> Result<(), Vec<String>> {
+    pub fn exec(&mut self) -> Result<(), Vec<Diagnostic>> {
         let tree = self.ast.get_tree();
-        let errs = tree.iter().fold(Vec::<String>::new(), |mut init, t| {
-            match self.analysis(&t) {
+        let mut warnings: Vec<Diagnostic> = tree.iter().flat_map(|t| self.analysis_warning(t)).collect();
+        warnings.extend(tree.iter().flat_map(|t| self.analysis_unsigned_minus(t)));
+        warnings.extend(tree.iter().flat_map(|t| self.analysis_return_conversion(t)));
+        self.warnings = warnings;
+
+        let mut errs = tree.iter().fold(Vec::<Diagnostic>::new(), |mut init, t| {
+            match self.analysis(t) {
                 Err(ref mut r) => {
                     init.append(r);
                     init
@@ -39,11 +51,18 @@ impl<'a> Semantic<'a> {
                 Ok(_) => init,
             }
         });
+        errs.extend(tree.iter().flat_map(|t| self.analysis_void_value(t)));
+        errs.extend(tree.iter().flat_map(|t| self.analysis_pointer_difference(t)));
+        errs.extend(tree.iter().flat_map(|t| self.analysis_unary_operand(t)));
+        errs.extend(tree.iter().flat_map(|t| self.analysis_return_struct(t)));
+        errs.extend(tree.iter().flat_map(|t| self.analysis_assign_struct(t)));
+        errs.extend(tree.iter().flat_map(|t| self.analysis_bit_field_address(t)));
+        errs.extend(tree.iter().flat_map(|t| self.analysis_label(t)));
         analyzed!(errs)
     }
 
     // 解析
-    fn analysis(&self, ast: &AstType) -> Result<(), Vec<String>> {
+    fn analysis(&self, ast: &AstType) -> Result<(), Vec<Diagnostic>> {
         match ast {
             AstType::FuncDef(ref t, ref s, ref n, ref a, ref stmt) => {
                 self.analysis_funcdef(t, s, n, a, stmt)
@@ -62,6 +81,214 @@ impl<'a> Semantic<'a> {
         }
     }
 
+    // ポインタ同士の減算解析
+    //
+    // 異なる型を指すポインタ同士の減算はエラーとする
+    fn analysis_pointer_difference(&self, ast: &AstType) -> Vec<Diagnostic> {
+        let mut errs: Vec<Diagnostic> =
+            ast.children().iter().flat_map(|c| self.analysis_pointer_difference(c)).collect();
+        if let AstType::Minus(ref a, ref b) = ast {
+            match (a.pointee_type(), b.pointee_type()) {
+                (Some(ref t1), Some(ref t2)) if t1 != t2 => errs.push(Diagnostic::error(None, format!(
+                    "invalid operands to binary - (have '{:?}*' and '{:?}*')",
+                    t1, t2
+                ))),
+                _ => {}
+            }
+        }
+        errs
+    }
+
+    // 単項演算子(+/-)のオペランド解析
+    //
+    // オペランドは算術型のみとし、ポインタや構造体はエラーとする
+    fn analysis_unary_operand(&self, ast: &AstType) -> Vec<Diagnostic> {
+        let mut errs: Vec<Diagnostic> =
+            ast.children().iter().flat_map(|c| self.analysis_unary_operand(c)).collect();
+        let (ope, a) = match ast {
+            AstType::UnPlus(ref a) => ("plus", a),
+            AstType::UnMinus(ref a) => ("minus", a),
+            _ => return errs,
+        };
+        if a.is_pointer() || matches!(a.type_of(), Type::Struct(_)) {
+            errs.push(Diagnostic::error(None, format!("wrong type argument to unary {}", ope)));
+        }
+        errs
+    }
+
+    // ビットフィールドのアドレス解析
+    //
+    // ビットフィールドはアドレスを持たないため、&演算子のオペランドはエラーとする
+    fn analysis_bit_field_address(&self, ast: &AstType) -> Vec<Diagnostic> {
+        let mut errs: Vec<Diagnostic> =
+            ast.children().iter().flat_map(|c| self.analysis_bit_field_address(c)).collect();
+        if let AstType::Address(ref a) = ast {
+            if let AstType::MemberAccess(_, ref m) = **a {
+                if let AstType::Variable(_, Structure::BitField(_, _), ref name) = **m {
+                    errs.push(Diagnostic::error(None, format!("cannot take address of bit-field '{}'", name)));
+                }
+            }
+        }
+        errs
+    }
+
+    // ラベル解析
+    //
+    // 関数内で重複したラベル、未定義のラベルへのgotoはエラーとする
+    fn analysis_label(&self, ast: &AstType) -> Vec<Diagnostic> {
+        let body = match ast {
+            AstType::FuncDef(_, _, _, _, ref body) => body,
+            _ => return vec![],
+        };
+        let labels = body.labels();
+        let mut errs: Vec<Diagnostic> = labels
+            .iter()
+            .enumerate()
+            .filter(|(i, l)| labels[..*i].contains(l))
+            .map(|(_, l)| Diagnostic::error(None, format!("duplicate label '{}'", l)))
+            .collect();
+        let gotos = body.gotos();
+        errs.extend(
+            gotos
+                .iter()
+                .enumerate()
+                .filter(|(i, g)| !labels.contains(g) && !gotos[..*i].contains(g))
+                .map(|(_, g)| Diagnostic::error(None, format!("label '{}' used but not defined", g))),
+        );
+        errs
+    }
+
+    // 符号なし型への単項マイナス解析
+    //
+    // 演算結果も符号なし型のままとなるため、警告とする
+    fn analysis_unsigned_minus(&self, ast: &AstType) -> Vec<Diagnostic> {
+        let mut w: Vec<Diagnostic> =
+            ast.children().iter().flat_map(|c| self.analysis_unsigned_minus(c)).collect();
+        if let AstType::UnMinus(ref a) = ast {
+            if !a.is_pointer() && a.type_of() == Type::UInt {
+                w.push(Diagnostic::warning(None, "unary minus operator applied to unsigned type, result still unsigned".to_string()));
+            }
+        }
+        w
+    }
+
+    // 戻り値の変換解析
+    //
+    // ポインタと整数の間の暗黙の変換は警告とする(整数定数0はヌルポインタ定数として扱う)
+    fn analysis_return_conversion(&self, ast: &AstType) -> Vec<Diagnostic> {
+        let (t, s, name, stmt) = match ast {
+            AstType::FuncDef(ref t, ref s, ref n, _, ref stmt) => (t, s, n, stmt),
+            _ => return vec![],
+        };
+        let ret_pointer = *s == Structure::Pointer;
+        Semantic::return_values(stmt)
+            .iter()
+            .filter_map(|e| match (ret_pointer, e.is_pointer()) {
+                (false, true) if !matches!(t, Type::Struct(_)) => Some(Diagnostic::warning(None, format!(
+                    "returning '{}' from a function with return type '{}' makes integer from pointer without a cast: {}",
+                    Semantic::type_name(e), Semantic::return_type_name(t, s), name
+                ))),
+                (true, false) if **e != AstType::Factor(0) && !matches!(e.type_of(), Type::Struct(_)) => Some(Diagnostic::warning(None, format!(
+                    "returning '{}' from a function with return type '{}' makes pointer from integer without a cast: {}",
+                    Semantic::type_name(e), Semantic::return_type_name(t, s), name
+                ))),
+                _ => None,
+            })
+            .collect()
+    }
+
+    // 戻り値の構造体解析
+    //
+    // 構造体と他の型の間では変換できないため、エラーとする
+    fn analysis_return_struct(&self, ast: &AstType) -> Vec<Diagnostic> {
+        let (t, s, stmt) = match ast {
+            AstType::FuncDef(ref t, ref s, _, _, ref stmt) => (t, s, stmt),
+            _ => return vec![],
+        };
+        let expected = if *s == Structure::Pointer { None } else { Some(t) };
+        Semantic::return_values(stmt)
+            .iter()
+            .filter(|e| {
+                let actual = e.type_of();
+                let is_struct = |t: Option<&Type>| matches!(t, Some(Type::Struct(_)));
+                let actual = if e.is_pointer() { None } else { Some(&actual) };
+                (is_struct(expected) || is_struct(actual)) && expected != actual
+            })
+            .map(|e| Diagnostic::error(None, format!(
+                "incompatible types when returning type '{}' but '{}' was expected",
+                Semantic::type_name(e), Semantic::return_type_name(t, s)
+            )))
+            .collect()
+    }
+
+    // 構造体の代入解析
+    //
+    // 構造体の代入は同じ型同士のみとし、それ以外はエラーとする
+    fn analysis_assign_struct(&self, ast: &AstType) -> Vec<Diagnostic> {
+        let mut errs: Vec<Diagnostic> =
+            ast.children().iter().flat_map(|c| self.analysis_assign_struct(c)).collect();
+        if let AstType::Assign(ref a, ref b) = ast {
+            let value_type = |e: &AstType| if e.is_pointer() { None } else { Some(e.type_of()) };
+            let (t1, t2) = (value_type(a), value_type(b));
+            let is_struct = |t: &Option<Type>| matches!(t, Some(Type::Struct(_)));
+            if (is_struct(&t1) || is_struct(&t2)) && t1 != t2 {
+                errs.push(Diagnostic::error(None, format!(
+                    "incompatible types when assigning to type '{}' from type '{}'",
+                    Semantic::type_name(a), Semantic::type_name(b)
+                )));
+            }
+        }
+        errs
+    }
+
+    // return文の戻り値の式を取得(値なしのreturnは対象外)
+    fn return_values(ast: &AstType) -> Vec<&AstType> {
+        match ast {
+            AstType::Return(ref a) if **a == AstType::Void() => vec![],
+            AstType::Return(ref a) => vec![a],
+            _ => ast.children().into_iter().flat_map(Semantic::return_values).collect(),
+        }
+    }
+
+    // 式の型名(ポインタは*を付加)
+    fn type_name(e: &AstType) -> String {
+        match e.pointee_type() {
+            Some(t) if e.is_pointer() => Semantic::c_type_name(&t, 1),
+            _ => Semantic::c_type_name(&e.type_of(), 0),
+        }
+    }
+
+    // 関数の戻り値の型名
+    fn return_type_name(t: &Type, s: &Structure) -> String {
+        match s {
+            Structure::Pointer => Semantic::c_type_name(t, 1),
+            _ => Semantic::c_type_name(t, 0),
+        }
+    }
+
+    // C言語の表記での型名(ptrはポインタの段数)
+    fn c_type_name(t: &Type, ptr: usize) -> String {
+        let base = match t {
+            Type::Pointer(ref t) => return Semantic::c_type_name(t, ptr + 1),
+            Type::Int => "int".to_string(),
+            Type::UInt => "unsigned int".to_string(),
+            Type::Char => "char".to_string(),
+            Type::UChar => "unsigned char".to_string(),
+            Type::Short => "short".to_string(),
+            Type::Long => "long".to_string(),
+            Type::ULong => "unsigned long".to_string(),
+            Type::Float => "float".to_string(),
+            Type::Double => "double".to_string(),
+            Type::Void => "void".to_string(),
+            Type::Struct(ref tag) => format!("struct {}", tag),
+            Type::Unknown(ref n) => n.clone(),
+        };
+        match ptr {
+            0 => base,
+            _ => format!("{} {}", base, "*".repeat(ptr)),
+        }
+    }
+
     // 関数定義解析
     fn analysis_funcdef(
         &self,
@@ -70,10 +297,10 @@ impl<'a> Semantic<'a> {
         _name: &str,
         args: &AstType,
         stmt: &AstType,
-    ) -> Result<(), Vec<String>> {
+    ) -> Result<(), Vec<Diagnostic>> {
         let mut errs = vec![];
         if let Type::Unknown(n) = t {
-            errs.push(format!("Cannot found Type: {:?}", n));
+            errs.push(Diagnostic::error(None, format!("Cannot found Type: {:?}", n)));
         }
         if let Err(ref mut e) = self.analysis(args) {
             errs.append(e);
@@ -85,22 +312,22 @@ impl<'a> Semantic<'a> {
     }
 
     // 関数コール解析
-    fn analysis_funccall(&self, v: &AstType, _a: &AstType) -> Result<(), Vec<String>> {
+    fn analysis_funccall(&self, v: &AstType, _a: &AstType) -> Result<(), Vec<Diagnostic>> {
         let mut errs = vec![];
         match v {
             AstType::Variable(ref _t, ref _s, ref n) => {
                 if self.sym_table.search(&Scope::Func, n).is_none() {
-                    errs.push(format!("Not define function name: {:?}", n));
+                    errs.push(Diagnostic::error(None, format!("Not define function name: {:?}", n)));
                 }
             }
-            _ => errs.push(format!("AstType is not Variable: {:?}", v)),
+            _ => errs.push(Diagnostic::error(None, format!("AstType is not Variable: {:?}", v))),
         }
         analyzed!(errs)
     }
 
     // 関数引数解析
-    fn analysis_argment(&self, args: &[AstType]) -> Result<(), Vec<String>> {
-        let mut errs = args.iter().fold(Vec::<String>::new(), |mut acc, ref a| {
+    fn analysis_argment(&self, args: &[AstType]) -> Result<(), Vec<Diagnostic>> {
+        let mut errs = args.iter().fold(Vec::<Diagnostic>::new(), |mut acc, a| {
             match self.analysis(a) {
                 Ok(_) => acc,
                 Err(ref mut e) => {
@@ -112,14 +339,14 @@ impl<'a> Semantic<'a> {
 
         // 引数の数をチェック
         if args.len() > 6 {
-            errs.push("Argment Count is over six(max count is six)".to_string());
+            errs.push(Diagnostic::error(None, "Argment Count is over six(max count is six)".to_string()));
         }
         analyzed!(errs)
     }
 
     // global解析
-    fn analysis_global(&self, glb: &[AstType]) -> Result<(), Vec<String>> {
-        let errs = glb.iter().fold(Vec::<String>::new(), |mut acc, ref s| {
+    fn analysis_global(&self, glb: &[AstType]) -> Result<(), Vec<Diagnostic>> {
+        let errs = glb.iter().fold(Vec::<Diagnostic>::new(), |mut acc, s| {
             match self.analysis(s) {
                 Ok(_) => acc,
                 Err(ref mut e) => {
@@ -132,8 +359,8 @@ impl<'a> Semantic<'a> {
     }
 
     // statement解析
-    fn analysis_statement(&self, stmt: &[AstType]) -> Result<(), Vec<String>> {
-        let errs = stmt.iter().fold(Vec::<String>::new(), |mut acc, ref s| {
+    fn analysis_statement(&self, stmt: &[AstType]) -> Result<(), Vec<Diagnostic>> {
+        let errs = stmt.iter().fold(Vec::<Diagnostic>::new(), |mut acc, s| {
             match self.analysis(s) {
                 Ok(_) => acc,
                 Err(ref mut e) => {
@@ -145,13 +372,37 @@ impl<'a> Semantic<'a> {
         analyzed!(errs)
     }
 
+    // void値の使用解析.
+    //
+    // voidへキャストした式は、文(forの初期化、更新部を含む)としてのみ使用できる
+    fn analysis_void_value(&self, ast: &AstType) -> Vec<Diagnostic> {
+        let ignored: Vec<&AstType> = match ast {
+            AstType::Statement(ref v) => v.iter().collect(),
+            AstType::For(ref a, _, ref c, _) => a.iter().chain(c.iter()).collect(),
+            _ => vec![],
+        };
+        ast.children()
+            .iter()
+            .flat_map(|c| {
+                let mut errs = match c {
+                    AstType::Cast(Type::Void, _) if !ignored.iter().any(|i| std::ptr::eq(*i, *c)) => {
+                        vec![Diagnostic::error(None, "void value not ignored as it ought to be".to_string())]
+                    }
+                    _ => vec![],
+                };
+                errs.append(&mut self.analysis_void_value(c));
+                errs
+            })
+            .collect()
+    }
+
     // return文解析
-    fn analysis_return(&self, s: &AstType) -> Result<(), Vec<String>> {
+    fn analysis_return(&self, s: &AstType) -> Result<(), Vec<Diagnostic>> {
         self.analysis(s)
     }
 
     // 四則演算解析
-    fn analysis_arithmetic(&self, a: &AstType, b: &AstType) -> Result<(), Vec<String>> {
+    fn analysis_arithmetic(&self, a: &AstType, b: &AstType) -> Result<(), Vec<Diagnostic>> {
         // 左辺、右辺の解析
         let mut errs = vec![];
         if let Err(ref mut e) = self.analysis(a) {
@@ -163,16 +414,95 @@ impl<'a> Semantic<'a> {
         analyzed!(errs)
     }
 
+    // 警告解析
+    fn analysis_warning(&self, ast: &AstType) -> Vec<Diagnostic> {
+        match ast {
+            AstType::FuncDef(ref t, _, ref n, _, ref stmt) => {
+                let mut w = self.analysis_warning(stmt);
+                if *t != Type::Void {
+                    w.append(&mut self.analysis_bare_return(n, stmt));
+
+                    // mainは暗黙的にreturn 0となる
+                    if n != "main" && !stmt.always_returns() {
+                        w.push(Diagnostic::warning(None, format!("control reaches end of non-void function: {}", n)));
+                    }
+                }
+                w
+            }
+            AstType::Statement(ref stmt) | AstType::Global(ref stmt) => {
+                stmt.iter().flat_map(|s| self.analysis_warning(s)).collect()
+            }
+            AstType::While(ref a, ref b) | AstType::Do(ref a, ref b) => {
+                [a, b].iter().flat_map(|s| self.analysis_warning(s)).collect()
+            }
+            AstType::If(ref a, ref b, ref c) => {
+                let mut w = self.analysis_warning(a);
+                w.append(&mut self.analysis_warning(b));
+                if let Some(e) = c.as_ref() {
+                    w.append(&mut self.analysis_warning(e));
+                }
+                w
+            }
+            AstType::For(ref a, ref b, ref c, ref d) => {
+                let mut w: Vec<Diagnostic> = [a, b, c]
+                    .iter()
+                    .filter_map(|s| s.as_ref().as_ref())
+                    .flat_map(|s| self.analysis_warning(s))
+                    .collect();
+                w.append(&mut self.analysis_warning(d));
+                w
+            }
+            AstType::Return(ref a) => self.analysis_warning(a),
+            AstType::Assign(ref a, ref b) => self.analysis_pointer_assign(a, b),
+            _ => vec![],
+        }
+    }
+
+    // 値なしreturn解析
+    //
+    // void以外の関数でのreturn;は警告とする
+    fn analysis_bare_return(&self, name: &str, ast: &AstType) -> Vec<Diagnostic> {
+        match ast {
+            AstType::Return(ref a) if **a == AstType::Void() => {
+                vec![Diagnostic::warning(None, format!("'return' with no value, in function returning non-void: {}", name))]
+            }
+            _ => ast.children().iter().flat_map(|c| self.analysis_bare_return(name, c)).collect(),
+        }
+    }
+
+    // ポインタへの代入解析
+    //
+    // 整数定数0はヌルポインタ定数として扱い、0以外の整数定数の代入は警告とする
+    fn analysis_pointer_assign(&self, a: &AstType, b: &AstType) -> Vec<Diagnostic> {
+        let name = match a {
+            AstType::Variable(_, Structure::Pointer, ref n) => n,
+            _ => return vec![],
+        };
+        match b {
+            AstType::Factor(0) => vec![],
+            _ if b.is_constant() => {
+                vec![Diagnostic::warning(None, format!("assignment makes pointer from integer without a cast: {}", name))]
+            }
+            _ => vec![],
+        }
+    }
+
     // 変数定義解析
-    fn analysis_variable(&self, t: &Type, _s: &Structure, _n: &str) -> Result<(), Vec<String>> {
+    fn analysis_variable(&self, t: &Type, _s: &Structure, _n: &str) -> Result<(), Vec<Diagnostic>> {
         let mut errs = vec![];
         if let Type::Unknown(n) = t {
-            errs.push(format!("Cannot found Type: {:?}", n));
+            errs.push(Diagnostic::error(None, format!("Cannot found Type: {:?}", n)));
         }
         analyzed!(errs)
     }
 }
 
+// 診断をメッセージの文字列にする
+#[cfg(test)]
+fn messages(d: &[Diagnostic]) -> Vec<String> {
+    d.iter().map(|e| e.to_string()).collect()
+}
+
 #[test]
 fn test_func_type() {
     // 正常系
@@ -523,3 +853,331 @@ fn test_global() {
         assert!(r.err().unwrap().len() == 1);
     }
 }
+
+#[test]
+fn test_null_pointer() {
+    let pointer = || Box::new(AstType::Variable(Type::Int, Structure::Pointer, "p".to_string()));
+    // ヌルポインタ定数の代入、比較
+    {
+        let ast = vec![AstType::FuncDef(
+            Type::Int,
+            Structure::Identifier,
+            "main".to_string(),
+            Box::new(AstType::Argment(vec![])),
+            Box::new(AstType::Statement(vec![
+                AstType::Assign(pointer(), Box::new(AstType::Factor(0))),
+                AstType::If(
+                    Box::new(AstType::Equal(pointer(), Box::new(AstType::Factor(0)))),
+                    Box::new(AstType::Statement(vec![AstType::Assign(pointer(), Box::new(AstType::Factor(0)))])),
+                    Box::new(None),
+                ),
+            ])),
+        )];
+        let tree = AstTree { tree: ast };
+        let sym = SymbolTable::new();
+        let mut sem = Semantic::new(&tree, &sym);
+        assert!(sem.exec().is_ok());
+        assert!(sem.get_warnings().is_empty());
+    }
+    // 0以外の整数の代入は警告
+    {
+        let ast = vec![AstType::FuncDef(
+            Type::Int,
+            Structure::Identifier,
+            "main".to_string(),
+            Box::new(AstType::Argment(vec![])),
+            Box::new(AstType::Statement(vec![
+                AstType::Assign(pointer(), Box::new(AstType::Factor(5))),
+                AstType::While(
+                    Box::new(AstType::Factor(1)),
+                    Box::new(AstType::Statement(vec![AstType::Assign(
+                        pointer(),
+                        Box::new(AstType::UnMinus(Box::new(AstType::Factor(1)))),
+                    )])),
+                ),
+            ])),
+        )];
+        let tree = AstTree { tree: ast };
+        let sym = SymbolTable::new();
+        let mut sem = Semantic::new(&tree, &sym);
+        assert!(sem.exec().is_ok());
+        assert_eq!(sem.get_warnings().len(), 2);
+    }
+}
+
+#[test]
+fn test_bare_return() {
+    let func = |t: Type| {
+        AstType::FuncDef(
+            t,
+            Structure::Identifier,
+            "f".to_string(),
+            Box::new(AstType::Argment(vec![])),
+            Box::new(AstType::Statement(vec![AstType::If(
+                Box::new(AstType::Factor(1)),
+                Box::new(AstType::Statement(vec![AstType::Return(Box::new(AstType::Void()))])),
+                Box::new(None),
+            )])),
+        )
+    };
+
+    // void関数ではreturn;を許可
+    {
+        let tree = AstTree { tree: vec![func(Type::Void)] };
+        let sym = SymbolTable::new();
+        let mut sem = Semantic::new(&tree, &sym);
+        assert!(sem.exec().is_ok());
+        assert!(sem.get_warnings().is_empty());
+    }
+    // void以外の関数では警告
+    {
+        let tree = AstTree { tree: vec![func(Type::Int)] };
+        let sym = SymbolTable::new();
+        let mut sem = Semantic::new(&tree, &sym);
+        assert!(sem.exec().is_ok());
+        assert_eq!(
+            messages(sem.get_warnings()),
+            vec![
+                "'return' with no value, in function returning non-void: f".to_string(),
+                "control reaches end of non-void function: f".to_string(),
+            ]
+        );
+    }
+}
+
+#[test]
+fn test_missing_return() {
+    let func = |n: &str, stmt: Vec<AstType>| {
+        AstType::FuncDef(
+            Type::Int,
+            Structure::Identifier,
+            n.to_string(),
+            Box::new(AstType::Argment(vec![])),
+            Box::new(AstType::Statement(stmt)),
+        )
+    };
+    let ret = || AstType::Return(Box::new(AstType::Factor(1)));
+    let block = |v: Vec<AstType>| Box::new(AstType::Statement(v));
+
+    // 全ての経路でreturnする、もしくはmain関数の場合は警告なし
+    {
+        let ast = vec![
+            func("main", vec![]),
+            func("a", vec![ret()]),
+            func("b", vec![AstType::If(Box::new(AstType::Factor(1)), block(vec![ret()]), Box::new(Some(*block(vec![ret()]))))]),
+            func("c", vec![AstType::While(Box::new(AstType::Factor(1)), block(vec![]))]),
+        ];
+        let tree = AstTree { tree: ast };
+        let sym = SymbolTable::new();
+        let mut sem = Semantic::new(&tree, &sym);
+        assert!(sem.exec().is_ok());
+        assert!(sem.get_warnings().is_empty());
+    }
+    // 末尾へ到達する経路があれば警告
+    {
+        let ast = vec![
+            func("a", vec![]),
+            func("b", vec![AstType::If(Box::new(AstType::Factor(1)), block(vec![ret()]), Box::new(None))]),
+            func("c", vec![AstType::While(Box::new(AstType::Factor(1)), block(vec![AstType::Break(1)]))]),
+        ];
+        let tree = AstTree { tree: ast };
+        let sym = SymbolTable::new();
+        let mut sem = Semantic::new(&tree, &sym);
+        assert!(sem.exec().is_ok());
+        assert_eq!(
+            messages(sem.get_warnings()),
+            vec![
+                "control reaches end of non-void function: a".to_string(),
+                "control reaches end of non-void function: b".to_string(),
+                "control reaches end of non-void function: c".to_string(),
+            ]
+        );
+    }
+}
+
+#[test]
+fn test_unary_operand() {
+    let func = |stmt: Vec<AstType>| {
+        AstType::FuncDef(
+            Type::Int,
+            Structure::Identifier,
+            "main".to_string(),
+            Box::new(AstType::Argment(vec![])),
+            Box::new(AstType::Statement(stmt)),
+        )
+    };
+    let var = |t: Type, s: Structure| Box::new(AstType::Variable(t, s, "x".to_string()));
+
+    // 算術型へのマイナスは問題なし
+    {
+        let tree = AstTree { tree: vec![func(vec![AstType::Return(Box::new(AstType::UnMinus(var(Type::Int, Structure::Identifier))))])] };
+        let sym = SymbolTable::new();
+        let mut sem = Semantic::new(&tree, &sym);
+        assert!(sem.exec().is_ok());
+        assert!(sem.get_warnings().is_empty());
+    }
+    // ポインタ、構造体へのプラス、マイナスはエラー
+    {
+        let tree = AstTree {
+            tree: vec![func(vec![
+                AstType::Return(Box::new(AstType::UnMinus(var(Type::Int, Structure::Pointer)))),
+                AstType::Return(Box::new(AstType::UnPlus(var(Type::Char, Structure::Pointer)))),
+                AstType::Return(Box::new(AstType::UnMinus(var(Type::Struct("A".to_string()), Structure::Struct)))),
+            ])],
+        };
+        let sym = SymbolTable::new();
+        let mut sem = Semantic::new(&tree, &sym);
+        assert_eq!(
+            sem.exec().map_err(|e| messages(&e)),
+            Err(vec![
+                "wrong type argument to unary minus".to_string(),
+                "wrong type argument to unary plus".to_string(),
+                "wrong type argument to unary minus".to_string(),
+            ])
+        );
+    }
+    // 符号なし型へのマイナスは警告
+    {
+        let tree = AstTree { tree: vec![func(vec![AstType::Return(Box::new(AstType::UnMinus(var(Type::UInt, Structure::Identifier))))])] };
+        let sym = SymbolTable::new();
+        let mut sem = Semantic::new(&tree, &sym);
+        assert!(sem.exec().is_ok());
+        assert_eq!(
+            messages(sem.get_warnings()),
+            vec!["unary minus operator applied to unsigned type, result still unsigned".to_string()]
+        );
+    }
+}
+
+#[test]
+fn test_return_conversion() {
+    let func = |t: Type, s: Structure, e: AstType| AstTree {
+        tree: vec![AstType::FuncDef(
+            t,
+            s,
+            "f".to_string(),
+            Box::new(AstType::Argment(vec![])),
+            Box::new(AstType::Statement(vec![AstType::Return(Box::new(e))])),
+        )],
+    };
+    let var = |t: Type, s: Structure| AstType::Variable(t, s, "x".to_string());
+    let sym = SymbolTable::new();
+
+    // int型の関数からchar型を返すのは問題なし(整数拡張)
+    {
+        let tree = func(Type::Int, Structure::Identifier, var(Type::Char, Structure::Identifier));
+        let mut sem = Semantic::new(&tree, &sym);
+        assert!(sem.exec().is_ok());
+        assert!(sem.get_warnings().is_empty());
+    }
+    // int型の関数からポインタを返すのは警告
+    {
+        let tree = func(Type::Int, Structure::Identifier, var(Type::Char, Structure::Pointer));
+        let mut sem = Semantic::new(&tree, &sym);
+        assert!(sem.exec().is_ok());
+        assert_eq!(
+            messages(sem.get_warnings()),
+            vec!["returning 'char *' from a function with return type 'int' makes integer from pointer without a cast: f".to_string()]
+        );
+        let tree = func(Type::UInt, Structure::Identifier, var(Type::Pointer(Box::new(Type::Char)), Structure::Pointer));
+        let mut sem = Semantic::new(&tree, &sym);
+        assert!(sem.exec().is_ok());
+        assert_eq!(
+            messages(sem.get_warnings()),
+            vec!["returning 'char **' from a function with return type 'unsigned int' makes integer from pointer without a cast: f".to_string()]
+        );
+    }
+    // ポインタを返す関数から整数を返すのは警告(定数0は除く)
+    {
+        let tree = func(Type::Int, Structure::Pointer, var(Type::Int, Structure::Identifier));
+        let mut sem = Semantic::new(&tree, &sym);
+        assert!(sem.exec().is_ok());
+        assert_eq!(
+            messages(sem.get_warnings()),
+            vec!["returning 'int' from a function with return type 'int *' makes pointer from integer without a cast: f".to_string()]
+        );
+        let tree = func(Type::Int, Structure::Pointer, AstType::Factor(0));
+        let mut sem = Semantic::new(&tree, &sym);
+        assert!(sem.exec().is_ok());
+        assert!(sem.get_warnings().is_empty());
+    }
+    // 構造体と他の型の間はエラー
+    {
+        let tree = func(Type::Int, Structure::Identifier, var(Type::Struct("A".to_string()), Structure::Struct));
+        let mut sem = Semantic::new(&tree, &sym);
+        assert_eq!(
+            sem.exec().map_err(|e| messages(&e)),
+            Err(vec!["incompatible types when returning type 'struct A' but 'int' was expected".to_string()])
+        );
+    }
+}
+
+#[test]
+fn test_assign_struct() {
+    let assign = |a: AstType, b: AstType| AstTree {
+        tree: vec![AstType::Statement(vec![AstType::Assign(Box::new(a), Box::new(b))])],
+    };
+    let var = |t: Type, s: Structure, n: &str| AstType::Variable(t, s, n.to_string());
+    let st = |tag: &str| Type::Struct(tag.to_string());
+    let sym = SymbolTable::new();
+
+    // 同じ構造体同士の代入は問題なし
+    {
+        let tree = assign(var(st("A"), Structure::Struct, "a"), var(st("A"), Structure::Struct, "b"));
+        let mut sem = Semantic::new(&tree, &sym);
+        assert!(sem.exec().is_ok());
+    }
+    // 異なる構造体、構造体と他の型の間の代入はエラー
+    {
+        let tree = assign(var(st("A"), Structure::Struct, "a"), var(st("B"), Structure::Struct, "b"));
+        let mut sem = Semantic::new(&tree, &sym);
+        assert_eq!(
+            sem.exec().map_err(|e| messages(&e)),
+            Err(vec!["incompatible types when assigning to type 'struct A' from type 'struct B'".to_string()])
+        );
+        let tree = assign(var(Type::Int, Structure::Identifier, "a"), var(st("A"), Structure::Struct, "b"));
+        let mut sem = Semantic::new(&tree, &sym);
+        assert_eq!(
+            sem.exec().map_err(|e| messages(&e)),
+            Err(vec!["incompatible types when assigning to type 'int' from type 'struct A'".to_string()])
+        );
+    }
+    // 構造体へのポインタの代入は対象外
+    {
+        let tree = assign(var(st("A"), Structure::Pointer, "p"), var(st("A"), Structure::Pointer, "q"));
+        let mut sem = Semantic::new(&tree, &sym);
+        assert!(sem.exec().is_ok());
+    }
+}
+
+#[test]
+fn test_label() {
+    let func = |stmts: Vec<AstType>| AstTree {
+        tree: vec![AstType::FuncDef(
+            Type::Int,
+            Structure::Identifier,
+            "f".to_string(),
+            Box::new(AstType::Argment(vec![])),
+            Box::new(AstType::Statement(stmts)),
+        )],
+    };
+    let goto = |n: &str| AstType::Goto(n.to_string());
+    let label = |n: &str| AstType::Label(n.to_string());
+    let sym = SymbolTable::new();
+
+    // 前方、後方のgotoは問題なし
+    {
+        let tree = func(vec![label("a"), goto("b"), goto("a"), label("b")]);
+        let mut sem = Semantic::new(&tree, &sym);
+        assert!(sem.exec().is_ok());
+    }
+    // 未定義のラベル、重複したラベルはエラー
+    {
+        let tree = func(vec![goto("x"), goto("x"), label("a"), label("a")]);
+        let mut sem = Semantic::new(&tree, &sym);
+        assert_eq!(
+            sem.exec().map_err(|e| messages(&e)),
+            Err(vec!["duplicate label 'a'".to_string(), "label 'x' used but not defined".to_string()])
+        );
+    }
+}
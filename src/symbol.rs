@@ -1,28 +1,38 @@
 /**
  * シンボルテーブル
  */
-#[allow(dead_code)]
 #[derive(Debug, Clone, PartialEq, Eq, Hash)]
 pub enum Scope {
     Global,         // グローバル
     Local(String),  // ローカルスコープ
-    Block(String),  // ブロックスコープ
     Func,           // 関数シンボル
     Unknown,
 }
 
-#[allow(dead_code)]
 #[derive(Debug, Clone, PartialEq)]
 pub enum Type {
     Int,
+    UInt,
     Char,
+    UChar,
     Short,
     Long,
+    ULong, // size_t
+    Float,
+    Double,
+    Void,
     Struct(String), // struct Test → Struct(Test)
+    Pointer(Box<Type>), // ポインタを要素とするポインタの参照先 char** → Pointer(Char)
     Unknown(String),
 }
 
-#[allow(dead_code)]
+impl Type {
+    // 浮動小数点数型チェック
+    pub fn is_float(&self) -> bool {
+        matches!(self, Type::Float | Type::Double)
+    }
+}
+
 #[derive(Debug, Clone, PartialEq)]
 pub enum Structure {
     Identifier,
@@ -30,6 +40,8 @@ pub enum Structure {
     Array(Vec<usize>),
     // ToDo(Arrayみたいに、ここにメンバーをもたせたほうがいい？？)
     Struct,
+    Incomplete, // 前方宣言のみの構造体（struct Name;）
+    BitField(usize, usize), // ビットフィールドのメンバー（幅、格納単位内のビット位置）
     Unknown,
 }
 
@@ -43,6 +55,9 @@ pub struct Symbol {
     pub offset: usize,        // オフセット
     pub size: usize,          // サイズ
     pub members: Vec<Symbol>, // メンバー変数
+    pub is_static: bool,      // static宣言
+    pub is_union: bool,       // 共用体
+    pub is_array_param: bool, // 配列として宣言された引数(ポインタへ変換済み)
 }
 
 #[derive(Debug, Clone, PartialEq)]
@@ -52,7 +67,6 @@ pub struct SymbolTable {
 
 impl Symbol {
     // コンストラクタ
-    #[allow(dead_code)]
     pub fn new(s: Scope, v: String, ty: Type, st: Structure) -> Self {
         Symbol {
             scope: s,
@@ -63,6 +77,9 @@ impl Symbol {
             offset: 0,
             size: 0,
             members: vec![],
+            is_static: false,
+            is_union: false,
+            is_array_param: false,
         }
     }
 
@@ -70,9 +87,23 @@ impl Symbol {
     pub fn regist_mem(&mut self, mem: Vec<Symbol>) {
         // サイズを設定したメンバーを保存
         self.members = mem.into_iter().map(|mut m| {
-            m.size = m.type_size();
+            m.size = m.member_size();
             m
         }).collect();
+
+        // 各メンバーへ、構造体先頭からのオフセット（ビットフィールドはビット位置も）を設定
+        let layout: Vec<(usize, usize)> = self.member_layout().iter().map(|(_, offset, bit)| (*offset, *bit)).collect();
+        self.members.iter_mut().zip(layout).for_each(|(m, (offset, bit))| {
+            m.offset = offset;
+            if let Structure::BitField(width, _) = m.strt {
+                m.strt = Structure::BitField(width, bit);
+            }
+        });
+    }
+
+    /// メンバーのオフセット取得(メンバーが存在しない場合はNone)
+    pub fn member_offset(&self, name: &str) -> Option<usize> {
+        self.members.iter().find(|m| m.var == name).map(|m| m.offset)
     }
 
     /// 型に応じたサイズ取得
@@ -82,39 +113,130 @@ impl Symbol {
             Structure::Array(_) => 8,
             _ => {
                 match self.t {
-                    Type::Int => 4,
-                    Type::Char => 1,
-                    Type::Struct(_) => {
-                        // メンバーのサイズを加算し、返す
-                        if let Some(max_mem) = self.members.iter().max_by(|a, b| a.size.cmp(&b.size)) {
-                            // 各メンバーサイズを加算
-                            let size = self.members.iter().fold(0, |acc, s| acc + s.type_size());
-
-                            // アライメントを考慮したサイズを返す
-                            if size % max_mem.size == 0 { size }
-                            else { (size / max_mem.size) * max_mem.size + max_mem.size }
-                        }
-                        else {
-                            0
-                        }
-                    }
+                    Type::Int | Type::UInt => 4,
+                    Type::Char | Type::UChar => 1,
+                    Type::Short => 2,
+                    Type::Float => 4,
+                    Type::Double | Type::Long | Type::ULong | Type::Pointer(_) => 8,
+                    Type::Struct(_) => self.struct_size(),
                     _ => 0,
                 }
             }
         }
     }
 
+    /// メンバーとしてのサイズ取得
+    ///
+    /// 配列のメンバーは、要素の型のサイズ×要素数とする
+    pub fn member_size(&self) -> usize {
+        match self.strt {
+            Structure::Array(ref v) => self.scalar_size() * v.iter().product::<usize>(),
+            _ => self.type_size(),
+        }
+    }
+
+    // メンバーのアライメント取得(配列のメンバーは要素の型のサイズ)
+    fn member_align(&self) -> usize {
+        match self.strt {
+            Structure::Array(_) => self.scalar_size(),
+            _ => self.type_size(),
+        }
+    }
+
+    /// 配列の要素の型のサイズ取得
+    pub fn scalar_size(&self) -> usize {
+        Symbol { strt: Structure::Identifier, ..self.clone() }.type_size()
+    }
+
+    /// 配列要素のサイズ取得
+    ///
+    /// 構造体は構造体のサイズ、それ以外は要素の型のサイズとする
+    pub fn element_size(&self) -> usize {
+        match self.t {
+            Type::Struct(_) => self.struct_size(),
+            _ => self.scalar_size(),
+        }
+    }
+
+    // 構造体のサイズ取得
+    //
+    // 最後のメンバーの終端を、最大のメンバーサイズでアライメントする
+    fn struct_size(&self) -> usize {
+        let align = self.members.iter().map(|m| m.member_align()).max().unwrap_or(1).max(1);
+        let size = self
+            .member_offsets()
+            .iter()
+            .map(|(m, offset)| offset + m.member_size())
+            .max()
+            .unwrap_or(0);
+        SymbolTable::align(size, align)
+    }
+
+    /// メンバーのオフセット取得
+    ///
+    /// 各メンバーはサイズの境界に配置する
+    pub fn member_offsets(&self) -> Vec<(&Symbol, usize)> {
+        self.member_layout().into_iter().map(|(m, offset, _)| (m, offset)).collect()
+    }
+
+    /// メンバーの配置（オフセット、ビット位置）取得
+    ///
+    /// 連続するビットフィールドは、収まる限り同じ格納単位へ詰め、収まらない場合は次の格納単位へ配置する.
+    /// 共用体は、全てのメンバーを先頭に重ねて配置する
+    fn member_layout(&self) -> Vec<(&Symbol, usize, usize)> {
+        if self.is_union {
+            return self.members.iter().map(|m| (m, 0, 0)).collect();
+        }
+        self.members
+            .iter()
+            .scan((0usize, None), |(pos, unit): &mut (usize, Option<(usize, usize)>), m| {
+                let size = m.member_align().max(1);
+                match (&m.strt, *unit) {
+                    (Structure::BitField(width, _), Some((offset, used))) if used + width <= size * 8 => {
+                        *unit = Some((offset, used + width));
+                        Some((m, offset, used))
+                    }
+                    (Structure::BitField(width, _), _) => {
+                        let offset = pos.div_ceil(size) * size;
+                        *pos = offset + size;
+                        *unit = Some((offset, *width));
+                        Some((m, offset, 0))
+                    }
+                    _ => {
+                        let offset = pos.div_ceil(size) * size;
+                        *pos = offset + m.member_size();
+                        *unit = None;
+                        Some((m, offset, 0))
+                    }
+                }
+            })
+            .collect()
+    }
+
+    /// スタック上の領域サイズとアライメント取得
+    ///
+    /// int型は64bitで転送しているため8バイト単位、配列は8バイト境界に確保する
+    pub fn stack_layout(&self) -> (usize, usize) {
+        match self.strt {
+            Structure::Pointer => (8, 8),
+            Structure::Array(_) => (self.size, 8),
+            Structure::Identifier => match self.t {
+                Type::Char | Type::UChar => (1, 1),
+                Type::Short => (2, 2),
+                _ => (8, 8),
+            },
+            _ => (self.type_size(), 8),
+        }
+    }
 }
 
 impl SymbolTable {
     // コンストラクタ
-    #[allow(dead_code)]
     pub fn new() -> Self {
         SymbolTable { table: vec![] }
     }
 
     // シンボル登録
-    #[allow(dead_code)]
     pub fn register_sym(&mut self, sym: Symbol) {
         // 同じシンボルがなければ、登録
         if self.search(&sym.scope, &sym.var).is_none() {
@@ -125,6 +247,16 @@ impl SymbolTable {
         }
     }
 
+    // 不完全型の構造体定義を、メンバーを設定して完全型にする
+    pub fn complete_struct(&mut self, scope: &Scope, var: &str, mem: Vec<Symbol>, is_union: bool) {
+        if let Some(sym) = self.table.iter_mut().find(|s| s.scope == *scope && s.var == var) {
+            sym.strt = Structure::Struct;
+            sym.is_union = is_union;
+            sym.regist_mem(mem);
+            sym.size = sym.type_size();
+        }
+    }
+
     // 関数シンボル登録
     fn register_func(&mut self, sym: Symbol) {
         // 関数シンボルの場合、ポジション算出は不要なのでそのまま登録
@@ -142,56 +274,105 @@ impl SymbolTable {
         let last = self
             .table
             .iter()
-            .filter(|s| s.scope == sym.scope)
-            .cloned()
-            .last();
-
-        // 前の要素をもとにオフセット等の情報を算出
-        match last {
-            None => {
-                // 配列の場合、要素数を考慮し、サイズ算出
-                let size = match sym.strt {
-                    Structure::Array(ref v) => sym.type_size() * v.iter().product::<usize>(),
-                    _ => sym.type_size()
-                };
-                reg.pos = 1;
-                reg.offset = 0;
-                reg.size = size;
-                self.table.push(reg);
-            }
-            Some(pre_sym) => {
+            .rfind(|s| s.scope == sym.scope)
+            .cloned();
+
+        // 配列の場合、要素数を考慮し、サイズ算出
+        reg.size = match sym.strt {
+            Structure::Array(ref v) => sym.element_size() * v.iter().product::<usize>(),
+            _ => sym.type_size()
+        };
+
+        // 前の要素をもとにポジションを算出(オフセットはlayoutで決定する)
+        reg.pos = match last {
+            None => 1,
+            Some(pre_sym) => match pre_sym.strt {
                 // 配列の場合、要素数を考慮
-                match pre_sym.strt {
-                    Structure::Array(ref v) => {
-                        // 要素数分、オフセットなどを計算
-                        let count: usize = v.iter().product();
-                        reg.pos = pre_sym.pos + count;
-                        reg.size = sym.type_size() * count;
-                        reg.offset = pre_sym.offset + pre_sym.type_size() * count;
-                        reg.offset = (reg.offset / 8) * 8;
-                        self.table.push(reg);
-                    }
-                    Structure::Pointer => {
-                        reg.pos = pre_sym.pos + 1;
-                        reg.size = sym.type_size();
-                        reg.offset = pre_sym.offset + pre_sym.type_size();
-                        reg.offset = (reg.offset / 8) * 8;
-                        self.table.push(reg);
-                    }
-                    _ => {
-                        reg.pos = pre_sym.pos + 1;
-                        reg.size = sym.type_size();
-                        reg.offset = pre_sym.offset + pre_sym.type_size();
-                        reg.offset = (reg.offset / 8) * 8 + 8;
-                        self.table.push(reg);
-                    }
-                }
-            }
+                Structure::Array(ref v) => pre_sym.pos + v.iter().product::<usize>(),
+                _ => pre_sym.pos + 1,
+            },
         };
+        self.table.push(reg);
+    }
+
+    // スタックフレーム上の配置決定
+    //
+    // 宣言順(ブロック内の宣言も含む)に、%rbpからのオフセットを割り当てる.
+    // 変数は-offset(%rbp)から領域サイズ分を使用する
+    pub fn layout(&mut self, scope: &Scope) {
+        self.table
+            .iter_mut()
+            .filter(|s| s.scope == *scope)
+            .fold(0, |pos, sym| {
+                let (size, align) = sym.stack_layout();
+                sym.offset = SymbolTable::align(pos + size, align);
+                sym.offset
+            });
+    }
+
+    // スタックフレームサイズ(16バイトアライメント)
+    pub fn frame_size(&self, scope: &Scope) -> usize {
+        let size = self
+            .table
+            .iter()
+            .filter(|s| s.scope == *scope)
+            .map(|s| s.offset)
+            .max()
+            .unwrap_or(0);
+        SymbolTable::align(size, 16)
+    }
+
+    // シンボルテーブルを表形式で出力
+    //
+    // Global、Func、各Localの順にスコープごとにまとめる
+    pub fn dump(&self) -> String {
+        let mut scopes = vec![Scope::Global, Scope::Func];
+        self.table.iter().for_each(|s| {
+            if !scopes.contains(&s.scope) {
+                scopes.push(s.scope.clone());
+            }
+        });
+
+        scopes.iter().fold(String::new(), |acc, scope| {
+            let syms: Vec<&Symbol> = self.table.iter().filter(|s| s.scope == *scope).collect();
+            if syms.is_empty() {
+                return acc;
+            }
+            let header = format!(
+                "[{:?}]\n  {:<16} {:<16} {:<16} {:>6} {:>6}\n",
+                scope, "name", "type", "structure", "size", "offset"
+            );
+            syms.iter().fold(format!("{}{}", acc, header), |acc, s| {
+                let line = format!(
+                    "  {:<16} {:<16} {:<16} {:>6} {:>6}\n",
+                    s.var,
+                    format!("{:?}", s.t),
+                    format!("{:?}", s.strt),
+                    s.size,
+                    s.offset
+                );
+                // 構造体の場合、メンバーも出力
+                s.member_offsets().iter().fold(format!("{}{}", acc, line), |acc, (m, offset)| {
+                    format!(
+                        "{}    .{:<13} {:<16} {:<16} {:>6} {:>6}\n",
+                        acc,
+                        m.var,
+                        format!("{:?}", m.t),
+                        format!("{:?}", m.strt),
+                        m.member_size(),
+                        offset
+                    )
+                })
+            })
+        })
+    }
+
+    // アライメント調整
+    fn align(n: usize, align: usize) -> usize {
+        n.div_ceil(align) * align
     }
 
     // シンボルサーチ
-    #[allow(dead_code)]
     pub fn search(&self, scope: &Scope, var: &str) -> Option<Symbol> {
         self.table
             .iter()
@@ -200,11 +381,11 @@ impl SymbolTable {
     }
 
     // カウント取得
-    #[allow(dead_code)]
+    #[cfg(test)]
     pub fn count_all(&self) -> usize {
         self.table.len()
     }
-    #[allow(dead_code)]
+    #[cfg(test)]
     pub fn count(&self, scope: &Scope) -> usize {
         self.table
             .iter()
@@ -213,7 +394,7 @@ impl SymbolTable {
     }
 
     // 変数トータルサイズ
-    #[allow(dead_code)]
+    #[cfg(test)]
     pub fn size(&self, scope: &Scope) -> usize {
         // 各要素のサイズを畳み込み
         self.table
@@ -224,7 +405,7 @@ impl SymbolTable {
                 Structure::Identifier => acc + sym.type_size(),
                 // 配列の場合、要素数を考慮
                 Structure::Array(ref items) => {
-                    acc + items.iter().fold(0, |acc2, i| acc2 + (i * sym.type_size()))
+                    acc + items.iter().fold(0, |acc2, i| acc2 + (i * sym.element_size()))
                 }
                 _ => acc,
             })
@@ -251,7 +432,7 @@ mod test {
             assert_eq!(table.count_all(), 1);
             assert_eq!(table.count(&Scope::Global), 1);
             assert_eq!(
-                table.search(&Scope::Global, &"a".to_string()),
+                table.search(&Scope::Global, "a"),
                 Some(Symbol {
                     scope: Scope::Global,
                     var: "a".to_string(),
@@ -260,6 +441,9 @@ mod test {
                     pos: 1,
                     offset: 0,
                     size: 4,
+                    is_static: false,
+                    is_union: false,
+                    is_array_param: false,
                     members: vec![],
                 })
             );
@@ -284,7 +468,7 @@ mod test {
             assert_eq!(table.count_all(), 2);
             assert_eq!(table.count(&Scope::Local("test".to_string())), 2);
             assert_eq!(
-                table.search(&Scope::Local("test".to_string()), &"a".to_string()),
+                table.search(&Scope::Local("test".to_string()), "a"),
                 Some(Symbol {
                     scope: Scope::Local("test".to_string()),
                     var: "a".to_string(),
@@ -293,19 +477,25 @@ mod test {
                     pos: 1,
                     offset: 0,
                     size: 4,
+                    is_static: false,
+                    is_union: false,
+                    is_array_param: false,
                     members: vec![],
                 })
             );
             assert_eq!(
-                table.search(&Scope::Local("test".to_string()), &"b".to_string()),
+                table.search(&Scope::Local("test".to_string()), "b"),
                 Some(Symbol {
                     scope: Scope::Local("test".to_string()),
                     var: "b".to_string(),
                     t: Type::Int,
                     strt: Structure::Identifier,
                     pos: 2,
-                    offset: 8,
+                    offset: 0,
                     size: 4,
+                    is_static: false,
+                    is_union: false,
+                    is_array_param: false,
                     members: vec![],
                 })
             );
@@ -330,7 +520,7 @@ mod test {
             assert_eq!(table.count_all(), 2);
             assert_eq!(table.count(&Scope::Local("test".to_string())), 2);
             assert_eq!(
-                table.search(&Scope::Local("test".to_string()), &"a".to_string()),
+                table.search(&Scope::Local("test".to_string()), "a"),
                 Some(Symbol {
                     scope: Scope::Local("test".to_string()),
                     var: "a".to_string(),
@@ -339,19 +529,25 @@ mod test {
                     pos: 1,
                     offset: 0,
                     size: 4,
+                    is_static: false,
+                    is_union: false,
+                    is_array_param: false,
                     members: vec![],
                 })
             );
             assert_eq!(
-                table.search(&Scope::Local("test".to_string()), &"b".to_string()),
+                table.search(&Scope::Local("test".to_string()), "b"),
                 Some(Symbol {
                     scope: Scope::Local("test".to_string()),
                     var: "b".to_string(),
                     t: Type::Char,
                     strt: Structure::Identifier,
                     pos: 2,
-                    offset: 8,
+                    offset: 0,
                     size: 1,
+                    is_static: false,
+                    is_union: false,
+                    is_array_param: false,
                     members: vec![],
                 })
             );
@@ -366,11 +562,11 @@ mod test {
             ));
 
             // 期待値
-            assert_eq!(table.size(&Scope::Global), 80);
+            assert_eq!(table.size(&Scope::Global), 40);
             assert_eq!(table.count_all(), 1);
             assert_eq!(table.count(&Scope::Global), 1);
             assert_eq!(
-                table.search(&Scope::Global, &"a".to_string()),
+                table.search(&Scope::Global, "a"),
                 Some(Symbol {
                     scope: Scope::Global,
                     var: "a".to_string(),
@@ -378,7 +574,10 @@ mod test {
                     strt: Structure::Array(vec![10]),
                     pos: 1,
                     offset: 0,
-                    size: 80,
+                    size: 40,
+                    is_static: false,
+                    is_union: false,
+                    is_array_param: false,
                     members: vec![],
                 })
             );
@@ -393,11 +592,11 @@ mod test {
             ));
 
             // 期待値
-            assert_eq!(table.size(&Scope::Global), 80);
+            assert_eq!(table.size(&Scope::Global), 10);
             assert_eq!(table.count_all(), 1);
             assert_eq!(table.count(&Scope::Global), 1);
             assert_eq!(
-                table.search(&Scope::Global, &"a".to_string()),
+                table.search(&Scope::Global, "a"),
                 Some(Symbol {
                     scope: Scope::Global,
                     var: "a".to_string(),
@@ -405,7 +604,10 @@ mod test {
                     strt: Structure::Array(vec![10]),
                     pos: 1,
                     offset: 0,
-                    size: 80,
+                    size: 10,
+                    is_static: false,
+                    is_union: false,
+                    is_array_param: false,
                     members: vec![],
                 })
             );
@@ -424,7 +626,7 @@ mod test {
             assert_eq!(table.size(&Scope::Local("test".to_string())), 8);
             assert_eq!(table.count(&Scope::Local("test".to_string())), 1);
             assert_eq!(
-                table.search(&Scope::Local("test".to_string()), &"a".to_string()),
+                table.search(&Scope::Local("test".to_string()), "a"),
                 Some(Symbol {
                     scope: Scope::Local("test".to_string()),
                     var: "a".to_string(),
@@ -433,6 +635,9 @@ mod test {
                     pos: 1,
                     offset: 0,
                     size: 8,
+                    is_static: false,
+                    is_union: false,
+                    is_array_param: false,
                     members: vec![],
                 })
             );
@@ -459,7 +664,7 @@ mod test {
             assert_eq!(table.count(&Scope::Local("test".to_string())), 1);
             assert_eq!(table.size(&Scope::Local("test".to_string())), 1);
             assert_eq!(
-                table.search(&Scope::Global, &"a".to_string()),
+                table.search(&Scope::Global, "a"),
                 Some(Symbol {
                     scope: Scope::Global,
                     var: "a".to_string(),
@@ -468,11 +673,14 @@ mod test {
                     pos: 1,
                     offset: 0,
                     size: 4,
+                    is_static: false,
+                    is_union: false,
+                    is_array_param: false,
                     members: vec![],
                 })
             );
             assert_eq!(
-                table.search(&Scope::Local("test".to_string()), &"a".to_string()),
+                table.search(&Scope::Local("test".to_string()), "a"),
                 Some(Symbol {
                     scope: Scope::Local("test".to_string()),
                     var: "a".to_string(),
@@ -481,12 +689,51 @@ mod test {
                     pos: 1,
                     offset: 0,
                     size: 1,
+                    is_static: false,
+                    is_union: false,
+                    is_array_param: false,
                     members: vec![],
                 })
             );
         }
     }
 
+    #[test]
+    fn test_layout() {
+        {
+            let scope = Scope::Local("test".to_string());
+            let mut table = SymbolTable::new();
+            table.register_sym(Symbol::new(scope.clone(), "a".to_string(), Type::Char, Structure::Identifier));
+            table.register_sym(Symbol::new(scope.clone(), "b".to_string(), Type::Int, Structure::Identifier));
+            table.register_sym(Symbol::new(scope.clone(), "c".to_string(), Type::Char, Structure::Identifier));
+            table.layout(&scope);
+
+            // 期待値(charは1バイト、intは8バイト境界に配置)
+            assert_eq!(table.search(&scope, "a").unwrap().offset, 1);
+            assert_eq!(table.search(&scope, "b").unwrap().offset, 16);
+            assert_eq!(table.search(&scope, "c").unwrap().offset, 17);
+            assert_eq!(table.frame_size(&scope), 32);
+        }
+        {
+            let scope = Scope::Local("test".to_string());
+            let mut table = SymbolTable::new();
+            table.register_sym(Symbol::new(scope.clone(), "a".to_string(), Type::Int, Structure::Identifier));
+            table.register_sym(Symbol::new(scope.clone(), "b".to_string(), Type::Int, Structure::Array(vec![3])));
+            table.register_sym(Symbol::new(scope.clone(), "c".to_string(), Type::Char, Structure::Pointer));
+            table.register_sym(Symbol::new(Scope::Local("other".to_string()), "d".to_string(), Type::Int, Structure::Identifier));
+            table.layout(&scope);
+
+            // 期待値(配列は先頭要素が最も低いアドレス)
+            assert_eq!(table.search(&scope, "a").unwrap().offset, 8);
+            assert_eq!(table.search(&scope, "b").unwrap().offset, 24);
+            assert_eq!(table.search(&scope, "c").unwrap().offset, 32);
+            assert_eq!(table.frame_size(&scope), 32);
+
+            // 他のスコープは対象外
+            assert_eq!(table.search(&Scope::Local("other".to_string()), "d").unwrap().offset, 0);
+        }
+    }
+
     #[test]
     fn test_type_size() {
         {
@@ -498,6 +745,9 @@ mod test {
                 pos: 0,
                 offset: 0,
                 size: 1,
+                is_static: false,
+                is_union: false,
+                is_array_param: false,
                 members: vec![],
             };
             assert_eq!( 1, sym.type_size());
@@ -511,6 +761,9 @@ mod test {
                 pos: 0,
                 offset: 0,
                 size: 4,
+                is_static: false,
+                is_union: false,
+                is_array_param: false,
                 members: vec![],
             };
             assert_eq!(4, sym.type_size());
@@ -524,6 +777,9 @@ mod test {
                 pos: 0,
                 offset: 0,
                 size: 0,
+                is_static: false,
+                is_union: false,
+                is_array_param: false,
                 members: vec![
                     Symbol {
                         scope: Scope::Local("test".to_string()),
@@ -533,6 +789,9 @@ mod test {
                         pos: 0,
                         offset: 0,
                         size: 1,
+                        is_static: false,
+                        is_union: false,
+                        is_array_param: false,
                         members: vec![],
                     }
                 ],
@@ -548,6 +807,9 @@ mod test {
                 pos: 0,
                 offset: 0,
                 size: 0,
+                is_static: false,
+                is_union: false,
+                is_array_param: false,
                 members: vec![
                     Symbol {
                         scope: Scope::Local("test".to_string()),
@@ -557,6 +819,9 @@ mod test {
                         pos: 0,
                         offset: 0,
                         size: 1,
+                        is_static: false,
+                        is_union: false,
+                        is_array_param: false,
                         members: vec![],
                     },
                     Symbol {
@@ -567,6 +832,9 @@ mod test {
                         pos: 0,
                         offset: 0,
                         size: 1,
+                        is_static: false,
+                        is_union: false,
+                        is_array_param: false,
                         members: vec![],
                     }
                 ],
@@ -582,6 +850,9 @@ mod test {
                 pos: 0,
                 offset: 0,
                 size: 0,
+                is_static: false,
+                is_union: false,
+                is_array_param: false,
                 members: vec![
                     Symbol {
                         scope: Scope::Local("test".to_string()),
@@ -591,6 +862,9 @@ mod test {
                         pos: 0,
                         offset: 0,
                         size: 1,
+                        is_static: false,
+                        is_union: false,
+                        is_array_param: false,
                         members: vec![],
                     },
                     Symbol {
@@ -601,11 +875,133 @@ mod test {
                         pos: 0,
                         offset: 0,
                         size: 4,
+                        is_static: false,
+                        is_union: false,
+                        is_array_param: false,
                         members: vec![],
                     }
                 ],
             };
             assert_eq!(8, sym.type_size());
         }
+        {
+            // メンバー間の詰め物を含めたサイズ(char, int, char)
+            let scope = Scope::Local("test".to_string());
+            let mut sym = Symbol::new(scope.clone(), "s".to_string(), Type::Struct("S".to_string()), Structure::Struct);
+            sym.regist_mem(vec![
+                Symbol::new(scope.clone(), "a".to_string(), Type::Char, Structure::Identifier),
+                Symbol::new(scope.clone(), "b".to_string(), Type::Int, Structure::Identifier),
+                Symbol::new(scope.clone(), "c".to_string(), Type::Char, Structure::Identifier),
+            ]);
+            assert_eq!(12, sym.type_size());
+        }
+    }
+
+    #[test]
+    fn test_member_offset() {
+        // char, int, char* の順に、それぞれのサイズの境界へ配置
+        let scope = Scope::Local("test".to_string());
+        let mut sym = Symbol::new(scope.clone(), "S".to_string(), Type::Struct("S".to_string()), Structure::Struct);
+        sym.regist_mem(vec![
+            Symbol::new(scope.clone(), "a".to_string(), Type::Char, Structure::Identifier),
+            Symbol::new(scope.clone(), "b".to_string(), Type::Int, Structure::Identifier),
+            Symbol::new(scope.clone(), "c".to_string(), Type::Char, Structure::Pointer),
+        ]);
+        assert_eq!(sym.member_offset("a"), Some(0));
+        assert_eq!(sym.member_offset("b"), Some(4));
+        assert_eq!(sym.member_offset("c"), Some(8));
+        assert_eq!(sym.members[1].offset, 4);
+
+        // 存在しないメンバー
+        assert_eq!(sym.member_offset("d"), None);
+
+        // 配列のメンバーは要素の型のサイズで詰め、要素の型の境界へ配置
+        let mut sym = Symbol::new(scope.clone(), "S".to_string(), Type::Struct("S".to_string()), Structure::Struct);
+        sym.regist_mem(vec![
+            Symbol::new(scope.clone(), "a".to_string(), Type::Char, Structure::Array(vec![3])),
+            Symbol::new(scope.clone(), "b".to_string(), Type::Int, Structure::Array(vec![2, 3])),
+            Symbol::new(scope.clone(), "c".to_string(), Type::Char, Structure::Identifier),
+        ]);
+        assert_eq!(sym.member_offset("b"), Some(4));
+        assert_eq!(sym.member_offset("c"), Some(28));
+        assert_eq!(sym.members[1].size, 24);
+        assert_eq!(sym.type_size(), 32);
+
+        // 共用体は最大のメンバーのサイズ
+        let mut sym = Symbol::new(scope.clone(), "U".to_string(), Type::Struct("U".to_string()), Structure::Struct);
+        sym.is_union = true;
+        sym.regist_mem(vec![
+            Symbol::new(scope.clone(), "i".to_string(), Type::Int, Structure::Identifier),
+            Symbol::new(scope.clone(), "c".to_string(), Type::Char, Structure::Array(vec![4])),
+        ]);
+        assert_eq!(sym.member_offset("c"), Some(0));
+        assert_eq!(sym.type_size(), 4);
+    }
+
+    #[test]
+    fn test_bit_field_offset() {
+        // 連続するビットフィールドは同じ格納単位へ詰め、収まらない場合は次の格納単位へ配置
+        let scope = Scope::Local("test".to_string());
+        let mem = |n: &str, t: Type, s: Structure| Symbol::new(scope.clone(), n.to_string(), t, s);
+        let mut sym = Symbol::new(scope.clone(), "S".to_string(), Type::Struct("S".to_string()), Structure::Struct);
+        sym.regist_mem(vec![
+            mem("a", Type::UInt, Structure::BitField(3, 0)),
+            mem("b", Type::UInt, Structure::BitField(5, 0)),
+            mem("c", Type::Int, Structure::Identifier),
+            mem("d", Type::Int, Structure::BitField(30, 0)),
+            mem("e", Type::UInt, Structure::BitField(4, 0)),
+        ]);
+        let layout: Vec<(usize, Structure)> = sym.members.iter().map(|m| (m.offset, m.strt.clone())).collect();
+        assert_eq!(
+            layout,
+            vec![
+                (0, Structure::BitField(3, 0)),
+                (0, Structure::BitField(5, 3)),
+                (4, Structure::Identifier),
+                (8, Structure::BitField(30, 0)),
+                (12, Structure::BitField(4, 0)),
+            ]
+        );
+        assert_eq!(sym.type_size(), 16);
+    }
+
+    #[test]
+    fn test_union_offset() {
+        // 共用体は全てのメンバーを先頭に配置し、最大のメンバーのサイズとする
+        let scope = Scope::Local("test".to_string());
+        let mut table = SymbolTable::new();
+        table.register_sym(Symbol::new(scope.clone(), "U".to_string(), Type::Struct("U".to_string()), Structure::Incomplete));
+        table.complete_struct(
+            &scope,
+            "U",
+            vec![
+                Symbol::new(scope.clone(), "c".to_string(), Type::Char, Structure::Identifier),
+                Symbol::new(scope.clone(), "i".to_string(), Type::Int, Structure::Identifier),
+                Symbol::new(scope.clone(), "p".to_string(), Type::Int, Structure::Pointer),
+            ],
+            true,
+        );
+        let sym = table.search(&scope, "U").unwrap();
+        assert!(sym.is_union);
+        assert_eq!(sym.members.iter().map(|m| m.offset).collect::<Vec<usize>>(), vec![0, 0, 0]);
+        assert_eq!(sym.size, 8);
+    }
+
+    #[test]
+    fn test_struct_array_size() {
+        // 構造体の配列は、構造体のサイズを要素サイズとする
+        let scope = Scope::Local("test".to_string());
+        let mut table = SymbolTable::new();
+        let members = vec![
+            Symbol::new(scope.clone(), "x".to_string(), Type::Int, Structure::Identifier),
+            Symbol::new(scope.clone(), "y".to_string(), Type::Int, Structure::Identifier),
+            Symbol::new(scope.clone(), "c".to_string(), Type::Char, Structure::Identifier),
+        ];
+        let mut sym = Symbol::new(scope.clone(), "a".to_string(), Type::Struct("P".to_string()), Structure::Array(vec![4]));
+        sym.regist_mem(members);
+        table.register_sym(sym);
+        let sym = table.search(&scope, "a").unwrap();
+        assert_eq!(sym.element_size(), 12);
+        assert_eq!(sym.size, 48);
     }
 }
@@ -1,3 +1,5 @@
+use std::fmt;
+
 #[doc = "トークン"]
 // トークン識別子.
 #[derive(Debug, Clone, PartialEq)]
@@ -10,6 +12,10 @@ pub enum Token {
     Continue,         // continue文.
     Break,            // break文.
     Return,           // return文.
+    Switch,           // switch文.
+    Case,             // caseラベル.
+    Default,          // defaultラベル.
+    Goto,             // goto文.
     BitReverse,       // ビット反転演算子.
     And,              // &演算子
     BitOr,            // ビットOR演算子
@@ -28,6 +34,7 @@ pub enum Token {
     LessThanEqual,    // 比較演算子(<=).
     GreaterThanEqual, // 比較演算子(>=).
     Number,           // 数値.
+    FloatNumber,      // 浮動小数点数.
     Variable,         // 変数.
     Plus,             // プラス演算子.
     Minus,            // マイナス演算子.
@@ -44,9 +51,15 @@ pub enum Token {
     SemiColon,        // セミコロン.
     Assign,           // 代入演算子.
     Int,              // int型.
+    Unsigned,         // unsigned修飾子.
+    Signed,           // signed修飾子.
     IntPointer,       // intポインタ
     Char,             // char型
     CharPointer,      // charポインタ
+    Double,           // double型
+    Float,            // float型
+    Short,            // short型
+    Void,             // void型
     Inc,              // 後置インクリメント
     Dec,              // 後置デクリメント
     StringLiteral,    // 文字列リテラル
@@ -57,6 +70,15 @@ pub enum Token {
     DivisionAssign,   // /=演算子
     RemainderAssign,  // %=演算子
     Struct,           // struct宣言
+    Union,            // union宣言
+    Static,           // static宣言
+    Register,         // register指定
+    Auto,             // auto指定
+    Extern,           // extern指定
+    Typedef,          // typedef宣言
+    Sharp,            // プリプロセッサ指令
+    Dot,              // メンバーアクセス演算子(.)
+    Arrow,            // メンバーアクセス演算子(->)
     End,              // 終了.
 }
 
@@ -76,6 +98,18 @@ impl PosInfo {
             col: c,
         }
     }
+
+    // 行番号取得.
+    pub fn get_row(&self) -> usize {
+        self.row
+    }
+}
+
+// 位置情報の表示(ファイル名:行:列)
+impl fmt::Display for PosInfo {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}:{}:{}", self.name, self.row, self.col)
+    }
 }
 
 // トークンデータ.
@@ -107,3 +141,8 @@ impl TokenInfo {
         self.val.clone()
     }
 }
+
+// トークン列の出力(1行に位置情報、種別、内容).
+pub fn dump_tokens(tokens: &[TokenInfo]) -> String {
+    tokens.iter().map(|t| format!("{} {:?} {}\n", t.pos, t.token, t.val)).collect()
+}
@@ -0,0 +1,193 @@
+use std::env;
+use std::fs;
+use std::process::Command;
+
+// テスト用のソースファイルを作成
+fn create_source(name: &str, src: &str) -> String {
+    let path = env::temp_dir().join(name);
+    fs::write(&path, src).expect("write source error");
+    path.to_string_lossy().into_owned()
+}
+
+#[test]
+fn test_output_file() {
+    let src = create_source("rcc_cli_output.c", "int main() { return 3; }");
+    let out = env::temp_dir().join("rcc_cli_output.s");
+    let _ = fs::remove_file(&out);
+
+    // -o指定時はファイルへ出力し、標準出力へは出力しない
+    let result = Command::new(env!("CARGO_BIN_EXE_rcc"))
+        .args([&src, "-o", &out.to_string_lossy()])
+        .output()
+        .expect("rcc is error");
+    assert!(result.status.success());
+    assert!(result.stdout.is_empty());
+    let inst = fs::read_to_string(&out).expect("read output error");
+    assert!(inst.contains("main:"));
+    assert!(inst.contains("  mov $3, %rax\n"));
+
+    // -o未指定時は、同じ内容を標準出力へ出力
+    let result = Command::new(env!("CARGO_BIN_EXE_rcc")).arg(&src).output().expect("rcc is error");
+    assert_eq!(String::from_utf8_lossy(&result.stdout), inst);
+
+    let _ = fs::remove_file(&src);
+    let _ = fs::remove_file(&out);
+}
+
+#[test]
+fn test_output_file_error() {
+    let src = create_source("rcc_cli_output_error.c", "int main() { return 3; }");
+
+    // 書き込めない場合はエラー終了
+    let result = Command::new(env!("CARGO_BIN_EXE_rcc"))
+        .args([&src, "-o", "/nonexistent/dir/out.s"])
+        .output()
+        .expect("rcc is error");
+    assert!(!result.status.success());
+    assert!(String::from_utf8_lossy(&result.stderr).contains("cannot write /nonexistent/dir/out.s"));
+
+    let _ = fs::remove_file(&src);
+}
+
+#[test]
+fn test_main_arguments() {
+    let src = create_source(
+        "rcc_cli_main_args.c",
+        "int main(int argc, char** argv) { if (argv[1][0] != 97) { return 100; } return argc; }",
+    );
+    let asm = env::temp_dir().join("rcc_cli_main_args.s");
+    let bin = env::temp_dir().join("rcc_cli_main_args");
+
+    // argc、argvはスタートアップから渡された値となる
+    let result = Command::new(env!("CARGO_BIN_EXE_rcc"))
+        .args([&src, "-o", &asm.to_string_lossy()])
+        .output()
+        .expect("rcc is error");
+    assert!(result.status.success());
+    let result = Command::new("gcc")
+        .args(["-no-pie", &asm.to_string_lossy(), "-o", &bin.to_string_lossy()])
+        .output()
+        .expect("gcc is error");
+    assert!(result.status.success());
+    let status = Command::new(&bin).args(["abc", "x", "y"]).status().expect("run is error");
+    assert_eq!(status.code(), Some(4));
+
+    let _ = fs::remove_file(&src);
+    let _ = fs::remove_file(&asm);
+    let _ = fs::remove_file(&bin);
+}
+
+#[test]
+fn test_run() {
+    let src = create_source("rcc_cli_run.c", "int main(){return 5;}");
+
+    // --run指定時は、実行したプログラムの終了コードで終了
+    let result = Command::new(env!("CARGO_BIN_EXE_rcc")).args([&src, "--run"]).output().expect("rcc is error");
+    assert_eq!(result.status.code(), Some(5));
+    assert!(result.stdout.is_empty());
+
+    // シグナルで終了した場合はエラー終了
+    let signal = create_source("rcc_cli_run_signal.c", "int main(){int* p = 0; return *p;}");
+    let result = Command::new(env!("CARGO_BIN_EXE_rcc")).args([&signal, "--run"]).output().expect("rcc is error");
+    assert_eq!(result.status.code(), Some(1));
+    assert!(String::from_utf8_lossy(&result.stderr).contains("program terminated by signal"));
+
+    let _ = fs::remove_file(&src);
+    let _ = fs::remove_file(&signal);
+}
+
+#[test]
+fn test_separate_compilation() {
+    let lib = create_source(
+        "rcc_cli_unit_lib.c",
+        "int base = 10; int add(int a, int b) { return a + b + base; } static int twice(int x) { return x * 2; } int scale(int x) { return twice(x); }",
+    );
+    let main = create_source(
+        "rcc_cli_unit_main.c",
+        "extern int base; int add(int a, int b); extern int scale(int x); int main() { return add(2, 3) + scale(base); }",
+    );
+
+    // 翻訳単位ごとにアセンブル、リンクし、他の翻訳単位の関数、変数を参照する
+    let objs: Vec<String> = [&lib, &main]
+        .iter()
+        .map(|src| {
+            let asm = format!("{}.s", src);
+            let obj = format!("{}.o", src);
+            let result = Command::new(env!("CARGO_BIN_EXE_rcc")).args([src, "-o", &asm]).output().expect("rcc is error");
+            assert!(result.status.success());
+            let result = Command::new("gcc").args(["-c", &asm, "-o", &obj]).output().expect("gcc is error");
+            assert!(result.status.success(), "{}", String::from_utf8_lossy(&result.stderr));
+            let _ = fs::remove_file(&asm);
+            obj
+        })
+        .collect();
+    let bin = env::temp_dir().join("rcc_cli_unit");
+    let result = Command::new("gcc")
+        .args(["-no-pie", &objs[0], &objs[1], "-o", &bin.to_string_lossy()])
+        .output()
+        .expect("gcc is error");
+    assert!(result.status.success(), "{}", String::from_utf8_lossy(&result.stderr));
+    let status = Command::new(&bin).status().expect("run is error");
+    assert_eq!(status.code(), Some(35));
+
+    // static関数は、他の翻訳単位から参照できない
+    let result = Command::new("nm").arg(&objs[0]).output().expect("nm is error");
+    let symbols = String::from_utf8_lossy(&result.stdout);
+    assert!(symbols.contains(" T add\n"));
+    assert!(symbols.contains(" D base\n"));
+    assert!(symbols.contains(" t twice\n"));
+
+    objs.iter().for_each(|o| {
+        let _ = fs::remove_file(o);
+    });
+    let _ = fs::remove_file(&lib);
+    let _ = fs::remove_file(&main);
+    let _ = fs::remove_file(&bin);
+}
+
+#[test]
+fn test_char_abi() {
+    // rccでコンパイルした関数(引数は上位ビットが不定のレジスタで受け取る)
+    let callee = create_source(
+        "rcc_cli_char_callee.c",
+        "char inc(char x) { return x + 1; } int twice(char c) { return c * 2; } unsigned char uinc(unsigned char x) { return x + 1; } int stwice(short s) { return s * 2; } short sinc(short x) { return x + 1; }",
+    );
+    // gccでコンパイルする呼び出し側(int型で宣言し、上位ビットに値を残して渡す)
+    let caller = create_source(
+        "rcc_cli_char_caller.c",
+        "int twice(int c); char inc(char x); unsigned char uinc(unsigned char x); int stwice(int s); short sinc(short x);\nchar gcc_inc(char x) { return x + 1; }\nunsigned char gcc_uinc(unsigned char x) { return x + 1; }\nshort gcc_sinc(short x) { return x + 1; }\nint rcc_main();\nint main() { if (twice(0x1200 + 200) != -112) { return 1; } if (inc(127) != -128) { return 2; } if (uinc(255) != 0) { return 3; } if (stwice(0x120000 + 40000) != -51072) { return 6; } if (sinc(32767) != -32768) { return 7; } return rcc_main(); }",
+    );
+    // rccでコンパイルする呼び出し側(gccの関数は、戻り値の上位ビットを拡張しない)
+    let rcc_main = create_source(
+        "rcc_cli_char_main.c",
+        "char gcc_inc(char x); unsigned char gcc_uinc(unsigned char x); short gcc_sinc(short x); int rcc_main() { if (gcc_inc(127) != -128) { return 4; } if (gcc_uinc(255) + 1 != 1) { return 5; } if (gcc_sinc(32767) != -32768) { return 8; } return 0; }",
+    );
+
+    let mut objs: Vec<String> = [&callee, &rcc_main]
+        .iter()
+        .map(|src| {
+            let asm = format!("{}.s", src);
+            let result = Command::new(env!("CARGO_BIN_EXE_rcc")).args([src, "-o", &asm]).output().expect("rcc is error");
+            assert!(result.status.success());
+            asm
+        })
+        .collect();
+    objs.push(caller.clone());
+    let bin = env::temp_dir().join("rcc_cli_char");
+    let result = Command::new("gcc")
+        .arg("-no-pie")
+        .args(&objs)
+        .args(["-o", &bin.to_string_lossy()])
+        .output()
+        .expect("gcc is error");
+    assert!(result.status.success(), "{}", String::from_utf8_lossy(&result.stderr));
+    let status = Command::new(&bin).status().expect("run is error");
+    assert_eq!(status.code(), Some(0));
+
+    objs.iter().for_each(|o| {
+        let _ = fs::remove_file(o);
+    });
+    let _ = fs::remove_file(&rcc_main);
+    let _ = fs::remove_file(&callee);
+    let _ = fs::remove_file(&bin);
+}